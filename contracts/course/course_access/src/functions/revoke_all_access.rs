@@ -74,11 +74,15 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
         .instance()
         .get(&(KEY_COURSE_REG_ADDR,))
         .expect("course_registry_addr not configured; call initialize/set_config");
-    let is_creator: bool = env.invoke_contract(
-        &course_registry_addr,
-        &Symbol::new(&env, "is_course_creator"),
-        (course_id.clone(), caller.clone()).into_val(&env),
-    );
+    // The registry keys courses by numeric ID; non-numeric IDs have no creator there
+    let is_creator: bool = match parse_course_id(&course_id) {
+        Some(registry_id) => env.invoke_contract(
+            &course_registry_addr,
+            &Symbol::new(&env, "is_course_creator"),
+            (registry_id, caller.clone()).into_val(&env),
+        ),
+        None => false,
+    };
 
     // Authorization: only admin or course creator
     if !(is_admin || is_creator) {
@@ -149,3 +153,23 @@ pub fn revoke_all_access(env: Env, caller: Address, course_id: String) -> u32 {
 
     count
 }
+
+/// Parses a decimal course ID into the `u128` key used by the course registry.
+fn parse_course_id(course_id: &String) -> Option<u128> {
+    let len = course_id.len() as usize;
+    if len == 0 || len > 39 {
+        return None;
+    }
+
+    let mut buf = [0u8; 39];
+    course_id.copy_into_slice(&mut buf[..len]);
+
+    let mut value: u128 = 0;
+    for &b in &buf[..len] {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value.checked_mul(10)?.checked_add((b - b'0') as u128)?;
+    }
+    Some(value)
+}
@@ -43,8 +43,124 @@ pub enum Error {
     InvalidInput = 29,
     InvalidPrice100 = 30,
     AlreadyInitialized = 31,
+    CourseVersionNotFound = 32,
+    TitleTooLong = 33,
+    DescriptionTooLong = 34,
+    ThumbnailUrlTooLong = 35,
+    InvalidThumbnailUrl = 36,
+    InvalidSlug = 37,
+    DuplicateSlug = 38,
+    CoursePrivate = 39,
+    CourseSuspended = 40,
+    RateLimitExceeded = 41,
+    LessonNotFound = 42,
+    QuizNotFound = 43,
+    AssignmentNotFound = 44,
+    LiveSessionNotFound = 45,
 }
 
-pub fn handle_error(env: &Env, error: Error) -> ! {
+/// Errors raised by enrollment, module access and student submissions.
+///
+/// Kept apart from `Error`, which is close to the 50-variant limit of a
+/// contract error enum.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EnrollmentError {
+    AlreadyEnrolled = 501,
+    CourseNotOpen = 502,
+    ModuleLocked = 503,
+    SubmissionLimitReached = 504,
+    DeadlinePassed = 505,
+    SessionNotLive = 506,
+    NotEnrolled = 507,
+    AccessRevoked = 508,
+    CourseFull = 509,
+    EnrollmentClosed = 510,
+    CohortNotFound = 511,
+    CohortFull = 512,
+    AlreadyWaitlisted = 513,
+    NotWaitlisted = 514,
+    NoWaitlistOffer = 515,
+    WaitlistOfferExpired = 516,
+    TransfersDisabled = 517,
+    NotAllowlisted = 518,
+    TokenGateNotMet = 519,
+    PrerequisitesNotMet = 520,
+    StudentBanned = 521,
+    ReceiptNotFound = 522,
+    ReceiptNotTransferable = 523,
+    InviteNotFound = 524,
+    InviteUsed = 525,
+    InviteExpired = 526,
+    VouchersDisabled = 527,
+    VoucherExpired = 528,
+    VoucherUsed = 529,
+    AirdropNotFound = 530,
+    NotEligible = 531,
+    AirdropClaimed = 532,
+}
+
+/// Errors raised by the course catalog: categories, tags, discovery and
+/// moderation.
+///
+/// Kept apart from `Error`, which has reached the 50-variant limit of a
+/// contract error enum.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CatalogError {
+    CategoryNotFound = 601,
+    CategoryExists = 602,
+    CategoryInactive = 603,
+    TagLimitReached = 604,
+    TagNotFound = 605,
+    SkillNotFound = 606,
+    SkillExists = 607,
+    FeaturedListFull = 608,
+    NotFeatured = 609,
+    TitleBlocked = 610,
+    TitleReserved = 611,
+}
+
+/// Errors raised by course payments.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PaymentError {
+    UnsupportedToken = 701,
+    NothingToWithdraw = 702,
+    RefundWindowClosed = 703,
+    EscrowActive = 704,
+    PaymentNotFound = 705,
+    AmountBelowMinimum = 706,
+    CouponNotFound = 707,
+    CouponExpired = 708,
+    CouponUsedUp = 709,
+    BundleNotFound = 710,
+    PlanNotFound = 711,
+    PlanInactive = 712,
+    SubscriptionActive = 713,
+    SubscriptionNotFound = 714,
+    InstallmentsNotOffered = 715,
+    NothingDue = 716,
+    OracleUnavailable = 717,
+    PoolNotFound = 718,
+    PoolExhausted = 719,
+    NotEligible = 720,
+    GiftNotFound = 721,
+    GiftExpired = 722,
+    GiftNotExpired = 723,
+    GiftPending = 724,
+    NoSeatsLeft = 725,
+    RefundRequestNotFound = 726,
+    RefundRequestExists = 727,
+    RefundRequestClosed = 728,
+    InsufficientEarnings = 729,
+    NotRefundable = 730,
+    PaymentTokenNotSet = 731,
+}
+
+pub fn handle_error(env: &Env, error: impl Into<soroban_sdk::Error>) -> ! {
     panic_with_error!(env, error);
 }
@@ -3,7 +3,7 @@
 
 use crate::error::{handle_error, Error};
 use crate::schema::Course;
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol, IntoVal};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, IntoVal};
 
 const KEY_USER_MGMT_ADDR: &str = "user_mgmt_addr";
 const KEY_OWNER: &str = "owner";
@@ -30,8 +30,8 @@ pub fn is_admin(env: &Env, who: &Address) -> bool {
 }
 
 /// Check if a user is the creator of a specific course
-pub fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
-    let key = (symbol_short!("course"), course_id.clone());
+pub fn is_course_creator(env: &Env, course_id: u128, who: &Address) -> bool {
+    let key = (symbol_short!("course"), course_id);
     
     match env.storage().persistent().get::<_, Course>(&key) {
         Some(course) => course.creator == *who,
@@ -43,7 +43,7 @@ pub fn is_course_creator(env: &Env, course_id: &String, who: &Address) -> bool {
 /// Authorization is granted if the caller is:
 /// 1. The course creator
 /// 2. An admin
-pub fn require_course_management_auth(env: &Env, caller: &Address, course_id: &String) {
+pub fn require_course_management_auth(env: &Env, caller: &Address, course_id: u128) {
     // Always require basic authentication
     caller.require_auth();
     
@@ -55,6 +55,7 @@ pub fn require_course_management_auth(env: &Env, caller: &Address, course_id: &S
 
 /// Initialize access control settings
 pub fn initialize(env: &Env, owner: &Address, user_mgmt_addr: &Address) {
+    owner.require_auth();
     if env.storage().instance().has(&(KEY_OWNER,)) {
         handle_error(env, Error::AlreadyInitialized)
     }
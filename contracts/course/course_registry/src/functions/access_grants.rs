@@ -0,0 +1,338 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::access_log::append_access_log;
+use super::cohorts::leave_cohort;
+use super::course_capacity::free_seat;
+use super::enrollment::{get_enrollment, is_enrolled, record_student_enrollment};
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{
+    AccessChange, AccessLogEntry, Course, CourseStatus, DataKey, Enrollment, EnrollmentSource,
+    EnrollmentStatus, MAX_GRANT_BATCH,
+};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const GRANT_ACCESS_EVENT: Symbol = symbol_short!("grant");
+const GRANT_BATCH_EVENT: Symbol = symbol_short!("grantMany");
+const REVOKE_ACCESS_EVENT: Symbol = symbol_short!("revoke");
+
+/// Enrolls a student in a course without payment, for beta testers, teaching
+/// assistants and the like.
+///
+/// The enrollment is recorded with `EnrollmentSource::Granted` and shows up
+/// in the student's courses like any other. Access can be granted to a draft
+/// course, so testers can review it before it is published.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - student: the student to give access to.
+///
+/// Returns:
+/// - Enrollment: the new enrollment record.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is archived.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Returns `EnrollmentError::CourseFull` if the course has no seat left.
+///
+/// Granting access again to a student whose access was revoked restores it.
+pub fn grant_access(env: &Env, creator: Address, course_id: u128, student: Address) -> Enrollment {
+    creator.require_auth();
+
+    let course: Course = load_grantable_course(env, &creator, course_id);
+
+    env.events()
+        .publish((GRANT_ACCESS_EVENT, course_id), student.clone());
+
+    grant(env, &course, &creator, &student)
+}
+
+/// Gives a whole class access to a course at once.
+///
+/// The batch is all-or-nothing: if any student cannot be granted access,
+/// for instance because they are already enrolled or listed twice, or the
+/// course runs out of seats, nothing is granted. One summary event lists the
+/// students.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - students: the students to give access to, at most `MAX_GRANT_BATCH`.
+///
+/// Returns:
+/// - Vec<Enrollment>: the new enrollment records, in the order of `students`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the batch is empty or too large.
+/// - Any error `grant_access` can return, for any of the students.
+pub fn grant_access_batch(
+    env: &Env,
+    creator: Address,
+    course_id: u128,
+    students: Vec<Address>,
+) -> Vec<Enrollment> {
+    creator.require_auth();
+    if students.is_empty() || students.len() > MAX_GRANT_BATCH {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let course: Course = load_grantable_course(env, &creator, course_id);
+    let mut enrollments: Vec<Enrollment> = Vec::new(env);
+    for student in students.iter() {
+        enrollments.push_back(grant(env, &course, &creator, &student));
+    }
+
+    env.events()
+        .publish((GRANT_BATCH_EVENT, course_id), (students.len(), students));
+
+    enrollments
+}
+
+/// Takes a student's access to a course away.
+///
+/// The enrollment is kept, marked `EnrollmentStatus::Revoked`, so the
+/// student stays in the course roster but fails every access check, and
+/// cannot enroll again unless the creator grants access. Payments are not
+/// refunded and the student's seat, and cohort place, are freed. The event records who revoked
+/// access and why.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - student: the student losing access.
+/// - reason: why access is revoked.
+///
+/// Returns:
+/// - Enrollment: the revoked enrollment.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `EnrollmentError::NotEnrolled` if the student has no active enrollment.
+pub fn revoke_access(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    student: Address,
+    reason: String,
+) -> Enrollment {
+    require_course_management_auth(env, &caller, course_id);
+
+    revoke_enrollment(env, &caller, course_id, &student, reason)
+}
+
+/// Revokes an active enrollment on behalf of `caller`, who has been
+/// authorized, and logs the change.
+///
+/// Errors:
+/// - Returns `EnrollmentError::NotEnrolled` if the student has no active
+///   enrollment in the course.
+pub fn revoke_enrollment(
+    env: &Env,
+    caller: &Address,
+    course_id: u128,
+    student: &Address,
+    reason: String,
+) -> Enrollment {
+    let mut enrollment: Enrollment = match get_enrollment(env, course_id, student.clone()) {
+        Some(enrollment) if enrollment.status == EnrollmentStatus::Active => enrollment,
+        _ => handle_error(env, EnrollmentError::NotEnrolled),
+    };
+    enrollment.status = EnrollmentStatus::Revoked;
+    free_seat(env, course_id);
+    leave_cohort(env, course_id, student);
+    env.storage().persistent().set(
+        &DataKey::Enrollment(course_id, student.clone()),
+        &enrollment,
+    );
+    append_access_log(
+        env,
+        course_id,
+        AccessLogEntry {
+            student: student.clone(),
+            change: AccessChange::Revoked,
+            actor: Some(caller.clone()),
+            reason: Some(reason.clone()),
+            at: env.ledger().timestamp(),
+        },
+    );
+
+    env.events().publish(
+        (REVOKE_ACCESS_EVENT, course_id),
+        (student.clone(), caller.clone(), reason),
+    );
+
+    enrollment
+}
+
+fn load_grantable_course(env: &Env, creator: &Address, course_id: u128) -> Course {
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    if course.creator != *creator {
+        handle_error(env, Error::Unauthorized)
+    }
+    if course.status == CourseStatus::Archived {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    course
+}
+
+fn grant(env: &Env, course: &Course, creator: &Address, student: &Address) -> Enrollment {
+    if is_enrolled(env, course.id, student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+    let enrollment: Enrollment =
+        record_student_enrollment(env, course, student, 0, EnrollmentSource::Granted);
+    append_access_log(
+        env,
+        course.id,
+        AccessLogEntry {
+            student: student.clone(),
+            change: AccessChange::Granted,
+            actor: Some(creator.clone()),
+            reason: None,
+            at: env.ledger().timestamp(),
+        },
+    );
+    enrollment
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{EditCourseParams, EnrollmentSource, EnrollmentStatus};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address) -> u128 {
+        client
+            .create_course(
+                creator,
+                &String::from_str(&client.env, "Course"),
+                &String::from_str(&client.env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    #[test]
+    fn test_grant_access_to_draft_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let tester = Address::generate(&env);
+        let course_id = create_course(&client, &creator);
+
+        let enrollment = client.grant_access(&creator, &course_id, &tester);
+        assert_eq!(enrollment.source, EnrollmentSource::Granted);
+        assert_eq!(enrollment.amount_paid, 0);
+        assert!(client.is_enrolled(&tester, &course_id));
+    }
+
+    #[test]
+    fn test_grant_access_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course_id = create_course(&client, &creator);
+        let enrolled = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.grant_access(&creator, &course_id, &enrolled);
+
+        // One student already enrolled fails the whole batch
+        assert!(client
+            .try_grant_access_batch(
+                &creator,
+                &course_id,
+                &vec![&env, first.clone(), enrolled.clone()]
+            )
+            .is_err());
+        assert!(!client.is_enrolled(&first, &course_id));
+
+        let enrollments = client.grant_access_batch(
+            &creator,
+            &course_id,
+            &vec![&env, first.clone(), second.clone()],
+        );
+        assert_eq!(enrollments.len(), 2);
+        assert_eq!(enrollments.get(1).unwrap().student, second);
+        assert!(client.is_enrolled(&first, &course_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_grant_access_by_other_user() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course_id = create_course(&client, &Address::generate(&env));
+
+        client.grant_access(
+            &Address::generate(&env),
+            &course_id,
+            &Address::generate(&env),
+        );
+    }
+
+    #[test]
+    fn test_revoke_access() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+        let course_id = create_course(&client, &creator);
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(true),
+            new_duration_hours: None,
+            new_is_free: Some(true),
+        };
+        client.edit_course(&creator, &course_id, &params);
+        client.enroll(&student, &course_id, &None, &None, &None, &None);
+
+        let reason = String::from_str(&env, "Shared account");
+        let enrollment = client.revoke_access(&creator, &course_id, &student, &reason);
+        assert_eq!(enrollment.status, EnrollmentStatus::Revoked);
+        assert!(!client.is_enrolled(&student, &course_id));
+        assert_eq!(
+            client.get_enrollment(&course_id, &student),
+            Some(enrollment)
+        );
+        assert!(client
+            .try_enroll(&student, &course_id, &None, &None, &None, &None)
+            .is_err());
+        assert!(client
+            .try_revoke_access(&creator, &course_id, &student, &reason)
+            .is_err());
+
+        client.grant_access(&creator, &course_id, &student);
+        assert!(client.is_enrolled(&student, &course_id));
+    }
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::enrollment_events::publish_transition;
+use crate::error::{handle_error, Error};
+use crate::schema::{
+    AccessChange, AccessLogEntry, EnrollmentKey, EnrollmentTransition, MAX_PAGE_SIZE,
+};
+use soroban_sdk::{Env, Vec};
+
+/// Appends an entry to the access log of a course.
+///
+/// Grants, revocations, transfers and withdrawals are logged when they
+/// happen. Access that runs out does so without a transaction, so an expiry
+/// is logged, with the time it happened, when the student renews. Entries are
+/// never changed or removed, not even when the course is deleted, so they
+/// stay available for dispute resolution.
+///
+/// Each entry is also published as an enrollment lifecycle event.
+///
+/// Storage used:
+/// - EnrollmentKey::AccessLogCount(course_id) -> u32
+/// - EnrollmentKey::AccessLog(course_id, index) -> AccessLogEntry
+pub fn append_access_log(env: &Env, course_id: u128, entry: AccessLogEntry) {
+    let count_key = EnrollmentKey::AccessLogCount(course_id);
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::AccessLog(course_id, count), &entry);
+    env.storage().persistent().set(&count_key, &(count + 1));
+
+    let transition: EnrollmentTransition = match entry.change {
+        AccessChange::Granted => EnrollmentTransition::Granted,
+        AccessChange::Revoked => EnrollmentTransition::Revoked,
+        AccessChange::Transferred(to) => EnrollmentTransition::Transferred(to),
+        AccessChange::Expired => EnrollmentTransition::Expired,
+        AccessChange::Withdrawn => EnrollmentTransition::Withdrawn,
+    };
+    publish_transition(env, course_id, &entry.student, transition);
+}
+
+/// Returns a page of the access log of a course, oldest first.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - course_id: unique identifier of the course.
+/// - offset: number of entries to skip.
+/// - limit: number of entries to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn get_access_history(
+    env: &Env,
+    course_id: u128,
+    offset: u32,
+    limit: u32,
+) -> Vec<AccessLogEntry> {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let count: u32 = env
+        .storage()
+        .persistent()
+        .get(&EnrollmentKey::AccessLogCount(course_id))
+        .unwrap_or(0);
+    let mut page: Vec<AccessLogEntry> = Vec::new(env);
+    let end: u32 = count.min(offset.saturating_add(limit));
+    for i in offset..end {
+        if let Some(entry) = env
+            .storage()
+            .persistent()
+            .get(&EnrollmentKey::AccessLog(course_id, i))
+        {
+            page.push_back(entry);
+        }
+    }
+    page
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::{AccessChange, AccessTerms};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env, String,
+    };
+
+    #[test]
+    fn test_access_history() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        let terms = AccessTerms {
+            duration: 100,
+            renewal_price: 0,
+        };
+        client.set_access_terms(&creator, &course.id, &Some(terms));
+
+        let tester = Address::generate(&env);
+        let buyer = Address::generate(&env);
+        let friend = Address::generate(&env);
+        client.grant_access(&creator, &course.id, &tester);
+        let reason = String::from_str(&env, "Beta is over");
+        client.revoke_access(&creator, &course.id, &tester, &reason);
+        client.enroll(&buyer, &course.id, &None, &None, &None, &None);
+        client.transfer_enrollment(&course.id, &buyer, &friend, &None);
+        env.ledger().set_timestamp(250);
+        client.renew_access(&friend, &course.id, &None);
+
+        let history = client.get_access_history(&course.id, &0, &10);
+        assert_eq!(history.len(), 4);
+        let revoked = history.get(1).unwrap();
+        assert_eq!(revoked.change, AccessChange::Revoked);
+        assert_eq!(revoked.actor, Some(creator.clone()));
+        assert_eq!(revoked.reason, Some(reason));
+        assert_eq!(
+            history.get(2).unwrap().change,
+            AccessChange::Transferred(friend.clone())
+        );
+        let expired = history.get(3).unwrap();
+        assert_eq!(expired.student, friend);
+        assert_eq!(expired.change, AccessChange::Expired);
+        assert_eq!(expired.actor, None);
+        assert_eq!(expired.at, 100);
+
+        let page = client.get_access_history(&course.id, &3, &10);
+        assert_eq!(page.len(), 1);
+        assert!(client.try_get_access_history(&course.id, &0, &0).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::access_log::append_access_log;
+use super::enrollment::get_enrollment;
+use super::enrollment_events::publish_transition;
+use super::payments::{
+    bps_of, get_payment_token, get_platform_fee, settle_payment, to_token_amount,
+};
+use crate::error::{handle_error, EnrollmentError, Error, PaymentError};
+use crate::schema::{
+    AccessChange, AccessLogEntry, AccessTerms, Course, DataKey, Enrollment, EnrollmentKey,
+    EnrollmentStatus, EnrollmentTransition, Payment, PaymentStatus,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_ACCESS_TERMS_EVENT: Symbol = symbol_short!("setTerms");
+const RENEW_EVENT: Symbol = symbol_short!("renewAcc");
+
+/// Sells a course for a limited time of access, or for good again.
+///
+/// The terms apply to enrollments made from then on; existing enrollments
+/// keep their expiry.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - terms: access duration and renewal price, or `None` for lifetime access.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the duration is 0.
+///
+/// Storage used:
+/// - EnrollmentKey::AccessTerms(course_id) -> AccessTerms
+pub fn set_access_terms(env: &Env, caller: Address, course_id: u128, terms: Option<AccessTerms>) {
+    load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::AccessTerms(course_id);
+    match &terms {
+        Some(terms) => {
+            if terms.duration == 0 {
+                handle_error(env, Error::InvalidInput)
+            }
+            env.storage().persistent().set(&key, terms);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_ACCESS_TERMS_EVENT, course_id), terms);
+}
+
+/// Returns the access terms of a course; `None` for lifetime access.
+pub fn get_access_terms(env: &Env, course_id: u128) -> Option<AccessTerms> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::AccessTerms(course_id))
+}
+
+/// Extends a student's access to a course by the access duration, charging
+/// the renewal price.
+///
+/// Renewing before the access expires extends it from the current expiry, so
+/// no time is lost. The renewal is paid in the payment token and released to
+/// the creator at once; renewals are not refundable.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolled student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - token: the payment token, when the renewal has a price.
+///
+/// Returns:
+/// - Enrollment: the enrollment with its new expiry.
+///
+/// Errors:
+/// - Returns `EnrollmentError::NotEnrolled` if the student has no active enrollment.
+/// - Returns `Error::InvalidInput` if the course or the enrollment is not
+///   time-limited.
+/// - Returns `PaymentError::PaymentTokenNotSet` if the renewal has a price
+///   and no payment token is set.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is not the payment token.
+pub fn renew_access(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    token: Option<Address>,
+) -> Enrollment {
+    student.require_auth();
+
+    let mut enrollment: Enrollment = match get_enrollment(env, course_id, student.clone()) {
+        Some(enrollment) if enrollment.status == EnrollmentStatus::Active => enrollment,
+        _ => handle_error(env, EnrollmentError::NotEnrolled),
+    };
+    let (terms, expires_at): (AccessTerms, u64) =
+        match (get_access_terms(env, course_id), enrollment.expires_at) {
+            (Some(terms), Some(expires_at)) => (terms, expires_at),
+            _ => handle_error(env, Error::InvalidInput),
+        };
+    let course: Course = load_course(env, course_id);
+
+    if terms.renewal_price > 0 {
+        let accepted: Address = match get_payment_token(env) {
+            Some(accepted) if token.as_ref() == Some(&accepted) => accepted,
+            Some(_) => handle_error(env, PaymentError::UnsupportedToken),
+            None => handle_error(env, PaymentError::PaymentTokenNotSet),
+        };
+        charge_renewal(env, &course, &student, &accepted, terms.renewal_price);
+        enrollment.amount_paid += terms.renewal_price;
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    if expires_at <= now {
+        append_access_log(
+            env,
+            course_id,
+            AccessLogEntry {
+                student: student.clone(),
+                change: AccessChange::Expired,
+                actor: None,
+                reason: None,
+                at: expires_at,
+            },
+        );
+    }
+    enrollment.expires_at = Some(expires_at.max(now) + terms.duration);
+    env.storage().persistent().set(
+        &DataKey::Enrollment(course_id, student.clone()),
+        &enrollment,
+    );
+
+    env.events()
+        .publish((RENEW_EVENT, course_id), (student, enrollment.expires_at));
+
+    enrollment
+}
+
+fn charge_renewal(env: &Env, course: &Course, student: &Address, token: &Address, amount: u128) {
+    token::Client::new(env, token).transfer(
+        student,
+        &env.current_contract_address(),
+        &to_token_amount(env, amount),
+    );
+    let platform_fee: u128 = match get_platform_fee(env) {
+        Some(fee) => bps_of(amount, fee.fee_bps),
+        None => 0,
+    };
+    let now: u64 = env.ledger().timestamp();
+    let mut payment = Payment {
+        course_id: course.id,
+        payer: student.clone(),
+        creator: course.creator.clone(),
+        token: token.clone(),
+        amount,
+        platform_fee,
+        creator_amount: amount - platform_fee,
+        referrer: None,
+        referral_commission: 0,
+        paid_at: now,
+        status: PaymentStatus::Escrowed,
+        escrow_until: now,
+    };
+    settle_payment(env, &mut payment);
+    publish_transition(
+        env,
+        course.id,
+        student,
+        EnrollmentTransition::Paid(token.clone(), amount),
+    );
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::AccessTerms;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    #[test]
+    fn test_access_expires_and_renews() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let terms = AccessTerms {
+            duration: 365 * DAY,
+            renewal_price: 300,
+        };
+        client.set_access_terms(&creator, &course.id, &Some(terms));
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1300);
+        let enrollment = client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(enrollment.expires_at, Some(365 * DAY));
+        assert!(client.has_course_access(&course.id, &student));
+
+        env.ledger().set_timestamp(365 * DAY);
+        assert!(!client.has_course_access(&course.id, &student));
+
+        env.ledger().set_timestamp(400 * DAY);
+        let renewed = client.renew_access(&student, &course.id, &Some(token.clone()));
+        assert_eq!(renewed.expires_at, Some(765 * DAY));
+        assert_eq!(renewed.amount_paid, 1300);
+        assert!(client.has_course_access(&course.id, &student));
+        assert_eq!(client.get_earnings(&creator, &token), 1300);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_renew_lifetime_access() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.grant_access(&creator, &course.id, &student);
+
+        client.renew_access(&student, &course.id, &None);
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::course_index::index_goal;
 use crate::error::{handle_error, Error};
 use crate::functions::utils::{self, trim};
 use crate::schema::{Course, CourseGoal, DataKey};
@@ -8,11 +9,11 @@ use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 const GOAL_ADDED_EVENT: Symbol = symbol_short!("goaladd");
 
-pub fn add_goal(env: Env, creator: Address, course_id: String, content: String) -> CourseGoal {
+pub fn add_goal(env: Env, creator: Address, course_id: u128, content: String) -> CourseGoal {
     creator.require_auth();
     
     // Validate input parameters
-    if course_id.is_empty() {
+    if course_id == 0 {
         handle_error(&env, Error::EmptyCourseId);
     }
     
@@ -22,16 +23,12 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
     }
     
     // Check string lengths to prevent extremely long values
-    if course_id.len() > 100 {
-        handle_error(&env, Error::InvalidInput);
-    }
-    
     if content.len() > 1000 {
         handle_error(&env, Error::InvalidInput);
     }
 
     // Load course
-    let storage_key = (symbol_short!("course"), course_id.clone());
+    let storage_key = (symbol_short!("course"), course_id);
     let course: Course = env
         .storage()
         .persistent()
@@ -48,7 +45,7 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
 
     // Create new goal
     let goal = CourseGoal {
-        course_id: course_id.clone(),
+        course_id,
         goal_id: goal_id.clone(),
         content: content.clone(),
         created_by: creator.clone(),
@@ -57,13 +54,14 @@ pub fn add_goal(env: Env, creator: Address, course_id: String, content: String)
 
     // Save the new goal directly
     env.storage().persistent().set(
-        &DataKey::CourseGoal(course_id.clone(), goal_id.clone()),
+        &DataKey::CourseGoal(course_id, goal_id.clone()),
         &goal,
     );
+    index_goal(&env, course_id, &goal_id);
 
     // Emit event
     env.events().publish(
-        (GOAL_ADDED_EVENT, course_id.clone(), goal_id.clone()),
+        (GOAL_ADDED_EVENT, course_id, goal_id.clone()),
         content.clone(),
     );
 
@@ -93,7 +91,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -125,7 +123,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -144,7 +142,7 @@ mod test {
         let client = CourseRegistryClient::new(&env, &contract_id);
 
         let creator: Address = Address::generate(&env);
-        let fake_course_id = String::from_str(&env, "nonexistent_course");
+        let fake_course_id = 999_u128;
 
         let goal_content = String::from_str(&env, "Learn the basics of Rust");
         client.add_goal(&creator, &fake_course_id, &goal_content);
@@ -168,7 +166,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -194,7 +192,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -1,10 +1,12 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::{concat_strings, u32_to_string};
+use super::course_index::index_module;
+use super::course_totals::recompute_course_totals;
+use super::utils::{concat_strings, u128_to_string, u32_to_string};
 use crate::error::{handle_error, Error};
-pub use crate::schema::{Course, CourseModule};
-use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol};
+pub use crate::schema::{Course, CourseModule, ModuleInput, ModuleRelease, MAX_BATCH_MODULES};
+use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
@@ -12,80 +14,155 @@ const MODULE_KEY: Symbol = symbol_short!("module");
 pub fn course_registry_add_module(
     env: Env,
     caller: Address,
-    course_id: String,
+    course_id: u128,
     position: u32,
     title: String,
 ) -> CourseModule {
     // Validate input parameters
-    if course_id.is_empty() {
+    if course_id == 0 {
         handle_error(&env, Error::EmptyCourseId);
     }
-    
-    if title.is_empty() {
-        handle_error(&env, Error::InvalidModuleTitle);
-    }
-    
-    // Check string lengths to prevent extremely long values
-    if course_id.len() > 100 {
-        handle_error(&env, Error::EmptyCourseId);
-    }
-    
-    if title.len() > 500 {
-        handle_error(&env, Error::InvalidModuleTitle);
-    }
-    
-    // Validate position is reasonable (not extremely large)
-    if position > 10000 {
-        handle_error(&env, Error::InvalidModulePosition);
-    }
 
-    let course_storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    validate_module_input(&env, position, &title);
+
+    let course_storage_key: (Symbol, u128) = (COURSE_KEY, course_id);
 
     if !env.storage().persistent().has(&course_storage_key) {
         handle_error(&env, Error::CourseIdNotExist)
     }
 
     // Verify caller has proper authorization
-    super::access_control::require_course_management_auth(&env, &caller, &course_id);
+    super::access_control::require_course_management_auth(&env, &caller, course_id);
 
     // Check for duplicate position
-    let position_key: (Symbol, String, u32) = (symbol_short!("pos"), course_id.clone(), position);
+    let position_key: (Symbol, u128, u32) = (symbol_short!("pos"), course_id, position);
     if env.storage().persistent().has(&position_key) {
         handle_error(&env, Error::DuplicateModulePosition)
     }
 
-    let ledger_seq: u32 = env.ledger().sequence();
+    let module = insert_module(&env, course_id, position, title);
+    recompute_course_totals(&env, course_id);
 
-    let arr = vec![
-        &env,
-        String::from_str(&env, "module_"),
-        course_id.clone(),
-        String::from_str(&env, "_"),
-        u32_to_string(&env, position),
-        String::from_str(&env, "_"),
-        u32_to_string(&env, ledger_seq),
-    ];
+    module
+}
+
+/// Adds several modules to a course in one call.
+///
+/// Every entry is validated before anything is stored, so either all modules
+/// are added or none are.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - inputs: between 1 and `MAX_BATCH_MODULES` module definitions.
+///
+/// Returns:
+/// - Vec<CourseModule>: the created modules, in input order.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the batch is empty or too large.
+/// - Returns `Error::DuplicateModulePosition` if a position is repeated or
+///   already used by the course.
+/// - Any error `add_module` can return for an individual entry.
+pub fn course_registry_add_modules(
+    env: Env,
+    caller: Address,
+    course_id: u128,
+    inputs: Vec<ModuleInput>,
+) -> Vec<CourseModule> {
+    if course_id == 0 {
+        handle_error(&env, Error::EmptyCourseId);
+    }
+    if inputs.is_empty() || inputs.len() > MAX_BATCH_MODULES {
+        handle_error(&env, Error::InvalidInput)
+    }
+
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id)) {
+        handle_error(&env, Error::CourseIdNotExist)
+    }
+    super::access_control::require_course_management_auth(&env, &caller, course_id);
+
+    let mut positions: Vec<u32> = Vec::new(&env);
+    for input in inputs.iter() {
+        validate_module_input(&env, input.position, &input.title);
+
+        let position_key: (Symbol, u128, u32) = (symbol_short!("pos"), course_id, input.position);
+        if positions.contains(input.position) || env.storage().persistent().has(&position_key) {
+            handle_error(&env, Error::DuplicateModulePosition)
+        }
+        positions.push_back(input.position);
+    }
+
+    let mut modules: Vec<CourseModule> = Vec::new(&env);
+    for input in inputs.iter() {
+        modules.push_back(insert_module(&env, course_id, input.position, input.title));
+    }
+    recompute_course_totals(&env, course_id);
+
+    modules
+}
 
-    let module_id = concat_strings(&env, arr);
+fn validate_module_input(env: &Env, position: u32, title: &String) {
+    if title.is_empty() {
+        handle_error(env, Error::InvalidModuleTitle);
+    }
+
+    // Check string lengths to prevent extremely long values
+    if title.len() > 500 {
+        handle_error(env, Error::InvalidModuleTitle);
+    }
+
+    // Validate position is reasonable (not extremely large)
+    if position > 10000 {
+        handle_error(env, Error::InvalidModulePosition);
+    }
+}
+
+/// Stores a new module at a free position and indexes it under its course.
+fn insert_module(env: &Env, course_id: u128, position: u32, title: String) -> CourseModule {
+    let module_id = build_module_id(env, course_id, position);
 
     // Create new module
     let module: CourseModule = CourseModule {
         id: module_id.clone(),
-        course_id: course_id.clone(),
+        course_id,
         position,
         title,
         created_at: env.ledger().timestamp(),
+        lesson_count: 0,
+        duration_minutes: 0,
+        release: ModuleRelease::Immediate,
+        is_preview: false,
+        version: 1,
+        content_hash: None,
     };
 
     let storage_key: (Symbol, String) = (MODULE_KEY, module_id.clone());
-    let position_key: (Symbol, String, u32) = (symbol_short!("pos"), course_id.clone(), position);
+    let position_key: (Symbol, u128, u32) = (symbol_short!("pos"), course_id, position);
 
     env.storage().persistent().set(&storage_key, &module);
     env.storage().persistent().set(&position_key, &true);
+    index_module(env, course_id, &module_id);
 
     module
 }
 
+/// Builds the ID of a module from its course, position and the current ledger sequence.
+pub fn build_module_id(env: &Env, course_id: u128, position: u32) -> String {
+    let arr = vec![
+        env,
+        String::from_str(env, "module_"),
+        u128_to_string(env, course_id),
+        String::from_str(env, "_"),
+        u32_to_string(env, position),
+        String::from_str(env, "_"),
+        u32_to_string(env, env.ledger().sequence()),
+    ];
+
+    concat_strings(env, arr)
+}
+
 #[cfg(test)]
 mod test {
     extern crate std;
@@ -174,6 +251,42 @@ mod test {
         assert_eq!(module.title, String::from_str(&env, "Module 1"));
     }
 
+    #[test]
+    fn test_add_modules_batch() {
+        let (env, _, _, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator);
+
+        let inputs = vec![
+            &env,
+            ModuleInput { position: 0, title: String::from_str(&env, "Setup") },
+            ModuleInput { position: 1, title: String::from_str(&env, "Basics") },
+            ModuleInput { position: 2, title: String::from_str(&env, "Advanced") },
+        ];
+        let modules = client.add_modules(&creator, &course.id, &inputs);
+
+        assert_eq!(modules.len(), 3);
+        assert_eq!(modules.get(2).unwrap().position, 2);
+        assert_eq!(client.get_modules(&course.id).len(), 3);
+    }
+
+    #[test]
+    fn test_add_modules_is_atomic() {
+        let (env, _, _, client) = setup_test_env();
+        let creator = Address::generate(&env);
+        let course = create_course(&client, &creator);
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Existing"));
+
+        // Position 1 is taken, so nothing from the batch is stored
+        let inputs = vec![
+            &env,
+            ModuleInput { position: 0, title: String::from_str(&env, "Setup") },
+            ModuleInput { position: 1, title: String::from_str(&env, "Clash") },
+        ];
+        assert!(client.try_add_modules(&creator, &course.id, &inputs).is_err());
+        assert_eq!(client.get_modules(&course.id).len(), 1);
+    }
+
     #[test]
     #[should_panic(expected = "Error(Contract, #6)")] // Unauthorized error
     fn test_add_module_unauthorized() {
@@ -200,7 +313,7 @@ mod test {
         let unauthorized_user = Address::generate(&env);
         client.add_module(
             &unauthorized_user,
-            &String::from_str(&env, "invalid_course"),
+            &999_u128,
             &1,
             &String::from_str(&env, "Module 1"),
         );
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::payments::bps_of;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, PaymentKey, MAX_FEE_BPS};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_AFFILIATE_EVENT: Symbol = symbol_short!("setAffil");
+const REFERRAL_EVENT: Symbol = symbol_short!("referral");
+
+/// Registers an affiliate of a course with a commission, or removes them.
+///
+/// Students enrolling with the affiliate as referrer pay the affiliate the
+/// commission out of the price, next to the platform fee; the instructor gets
+/// the rest.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - affiliate: the referring address.
+/// - commission_bps: commission in basis points, up to `MAX_FEE_BPS`, or
+///   `None` to remove the affiliate.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the commission exceeds `MAX_FEE_BPS`.
+///
+/// Storage used:
+/// - PaymentKey::Affiliate(course_id, affiliate) -> u32
+pub fn set_affiliate(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    affiliate: Address,
+    commission_bps: Option<u32>,
+) {
+    let _course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::Affiliate(course_id, affiliate.clone());
+    match commission_bps {
+        Some(bps) if bps > MAX_FEE_BPS => handle_error(env, Error::InvalidInput),
+        Some(bps) => env.storage().persistent().set(&key, &bps),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events().publish(
+        (SET_AFFILIATE_EVENT, course_id),
+        (affiliate, commission_bps),
+    );
+}
+
+/// Returns the commission of an affiliate of a course, if they are one.
+pub fn get_affiliate_commission(env: &Env, course_id: u128, affiliate: Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Affiliate(course_id, affiliate))
+}
+
+/// Returns the commission a referrer earns on a payment.
+///
+/// The commission is a share of the amount paid, capped at what is left
+/// after the platform fee. Nothing
+/// is owed to an address that is not an affiliate of the course, or that
+/// refers itself.
+pub fn referral_commission(
+    env: &Env,
+    course_id: u128,
+    referrer: &Address,
+    student: &Address,
+    amount: u128,
+    platform_fee: u128,
+) -> u128 {
+    if referrer == student {
+        return 0;
+    }
+    match get_affiliate_commission(env, course_id, referrer.clone()) {
+        Some(bps) => bps_of(amount, bps).min(amount - platform_fee),
+        None => 0,
+    }
+}
+
+/// Credits a referral commission to the affiliate's withdrawable earnings.
+///
+/// Storage used:
+/// - PaymentKey::Earnings(affiliate, token) -> u128
+/// - PaymentKey::AffiliateEarnings(affiliate, token) -> u128
+pub fn credit_affiliate(
+    env: &Env,
+    affiliate: &Address,
+    course_id: u128,
+    token: &Address,
+    amount: u128,
+) {
+    if amount == 0 {
+        return;
+    }
+    let key = PaymentKey::Earnings(affiliate.clone(), token.clone());
+    let balance: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(balance + amount));
+
+    let total_key = PaymentKey::AffiliateEarnings(affiliate.clone(), token.clone());
+    let total: u128 = env.storage().persistent().get(&total_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&total_key, &(total + amount));
+
+    env.events()
+        .publish((REFERRAL_EVENT, course_id), (affiliate.clone(), amount));
+}
+
+/// Returns the commissions ever credited to an affiliate in a token.
+///
+/// Commissions are withdrawn with `withdraw_earnings`, like instructor earnings.
+pub fn get_affiliate_earnings(env: &Env, affiliate: Address, token: Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::AffiliateEarnings(affiliate, token))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+    #[test]
+    fn test_referral_splits_payment_three_ways() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let treasury = Address::generate(&env);
+        client.set_payment_token(&admin, &token);
+        client.set_platform_fee(&admin, &1_000, &treasury);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let affiliate = Address::generate(&env);
+        client.set_affiliate(&creator, &course.id, &affiliate, &Some(2_000));
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &Some(affiliate.clone()),
+        );
+
+        let payment = client.get_payment(&course.id, &student).unwrap();
+        assert_eq!(payment.platform_fee, 100);
+        assert_eq!(payment.referral_commission, 200);
+        assert_eq!(payment.creator_amount, 700);
+        assert_eq!(client.get_earnings(&creator, &token), 700);
+        assert_eq!(client.get_affiliate_earnings(&affiliate, &token), 200);
+
+        assert_eq!(client.withdraw_earnings(&affiliate, &token), 200);
+        assert_eq!(token::Client::new(&env, &token).balance(&affiliate), 200);
+    }
+}
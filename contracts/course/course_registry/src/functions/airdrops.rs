@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{
+    Course, CourseStatus, Enrollment, EnrollmentKey, EnrollmentSource, MAX_AIRDROP_PROOF,
+};
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_AIRDROP_EVENT: Symbol = symbol_short!("airdrop");
+const CLAIM_AIRDROP_EVENT: Symbol = symbol_short!("claimDrop");
+
+/// Commits the Merkle root of the addresses eligible for a course's airdrop,
+/// or ends the airdrop.
+///
+/// Each leaf is the SHA-256 hash of an address's XDR, and each node the
+/// SHA-256 hash of its two children concatenated smallest first, so proofs
+/// need no position bits. Replacing the root changes who may still claim;
+/// addresses that already claimed keep their enrollment.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - root: the Merkle root, or `None` to end the airdrop.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::AirdropRoot(course_id) -> BytesN<32>
+pub fn set_airdrop_root(env: &Env, caller: Address, course_id: u128, root: Option<BytesN<32>>) {
+    load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::AirdropRoot(course_id);
+    match &root {
+        Some(root) => env.storage().persistent().set(&key, root),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events().publish((SET_AIRDROP_EVENT, course_id), root);
+}
+
+/// Returns the Merkle root of a course's airdrop, if one is running.
+pub fn get_airdrop_root(env: &Env, course_id: u128) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::AirdropRoot(course_id))
+}
+
+/// Enrolls a student for free with a Merkle proof that they are eligible
+/// for the course's airdrop.
+///
+/// Each address can claim once, even after unenrolling.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the eligible address (must authorize).
+/// - course_id: unique identifier of the course.
+/// - proof: sibling hashes from the student's leaf up to the root.
+///
+/// Errors:
+/// - Returns `EnrollmentError::AirdropNotFound` if the course has no airdrop.
+/// - Returns `EnrollmentError::AirdropClaimed` if the student already claimed.
+/// - Returns `Error::InvalidInput` if the proof has more than
+///   `MAX_AIRDROP_PROOF` hashes.
+/// - Returns `EnrollmentError::NotEligible` if the proof does not lead to the root.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+///
+/// Storage used:
+/// - EnrollmentKey::AirdropClaimed(course_id, student) -> bool
+pub fn claim_airdrop_access(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    proof: Vec<BytesN<32>>,
+) -> Enrollment {
+    student.require_auth();
+
+    let root: BytesN<32> = match get_airdrop_root(env, course_id) {
+        Some(root) => root,
+        None => handle_error(env, EnrollmentError::AirdropNotFound),
+    };
+    let claimed_key = EnrollmentKey::AirdropClaimed(course_id, student.clone());
+    if env.storage().persistent().has(&claimed_key) {
+        handle_error(env, EnrollmentError::AirdropClaimed)
+    }
+    if proof.len() > MAX_AIRDROP_PROOF {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut node: BytesN<32> = env.crypto().sha256(&student.clone().to_xdr(env)).to_bytes();
+    for sibling in proof.iter() {
+        let (left, right) = if node <= sibling {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut pair: Bytes = Bytes::from_array(env, &left.to_array());
+        pair.extend_from_array(&right.to_array());
+        node = env.crypto().sha256(&pair).to_bytes();
+    }
+    if node != root {
+        handle_error(env, EnrollmentError::NotEligible)
+    }
+
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    env.storage().persistent().set(&claimed_key, &true);
+
+    env.events()
+        .publish((CLAIM_AIRDROP_EVENT, course_id), student.clone());
+
+    record_student_enrollment(env, &course, &student, 0, EnrollmentSource::Airdrop)
+}
+
+/// Returns whether a student already claimed a course's airdrop.
+pub fn has_claimed_airdrop(env: &Env, course_id: u128, student: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&EnrollmentKey::AirdropClaimed(course_id, student))
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_params;
+    use crate::schema::EnrollmentSource;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, String,
+    };
+
+    fn leaf(env: &Env, student: &Address) -> BytesN<32> {
+        env.crypto().sha256(&student.clone().to_xdr(env)).to_bytes()
+    }
+
+    fn parent(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        let mut pair = Bytes::from_array(env, &left.to_array());
+        pair.extend_from_array(&right.to_array());
+        env.crypto().sha256(&pair).to_bytes()
+    }
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_claim_airdrop_with_proof() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let students = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let leaves = [
+            leaf(&env, &students[0]),
+            leaf(&env, &students[1]),
+            leaf(&env, &students[2]),
+        ];
+        let left = parent(&env, &leaves[0], &leaves[1]);
+        let root = parent(&env, &left, &leaves[2]);
+        client.set_airdrop_root(&creator, &course_id, &Some(root.clone()));
+        assert_eq!(client.get_airdrop_root(&course_id), Some(root));
+
+        let proof = vec![&env, leaves[0].clone(), leaves[2].clone()];
+        let enrollment = client.claim_airdrop_access(&students[1], &course_id, &proof);
+        assert_eq!(enrollment.amount_paid, 0);
+        assert_eq!(enrollment.source, EnrollmentSource::Airdrop);
+        assert!(client.has_claimed_airdrop(&course_id, &students[1]));
+        assert!(client
+            .try_claim_airdrop_access(&students[1], &course_id, &proof)
+            .is_err());
+
+        let proof = vec![&env, left];
+        client.claim_airdrop_access(&students[2], &course_id, &proof);
+        assert!(!client.has_claimed_airdrop(&course_id, &students[0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #531)")]
+    fn test_claim_airdrop_not_eligible() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let eligible = leaf(&env, &Address::generate(&env));
+        let other = leaf(&env, &Address::generate(&env));
+        let root = parent(&env, &eligible, &other);
+        client.set_airdrop_root(&creator, &course_id, &Some(root));
+
+        client.claim_airdrop_access(&Address::generate(&env), &course_id, &vec![&env, other]);
+    }
+}
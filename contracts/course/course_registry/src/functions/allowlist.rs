@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{EnrollmentKey, MAX_ALLOWLIST_BATCH};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const SET_ALLOWLIST_MODE_EVENT: Symbol = symbol_short!("allowMode");
+const ALLOW_EVENT: Symbol = symbol_short!("allow");
+const DISALLOW_EVENT: Symbol = symbol_short!("disallow");
+
+/// Restricts enrollment in a course to allowlisted students, or opens it to
+/// everyone again.
+///
+/// Useful for corporate trainings and private cohorts. The allowlist is kept
+/// when the mode is turned off. The creator can still grant access to anyone.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::AllowlistOnly(course_id) -> bool
+pub fn set_allowlist_mode(env: &Env, caller: Address, course_id: u128, enabled: bool) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::AllowlistOnly(course_id);
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((SET_ALLOWLIST_MODE_EVENT, course_id), enabled);
+}
+
+/// Adds students to the allowlist of a course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - students: the students to allow, at most `MAX_ALLOWLIST_BATCH`.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the batch is empty or too large.
+///
+/// Storage used:
+/// - EnrollmentKey::Allowlisted(course_id, student) -> bool
+pub fn add_to_allowlist(env: &Env, caller: Address, course_id: u128, students: Vec<Address>) {
+    require_course_management_auth(env, &caller, course_id);
+    validate_batch(env, &students);
+
+    for student in students.iter() {
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::Allowlisted(course_id, student), &true);
+    }
+
+    env.events().publish((ALLOW_EVENT, course_id), students);
+}
+
+/// Removes students from the allowlist of a course.
+///
+/// Students already enrolled keep their access.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the batch is empty or too large.
+pub fn remove_from_allowlist(env: &Env, caller: Address, course_id: u128, students: Vec<Address>) {
+    require_course_management_auth(env, &caller, course_id);
+    validate_batch(env, &students);
+
+    for student in students.iter() {
+        env.storage()
+            .persistent()
+            .remove(&EnrollmentKey::Allowlisted(course_id, student));
+    }
+
+    env.events().publish((DISALLOW_EVENT, course_id), students);
+}
+
+/// Whether a student may enroll in a course under its allowlist mode.
+pub fn is_allowlisted(env: &Env, course_id: u128, student: Address) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&EnrollmentKey::AllowlistOnly(course_id))
+        || env
+            .storage()
+            .persistent()
+            .has(&EnrollmentKey::Allowlisted(course_id, student))
+}
+
+/// Fails with `EnrollmentError::NotAllowlisted` if a course only takes
+/// allowlisted students and the student is not one of them.
+pub fn require_allowlisted(env: &Env, course_id: u128, student: &Address) {
+    if !is_allowlisted(env, course_id, student.clone()) {
+        handle_error(env, EnrollmentError::NotAllowlisted)
+    }
+}
+
+fn validate_batch(env: &Env, students: &Vec<Address>) {
+    if students.is_empty() || students.len() > MAX_ALLOWLIST_BATCH {
+        handle_error(env, Error::InvalidInput)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        client.set_allowlist_mode(&creator, &course.id, &true);
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_allowlisted_students_enroll() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let employee = Address::generate(&env);
+        let contractor = Address::generate(&env);
+        client.add_to_allowlist(
+            &creator,
+            &course_id,
+            &vec![&env, employee.clone(), contractor.clone()],
+        );
+        client.remove_from_allowlist(&creator, &course_id, &vec![&env, contractor.clone()]);
+        assert!(client.is_allowlisted(&course_id, &employee));
+        assert!(!client.is_allowlisted(&course_id, &contractor));
+
+        client.enroll(&employee, &course_id, &None, &None, &None, &None);
+        assert!(client
+            .try_enroll(&contractor, &course_id, &None, &None, &None, &None)
+            .is_err());
+
+        client.set_allowlist_mode(&creator, &course_id, &false);
+        client.enroll(&contractor, &course_id, &None, &None, &None, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #518)")]
+    fn test_enroll_without_allowlist_entry() {
+        let env = Env::default();
+        let (client, _creator, course_id) = setup(&env);
+
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+}
@@ -1,17 +1,19 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::title_index::release_title;
+use super::unpublish_refunds::record_unpublished;
 use crate::error::{handle_error, Error};
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
-use crate::schema::Course;
+use crate::schema::{Course, CourseStatus};
 
 const ARCHIVED_COURSE_EVENT: Symbol = symbol_short!("akhivecus");
 
-pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course {
+pub fn archive_course(env: &Env, creator: Address, course_id: u128) -> Course {
     creator.require_auth();
 
-    let key = (symbol_short!("course"), course_id.clone());
+    let key = (symbol_short!("course"), course_id);
     let mut course: Course = env
         .storage()
         .persistent()
@@ -22,14 +24,20 @@ pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course
         handle_error(&env, Error::OnlyCreatorCanArchive)
     }
 
-    if course.is_archived {
+    if course.status == CourseStatus::Archived {
         handle_error(&env, Error::CourseAlreadyArchived)
     }
-    course.is_archived = true;
+    if course.status == CourseStatus::Published {
+        record_unpublished(env, course_id);
+    }
+    course.status = CourseStatus::Archived;
+    course.updated_at = env.ledger().timestamp();
 
     env.storage().persistent().set(&key, &course);
+    // Archived courses no longer hold their title, so it can be reused
+    release_title(env, &course);
     env.events()
-        .publish((ARCHIVED_COURSE_EVENT, course_id.clone()), course.clone());
+        .publish((ARCHIVED_COURSE_EVENT, course_id), course.clone());
 
     course
 }
@@ -38,7 +46,7 @@ pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course
 mod tests {
     use super::*;
     use crate::{CourseRegistry, CourseRegistryClient};
-    use soroban_sdk::{testutils::Address as _, testutils::Events, Address, Env};
+    use soroban_sdk::{testutils::Address as _, testutils::Events, Address, Env, String};
 
     #[test]
     fn test_archive_course_success() {
@@ -57,19 +65,43 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
 
-        assert_eq!(new_course, client.get_course(&new_course.id.clone()));
+        assert_eq!(new_course, client.get_course(&new_course.id));
         assert_eq!(
             new_course.creator,
-            client.get_course(&new_course.id.clone()).creator
+            client.get_course(&new_course.id).creator
         );
 
-        let archived_new_course = client.archive_course(&creator, &new_course.id.clone());
-        assert!(archived_new_course.is_archived);
+        let archived_new_course = client.archive_course(&creator, &new_course.id);
+        assert_eq!(archived_new_course.status, CourseStatus::Archived);
+    }
+
+    #[test]
+    fn test_archive_course_frees_title() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let title = String::from_str(&env, "title");
+        let description = String::from_str(&env, "description");
+
+        let course: Course = client.create_course(
+            &creator, &title, &description, &1000_u128, &None, &None, &None, &None, &None,
+        );
+        client.archive_course(&creator, &course.id);
+
+        let replacement: Course = client.create_course(
+            &creator, &title, &description, &1000_u128, &None, &None, &None, &None, &None,
+        );
+        assert_eq!(replacement.title, title);
+        assert_ne!(replacement.id, course.id);
     }
 
     #[test]
@@ -82,7 +114,7 @@ mod tests {
         let client = CourseRegistryClient::new(&env, &contract_id);
 
         let creator: Address = Address::generate(&env);
-        let fake_course_id = String::from_str(&env, "nonexistent_course");
+        let fake_course_id = 999_u128;
 
         client.archive_course(&creator, &fake_course_id);
     }
@@ -106,7 +138,7 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -132,13 +164,13 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
 
         let archived_course = client.archive_course(&creator, &new_course.id);
-        assert!(archived_course.is_archived);
+        assert_eq!(archived_course.status, CourseStatus::Archived);
 
         client.archive_course(&creator, &new_course.id);
     }
@@ -160,7 +192,7 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::module_access::check_module_access;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{
+    Assignment, AssignmentSubmission, CourseModule, DataKey, LatePolicy, ModuleAccess,
+};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_ASSIGNMENT_EVENT: Symbol = symbol_short!("setAssign");
+const SUBMIT_EVENT: Symbol = symbol_short!("submit");
+
+/// Longest late penalty accepted, in percent.
+const MAX_LATE_PENALTY: u32 = 100;
+/// Most submissions a student may make to one assignment.
+const MAX_SUBMISSIONS: u32 = 20;
+
+/// Attaches an assignment to a module, or replaces its rules.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: the module holding the assignment.
+/// - deadline: ledger timestamp after which submissions are late.
+/// - max_submissions: submissions allowed per student, from 1 to 20.
+/// - late_policy: how submissions after the deadline are handled.
+///
+/// Returns:
+/// - Assignment: the assignment configuration.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the submission limit or late penalty is
+///   out of range.
+///
+/// Storage used:
+/// - DataKey::Assignment(module_id) -> Assignment
+pub fn set_assignment(
+    env: &Env,
+    caller: Address,
+    module_id: String,
+    deadline: u64,
+    max_submissions: u32,
+    late_policy: LatePolicy,
+) -> Assignment {
+    let module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    if max_submissions == 0 || max_submissions > MAX_SUBMISSIONS {
+        handle_error(env, Error::InvalidInput)
+    }
+    if let LatePolicy::Penalty(percent) = late_policy {
+        if percent == 0 || percent > MAX_LATE_PENALTY {
+            handle_error(env, Error::InvalidInput)
+        }
+    }
+
+    let assignment = Assignment {
+        module_id: module_id.clone(),
+        deadline,
+        max_submissions,
+        late_policy,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Assignment(module_id.clone()), &assignment);
+
+    env.events()
+        .publish((SET_ASSIGNMENT_EVENT, module.course_id), (module_id, deadline));
+
+    assignment
+}
+
+/// Records a student's submission, identified by the hash of the submitted file.
+///
+/// Errors:
+/// - Returns `Error::AssignmentNotFound` if the module has no assignment.
+/// - Returns `EnrollmentError::ModuleLocked` if the student may not read the module.
+/// - Returns `EnrollmentError::SubmissionLimitReached` if the student used all submissions.
+/// - Returns `EnrollmentError::DeadlinePassed` if the deadline passed and late
+///   submissions are rejected.
+///
+/// Storage used:
+/// - DataKey::AssignmentSubmissions(module_id, student) -> Vec<AssignmentSubmission>
+pub fn submit_assignment(
+    env: &Env,
+    student: Address,
+    module_id: String,
+    file_hash: BytesN<32>,
+) -> AssignmentSubmission {
+    student.require_auth();
+
+    let assignment: Assignment = get_assignment(env, module_id.clone());
+    if check_module_access(env, student.clone(), module_id.clone()) != ModuleAccess::Granted {
+        handle_error(env, EnrollmentError::ModuleLocked)
+    }
+
+    let mut submissions: Vec<AssignmentSubmission> =
+        get_submissions(env, module_id.clone(), student.clone());
+    if submissions.len() >= assignment.max_submissions {
+        handle_error(env, EnrollmentError::SubmissionLimitReached)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let late: bool = now > assignment.deadline;
+    if late && assignment.late_policy == LatePolicy::Reject {
+        handle_error(env, EnrollmentError::DeadlinePassed)
+    }
+
+    let submission = AssignmentSubmission {
+        file_hash,
+        submitted_at: now,
+        late,
+    };
+    submissions.push_back(submission.clone());
+    env.storage().persistent().set(
+        &DataKey::AssignmentSubmissions(module_id.clone(), student.clone()),
+        &submissions,
+    );
+
+    env.events()
+        .publish((SUBMIT_EVENT, module_id), (student, late));
+
+    submission
+}
+
+/// Returns the assignment configuration of a module.
+///
+/// Errors:
+/// - Returns `Error::AssignmentNotFound` if the module has no assignment.
+pub fn get_assignment(env: &Env, module_id: String) -> Assignment {
+    match env.storage().persistent().get(&DataKey::Assignment(module_id)) {
+        Some(assignment) => assignment,
+        None => handle_error(env, Error::AssignmentNotFound),
+    }
+}
+
+/// Returns a student's submissions to the assignment of a module, oldest first.
+pub fn get_submissions(env: &Env, module_id: String, student: Address) -> Vec<AssignmentSubmission> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssignmentSubmissions(module_id, student))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Deletes the assignment of a module, if any. Used when the module itself is removed.
+pub fn delete_assignment(env: &Env, module_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Assignment(module_id.clone()));
+}
+
+fn load_module(env: &Env, module_id: &String) -> CourseModule {
+    match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::{CourseModule, LatePolicy};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, BytesN, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, CourseModule, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Project"));
+
+        let student = Address::generate(env);
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+        (client, creator, module, student)
+    }
+
+    #[test]
+    fn test_submit_assignment() {
+        let env = Env::default();
+        let (client, creator, module, student) = setup(&env);
+        env.ledger().set_timestamp(100);
+
+        client.set_assignment(&creator, &module.id, &200, &2, &LatePolicy::Penalty(20));
+
+        let first = client.submit_assignment(&student, &module.id, &BytesN::from_array(&env, &[1; 32]));
+        assert!(!first.late);
+
+        env.ledger().set_timestamp(300);
+        let second = client.submit_assignment(&student, &module.id, &BytesN::from_array(&env, &[2; 32]));
+        assert!(second.late);
+
+        assert_eq!(client.get_submissions(&module.id, &student).len(), 2);
+        assert!(client
+            .try_submit_assignment(&student, &module.id, &BytesN::from_array(&env, &[3; 32]))
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #505)")]
+    fn test_late_submission_rejected() {
+        let env = Env::default();
+        let (client, creator, module, student) = setup(&env);
+
+        client.set_assignment(&creator, &module.id, &100, &3, &LatePolicy::Reject);
+        env.ledger().set_timestamp(101);
+        client.submit_assignment(&student, &module.id, &BytesN::from_array(&env, &[1; 32]));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #503)")]
+    fn test_submit_without_enrollment() {
+        let env = Env::default();
+        let (client, creator, module, _student) = setup(&env);
+
+        client.set_assignment(&creator, &module.id, &100, &3, &LatePolicy::Accept);
+        client.submit_assignment(&Address::generate(&env), &module.id, &BytesN::from_array(&env, &[1; 32]));
+    }
+}
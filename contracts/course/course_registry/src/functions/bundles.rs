@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::{get_payment_token, record_payment, to_token_amount};
+use crate::error::{handle_error, EnrollmentError, Error, PaymentError};
+use crate::schema::{
+    Bundle, Course, CourseStatus, Enrollment, EnrollmentSource, PaymentKey, MAX_BUNDLE_COURSES,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CREATE_BUNDLE_EVENT: Symbol = symbol_short!("newBundle");
+const BUY_BUNDLE_EVENT: Symbol = symbol_short!("buyBundle");
+
+/// Groups several courses under a single bundle price.
+///
+/// The caller must be an admin or the creator of every course in the bundle,
+/// so only an admin can bundle courses of different creators.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must authorize).
+/// - course_ids: 2 to `MAX_BUNDLE_COURSES` distinct paid courses.
+/// - price: the bundle price, above 0.
+///
+/// Returns:
+/// - Bundle: the new bundle.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if a course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage every course.
+/// - Returns `Error::InvalidInput` if the list is too short, too long,
+///   repeats a course or includes a free course.
+/// - Returns `Error::InvalidPrice` if the price is 0.
+///
+/// Storage used:
+/// - PaymentKey::BundleSeq -> u128
+/// - PaymentKey::Bundle(id) -> Bundle
+pub fn create_bundle(env: &Env, caller: Address, course_ids: Vec<u128>, price: u128) -> Bundle {
+    caller.require_auth();
+
+    if course_ids.len() < 2 || course_ids.len() > MAX_BUNDLE_COURSES {
+        handle_error(env, Error::InvalidInput)
+    }
+    if price == 0 {
+        handle_error(env, Error::InvalidPrice)
+    }
+    let caller_is_admin: bool = is_admin(env, &caller);
+    for (i, course_id) in course_ids.iter().enumerate() {
+        let course: Course = load_course(env, course_id);
+        if course.creator != caller && !caller_is_admin {
+            handle_error(env, Error::Unauthorized)
+        }
+        if course.is_free || course_ids.slice(0..i as u32).contains(course_id) {
+            handle_error(env, Error::InvalidInput)
+        }
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get::<_, u128>(&PaymentKey::BundleSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&PaymentKey::BundleSeq, &id);
+
+    let bundle = Bundle {
+        id,
+        creator: caller,
+        course_ids,
+        price,
+    };
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::Bundle(id), &bundle);
+
+    env.events()
+        .publish((CREATE_BUNDLE_EVENT, id), bundle.price);
+
+    bundle
+}
+
+/// Returns a bundle by ID.
+///
+/// Errors:
+/// - Returns `PaymentError::BundleNotFound` if the bundle does not exist.
+pub fn get_bundle(env: &Env, bundle_id: u128) -> Bundle {
+    match env
+        .storage()
+        .persistent()
+        .get(&PaymentKey::Bundle(bundle_id))
+    {
+        Some(bundle) => bundle,
+        None => handle_error(env, PaymentError::BundleNotFound),
+    }
+}
+
+/// Buys a bundle, enrolling the student in every course it includes.
+///
+/// The bundle price is charged once and divided between the courses in
+/// proportion to their regular prices. Each share is recorded as a payment
+/// for its course, so the platform fee, escrow and earnings of every creator
+/// apply as if the course had been bought on its own.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the buying student (must authorize).
+/// - bundle_id: unique identifier of the bundle.
+/// - token: the token to pay with; must be the accepted payment token.
+///
+/// Returns:
+/// - Vec<Enrollment>: the new enrollments, in bundle order.
+///
+/// Errors:
+/// - Returns `PaymentError::BundleNotFound` if the bundle does not exist.
+/// - Returns `Error::CourseNotFound` if a course was deleted.
+/// - Returns `EnrollmentError::CourseNotOpen` if a course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is enrolled in
+///   one of the courses.
+/// - Returns `PaymentError::PaymentTokenNotSet` if no payment token is set.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is not accepted.
+pub fn purchase_bundle(
+    env: &Env,
+    student: Address,
+    bundle_id: u128,
+    token: Option<Address>,
+) -> Vec<Enrollment> {
+    student.require_auth();
+
+    let bundle: Bundle = get_bundle(env, bundle_id);
+    let mut courses: Vec<Course> = Vec::new(env);
+    let mut price_total: u128 = 0;
+    for course_id in bundle.course_ids.iter() {
+        let course: Course = load_course(env, course_id);
+        if course.status != CourseStatus::Published {
+            handle_error(env, EnrollmentError::CourseNotOpen)
+        }
+        if is_enrolled(env, course_id, &student) {
+            handle_error(env, EnrollmentError::AlreadyEnrolled)
+        }
+        price_total = match price_total.checked_add(course.price) {
+            Some(total) => total,
+            None => handle_error(env, Error::InvalidInput),
+        };
+        courses.push_back(course);
+    }
+
+    let accepted: Address = match get_payment_token(env) {
+        Some(accepted) if token.as_ref() == Some(&accepted) => accepted,
+        Some(_) => handle_error(env, PaymentError::UnsupportedToken),
+        None => handle_error(env, PaymentError::PaymentTokenNotSet),
+    };
+    token::Client::new(env, &accepted).transfer(
+        &student,
+        &env.current_contract_address(),
+        &to_token_amount(env, bundle.price),
+    );
+
+    let mut enrollments: Vec<Enrollment> = Vec::new(env);
+    let mut remaining: u128 = bundle.price;
+    for (i, course) in courses.iter().enumerate() {
+        let share: u128 = if i as u32 + 1 == courses.len() {
+            remaining
+        } else {
+            share_of(env, bundle.price, course.price, price_total)
+        };
+        remaining -= share;
+        let amount_paid: u128 =
+            record_payment(env, &course, &student, &student, &accepted, share, None).amount;
+        enrollments.push_back(record_student_enrollment(
+            env,
+            &course,
+            &student,
+            amount_paid,
+            EnrollmentSource::Bundle,
+        ));
+    }
+
+    env.events()
+        .publish((BUY_BUNDLE_EVENT, bundle_id), (student, bundle.price));
+
+    enrollments
+}
+
+/// Returns the part of `amount` matching `weight` out of `total`, rounded down.
+fn share_of(env: &Env, amount: u128, weight: u128, total: u128) -> u128 {
+    let rest: u128 = match (amount % total).checked_mul(weight) {
+        Some(rest) => rest / total,
+        None => handle_error(env, Error::InvalidInput),
+    };
+    amount / total * weight + rest
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{
+        create_published_course, setup_registry, setup_registry_with_token,
+    };
+    use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+    #[test]
+    fn test_purchase_bundle_splits_revenue() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let rust = create_published_course(&client, &alice, "Rust", 3000);
+        let soroban = create_published_course(&client, &bob, "Soroban", 1000);
+        let bundle = client.create_bundle(&admin, &vec![&env, rust.id, soroban.id], &2000);
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &2000);
+        let enrollments = client.purchase_bundle(&student, &bundle.id, &Some(token.clone()));
+
+        assert_eq!(enrollments.len(), 2);
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 0);
+        assert_eq!(client.get_earnings(&alice, &token), 1500);
+        assert_eq!(client.get_earnings(&bob, &token), 500);
+        assert!(client.get_enrollment(&soroban.id, &student).is_some());
+        assert_eq!(client.get_payment(&rust.id, &student).unwrap().amount, 1500);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #710)")]
+    fn test_purchase_unknown_bundle() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        client.purchase_bundle(&Address::generate(&env), &1, &Some(token));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #731)")]
+    fn test_purchase_bundle_without_payment_token() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let rust = create_published_course(&client, &creator, "Rust", 3000);
+        let soroban = create_published_course(&client, &creator, "Soroban", 1000);
+        let bundle = client.create_bundle(&admin, &vec![&env, rust.id, soroban.id], &2000);
+
+        client.purchase_bundle(&Address::generate(&env), &bundle.id, &None);
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use super::create_course_category::validate_category_name;
+use super::utils::normalize_title;
+use crate::error::{handle_error, CatalogError, Error};
+use crate::schema::{CourseCategory, DataKey};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const RENAME_CATEGORY_EVENT: Symbol = symbol_short!("renameCat");
+const SET_CATEGORY_ACTIVE_EVENT: Symbol = symbol_short!("catActive");
+
+/// Renames a category (admin-only).
+///
+/// The previous name stays mapped to the category, so courses filed under it
+/// keep counting towards the category and new courses using it are stored
+/// under the new name.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - category_id: the category to rename.
+/// - new_name: the new category name.
+///
+/// Returns:
+/// - CourseCategory: the renamed category.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `CatalogError::CategoryNotFound` if the category does not exist.
+/// - Returns `CatalogError::CategoryExists` if another category uses the name.
+pub fn rename_course_category(
+    env: &Env,
+    caller: Address,
+    category_id: u128,
+    new_name: String,
+) -> CourseCategory {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut category: CourseCategory = load_category(env, category_id);
+    validate_category_name(env, &new_name);
+
+    let name_key = DataKey::CategoryByName(normalize_title(env, &new_name));
+    match env.storage().persistent().get::<_, u128>(&name_key) {
+        Some(owner) if owner != category_id => handle_error(env, CatalogError::CategoryExists),
+        Some(_) => {}
+        None => env.storage().persistent().set(&name_key, &category_id),
+    }
+
+    category.name = new_name.clone();
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseCategory(category_id), &category);
+
+    env.events()
+        .publish((RENAME_CATEGORY_EVENT, category_id), new_name);
+
+    category
+}
+
+/// Activates or deactivates a category (admin-only).
+///
+/// Inactive categories are hidden from `list_categories` and cannot be
+/// assigned to courses. Courses already filed under them are left untouched.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `CatalogError::CategoryNotFound` if the category does not exist.
+pub fn set_course_category_active(env: &Env, caller: Address, category_id: u128, active: bool) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut category: CourseCategory = load_category(env, category_id);
+    category.active = active;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseCategory(category_id), &category);
+
+    env.events()
+        .publish((SET_CATEGORY_ACTIVE_EVENT, category_id), active);
+}
+
+/// Returns the registered category a course category name refers to.
+pub fn find_category(env: &Env, name: &String) -> Option<CourseCategory> {
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CategoryByName(normalize_title(env, name)))?;
+    env.storage().persistent().get(&DataKey::CourseCategory(id))
+}
+
+/// Checks a course category against the registry and returns the name to store.
+///
+/// While no category has been registered any non-empty name up to 100 bytes
+/// is accepted as is. Afterwards the name must match an active category,
+/// ignoring case and spacing, and its registered spelling is returned.
+///
+/// Errors:
+/// - Returns `Error::EmptyCategory` if the name is empty or too long.
+/// - Returns `CatalogError::CategoryNotFound` if the name is not registered.
+/// - Returns `CatalogError::CategoryInactive` if the category was deactivated.
+pub fn resolve_course_category(env: &Env, category: Option<String>) -> Option<String> {
+    let name: String = category?;
+    if name.is_empty() || name.len() > 100 {
+        handle_error(env, Error::EmptyCategory);
+    }
+    if !env.storage().persistent().has(&DataKey::CategorySeq) {
+        return Some(name);
+    }
+
+    match find_category(env, &name) {
+        Some(category) if category.active => Some(category.name),
+        Some(_) => handle_error(env, CatalogError::CategoryInactive),
+        None => handle_error(env, CatalogError::CategoryNotFound),
+    }
+}
+
+fn load_category(env: &Env, category_id: u128) -> CourseCategory {
+    match env
+        .storage()
+        .persistent()
+        .get(&DataKey::CourseCategory(category_id))
+    {
+        Some(category) => category,
+        None => handle_error(env, CatalogError::CategoryNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry};
+    use crate::schema::EditCourseParams;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(
+        env: &Env,
+        client: &CourseRegistryClient<'a>,
+        title: &str,
+        category: &str,
+    ) -> crate::schema::Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &Some(String::from_str(env, category)),
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_create_course_uses_registered_spelling() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.create_course_category(&admin, &String::from_str(&env, "Data Science"), &None);
+
+        let course = create_course(&env, &client, "Pandas", "data  science");
+        assert_eq!(course.category, Some(String::from_str(&env, "Data Science")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #601)")]
+    fn test_create_course_rejects_unregistered_category() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.create_course_category(&admin, &String::from_str(&env, "Programming"), &None);
+
+        create_course(&env, &client, "Cooking", "Cooking");
+    }
+
+    #[test]
+    fn test_rename_and_deactivate_category() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let id = client.create_course_category(&admin, &String::from_str(&env, "Web"), &None);
+        let course = create_course(&env, &client, "HTML", "Web");
+
+        let renamed = client.rename_course_category(&admin, &id, &String::from_str(&env, "Web Development"));
+        assert_eq!(renamed.name, String::from_str(&env, "Web Development"));
+        let cats = client.list_categories();
+        assert_eq!(cats.len(), 1);
+        assert_eq!(cats.get(0).unwrap().count, 1);
+
+        client.set_course_category_active(&admin, &id, &false);
+        assert!(client.list_categories().is_empty());
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: Some(Some(String::from_str(&env, "Web Development"))),
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        assert!(client.try_edit_course(&course.creator, &course.id, &params).is_err());
+    }
+
+    #[test]
+    fn test_registering_category_adopts_existing_courses() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let course = create_course(&env, &client, "Figma", "design");
+        client.edit_course(&course.creator, &course.id, &publish_params());
+
+        let id = client.create_course_category(&admin, &String::from_str(&env, "Design"), &None);
+        client.rename_course_category(&admin, &id, &String::from_str(&env, "UX Design"));
+
+        let listed = client.get_courses_by_category(&String::from_str(&env, "UX Design"), &0, &10);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed.get(0).unwrap().id, course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #602)")]
+    fn test_create_duplicate_category() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.create_course_category(&admin, &String::from_str(&env, "Design"), &None);
+        client.create_course_category(&admin, &String::from_str(&env, "design"), &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_rename_course_category_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let id = client.create_course_category(&admin, &String::from_str(&env, "Web"), &None);
+
+        client.rename_course_category(
+            &Address::generate(&env),
+            &id,
+            &String::from_str(&env, "Web Development"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_course_category_active_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let id = client.create_course_category(&admin, &String::from_str(&env, "Web"), &None);
+
+        client.set_course_category_active(&Address::generate(&env), &id, &false);
+    }
+}
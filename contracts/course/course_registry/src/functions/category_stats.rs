@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::category_key;
+use super::utils::normalize_title;
+use crate::schema::{CategoryKey, CategoryStats, CategoryTotals, DataKey};
+use soroban_sdk::{Env, String};
+
+/// Returns the course count, total enrollments and average price of a category.
+///
+/// The totals are kept up to date as courses are created, edited, enrolled in
+/// and deleted, so reading them costs a single storage access. A registered
+/// category is also found by any of its previous names.
+///
+/// Storage used:
+/// - DataKey::CategoryTotals(key) -> CategoryTotals
+pub fn get_category_stats(env: &Env, category: String) -> CategoryStats {
+    let totals = load_totals(env, &category_key(env, &category));
+    CategoryStats {
+        course_count: totals.course_count,
+        enrollment_count: totals.enrollment_count,
+        average_price: if totals.course_count == 0 {
+            0
+        } else {
+            totals.price_total / totals.course_count as u128
+        },
+    }
+}
+
+/// Adds a course to the totals of its category, if it has one.
+pub fn add_to_category_totals(env: &Env, category: &Option<String>, price: u128, enrollments: u32) {
+    if let Some(name) = category {
+        let key = category_key(env, name);
+        let mut totals = load_totals(env, &key);
+        totals.course_count += 1;
+        totals.enrollment_count += enrollments;
+        totals.price_total += price;
+        save_totals(env, &key, &totals);
+    }
+}
+
+/// Removes a course from the totals of its category, if it has one.
+pub fn remove_from_category_totals(
+    env: &Env,
+    category: &Option<String>,
+    price: u128,
+    enrollments: u32,
+) {
+    if let Some(name) = category {
+        let key = category_key(env, name);
+        let mut totals = load_totals(env, &key);
+        totals.course_count = totals.course_count.saturating_sub(1);
+        totals.enrollment_count = totals.enrollment_count.saturating_sub(enrollments);
+        totals.price_total = totals.price_total.saturating_sub(price);
+        save_totals(env, &key, &totals);
+    }
+}
+
+/// Replaces a course's price in the totals of its category.
+pub fn reprice_in_category_totals(
+    env: &Env,
+    category: &Option<String>,
+    old_price: u128,
+    new_price: u128,
+) {
+    if let Some(name) = category {
+        let key = category_key(env, name);
+        let mut totals = load_totals(env, &key);
+        totals.price_total = totals.price_total.saturating_sub(old_price) + new_price;
+        save_totals(env, &key, &totals);
+    }
+}
+
+/// Counts an enrollment in a course of the category, if it has one.
+pub fn record_category_enrollment(env: &Env, category: &Option<String>) {
+    if let Some(name) = category {
+        let key = category_key(env, name);
+        let mut totals = load_totals(env, &key);
+        totals.enrollment_count += 1;
+        save_totals(env, &key, &totals);
+    }
+}
+
+/// Moves the totals of a free-text category to the newly registered category
+/// of the same name.
+pub fn adopt_category_totals(env: &Env, name: &String, category_id: u128) {
+    let key = DataKey::CategoryTotals(CategoryKey::Unregistered(normalize_title(env, name)));
+    if let Some(totals) = env.storage().persistent().get::<_, CategoryTotals>(&key) {
+        env.storage().persistent().remove(&key);
+        save_totals(env, &CategoryKey::Registered(category_id), &totals);
+    }
+}
+
+fn load_totals(env: &Env, key: &CategoryKey) -> CategoryTotals {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryTotals(key.clone()))
+        .unwrap_or_default()
+}
+
+fn save_totals(env: &Env, key: &CategoryKey, totals: &CategoryTotals) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CategoryTotals(key.clone()), totals);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{mint, setup_registry_with_token};
+    use crate::schema::{Course, EditCourseParams};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_published_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        price: u128,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &price,
+            &Some(String::from_str(&client.env, "Data")),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &edit_params(Some(true), None, None))
+    }
+
+    fn edit_params(
+        published: Option<bool>,
+        price: Option<u128>,
+        category: Option<Option<String>>,
+    ) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: price,
+            new_category: category,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_get_category_stats() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let data = String::from_str(&env, "Data");
+
+        let first = create_published_course(&client, &creator, "Course 1", 100);
+        let second = create_published_course(&client, &creator, "Course 2", 300);
+        for course_id in [first.id, second.id, second.id] {
+            let student = Address::generate(&env);
+            mint(&env, &token, &student, 300);
+            client.enroll(&student, &course_id, &Some(token.clone()), &None, &None, &None);
+        }
+
+        let stats = client.get_category_stats(&data);
+        assert_eq!(stats.course_count, 2);
+        assert_eq!(stats.enrollment_count, 3);
+        assert_eq!(stats.average_price, 200);
+
+        client.edit_course(&creator, &first.id, &edit_params(None, Some(500), None));
+        assert_eq!(client.get_category_stats(&data).average_price, 400);
+
+        // Moving a course out of the category takes its enrollments along
+        client.edit_course(
+            &creator,
+            &second.id,
+            &edit_params(None, None, Some(Some(String::from_str(&env, "Design")))),
+        );
+        let stats = client.get_category_stats(&data);
+        assert_eq!(stats.course_count, 1);
+        assert_eq!(stats.enrollment_count, 1);
+        assert_eq!(
+            client
+                .get_category_stats(&String::from_str(&env, "design"))
+                .enrollment_count,
+            2
+        );
+
+        client.delete_course(&creator, &first.id);
+        assert_eq!(client.get_category_stats(&data).course_count, 0);
+        assert_eq!(client.get_category_stats(&data).average_price, 0);
+    }
+}
@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::add_module::build_module_id;
+use super::category_stats::add_to_category_totals;
+use super::content_limits::{get_content_limits, validate_title_length};
+use super::course_index::{
+    goal_ids, index_category, index_goal, index_language, index_level, index_module, index_price,
+    module_ids,
+};
+use super::create_course::generate_course_id;
+use super::creator_stats::record_course_created;
+use super::lessons::copy_module_lessons;
+use super::rate_limit::consume_creation_quota;
+use super::title_index::{is_title_taken, reserve_title};
+use super::title_moderation::validate_title_allowed;
+use super::title_search::index_title_prefixes;
+use super::utils::{generate_unique_id, trim};
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseGoal, CourseModule, CourseStatus, DataKey};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const CLONE_COURSE_EVENT: Symbol = symbol_short!("clonecrs");
+
+/// Creates a copy of an existing course under a new title.
+///
+/// Description, price, category, language, thumbnail, level, duration,
+/// off-chain metadata, prerequisites, modules and goals are copied into a fresh course owned by
+/// the caller. The copy starts as a draft at version 1 and without a slug.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: the course to copy.
+/// - new_title: title of the new course; must not be taken.
+///
+/// Returns:
+/// - Course: the newly created course.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the source course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the source course.
+/// - Returns `Error::EmptyCourseTitle` if the new title is empty.
+/// - Returns `Error::TitleTooLong` if the new title exceeds the configured limit.
+/// - Returns `Error::DuplicateCourseTitle` if the new title is already taken.
+/// - Returns `CatalogError::TitleBlocked` or `CatalogError::TitleReserved` if
+///   the new title is not allowed.
+pub fn clone_course(env: &Env, caller: Address, course_id: u128, new_title: String) -> Course {
+    require_course_management_auth(env, &caller, course_id);
+
+    let source: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+
+    if trim(env, &new_title).is_empty() {
+        handle_error(env, Error::EmptyCourseTitle)
+    }
+    validate_title_length(env, &get_content_limits(env), &new_title);
+    validate_title_allowed(env, &new_title, &caller);
+    if is_title_taken(env, &new_title) {
+        handle_error(env, Error::DuplicateCourseTitle)
+    }
+
+    consume_creation_quota(env, &caller);
+
+    let id: u128 = generate_course_id(env);
+    let now: u64 = env.ledger().timestamp();
+
+    let course: Course = Course {
+        id,
+        title: new_title,
+        description: source.description,
+        creator: caller,
+        price: source.price,
+        category: source.category,
+        language: source.language,
+        thumbnail_url: source.thumbnail_url,
+        status: CourseStatus::Draft,
+        prerequisites: source.prerequisites,
+        level: source.level,
+        duration_hours: source.duration_hours,
+        version: 1,
+        created_at: now,
+        updated_at: now,
+        slug: None,
+        visibility: source.visibility,
+        metadata_uri: source.metadata_uri,
+        metadata_hash: source.metadata_hash,
+        lesson_count: source.lesson_count,
+        total_duration_minutes: source.total_duration_minutes,
+        variant_group: None,
+        is_free: source.is_free,
+        max_students: source.max_students,
+        seats_remaining: source.max_students,
+    };
+
+    env.storage().persistent().set(&(COURSE_KEY, id), &course);
+    reserve_title(env, &course);
+    index_level(env, id, course.level);
+    index_category(env, id, &course.category);
+    add_to_category_totals(env, &course.category, course.price, 0);
+    index_language(env, id, &course.language);
+    index_price(env, id, course.price);
+    index_title_prefixes(env, id, &course.title);
+    record_course_created(env, &course.creator);
+
+    let prerequisites: Option<Vec<u128>> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id));
+    if let Some(prerequisites) = prerequisites {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CoursePrerequisites(id), &prerequisites);
+    }
+
+    clone_modules(env, course_id, id);
+    clone_goals(env, course_id, &course);
+
+    env.events().publish((CLONE_COURSE_EVENT, course_id), id);
+
+    course
+}
+
+fn clone_modules(env: &Env, from: u128, to: u128) {
+    for module_id in module_ids(env, from).iter() {
+        let module: Option<CourseModule> = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, module_id.clone()));
+        if let Some(module) = module {
+            let new_id = build_module_id(env, to, module.position);
+            let copy = CourseModule {
+                id: new_id.clone(),
+                course_id: to,
+                position: module.position,
+                title: module.title,
+                created_at: env.ledger().timestamp(),
+                lesson_count: module.lesson_count,
+                duration_minutes: module.duration_minutes,
+                release: module.release,
+                is_preview: module.is_preview,
+                version: 1,
+                content_hash: module.content_hash,
+            };
+            env.storage()
+                .persistent()
+                .set(&(MODULE_KEY, new_id.clone()), &copy);
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("pos"), to, module.position), &true);
+            index_module(env, to, &new_id);
+            copy_module_lessons(env, &module_id, &new_id, to);
+        }
+    }
+}
+
+fn clone_goals(env: &Env, from: u128, course: &Course) {
+    for goal_id in goal_ids(env, from).iter() {
+        let goal: Option<CourseGoal> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CourseGoal(from, goal_id));
+        if let Some(goal) = goal {
+            let new_id = generate_unique_id(env);
+            let copy = CourseGoal {
+                goal_id: new_id.clone(),
+                course_id: course.id,
+                content: goal.content,
+                created_by: course.creator.clone(),
+                created_at: env.ledger().timestamp(),
+            };
+            env.storage()
+                .persistent()
+                .set(&DataKey::CourseGoal(course.id, new_id.clone()), &copy);
+            index_goal(env, course.id, &new_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::course_index::{goal_ids, module_ids};
+    use crate::schema::{Course, CourseStatus};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1500_u128,
+            &Some(String::from_str(&client.env, "Programming")),
+            &None,
+            &None,
+            &None,
+            &Some(12),
+        )
+    }
+
+    #[test]
+    fn test_clone_course_copies_content() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let source = create_course(&client, &creator, "Rust 2025");
+        client.add_module(&creator, &source.id, &0, &String::from_str(&env, "Ownership"));
+        client.add_module(&creator, &source.id, &1, &String::from_str(&env, "Lifetimes"));
+        client.add_goal(&creator, &source.id, &String::from_str(&env, "Write safe code"));
+
+        let copy = client.clone_course(&creator, &source.id, &String::from_str(&env, "Rust 2026"));
+
+        assert_ne!(copy.id, source.id);
+        assert_eq!(copy.title, String::from_str(&env, "Rust 2026"));
+        assert_eq!(copy.description, source.description);
+        assert_eq!(copy.price, source.price);
+        assert_eq!(copy.category, source.category);
+        assert_eq!(copy.duration_hours, Some(12));
+        assert_eq!(copy.status, CourseStatus::Draft);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(module_ids(&env, copy.id).len(), 2);
+            assert_eq!(goal_ids(&env, copy.id).len(), 1);
+            assert_eq!(module_ids(&env, source.id).len(), 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #10)")]
+    fn test_clone_course_duplicate_title() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let source = create_course(&client, &creator, "Rust 2025");
+        client.clone_course(&creator, &source.id, &String::from_str(&env, "rust 2025"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_clone_course_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let source = create_course(&client, &creator, "Rust 2025");
+        client.clone_course(
+            &Address::generate(&env),
+            &source.id,
+            &String::from_str(&env, "Rust 2026"),
+        );
+    }
+}
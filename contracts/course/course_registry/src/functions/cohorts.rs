@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::collect_payment;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{
+    Cohort, Course, CourseStatus, Enrollment, EnrollmentKey, EnrollmentSource, MAX_COHORTS,
+};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CREATE_COHORT_EVENT: Symbol = symbol_short!("newCohort");
+const JOIN_COHORT_EVENT: Symbol = symbol_short!("cohort");
+
+/// Adds a cohort to a course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - starts_at: ledger timestamp from which cohort students can study.
+/// - ends_at: ledger timestamp at which their access ends.
+/// - capacity: most students in the cohort.
+///
+/// Returns:
+/// - Cohort: the new cohort.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the window is empty or already over,
+///   the capacity is 0 or the course has `MAX_COHORTS` cohorts.
+///
+/// Storage used:
+/// - EnrollmentKey::Cohorts(course_id) -> Vec<Cohort>
+pub fn create_cohort(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    starts_at: u64,
+    ends_at: u64,
+    capacity: u32,
+) -> Cohort {
+    load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let mut cohorts: Vec<Cohort> = list_cohorts(env, course_id);
+    if ends_at <= starts_at
+        || ends_at <= env.ledger().timestamp()
+        || capacity == 0
+        || cohorts.len() >= MAX_COHORTS
+    {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let cohort = Cohort {
+        id: cohorts.len(),
+        course_id,
+        starts_at,
+        ends_at,
+        capacity,
+        enrolled: 0,
+    };
+    cohorts.push_back(cohort.clone());
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::Cohorts(course_id), &cohorts);
+
+    env.events()
+        .publish((CREATE_COHORT_EVENT, course_id), cohort.id);
+
+    cohort
+}
+
+/// Returns the cohorts of a course, by cohort ID.
+pub fn list_cohorts(env: &Env, course_id: u128) -> Vec<Cohort> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::Cohorts(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Enrolls a student in a cohort of a course, charging the course price.
+///
+/// Students can join until the cohort ends; their access is limited to the
+/// cohort window.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolling student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - cohort_id: the cohort to join.
+/// - token: the token to pay with; the payment token or one on the course
+///   price list.
+///
+/// Returns:
+/// - Enrollment: the new enrollment record.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Returns `EnrollmentError::CohortNotFound` if there is no such cohort.
+/// - Returns `EnrollmentError::EnrollmentClosed` if the cohort has ended.
+/// - Returns `EnrollmentError::CohortFull` if the cohort has no place left.
+/// - Any error `enrollment::enroll` can return for the payment.
+///
+/// Storage used:
+/// - EnrollmentKey::StudentCohort(course_id, student) -> u32
+pub fn enroll_in_cohort(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    cohort_id: u32,
+    token: Option<Address>,
+) -> Enrollment {
+    student.require_auth();
+
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    let mut cohorts: Vec<Cohort> = list_cohorts(env, course_id);
+    let mut cohort: Cohort = match cohorts.get(cohort_id) {
+        Some(cohort) => cohort,
+        None => handle_error(env, EnrollmentError::CohortNotFound),
+    };
+    if env.ledger().timestamp() >= cohort.ends_at {
+        handle_error(env, EnrollmentError::EnrollmentClosed)
+    }
+    if cohort.enrolled >= cohort.capacity {
+        handle_error(env, EnrollmentError::CohortFull)
+    }
+    cohort.enrolled += 1;
+    cohorts.set(cohort_id, cohort);
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::Cohorts(course_id), &cohorts);
+    env.storage().persistent().set(
+        &EnrollmentKey::StudentCohort(course_id, student.clone()),
+        &cohort_id,
+    );
+
+    let payment = collect_payment(env, &course, &student, &student, token, None, None, None);
+    let amount_paid: u128 = payment.map(|payment| payment.amount).unwrap_or(0);
+
+    env.events()
+        .publish((JOIN_COHORT_EVENT, course_id), (student.clone(), cohort_id));
+
+    record_student_enrollment(
+        env,
+        &course,
+        &student,
+        amount_paid,
+        EnrollmentSource::Purchase,
+    )
+}
+
+/// Returns the cohort a student joined in a course, if any.
+pub fn get_student_cohort(env: &Env, course_id: u128, student: Address) -> Option<Cohort> {
+    let cohort_id: u32 = env
+        .storage()
+        .persistent()
+        .get(&EnrollmentKey::StudentCohort(course_id, student))?;
+    list_cohorts(env, course_id).get(cohort_id)
+}
+
+/// Takes a student out of their cohort when their enrollment ends, freeing
+/// their place.
+pub fn leave_cohort(env: &Env, course_id: u128, student: &Address) {
+    let key = EnrollmentKey::StudentCohort(course_id, student.clone());
+    let cohort_id: u32 = match env.storage().persistent().get(&key) {
+        Some(cohort_id) => cohort_id,
+        None => return,
+    };
+    env.storage().persistent().remove(&key);
+
+    let mut cohorts: Vec<Cohort> = list_cohorts(env, course_id);
+    if let Some(mut cohort) = cohorts.get(cohort_id) {
+        cohort.enrolled = cohort.enrolled.saturating_sub(1);
+        cohorts.set(cohort_id, cohort);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::Cohorts(course_id), &cohorts);
+    }
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env, String,
+    };
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_access_scoped_to_cohort_window() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        client.create_cohort(&creator, &course_id, &100, &200, &30);
+        client.create_cohort(&creator, &course_id, &300, &400, &30);
+        assert_eq!(client.list_cohorts(&course_id).len(), 2);
+
+        let student = Address::generate(&env);
+        client.enroll_in_cohort(&student, &course_id, &1, &None);
+        let cohort = client.get_student_cohort(&course_id, &student).unwrap();
+        assert_eq!(cohort.id, 1);
+        assert_eq!(cohort.enrolled, 1);
+
+        env.ledger().set_timestamp(299);
+        assert!(!client.has_course_access(&course_id, &student));
+        env.ledger().set_timestamp(300);
+        assert!(client.has_course_access(&course_id, &student));
+        env.ledger().set_timestamp(400);
+        assert!(!client.has_course_access(&course_id, &student));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #512)")]
+    fn test_enroll_in_full_cohort() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        client.create_cohort(&creator, &course_id, &100, &200, &1);
+        client.enroll_in_cohort(&Address::generate(&env), &course_id, &0, &None);
+
+        client.enroll_in_cohort(&Address::generate(&env), &course_id, &0, &None);
+    }
+}
@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use crate::error::{handle_error, Error};
+use crate::schema::{
+    ContentLimits, DEFAULT_MAX_DESCRIPTION_LEN, DEFAULT_MAX_THUMBNAIL_URL_LEN,
+    DEFAULT_MAX_TITLE_LEN,
+};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const CONTENT_LIMITS_KEY: Symbol = symbol_short!("cnt_lim");
+
+/// Size of the buffers used to inspect titles and thumbnail URLs; also the hard
+/// cap for `max_title_len` and `max_thumbnail_url_len`.
+const TEXT_BUFFER_LEN: usize = 1024;
+
+const URL_SCHEMES: [&[u8]; 3] = [b"https://", b"http://", b"ipfs://"];
+
+const METADATA_URI_SCHEMES: [&[u8]; 3] = [b"ipfs://", b"ar://", b"https://"];
+
+/// Returns the length limits currently enforced on course text fields.
+///
+/// Falls back to the schema defaults when no admin override has been stored.
+pub fn get_content_limits(env: &Env) -> ContentLimits {
+    env.storage()
+        .instance()
+        .get(&CONTENT_LIMITS_KEY)
+        .unwrap_or(ContentLimits {
+            max_title_len: DEFAULT_MAX_TITLE_LEN,
+            max_description_len: DEFAULT_MAX_DESCRIPTION_LEN,
+            max_thumbnail_url_len: DEFAULT_MAX_THUMBNAIL_URL_LEN,
+        })
+}
+
+/// Overrides the length limits enforced on course text fields (admin-only).
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - limits: the new limits; every value must be non-zero and the title and
+///   URL limits may not exceed 1024 bytes.
+///
+/// Storage used:
+/// - ("cnt_lim",) -> ContentLimits    // instance storage
+pub fn set_content_limits(env: &Env, caller: Address, limits: ContentLimits) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if limits.max_title_len == 0
+        || limits.max_description_len == 0
+        || limits.max_thumbnail_url_len == 0
+        || limits.max_title_len as usize > TEXT_BUFFER_LEN
+        || limits.max_thumbnail_url_len as usize > TEXT_BUFFER_LEN
+    {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    env.storage().instance().set(&CONTENT_LIMITS_KEY, &limits);
+}
+
+/// Rejects titles longer than the configured maximum.
+pub fn validate_title_length(env: &Env, limits: &ContentLimits, title: &String) {
+    if title.len() > limits.max_title_len {
+        handle_error(env, Error::TitleTooLong)
+    }
+}
+
+/// Rejects descriptions longer than the configured maximum.
+pub fn validate_description_length(env: &Env, limits: &ContentLimits, description: &String) {
+    if description.len() > limits.max_description_len {
+        handle_error(env, Error::DescriptionTooLong)
+    }
+}
+
+/// Validates the length and basic shape of a thumbnail URL.
+///
+/// The URL must start with `https://`, `http://` or `ipfs://`, have something
+/// after the scheme and contain only printable, non-space ASCII characters.
+pub fn validate_thumbnail_url(env: &Env, limits: &ContentLimits, url: &String) {
+    let len = url.len() as usize;
+    if len > limits.max_thumbnail_url_len as usize || len > TEXT_BUFFER_LEN {
+        handle_error(env, Error::ThumbnailUrlTooLong)
+    }
+
+    if !is_well_formed_uri(url, &URL_SCHEMES) {
+        handle_error(env, Error::InvalidThumbnailUrl)
+    }
+}
+
+/// Validates an off-chain metadata URI.
+///
+/// The URI must start with `ipfs://`, `ar://` or `https://`, have something
+/// after the scheme, contain only printable, non-space ASCII characters and
+/// fit in 1024 bytes.
+pub fn validate_metadata_uri(env: &Env, uri: &String) {
+    if !is_well_formed_uri(uri, &METADATA_URI_SCHEMES) {
+        handle_error(env, Error::InvalidInput)
+    }
+}
+
+fn is_well_formed_uri(uri: &String, schemes: &[&[u8]]) -> bool {
+    let len = uri.len() as usize;
+    if len > TEXT_BUFFER_LEN {
+        return false;
+    }
+
+    let mut buf = [0u8; TEXT_BUFFER_LEN];
+    uri.copy_into_slice(&mut buf[..len]);
+    let bytes = &buf[..len];
+
+    let has_scheme = schemes
+        .iter()
+        .any(|scheme| bytes.len() > scheme.len() && bytes.starts_with(scheme));
+    let printable = bytes.iter().all(|b| b.is_ascii_graphic());
+
+    has_scheme && printable
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::{ContentLimits, EditCourseParams, DEFAULT_MAX_TITLE_LEN};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn create_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &String,
+        description: &String,
+        thumbnail_url: Option<String>,
+    ) -> crate::schema::Course {
+        client.create_course(
+            creator,
+            title,
+            description,
+            &1000_u128,
+            &None,
+            &None,
+            &thumbnail_url,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_default_limits() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        assert_eq!(client.get_content_limits().max_title_len, DEFAULT_MAX_TITLE_LEN);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #33)")]
+    fn test_create_course_title_too_long() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let title = String::from_bytes(&env, &[b'a'; 201]);
+        let description = String::from_str(&env, "description");
+        create_course(&client, &Address::generate(&env), &title, &description, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #34)")]
+    fn test_edit_course_description_too_long() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(
+            &client,
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            None,
+        );
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: Some(String::from_bytes(&env, &[b'd'; 2001])),
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #36)")]
+    fn test_create_course_invalid_thumbnail_url() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        create_course(
+            &client,
+            &Address::generate(&env),
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            Some(String::from_str(&env, "not a url")),
+        );
+    }
+
+    #[test]
+    fn test_set_content_limits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        let limits = ContentLimits {
+            max_title_len: 10,
+            max_description_len: 100,
+            max_thumbnail_url_len: 50,
+        };
+        client.set_content_limits(&admin, &limits);
+        assert_eq!(client.get_content_limits(), limits);
+
+        let result = client.try_create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "a title longer than ten"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_content_limits_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let limits = ContentLimits {
+            max_title_len: 10,
+            max_description_len: 100,
+            max_thumbnail_url_len: 50,
+        };
+        client.set_content_limits(&Address::generate(&env), &limits);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_content_limits_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+
+        let limits = ContentLimits {
+            max_title_len: 10,
+            max_description_len: 100,
+            max_thumbnail_url_len: 50,
+        };
+        client.set_content_limits(&Address::generate(&env), &limits);
+    }
+}
@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, Error, PaymentError};
+use crate::schema::{Coupon, Course, Discount, PaymentKey};
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, String, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CREATE_COUPON_EVENT: Symbol = symbol_short!("newCoupon");
+const REVOKE_COUPON_EVENT: Symbol = symbol_short!("rmCoupon");
+const REDEEM_COUPON_EVENT: Symbol = symbol_short!("useCoupon");
+
+/// Longest coupon code accepted, in bytes.
+const MAX_CODE_LEN: u32 = 64;
+
+/// Mints a coupon for a course.
+///
+/// Only the SHA-256 hash of the code is stored, so the code itself stays
+/// private until a student uses it. Minting a coupon under an existing hash
+/// replaces it.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - code_hash: SHA-256 hash of the coupon code.
+/// - discount: the price reduction granted.
+/// - max_uses: how many enrollments may use the coupon.
+/// - expires_at: ledger timestamp from which the coupon is rejected.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the discount is out of range, `max_uses`
+///   is 0 or `expires_at` has passed.
+///
+/// Storage used:
+/// - PaymentKey::Coupon(course_id, code_hash) -> Coupon
+pub fn create_coupon(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    code_hash: BytesN<32>,
+    discount: Discount,
+    max_uses: u32,
+    expires_at: u64,
+) -> Coupon {
+    let _course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    require_course_management_auth(env, &caller, course_id);
+
+    let valid_discount = match discount {
+        Discount::Percent(percent) => (1..=100).contains(&percent),
+        Discount::Fixed(amount) => amount > 0,
+    };
+    if !valid_discount || max_uses == 0 || expires_at <= env.ledger().timestamp() {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let coupon = Coupon {
+        course_id,
+        discount,
+        remaining_uses: max_uses,
+        expires_at,
+    };
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::Coupon(course_id, code_hash.clone()), &coupon);
+
+    env.events()
+        .publish((CREATE_COUPON_EVENT, course_id), code_hash);
+
+    coupon
+}
+
+/// Deletes a coupon before it expires or runs out.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `PaymentError::CouponNotFound` if the coupon does not exist.
+pub fn revoke_coupon(env: &Env, caller: Address, course_id: u128, code_hash: BytesN<32>) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::Coupon(course_id, code_hash.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(env, PaymentError::CouponNotFound)
+    }
+    env.storage().persistent().remove(&key);
+
+    env.events()
+        .publish((REVOKE_COUPON_EVENT, course_id), code_hash);
+}
+
+/// Returns a coupon by the hash of its code, if it exists.
+pub fn get_coupon(env: &Env, course_id: u128, code_hash: BytesN<32>) -> Option<Coupon> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Coupon(course_id, code_hash))
+}
+
+/// Validates a coupon code for a course and uses it once.
+///
+/// Errors:
+/// - Returns `PaymentError::CouponNotFound` if no coupon matches the code.
+/// - Returns `PaymentError::CouponExpired` if the coupon has expired.
+/// - Returns `PaymentError::CouponUsedUp` if the coupon has no uses left.
+pub fn redeem_coupon(env: &Env, course_id: u128, code: &String) -> Coupon {
+    let code_hash: BytesN<32> = match hash_code(env, code) {
+        Some(code_hash) => code_hash,
+        None => handle_error(env, PaymentError::CouponNotFound),
+    };
+    let key = PaymentKey::Coupon(course_id, code_hash.clone());
+    let mut coupon: Coupon = match env.storage().persistent().get(&key) {
+        Some(coupon) => coupon,
+        None => handle_error(env, PaymentError::CouponNotFound),
+    };
+    if env.ledger().timestamp() >= coupon.expires_at {
+        handle_error(env, PaymentError::CouponExpired)
+    }
+    if coupon.remaining_uses == 0 {
+        handle_error(env, PaymentError::CouponUsedUp)
+    }
+
+    coupon.remaining_uses -= 1;
+    env.storage().persistent().set(&key, &coupon);
+
+    env.events()
+        .publish((REDEEM_COUPON_EVENT, course_id), code_hash);
+
+    coupon
+}
+
+/// Returns the price left after a discount, never below 0.
+pub fn apply_discount(price: u128, discount: &Discount) -> u128 {
+    match discount {
+        Discount::Percent(percent) => {
+            let percent = *percent as u128;
+            price - (price / 100 * percent + price % 100 * percent / 100)
+        }
+        Discount::Fixed(amount) => price.saturating_sub(*amount),
+    }
+}
+
+/// Returns the SHA-256 hash of a code, or `None` if the code is empty or
+/// longer than `MAX_CODE_LEN` bytes.
+pub fn hash_code(env: &Env, code: &String) -> Option<BytesN<32>> {
+    let len = code.len() as usize;
+    if len == 0 || code.len() > MAX_CODE_LEN {
+        return None;
+    }
+    let mut buffer = [0u8; MAX_CODE_LEN as usize];
+    code.copy_into_slice(&mut buffer[..len]);
+    Some(
+        env.crypto()
+            .sha256(&Bytes::from_slice(env, &buffer[..len]))
+            .to_bytes(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::{Course, Discount};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Bytes, Env, String,
+    };
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Course) {
+        let (client, _admin, token) = setup_registry_with_token(env);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Paid Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.edit_course(&creator, &course.id, &publish_params());
+        (client, token, course)
+    }
+
+    #[test]
+    fn test_coupon_discounts_and_runs_out() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let code = String::from_str(&env, "LAUNCH25");
+        let code_hash = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"LAUNCH25"))
+            .to_bytes();
+        client.create_coupon(
+            &course.creator,
+            &course.id,
+            &code_hash,
+            &Discount::Percent(25),
+            &1,
+            &1_000,
+        );
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        let enrollment = client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &Some(code.clone()),
+            &None,
+        );
+        assert_eq!(enrollment.amount_paid, 750);
+        assert_eq!(
+            client
+                .get_coupon(&course.id, &code_hash)
+                .unwrap()
+                .remaining_uses,
+            0
+        );
+
+        let other = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&other, &1000);
+        assert!(client
+            .try_enroll(&other, &course.id, &Some(token.clone()), &None, &Some(code), &None)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #708)")]
+    fn test_expired_coupon() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let code_hash = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"EARLY"))
+            .to_bytes();
+        client.create_coupon(
+            &course.creator,
+            &course.id,
+            &code_hash,
+            &Discount::Fixed(100),
+            &10,
+            &1_000,
+        );
+
+        env.ledger().set_timestamp(1_000);
+        client.enroll(
+            &Address::generate(&env),
+            &course.id,
+            &Some(token),
+            &None,
+            &Some(String::from_str(&env, "EARLY")),
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #707)")]
+    fn test_unknown_coupon() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+
+        client.enroll(
+            &Address::generate(&env),
+            &course.id,
+            &Some(token),
+            &None,
+            &Some(String::from_str(&env, "NOPE")),
+            &None,
+        );
+    }
+}
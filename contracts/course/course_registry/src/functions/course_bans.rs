@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::access_grants::revoke_enrollment;
+use super::enrollment::is_enrolled;
+use super::waitlist::{get_waitlist_entry, remove_from_waitlist};
+use crate::error::{handle_error, Error};
+use crate::schema::EnrollmentKey;
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const BAN_EVENT: Symbol = symbol_short!("ban");
+const UNBAN_EVENT: Symbol = symbol_short!("unban");
+
+/// Bans a student from a course.
+///
+/// Unlike a revocation, a ban also stops the student from enrolling again,
+/// even through a grant, and from joining the waitlist, until the creator or
+/// an admin lifts it. An active enrollment is revoked with the same reason
+/// and the student is taken off the waitlist. A student can be banned before
+/// enrolling.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - student: the student to ban.
+/// - reason: why the student is banned.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the student is already banned.
+///
+/// Storage used:
+/// - EnrollmentKey::Banned(course_id, student) -> String
+pub fn ban_student(env: &Env, caller: Address, course_id: u128, student: Address, reason: String) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::Banned(course_id, student.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(env, Error::InvalidInput)
+    }
+    env.storage().persistent().set(&key, &reason);
+    if is_enrolled(env, course_id, &student) {
+        revoke_enrollment(env, &caller, course_id, &student, reason.clone());
+    }
+    if get_waitlist_entry(env, course_id, student.clone()).is_some() {
+        remove_from_waitlist(env, course_id, &student);
+    }
+
+    env.events()
+        .publish((BAN_EVENT, course_id), (student, caller, reason));
+}
+
+/// Lifts a student's ban from a course.
+///
+/// The student may then enroll again, but an enrollment revoked by the ban
+/// stays revoked until the creator grants access.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the student is not banned.
+pub fn unban_student(env: &Env, caller: Address, course_id: u128, student: Address) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::Banned(course_id, student.clone());
+    if !env.storage().persistent().has(&key) {
+        handle_error(env, Error::InvalidInput)
+    }
+    env.storage().persistent().remove(&key);
+
+    env.events()
+        .publish((UNBAN_EVENT, course_id), (student, caller));
+}
+
+/// Whether a student is banned from a course.
+pub fn is_banned(env: &Env, course_id: u128, student: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&EnrollmentKey::Banned(course_id, student))
+}
+
+/// Returns why a student was banned from a course, if they are.
+pub fn get_ban_reason(env: &Env, course_id: u128, student: Address) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::Banned(course_id, student))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::EnrollmentStatus;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_ban_and_unban() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let student = Address::generate(&env);
+        client.enroll(&student, &course_id, &None, &None, &None, &None);
+
+        let reason = String::from_str(&env, "Harassment in the forum");
+        client.ban_student(&creator, &course_id, &student, &reason);
+        assert!(client.is_banned(&course_id, &student));
+        assert_eq!(client.get_ban_reason(&course_id, &student), Some(reason));
+        assert_eq!(
+            client.get_enrollment(&course_id, &student).unwrap().status,
+            EnrollmentStatus::Revoked
+        );
+        assert!(client
+            .try_grant_access(&creator, &course_id, &student)
+            .is_err());
+
+        client.unban_student(&creator, &course_id, &student);
+        assert!(!client.is_banned(&course_id, &student));
+        client.grant_access(&creator, &course_id, &student);
+        assert!(client.is_enrolled(&student, &course_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #521)")]
+    fn test_banned_student_cannot_join_waitlist() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        client.set_max_students(&creator, &course_id, &Some(1));
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let student = Address::generate(&env);
+        client.ban_student(
+            &creator,
+            &course_id,
+            &student,
+            &String::from_str(&env, "Spam"),
+        );
+
+        client.join_waitlist(&student, &course_id);
+    }
+}
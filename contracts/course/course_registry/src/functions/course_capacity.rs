@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::waitlist::{get_waitlist_offers, offer_free_seats};
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{Course, EnrollmentKey};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_CAPACITY_EVENT: Symbol = symbol_short!("setCap");
+
+/// Limits how many students may have active access to a course at once, or
+/// removes the limit.
+///
+/// Seats added by raising the limit are offered to the course waitlist.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - max_students: the new limit, or `None` for no limit.
+///
+/// Returns:
+/// - Course: the updated course, with its seats remaining.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the limit is 0 or below the number of
+///   students already enrolled plus the seats offered to the waitlist and
+///   the seats organizations have yet to assign.
+pub fn set_max_students(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    max_students: Option<u32>,
+) -> Course {
+    let mut course: Course = load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let taken: u32 = active_student_count(env, course_id)
+        + get_waitlist_offers(env, course_id).len()
+        + reserved_seat_count(env, course_id);
+    course.seats_remaining = match max_students {
+        Some(max) if max == 0 || max < taken => handle_error(env, Error::InvalidInput),
+        Some(max) => Some(max - taken),
+        None => None,
+    };
+    course.max_students = max_students;
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course_id), &course);
+    offer_free_seats(env, course_id);
+
+    env.events()
+        .publish((SET_CAPACITY_EVENT, course_id), max_students);
+
+    load_course(env, course_id)
+}
+
+/// Returns the number of students with active access to a course.
+pub fn active_student_count(env: &Env, course_id: u128) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::ActiveStudents(course_id))
+        .unwrap_or(0)
+}
+
+/// Returns the number of seats organizations bought for a course and have not
+/// assigned yet.
+pub fn reserved_seat_count(env: &Env, course_id: u128) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::ReservedSeats(course_id))
+        .unwrap_or(0)
+}
+
+/// Holds seats of a course for an organization until it assigns them.
+///
+/// Errors:
+/// - Returns `EnrollmentError::CourseFull` if fewer seats are left.
+///
+/// Storage used:
+/// - EnrollmentKey::ReservedSeats(course_id) -> u32
+pub fn reserve_seats(env: &Env, course_id: u128, count: u32) {
+    let mut course: Course = load_course(env, course_id);
+    if let Some(seats) = course.seats_remaining {
+        if seats < count {
+            handle_error(env, EnrollmentError::CourseFull)
+        }
+        course.seats_remaining = Some(seats - count);
+        env.storage()
+            .persistent()
+            .set(&(COURSE_KEY, course_id), &course);
+    }
+    env.storage().persistent().set(
+        &EnrollmentKey::ReservedSeats(course_id),
+        &(reserved_seat_count(env, course_id) + count),
+    );
+}
+
+/// Hands back a seat held for an organization so that the enrollment of the
+/// member it is assigned to takes it.
+pub fn release_reserved_seat(env: &Env, course_id: u128) {
+    env.storage().persistent().set(
+        &EnrollmentKey::ReservedSeats(course_id),
+        &reserved_seat_count(env, course_id).saturating_sub(1),
+    );
+    let mut course: Course = load_course(env, course_id);
+    if let Some(seats) = course.seats_remaining {
+        course.seats_remaining = Some(seats + 1);
+        env.storage()
+            .persistent()
+            .set(&(COURSE_KEY, course_id), &course);
+    }
+}
+
+/// Takes a seat of a course for a new active enrollment.
+///
+/// Errors:
+/// - Returns `EnrollmentError::CourseFull` if no seat is left.
+///
+/// Storage used:
+/// - EnrollmentKey::ActiveStudents(course_id) -> u32
+pub fn take_seat(env: &Env, course_id: u128) {
+    let mut course: Course = load_course(env, course_id);
+    if let Some(seats) = course.seats_remaining {
+        if seats == 0 {
+            handle_error(env, EnrollmentError::CourseFull)
+        }
+        course.seats_remaining = Some(seats - 1);
+        env.storage()
+            .persistent()
+            .set(&(COURSE_KEY, course_id), &course);
+    }
+    env.storage().persistent().set(
+        &EnrollmentKey::ActiveStudents(course_id),
+        &(active_student_count(env, course_id) + 1),
+    );
+}
+
+/// Gives back the seat of an enrollment that lost active access, offering it
+/// to the course waitlist.
+pub fn free_seat(env: &Env, course_id: u128) {
+    let active: u32 = active_student_count(env, course_id);
+    env.storage().persistent().set(
+        &EnrollmentKey::ActiveStudents(course_id),
+        &active.saturating_sub(1),
+    );
+    let key = (COURSE_KEY, course_id);
+    if let Some(mut course) = env.storage().persistent().get::<_, Course>(&key) {
+        if let (Some(max), Some(seats)) = (course.max_students, course.seats_remaining) {
+            course.seats_remaining = Some(max.min(seats + 1));
+            env.storage().persistent().set(&key, &course);
+            offer_free_seats(env, course_id);
+        }
+    }
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_capacity_counts_active_students() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let student = Address::generate(&env);
+        client.enroll(&student, &course_id, &None, &None, &None, &None);
+
+        let course = client.set_max_students(&creator, &course_id, &Some(2));
+        assert_eq!(course.seats_remaining, Some(1));
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(client.get_course(&course_id).seats_remaining, Some(0));
+        assert!(client
+            .try_enroll(
+                &Address::generate(&env),
+                &course_id,
+                &None,
+                &None,
+                &None,
+                &None
+            )
+            .is_err());
+
+        client.revoke_access(
+            &creator,
+            &course_id,
+            &student,
+            &String::from_str(&env, "Left the team"),
+        );
+        assert_eq!(client.get_course(&course_id).seats_remaining, Some(1));
+        assert!(client
+            .try_set_max_students(&creator, &course_id, &Some(0))
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #509)")]
+    fn test_enroll_in_full_course() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        client.set_max_students(&creator, &course_id, &Some(1));
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::category_registry::find_category;
+use super::utils::normalize_title;
+use crate::error::{handle_error, Error};
+use crate::schema::{
+    CategoryKey, Course, CourseLevel, CourseStatus, CourseVisibility, DataKey, MAX_PAGE_SIZE,
+    MAX_RANKED_COURSES,
+};
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Returns the IDs of all modules attached to a course, in insertion order.
+///
+/// Storage used:
+/// - DataKey::CourseModules(course_id) -> Vec<String>
+pub fn module_ids(env: &Env, course_id: u128) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseModules(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a module ID in the course's module index.
+pub fn index_module(env: &Env, course_id: u128, module_id: &String) {
+    let mut ids = module_ids(env, course_id);
+    ids.push_back(module_id.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseModules(course_id), &ids);
+}
+
+/// Removes a module ID from the course's module index.
+pub fn unindex_module(env: &Env, course_id: u128, module_id: &String) {
+    let mut ids = module_ids(env, course_id);
+    if let Some(i) = ids.first_index_of(module_id) {
+        ids.remove(i);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseModules(course_id), &ids);
+    }
+}
+
+/// Returns the IDs of all goals attached to a course, in insertion order.
+///
+/// Storage used:
+/// - DataKey::CourseGoalList(course_id) -> Vec<String>
+pub fn goal_ids(env: &Env, course_id: u128) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseGoalList(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a goal ID in the course's goal index.
+pub fn index_goal(env: &Env, course_id: u128, goal_id: &String) {
+    let mut ids = goal_ids(env, course_id);
+    ids.push_back(goal_id.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseGoalList(course_id), &ids);
+}
+
+/// Removes a goal ID from the course's goal index.
+pub fn unindex_goal(env: &Env, course_id: u128, goal_id: &String) {
+    let mut ids = goal_ids(env, course_id);
+    if let Some(i) = ids.first_index_of(goal_id) {
+        ids.remove(i);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseGoalList(course_id), &ids);
+    }
+}
+
+/// Returns the IDs of all courses with the given level, in creation order.
+///
+/// Storage used:
+/// - DataKey::CoursesByLevel(level) -> Vec<u128>
+pub fn course_ids_by_level(env: &Env, level: CourseLevel) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoursesByLevel(level))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a course in the index of its level, if it has one.
+pub fn index_level(env: &Env, course_id: u128, level: CourseLevel) {
+    if level != CourseLevel::Unspecified {
+        let mut ids = course_ids_by_level(env, level);
+        ids.push_back(course_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CoursesByLevel(level), &ids);
+    }
+}
+
+/// Removes a course from the index of its level, if it has one.
+pub fn unindex_level(env: &Env, course_id: u128, level: CourseLevel) {
+    if level != CourseLevel::Unspecified {
+        let mut ids = course_ids_by_level(env, level);
+        if let Some(i) = ids.first_index_of(course_id) {
+            ids.remove(i);
+            env.storage()
+                .persistent()
+                .set(&DataKey::CoursesByLevel(level), &ids);
+        }
+    }
+}
+
+/// Returns the index key of a course category name.
+pub fn category_key(env: &Env, name: &String) -> CategoryKey {
+    match find_category(env, name) {
+        Some(category) => CategoryKey::Registered(category.id),
+        None => CategoryKey::Unregistered(normalize_title(env, name)),
+    }
+}
+
+/// Returns the IDs of all courses filed under a category, in filing order.
+///
+/// Storage used:
+/// - DataKey::CategoryCourses(key) -> Vec<u128>
+pub fn course_ids_by_category(env: &Env, name: &String) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CategoryCourses(category_key(env, name)))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a course in the index of its category, if it has one.
+pub fn index_category(env: &Env, course_id: u128, category: &Option<String>) {
+    if let Some(name) = category {
+        let mut ids = course_ids_by_category(env, name);
+        ids.push_back(course_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CategoryCourses(category_key(env, name)), &ids);
+    }
+}
+
+/// Removes a course from the index of its category, if it has one.
+pub fn unindex_category(env: &Env, course_id: u128, category: &Option<String>) {
+    if let Some(name) = category {
+        let mut ids = course_ids_by_category(env, name);
+        if let Some(i) = ids.first_index_of(course_id) {
+            ids.remove(i);
+            let key = DataKey::CategoryCourses(category_key(env, name));
+            if ids.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &ids);
+            }
+        }
+    }
+}
+
+/// Moves the courses filed under a free-text category into the index of the
+/// newly registered category of the same name.
+pub fn adopt_category_courses(env: &Env, name: &String, category_id: u128) {
+    let free_key = DataKey::CategoryCourses(CategoryKey::Unregistered(normalize_title(env, name)));
+    if let Some(ids) = env.storage().persistent().get::<_, Vec<u128>>(&free_key) {
+        env.storage().persistent().remove(&free_key);
+        env.storage().persistent().set(
+            &DataKey::CategoryCourses(CategoryKey::Registered(category_id)),
+            &ids,
+        );
+    }
+}
+
+/// Returns the IDs of all courses taught in a language, in creation order.
+///
+/// The language is matched ignoring case and surrounding whitespace.
+///
+/// Storage used:
+/// - DataKey::LanguageCourses(language) -> Vec<u128>
+pub fn course_ids_by_language(env: &Env, language: &String) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LanguageCourses(normalize_title(env, language)))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a course in the index of its language, if it has one.
+pub fn index_language(env: &Env, course_id: u128, language: &Option<String>) {
+    if let Some(language) = language {
+        let mut ids = course_ids_by_language(env, language);
+        ids.push_back(course_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::LanguageCourses(normalize_title(env, language)), &ids);
+    }
+}
+
+/// Removes a course from the index of its language, if it has one.
+pub fn unindex_language(env: &Env, course_id: u128, language: &Option<String>) {
+    if let Some(language) = language {
+        let mut ids = course_ids_by_language(env, language);
+        if let Some(i) = ids.first_index_of(course_id) {
+            ids.remove(i);
+            let key = DataKey::LanguageCourses(normalize_title(env, language));
+            if ids.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &ids);
+            }
+        }
+    }
+}
+
+/// Returns the coarse price bucket of a price: its bit length, so bucket `b`
+/// holds the prices from `2^(b-1)` to `2^b - 1`.
+pub fn price_bucket(price: u128) -> u32 {
+    u128::BITS - price.leading_zeros()
+}
+
+/// Returns the IDs of all courses in a price bucket, in creation order.
+///
+/// Storage used:
+/// - DataKey::PriceBucket(bucket) -> Vec<u128>
+pub fn course_ids_by_price_bucket(env: &Env, bucket: u32) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PriceBucket(bucket))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a course in the bucket of its price.
+pub fn index_price(env: &Env, course_id: u128, price: u128) {
+    let bucket = price_bucket(price);
+    let mut ids = course_ids_by_price_bucket(env, bucket);
+    ids.push_back(course_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::PriceBucket(bucket), &ids);
+}
+
+/// Removes a course from the bucket of its price.
+pub fn unindex_price(env: &Env, course_id: u128, price: u128) {
+    let bucket = price_bucket(price);
+    let mut ids = course_ids_by_price_bucket(env, bucket);
+    if let Some(i) = ids.first_index_of(course_id) {
+        ids.remove(i);
+        if ids.is_empty() {
+            env.storage().persistent().remove(&DataKey::PriceBucket(bucket));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::PriceBucket(bucket), &ids);
+        }
+    }
+}
+
+/// Returns the number of enrollments of a course.
+///
+/// Storage used:
+/// - DataKey::EnrollmentCount(course_id) -> u32
+pub fn enrollment_count(env: &Env, course_id: u128) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnrollmentCount(course_id))
+        .unwrap_or(0)
+}
+
+/// Returns the IDs of the most-enrolled courses, highest count first.
+///
+/// Storage used:
+/// - DataKey::EnrollmentRanking -> Vec<u128>
+pub fn enrollment_ranking(env: &Env) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::EnrollmentRanking)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Counts a new enrollment and moves the course up the enrollment ranking.
+///
+/// The ranking keeps the `MAX_RANKED_COURSES` most-enrolled courses; a course
+/// outside it enters once it has more enrollments than the last one.
+pub fn record_enrollment(env: &Env, course_id: u128) {
+    let count = enrollment_count(env, course_id) + 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::EnrollmentCount(course_id), &count);
+
+    let mut ranking = enrollment_ranking(env);
+    let mut i = match ranking.first_index_of(course_id) {
+        Some(i) => i,
+        None if ranking.len() < MAX_RANKED_COURSES => {
+            ranking.push_back(course_id);
+            ranking.len() - 1
+        }
+        None => {
+            let last = ranking.len() - 1;
+            if enrollment_count(env, ranking.get_unchecked(last)) >= count {
+                return;
+            }
+            ranking.set(last, course_id);
+            last
+        }
+    };
+    while i > 0 && enrollment_count(env, ranking.get_unchecked(i - 1)) < count {
+        let above = ranking.get_unchecked(i - 1);
+        ranking.set(i - 1, course_id);
+        ranking.set(i, above);
+        i -= 1;
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::EnrollmentRanking, &ranking);
+}
+
+/// Drops a course's enrollment count and removes it from the ranking.
+pub fn unrank_course(env: &Env, course_id: u128) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::EnrollmentCount(course_id));
+    let mut ranking = enrollment_ranking(env);
+    if let Some(i) = ranking.first_index_of(course_id) {
+        ranking.remove(i);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EnrollmentRanking, &ranking);
+    }
+}
+
+/// Loads one page of the published, public courses among `ids`.
+///
+/// Deleted, unpublished and hidden courses are skipped and do not count
+/// towards `offset`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn page_listed_courses(env: &Env, ids: &Vec<u128>, offset: u32, limit: u32) -> Vec<Course> {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut matched: u32 = 0;
+    for id in ids.iter() {
+        if results.len() >= limit {
+            break;
+        }
+
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, id)) {
+            Some(course) => course,
+            None => continue,
+        };
+        if course.status != CourseStatus::Published || course.visibility != CourseVisibility::Public {
+            continue;
+        }
+
+        if matched >= offset {
+            results.push_back(course);
+        }
+        matched += 1;
+    }
+    results
+}
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::{index_level, unindex_level};
+use super::get_course_version::save_course_version;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseLevel};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_LEVEL_EVENT: Symbol = symbol_short!("setLevel");
+
+/// Changes the difficulty level of a course and moves it between the level
+/// indexes.
+///
+/// Like `edit_course`, the previous state is kept as a version.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - level: the new level, or `CourseLevel::Unspecified` to clear it.
+///
+/// Returns:
+/// - Course: the updated course record.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+pub fn set_course_level(
+    env: &Env,
+    creator: Address,
+    course_id: u128,
+    level: CourseLevel,
+) -> Course {
+    creator.require_auth();
+
+    let key = (COURSE_KEY, course_id);
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("Course not found");
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if level != course.level {
+        unindex_level(env, course_id, course.level);
+        index_level(env, course_id, level);
+    }
+    save_course_version(env, &course);
+    course.level = level;
+    course.version += 1;
+    course.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(&key, &course);
+
+    env.events().publish((SET_LEVEL_EVENT, course_id), level);
+
+    course
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::CourseLevel;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_set_course_level_keeps_previous_version() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &Some(CourseLevel::Beginner),
+            &None,
+        );
+
+        let updated = client.set_course_level(&creator, &course.id, &CourseLevel::Unspecified);
+        assert_eq!(updated.level, CourseLevel::Unspecified);
+        assert_eq!(updated.version, 2);
+        assert_eq!(
+            client.get_course_version(&course.id, &1).level,
+            CourseLevel::Beginner
+        );
+        assert!(client
+            .try_set_course_level(&Address::generate(&env), &course.id, &CourseLevel::Advanced)
+            .is_err());
+    }
+}
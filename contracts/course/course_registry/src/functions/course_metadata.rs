@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::content_limits::validate_metadata_uri;
+use super::get_course_version::save_course_version;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_METADATA_EVENT: Symbol = symbol_short!("setMeta");
+
+/// Points a course at a rich off-chain document (e.g. on IPFS or Arweave).
+///
+/// Lets creators keep long descriptions and syllabi off-chain while the
+/// optional hash lets clients verify the document they fetched. Like
+/// `edit_course`, the previous state is kept as a version.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - metadata_uri: `ipfs://`, `ar://` or `https://` URI, or `None` to clear it.
+/// - metadata_hash: SHA-256 of the document; requires a URI.
+///
+/// Returns:
+/// - Course: the updated course record.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+/// - Returns `Error::InvalidInput` if the URI is malformed or a hash is given without a URI.
+pub fn set_course_metadata(
+    env: &Env,
+    creator: Address,
+    course_id: u128,
+    metadata_uri: Option<String>,
+    metadata_hash: Option<BytesN<32>>,
+) -> Course {
+    creator.require_auth();
+
+    let key = (COURSE_KEY, course_id);
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("Course not found");
+    if course.status == CourseStatus::Archived {
+        handle_error(env, Error::CourseAlreadyArchived)
+    }
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    validate_metadata(env, &metadata_uri, &metadata_hash);
+
+    save_course_version(env, &course);
+    course.metadata_uri = metadata_uri;
+    course.metadata_hash = metadata_hash;
+    course.version += 1;
+    course.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(&key, &course);
+
+    env.events()
+        .publish((SET_METADATA_EVENT, course_id), course.metadata_uri.clone());
+
+    course
+}
+
+/// Checks an off-chain metadata reference: the URI must be well formed and a
+/// hash is only meaningful together with a URI.
+pub fn validate_metadata(env: &Env, uri: &Option<String>, hash: &Option<BytesN<32>>) {
+    match uri {
+        Some(uri) => validate_metadata_uri(env, uri),
+        None if hash.is_some() => handle_error(env, Error::InvalidInput),
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, CourseInput, CourseLevel};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, "Course"),
+            &String::from_str(&client.env, ""),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_set_course_metadata() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        let uri = String::from_str(&env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+        let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        let updated = client.set_course_metadata(&creator, &course.id, &Some(uri.clone()), &Some(hash.clone()));
+        assert_eq!(updated.metadata_uri, Some(uri));
+        assert_eq!(updated.metadata_hash, Some(hash));
+        assert_eq!(updated.version, course.version + 1);
+        assert_eq!(client.get_course_version(&course.id, &course.version).metadata_uri, None);
+
+        let cleared = client.set_course_metadata(&creator, &course.id, &None, &None);
+        assert_eq!(cleared.metadata_uri, None);
+        assert_eq!(client.get_course(&course.id).metadata_hash, None);
+    }
+
+    #[test]
+    fn test_create_courses_with_metadata() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let uri = String::from_str(&env, "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U");
+        let input = CourseInput {
+            title: String::from_str(&env, "Course"),
+            description: String::from_str(&env, ""),
+            price: 1000,
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            level: CourseLevel::Unspecified,
+            duration_hours: None,
+            metadata_uri: Some(uri.clone()),
+            metadata_hash: None,
+            is_free: false,
+        };
+
+        let courses = client.create_courses(&creator, &vec![&env, input]);
+        assert_eq!(courses.get(0).unwrap().metadata_uri, Some(uri));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_set_course_metadata_invalid_uri() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        client.set_course_metadata(
+            &creator,
+            &course.id,
+            &Some(String::from_str(&env, "ftp://example.com/course.json")),
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_set_course_metadata_hash_without_uri() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        client.set_course_metadata(&creator, &course.id, &None, &Some(BytesN::from_array(&env, &[1u8; 32])));
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::get_course::get_course;
+use super::lessons::get_module_lessons;
+use super::reorder_modules::get_modules;
+use crate::schema::{Course, CourseOutline, LessonSummary, ModuleOutline};
+use soroban_sdk::{Env, Vec};
+
+/// Returns a course with its modules and lesson summaries in one read.
+///
+/// Modules are sorted by position and lessons follow their module's order.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - CourseOutline: the course, its modules and their lessons.
+///
+/// Errors:
+/// - Any error `get_course` returns for a missing, archived, suspended or
+///   private course.
+pub fn get_course_outline(env: &Env, course_id: u128) -> CourseOutline {
+    let course: Course = get_course(env, course_id);
+
+    let mut modules: Vec<ModuleOutline> = Vec::new(env);
+    for module in get_modules(env, course_id).iter() {
+        let mut lessons: Vec<LessonSummary> = Vec::new(env);
+        for lesson in get_module_lessons(env, module.id.clone()).iter() {
+            lessons.push_back(LessonSummary {
+                id: lesson.id,
+                title: lesson.title,
+                content_type: lesson.content_type,
+                duration_minutes: lesson.duration_minutes,
+            });
+        }
+        modules.push_back(ModuleOutline { module, lessons });
+    }
+
+    CourseOutline { course, modules }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::LessonContentType;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_get_course_outline() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let second = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Second"));
+        let first = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "First"));
+        client.add_lesson(
+            &creator,
+            &first.id,
+            &String::from_str(&env, "Welcome"),
+            &LessonContentType::Video,
+            &None,
+            &10,
+        );
+        client.add_lesson(
+            &creator,
+            &first.id,
+            &String::from_str(&env, "Check"),
+            &LessonContentType::Quiz,
+            &None,
+            &5,
+        );
+
+        let outline = client.get_course_outline(&course.id);
+        assert_eq!(outline.course.id, course.id);
+        assert_eq!(outline.course.lesson_count, 2);
+        assert_eq!(outline.modules.len(), 2);
+
+        let first_outline = outline.modules.get(0).unwrap();
+        assert_eq!(first_outline.module.id, first.id);
+        assert_eq!(first_outline.lessons.len(), 2);
+        assert_eq!(
+            first_outline.lessons.get(1).unwrap().content_type,
+            LessonContentType::Quiz
+        );
+        assert_eq!(outline.modules.get(1).unwrap().module.id, second.id);
+        assert!(outline.modules.get(1).unwrap().lessons.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::creator_stats::record_creator_rating;
+use super::enrollment::is_enrolled;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{Course, CourseRating, DataKey};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const RATE_EVENT: Symbol = symbol_short!("rate");
+
+/// Records an enrolled student's rating of a course, from 1 to 5 stars.
+///
+/// Rating again replaces the student's previous rating.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the rating student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - rating: the rating, from 1 to 5.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::InvalidInput` if the rating is out of range.
+/// - Returns `EnrollmentError::NotEnrolled` if the student is not enrolled.
+///
+/// Storage used:
+/// - DataKey::StudentRating(course_id, student) -> u32
+/// - DataKey::CourseRating(course_id) -> CourseRating
+pub fn rate_course(env: &Env, student: Address, course_id: u128, rating: u32) {
+    student.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+    if rating == 0 || rating > 5 {
+        handle_error(env, Error::InvalidInput)
+    }
+    if !is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::NotEnrolled)
+    }
+
+    let student_key = DataKey::StudentRating(course_id, student.clone());
+    let previous: Option<u32> = env.storage().persistent().get(&student_key);
+    env.storage().persistent().set(&student_key, &rating);
+
+    let mut totals: CourseRating = get_course_rating(env, course_id);
+    match previous {
+        Some(previous) => totals.total -= previous as u64,
+        None => totals.count += 1,
+    }
+    totals.total += rating as u64;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseRating(course_id), &totals);
+    record_creator_rating(env, &course.creator, previous, rating);
+
+    env.events().publish((RATE_EVENT, course_id), (student, rating));
+}
+
+/// Returns the rating totals of a course.
+pub fn get_course_rating(env: &Env, course_id: u128) -> CourseRating {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseRating(course_id))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_rate_course_replaces_previous_rating() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        let student = Address::generate(&env);
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+
+        client.rate_course(&student, &course.id, &2);
+        client.rate_course(&student, &course.id, &5);
+
+        let rating = client.get_course_rating(&course.id);
+        assert_eq!(rating.count, 1);
+        assert_eq!(rating.total, 5);
+        assert_eq!(client.get_creator_stats(&creator).rating_total, 5);
+        assert!(client.try_rate_course(&student, &course.id, &6).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #507)")]
+    fn test_rate_course_not_enrolled() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.rate_course(&Address::generate(&env), &course.id, &5);
+    }
+}
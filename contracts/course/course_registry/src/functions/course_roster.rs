@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::{get_course_student_ids, get_enrollment};
+use crate::error::{handle_error, Error};
+use crate::schema::{Enrollment, EnrollmentKey, MAX_PAGE_SIZE};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const SET_ROSTER_EVENT: Symbol = symbol_short!("setRoster");
+
+/// Makes the list of a course's students public, or private again.
+///
+/// Rosters are private by default: only the course creator and admins may
+/// list the students.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::PublicRoster(course_id) -> bool
+pub fn set_roster_public(env: &Env, caller: Address, course_id: u128, public: bool) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::PublicRoster(course_id);
+    if public {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events().publish((SET_ROSTER_EVENT, course_id), public);
+}
+
+/// Whether anyone may list the students of a course.
+pub fn is_roster_public(env: &Env, course_id: u128) -> bool {
+    env.storage()
+        .persistent()
+        .has(&EnrollmentKey::PublicRoster(course_id))
+}
+
+/// Returns a page of a course's enrollments, in enrollment order.
+///
+/// Revoked and withdrawn enrollments are included with their status. Unless the roster was
+/// made public, only the course creator and admins may list it.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the address listing the students (must authorize if the roster
+///   is private).
+/// - course_id: unique identifier of the course.
+/// - offset: number of enrollments to skip.
+/// - limit: number of enrollments to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+/// - Returns `Error::Unauthorized` if the roster is private and the caller
+///   may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::CourseStudents(course_id) -> Vec<Address>
+pub fn list_course_students(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    offset: u32,
+    limit: u32,
+) -> Vec<Enrollment> {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+    if !is_roster_public(env, course_id) {
+        require_course_management_auth(env, &caller, course_id);
+    }
+
+    let students: Vec<Address> = get_course_student_ids(env, course_id);
+    let mut page: Vec<Enrollment> = Vec::new(env);
+    let end: u32 = students.len().min(offset.saturating_add(limit));
+    for i in offset..end {
+        if let Some(enrollment) = get_enrollment(env, course_id, students.get_unchecked(i)) {
+            page.push_back(enrollment);
+        }
+    }
+    page
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        let (client, _) = setup_registry(env);
+
+        let creator = Address::generate(env);
+        let course_id = client
+            .create_course(
+                &creator,
+                &String::from_str(env, "Course"),
+                &String::from_str(env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id;
+        (client, creator, course_id)
+    }
+
+    #[test]
+    fn test_list_course_students() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let mut students: Vec<Address> = Vec::new(&env);
+        for _ in 0..3 {
+            let student = Address::generate(&env);
+            client.grant_access(&creator, &course_id, &student);
+            students.push_back(student);
+        }
+
+        let page = client.list_course_students(&creator, &course_id, &1, &5);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap().student, students.get(1).unwrap());
+        assert_eq!(page.get(1).unwrap().student, students.get(2).unwrap());
+        assert!(client
+            .try_list_course_students(&Address::generate(&env), &course_id, &0, &5)
+            .is_err());
+
+        client.set_roster_public(&creator, &course_id, &true);
+        let page = client.list_course_students(&Address::generate(&env), &course_id, &0, &5);
+        assert_eq!(page.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_list_course_students_page_too_large() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+
+        client.list_course_students(&creator, &course_id, &0, &21);
+    }
+}
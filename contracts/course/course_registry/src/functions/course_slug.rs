@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::get_course::get_course;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const SLUG_KEY: Symbol = symbol_short!("slug");
+
+const SET_SLUG_EVENT: Symbol = symbol_short!("setSlug");
+
+/// Maximum length of a course slug in bytes.
+pub const MAX_SLUG_LEN: u32 = 64;
+
+/// Sets or clears the human-readable slug of a course.
+///
+/// Slugs are unique across courses and may only contain lowercase ASCII
+/// letters, digits and single hyphens (e.g. `intro-to-soroban`).
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - slug: the new slug, or `None` to remove the current one.
+///
+/// Returns:
+/// - Course: the updated course record.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+/// - Returns `Error::InvalidSlug` if the slug is malformed.
+/// - Returns `Error::DuplicateSlug` if another course already uses the slug.
+///
+/// Storage used:
+/// - (("slug", slug),) -> u128    // course id by slug
+pub fn set_course_slug(env: &Env, creator: Address, course_id: u128, slug: Option<String>) -> Course {
+    creator.require_auth();
+
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+    if course.status == CourseStatus::Archived {
+        handle_error(env, Error::CourseAlreadyArchived)
+    }
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if let Some(ref new_slug) = slug {
+        validate_slug(env, new_slug);
+
+        let new_key: (Symbol, String) = (SLUG_KEY, new_slug.clone());
+        if let Some(owner) = env.storage().persistent().get::<_, u128>(&new_key) {
+            if owner != course_id {
+                handle_error(env, Error::DuplicateSlug)
+            }
+        }
+    }
+
+    release_slug(env, &course);
+    if let Some(ref new_slug) = slug {
+        env.storage()
+            .persistent()
+            .set(&(SLUG_KEY, new_slug.clone()), &course_id);
+    }
+
+    course.slug = slug;
+    course.updated_at = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course_id), &course);
+
+    env.events()
+        .publish((SET_SLUG_EVENT, course_id), course.slug.clone());
+
+    course
+}
+
+/// Retrieves a course by its slug.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if no course uses the slug.
+pub fn get_course_by_slug(env: &Env, slug: String) -> Course {
+    let course_id: u128 = match env.storage().persistent().get(&(SLUG_KEY, slug)) {
+        Some(id) => id,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    get_course(env, course_id)
+}
+
+/// Frees the slug held by a course, if any.
+pub fn release_slug(env: &Env, course: &Course) {
+    if let Some(ref slug) = course.slug {
+        env.storage()
+            .persistent()
+            .remove(&(SLUG_KEY, slug.clone()));
+    }
+}
+
+fn validate_slug(env: &Env, slug: &String) {
+    let len = slug.len();
+    if len == 0 || len > MAX_SLUG_LEN {
+        handle_error(env, Error::InvalidSlug)
+    }
+
+    let mut buf = [0u8; MAX_SLUG_LEN as usize];
+    let bytes = &mut buf[..len as usize];
+    slug.copy_into_slice(bytes);
+
+    let mut previous_hyphen = true; // disallows a leading hyphen
+    for &b in bytes.iter() {
+        match b {
+            b'a'..=b'z' | b'0'..=b'9' => previous_hyphen = false,
+            b'-' if !previous_hyphen => previous_hyphen = true,
+            _ => handle_error(env, Error::InvalidSlug),
+        }
+    }
+    if previous_hyphen {
+        handle_error(env, Error::InvalidSlug)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_set_and_get_course_by_slug() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Intro to Soroban");
+        let slug = String::from_str(&env, "intro-to-soroban");
+
+        let updated = client.set_course_slug(&creator, &course.id, &Some(slug.clone()));
+        assert_eq!(updated.slug, Some(slug.clone()));
+        assert_eq!(client.get_course_by_slug(&slug).id, course.id);
+    }
+
+    #[test]
+    fn test_changing_slug_frees_old_one() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Intro to Soroban");
+        let other = create_course(&client, &creator, "Advanced Soroban");
+        let old_slug = String::from_str(&env, "soroban");
+
+        client.set_course_slug(&creator, &course.id, &Some(old_slug.clone()));
+        client.set_course_slug(&creator, &course.id, &Some(String::from_str(&env, "soroban-101")));
+
+        assert!(client.try_get_course_by_slug(&old_slug).is_err());
+        client.set_course_slug(&creator, &other.id, &Some(old_slug.clone()));
+        assert_eq!(client.get_course_by_slug(&old_slug).id, other.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #38)")]
+    fn test_duplicate_slug() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course1 = create_course(&client, &creator, "Course 1");
+        let course2 = create_course(&client, &creator, "Course 2");
+        let slug = String::from_str(&env, "shared");
+
+        client.set_course_slug(&creator, &course1.id, &Some(slug.clone()));
+        client.set_course_slug(&creator, &course2.id, &Some(slug));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #37)")]
+    fn test_invalid_slug() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Course 1");
+        client.set_course_slug(&creator, &course.id, &Some(String::from_str(&env, "Intro To Soroban")));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_slug_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Course 1");
+        client.set_course_slug(
+            &Address::generate(&env),
+            &course.id,
+            &Some(String::from_str(&env, "course-1")),
+        );
+    }
+}
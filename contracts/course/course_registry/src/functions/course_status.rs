@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_STATUS_EVENT: Symbol = symbol_short!("setStatus");
+
+/// Suspends a course, hiding it from listings and blocking reads and enrollment.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - admin: admin performing the suspension (must authorize).
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - Course: the updated course record.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+/// - Returns `Error::CourseSuspended` if the course is already suspended.
+pub fn suspend_course(env: &Env, admin: Address, course_id: u128) -> Course {
+    let mut course: Course = load_as_admin(env, &admin, course_id);
+
+    match course.status {
+        CourseStatus::Archived => handle_error(env, Error::CourseAlreadyArchived),
+        CourseStatus::Suspended => handle_error(env, Error::CourseSuspended),
+        CourseStatus::Draft | CourseStatus::Published => {}
+    }
+
+    course.status = CourseStatus::Suspended;
+    save_status(env, &course);
+    course
+}
+
+/// Lifts a suspension. The course returns to `Draft` so its creator can
+/// review it before publishing again.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the course is not suspended.
+pub fn reinstate_course(env: &Env, admin: Address, course_id: u128) -> Course {
+    let mut course: Course = load_as_admin(env, &admin, course_id);
+
+    if course.status != CourseStatus::Suspended {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    course.status = CourseStatus::Draft;
+    save_status(env, &course);
+    course
+}
+
+fn load_as_admin(env: &Env, admin: &Address, course_id: u128) -> Course {
+    admin.require_auth();
+
+    if !is_admin(env, admin) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found")
+}
+
+fn save_status(env: &Env, course: &Course) {
+    let mut course = course.clone();
+    course.updated_at = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course.id), &course);
+
+    env.events()
+        .publish((SET_STATUS_EVENT, course.id), course.status);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::{CourseFilters, CourseLevel, CourseStatus, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address) {
+        env.mock_all_auths();
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let admin = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &admin, &user_mgmt_id);
+        });
+        (CourseRegistryClient::new(env, &contract_id), admin)
+    }
+
+    fn publish_params(published: bool) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: Some(published),
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_suspend_and_reinstate_course() {
+        let env = Env::default();
+        let (client, admin) = setup(&env);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params(true));
+
+        let suspended = client.suspend_course(&admin, &course.id);
+        assert_eq!(suspended.status, CourseStatus::Suspended);
+        assert!(client.try_get_course(&course.id).is_err());
+        let filters = CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: CourseLevel::Unspecified,
+            min_duration: None,
+            max_duration: None,
+        };
+        assert_eq!(client.list_courses_with_filters(&filters, &None, &None).len(), 0);
+
+        // The creator cannot republish a suspended course
+        assert!(client
+            .try_edit_course(&creator, &course.id, &publish_params(true))
+            .is_err());
+
+        let reinstated = client.reinstate_course(&admin, &course.id);
+        assert_eq!(reinstated.status, CourseStatus::Draft);
+        assert_eq!(client.get_course(&course.id).status, CourseStatus::Draft);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_reinstate_course_not_suspended() {
+        let env = Env::default();
+        let (client, admin) = setup(&env);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.reinstate_course(&admin, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_suspend_course_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.suspend_course(&creator, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_reinstate_course_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.suspend_course(&admin, &course.id);
+
+        client.reinstate_course(&creator, &course.id);
+    }
+}
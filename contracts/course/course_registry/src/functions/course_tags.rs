@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::course_index::page_listed_courses;
+use super::utils::normalize_title;
+use crate::error::{handle_error, CatalogError, Error};
+use crate::schema::{Course, DataKey, MAX_COURSE_TAGS};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const ADD_TAG_EVENT: Symbol = symbol_short!("addTag");
+const REMOVE_TAG_EVENT: Symbol = symbol_short!("rmTag");
+
+/// Longest tag accepted, in bytes.
+const MAX_TAG_LEN: u32 = 50;
+
+/// Adds a tag to a course and records the course in the tag index.
+///
+/// Tags are stored normalized (trimmed, lowercased, single-spaced), so
+/// "Smart Contracts" and "smart  contracts" are the same tag. Adding a tag the
+/// course already has does nothing.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - tag: the tag to add.
+///
+/// Returns:
+/// - Vec<String>: the tags of the course.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the tag is empty or too long.
+/// - Returns `CatalogError::TagLimitReached` if the course already has
+///   `MAX_COURSE_TAGS` tags.
+///
+/// Storage used:
+/// - DataKey::CourseTags(course_id) -> Vec<String>
+/// - DataKey::TagCourses(tag) -> Vec<u128>
+pub fn add_tag(env: &Env, caller: Address, course_id: u128, tag: String) -> Vec<String> {
+    let _course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+    require_course_management_auth(env, &caller, course_id);
+
+    let tag: String = normalize_title(env, &tag);
+    if tag.is_empty() || tag.len() > MAX_TAG_LEN {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut tags: Vec<String> = get_course_tags(env, course_id);
+    if tags.contains(&tag) {
+        return tags;
+    }
+    if tags.len() >= MAX_COURSE_TAGS {
+        handle_error(env, CatalogError::TagLimitReached)
+    }
+
+    tags.push_back(tag.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseTags(course_id), &tags);
+
+    let mut ids: Vec<u128> = tagged_course_ids(env, &tag);
+    ids.push_back(course_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::TagCourses(tag.clone()), &ids);
+
+    env.events().publish((ADD_TAG_EVENT, course_id), tag);
+
+    tags
+}
+
+/// Removes a tag from a course and drops the course from the tag index.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `CatalogError::TagNotFound` if the course does not have the tag.
+pub fn remove_tag(env: &Env, caller: Address, course_id: u128, tag: String) -> Vec<String> {
+    require_course_management_auth(env, &caller, course_id);
+
+    let tag: String = normalize_title(env, &tag);
+    let mut tags: Vec<String> = get_course_tags(env, course_id);
+    let index = match tags.first_index_of(&tag) {
+        Some(index) => index,
+        None => handle_error(env, CatalogError::TagNotFound),
+    };
+    tags.remove(index);
+    if tags.is_empty() {
+        env.storage().persistent().remove(&DataKey::CourseTags(course_id));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::CourseTags(course_id), &tags);
+    }
+    unindex_tag(env, course_id, &tag);
+
+    env.events().publish((REMOVE_TAG_EVENT, course_id), tag);
+
+    tags
+}
+
+/// Returns the tags of a course, in the order they were added.
+pub fn get_course_tags(env: &Env, course_id: u128) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseTags(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Lists published, public courses carrying a tag, in tagging order.
+///
+/// Reads the tag index instead of scanning every course. The tag is
+/// normalized the same way as in `add_tag`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn get_courses_by_tag(env: &Env, tag: String, offset: u32, limit: u32) -> Vec<Course> {
+    let tag: String = normalize_title(env, &tag);
+    page_listed_courses(env, &tagged_course_ids(env, &tag), offset, limit)
+}
+
+/// Removes every tag of a course from the tag index. Used when the course is deleted.
+pub fn delete_course_tags(env: &Env, course_id: u128) {
+    for tag in get_course_tags(env, course_id).iter() {
+        unindex_tag(env, course_id, &tag);
+    }
+    env.storage().persistent().remove(&DataKey::CourseTags(course_id));
+}
+
+fn tagged_course_ids(env: &Env, tag: &String) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TagCourses(tag.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn unindex_tag(env: &Env, course_id: u128, tag: &String) {
+    let mut ids: Vec<u128> = tagged_course_ids(env, tag);
+    if let Some(i) = ids.first_index_of(course_id) {
+        ids.remove(i);
+    }
+    let key = DataKey::TagCourses(tag.clone());
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &ids);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_published_course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_get_courses_by_tag() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let soroban = create_published_course(&client, &creator, "Soroban", 1000);
+        let solidity = create_published_course(&client, &creator, "Solidity", 1000);
+        client.add_tag(&creator, &soroban.id, &String::from_str(&env, "Smart Contracts"));
+        client.add_tag(&creator, &solidity.id, &String::from_str(&env, "smart  contracts"));
+        client.add_tag(&creator, &soroban.id, &String::from_str(&env, "Rust"));
+
+        let tagged = client.get_courses_by_tag(&String::from_str(&env, "smart contracts"), &0, &10);
+        assert_eq!(tagged.len(), 2);
+        assert_eq!(client.get_courses_by_tag(&String::from_str(&env, "rust"), &0, &10).len(), 1);
+
+        client.remove_tag(&creator, &soroban.id, &String::from_str(&env, "Smart Contracts"));
+        let tagged = client.get_courses_by_tag(&String::from_str(&env, "smart contracts"), &0, &10);
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged.get(0).unwrap().id, solidity.id);
+        assert_eq!(client.get_course_tags(&soroban.id).len(), 1);
+
+        client.delete_course(&creator, &solidity.id);
+        assert!(client
+            .get_courses_by_tag(&String::from_str(&env, "smart contracts"), &0, &10)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #604)")]
+    fn test_add_tag_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Tagged", 1000);
+
+        let tags = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"];
+        for tag in tags.iter() {
+            client.add_tag(&creator, &course.id, &String::from_str(&env, tag));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::course_index::module_ids;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseModule, CourseStats};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_DURATION_EVENT: Symbol = symbol_short!("setModDur");
+
+/// Longest module duration accepted (1,000 hours), in minutes.
+const MAX_MODULE_DURATION_MINUTES: u32 = 60_000;
+
+/// Sets the duration of a module that has no lessons, then updates the
+/// course totals.
+///
+/// Once a module has lessons its duration is the sum of theirs, so this is
+/// rejected for modules with lessons; edit the lessons instead.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: unique identifier of the module.
+/// - duration_minutes: expected time to complete the module.
+///
+/// Returns:
+/// - CourseModule: the updated module.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the module has lessons or the duration
+///   is too long.
+pub fn set_module_duration(env: &Env, caller: Address, module_id: String, duration_minutes: u32) -> CourseModule {
+    let key = (MODULE_KEY, module_id.clone());
+    let mut module: CourseModule = match env.storage().persistent().get(&key) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
+    require_course_management_auth(env, &caller, module.course_id);
+
+    if module.lesson_count > 0 || duration_minutes > MAX_MODULE_DURATION_MINUTES {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    module.duration_minutes = duration_minutes;
+    env.storage().persistent().set(&key, &module);
+    recompute_course_totals(env, module.course_id);
+
+    env.events()
+        .publish((SET_DURATION_EVENT, module.course_id), (module_id, duration_minutes));
+
+    module
+}
+
+/// Returns the module count, lesson count and total duration of a course.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+pub fn get_course_stats(env: &Env, course_id: u128) -> CourseStats {
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+
+    CourseStats {
+        module_count: module_ids(env, course_id).len(),
+        lesson_count: course.lesson_count,
+        total_duration_minutes: course.total_duration_minutes,
+    }
+}
+
+/// Recomputes the lesson count and total duration of a course from its modules.
+///
+/// Called after every module mutation so `Course::lesson_count` and
+/// `Course::total_duration_minutes` always match the sum of the module
+/// aggregates. Does nothing if the course does not exist.
+pub fn recompute_course_totals(env: &Env, course_id: u128) {
+    let key = (COURSE_KEY, course_id);
+    let mut course: Course = match env.storage().persistent().get(&key) {
+        Some(course) => course,
+        None => return,
+    };
+
+    let mut lesson_count: u32 = 0;
+    let mut total_duration_minutes: u32 = 0;
+    for module_id in module_ids(env, course_id).iter() {
+        let module: Option<CourseModule> = env.storage().persistent().get(&(MODULE_KEY, module_id));
+        if let Some(module) = module {
+            lesson_count = lesson_count.saturating_add(module.lesson_count);
+            total_duration_minutes = total_duration_minutes.saturating_add(module.duration_minutes);
+        }
+    }
+
+    if course.lesson_count != lesson_count || course.total_duration_minutes != total_duration_minutes {
+        course.lesson_count = lesson_count;
+        course.total_duration_minutes = total_duration_minutes;
+        env.storage().persistent().set(&key, &course);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, String};
+
+    #[test]
+    fn test_course_totals_follow_modules() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(course.lesson_count, 0);
+        assert_eq!(course.total_duration_minutes, 0);
+
+        let module1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module 1"));
+        let module2 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2"));
+
+        env.as_contract(&contract_id, || {
+            for (module, lessons, minutes) in [(module1.clone(), 4, 90), (module2.clone(), 8, 130)] {
+                let mut module = module;
+                module.lesson_count = lessons;
+                module.duration_minutes = minutes;
+                env.storage()
+                    .persistent()
+                    .set(&(MODULE_KEY, module.id.clone()), &module);
+            }
+            recompute_course_totals(&env, course.id);
+        });
+
+        let course = client.get_course(&course.id);
+        assert_eq!(course.lesson_count, 12);
+        assert_eq!(course.total_duration_minutes, 220);
+
+        client.remove_module(&creator, &module1.id);
+        let course = client.get_course(&course.id);
+        assert_eq!(course.lesson_count, 8);
+        assert_eq!(course.total_duration_minutes, 130);
+    }
+
+    #[test]
+    fn test_set_module_duration_and_stats() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module 1"));
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Module 2"));
+
+        client.set_module_duration(&creator, &module1.id, &45);
+
+        let stats = client.get_course_stats(&course.id);
+        assert_eq!(stats.module_count, 2);
+        assert_eq!(stats.lesson_count, 0);
+        assert_eq!(stats.total_duration_minutes, 45);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_set_module_duration_with_lessons() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module"));
+        client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Lesson"),
+            &crate::schema::LessonContentType::Text,
+            &None,
+            &20,
+        );
+
+        client.set_module_duration(&creator, &module.id, &45);
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, DataKey};
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const LINK_VARIANT_EVENT: Symbol = symbol_short!("lnkVarnt");
+const UNLINK_VARIANT_EVENT: Symbol = symbol_short!("unlnkVrnt");
+
+/// Links a course as a language variant (translation) of another course.
+///
+/// Both courses must belong to the caller. If `variant_of` is not in a group
+/// yet, a new group is created with an ID derived from `variant_of`.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: owner of both courses (must authorize).
+/// - course_id: the course to link.
+/// - variant_of: a course already in, or anchoring, the target group.
+///
+/// Returns:
+/// - BytesN<32>: the ID of the variant group.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if either course does not exist.
+/// - Returns `Error::Unauthorized` if the caller does not own both courses.
+/// - Returns `Error::InvalidInput` if both IDs are the same or `course_id` is
+///   already in a variant group.
+///
+/// Storage used:
+/// - DataKey::VariantGroup(group_id) -> Vec<u128>
+pub fn link_course_variant(env: &Env, creator: Address, course_id: u128, variant_of: u128) -> BytesN<32> {
+    creator.require_auth();
+
+    if course_id == variant_of {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut course: Course = load_course(env, course_id);
+    let mut anchor: Course = load_course(env, variant_of);
+    if course.creator != creator || anchor.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+    if course.variant_group.is_some() {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let group_id: BytesN<32> = match anchor.variant_group.clone() {
+        Some(group_id) => group_id,
+        None => {
+            let group_id = derive_group_id(env, variant_of);
+            anchor.variant_group = Some(group_id.clone());
+            save_course(env, &anchor);
+            group_id
+        }
+    };
+
+    let mut members: Vec<u128> = get_variant_ids(env, &group_id);
+    if members.is_empty() {
+        members.push_back(variant_of);
+    }
+    members.push_back(course_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::VariantGroup(group_id.clone()), &members);
+
+    course.variant_group = Some(group_id.clone());
+    save_course(env, &course);
+
+    env.events()
+        .publish((LINK_VARIANT_EVENT, course_id), group_id.clone());
+
+    group_id
+}
+
+/// Removes a course from its variant group.
+///
+/// When only one course would remain in the group, the group is dissolved.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+/// - Returns `Error::InvalidInput` if the course is not in a variant group.
+pub fn unlink_course_variant(env: &Env, creator: Address, course_id: u128) {
+    creator.require_auth();
+
+    let course: Course = load_course(env, course_id);
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+    if course.variant_group.is_none() {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    remove_from_variant_group(env, &course);
+
+    env.events().publish((UNLINK_VARIANT_EVENT, course_id), ());
+}
+
+/// Returns the courses in a variant group, in the order they were linked.
+pub fn get_course_variants(env: &Env, group_id: BytesN<32>) -> Vec<Course> {
+    let mut results: Vec<Course> = Vec::new(env);
+    for id in get_variant_ids(env, &group_id).iter() {
+        if let Some(course) = env.storage().persistent().get::<_, Course>(&(COURSE_KEY, id)) {
+            results.push_back(course);
+        }
+    }
+    results
+}
+
+/// Detaches a course from its variant group, if any, and dissolves groups
+/// left with a single member.
+pub fn remove_from_variant_group(env: &Env, course: &Course) {
+    let group_id = match course.variant_group.clone() {
+        Some(group_id) => group_id,
+        None => return,
+    };
+
+    let mut members: Vec<u128> = get_variant_ids(env, &group_id);
+    if let Some(i) = members.first_index_of(course.id) {
+        members.remove(i);
+    }
+
+    let mut detached = course.clone();
+    detached.variant_group = None;
+    save_course(env, &detached);
+
+    if members.len() <= 1 {
+        for id in members.iter() {
+            let mut last: Course = load_course(env, id);
+            last.variant_group = None;
+            save_course(env, &last);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::VariantGroup(group_id));
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::VariantGroup(group_id), &members);
+    }
+}
+
+fn get_variant_ids(env: &Env, group_id: &BytesN<32>) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::VariantGroup(group_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn derive_group_id(env: &Env, anchor_id: u128) -> BytesN<32> {
+    let data = Bytes::from_array(env, &anchor_id.to_be_bytes());
+    env.crypto().sha256(&data).to_bytes()
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    env.storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found")
+}
+
+fn save_course(env: &Env, course: &Course) {
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course.id), course);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_link_and_unlink_variants() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let english = create_course(&client, &creator, "Intro to Soroban");
+        let spanish = create_course(&client, &creator, "Introduccion a Soroban");
+        let french = create_course(&client, &creator, "Introduction a Soroban");
+
+        let group = client.link_course_variant(&creator, &spanish.id, &english.id);
+        assert_eq!(client.link_course_variant(&creator, &french.id, &spanish.id), group);
+
+        let variants = client.get_course_variants(&group);
+        assert_eq!(variants.len(), 3);
+        assert_eq!(variants.get(0).unwrap().id, english.id);
+        assert_eq!(client.get_course(&english.id).variant_group, Some(group.clone()));
+
+        client.unlink_course_variant(&creator, &french.id);
+        assert_eq!(client.get_course_variants(&group).len(), 2);
+        assert_eq!(client.get_course(&french.id).variant_group, None);
+
+        // A group left with one course is dissolved
+        client.unlink_course_variant(&creator, &spanish.id);
+        assert_eq!(client.get_course_variants(&group).len(), 0);
+        assert_eq!(client.get_course(&english.id).variant_group, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_link_variant_of_foreign_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let own = create_course(&client, &creator, "Intro to Soroban");
+        let foreign = create_course(&client, &Address::generate(&env), "Introduccion a Soroban");
+        client.link_course_variant(&creator, &own.id, &foreign.id);
+    }
+
+    #[test]
+    fn test_delete_course_leaves_variant_group() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let english = create_course(&client, &creator, "Intro to Soroban");
+        let spanish = create_course(&client, &creator, "Introduccion a Soroban");
+        let group = client.link_course_variant(&creator, &spanish.id, &english.id);
+
+        client.delete_course(&creator, &spanish.id);
+        assert_eq!(client.get_course_variants(&group).len(), 0);
+        assert_eq!(client.get_course(&english.id).variant_group, None);
+    }
+}
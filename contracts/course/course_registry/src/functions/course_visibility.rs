@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_VISIBILITY_EVENT: Symbol = symbol_short!("setVis");
+
+/// Changes who can discover and read a course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - visibility: the new visibility level.
+///
+/// Returns:
+/// - Course: the updated course record.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+pub fn set_course_visibility(
+    env: &Env,
+    creator: Address,
+    course_id: u128,
+    visibility: CourseVisibility,
+) -> Course {
+    creator.require_auth();
+
+    let key = (COURSE_KEY, course_id);
+    let mut course: Course = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("Course not found");
+
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    course.visibility = visibility;
+    course.updated_at = env.ledger().timestamp();
+    env.storage().persistent().set(&key, &course);
+
+    env.events()
+        .publish((SET_VISIBILITY_EVENT, course_id), visibility);
+
+    course
+}
+
+/// Retrieves a course on behalf of a specific viewer.
+///
+/// Unlike `get_course`, this also serves private courses to viewers allowed
+/// to read them: the course creator and admins.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+/// - Returns `Error::CourseSuspended` if the course is suspended and the viewer is not an admin.
+/// - Returns `Error::CoursePrivate` if the course is private and the viewer may not read it.
+pub fn get_course_as(env: &Env, viewer: Address, course_id: u128) -> Course {
+    viewer.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+
+    match course.status {
+        CourseStatus::Archived => handle_error(env, Error::CourseAlreadyArchived),
+        CourseStatus::Suspended if !is_admin(env, &viewer) => {
+            handle_error(env, Error::CourseSuspended)
+        }
+        _ => {}
+    }
+
+    if !can_view(env, &course, &viewer) {
+        handle_error(env, Error::CoursePrivate)
+    }
+
+    course
+}
+
+/// Returns whether `viewer` may read the metadata of `course`.
+pub fn can_view(env: &Env, course: &Course, viewer: &Address) -> bool {
+    match course.visibility {
+        CourseVisibility::Public | CourseVisibility::Unlisted => true,
+        CourseVisibility::Private => course.creator == *viewer || is_admin(env, viewer),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_published_course;
+    use crate::schema::{CourseFilters, CourseLevel, CourseVisibility};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+
+    fn no_filters() -> CourseFilters {
+        CourseFilters {
+            min_price: None,
+            max_price: None,
+            category: None,
+            level: CourseLevel::Unspecified,
+            min_duration: None,
+            max_duration: None,
+        }
+    }
+
+    #[test]
+    fn test_unlisted_course_hidden_from_listings() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let public = create_published_course(&client, &creator, "Public Course", 1000);
+        let unlisted = create_published_course(&client, &creator, "Unlisted Course", 1000);
+        client.set_course_visibility(&creator, &unlisted.id, &CourseVisibility::Unlisted);
+
+        let listed = client.list_courses_with_filters(&no_filters(), &None, &None);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed.get(0).unwrap().id, public.id);
+        assert_eq!(client.get_courses_by_instructor(&creator).len(), 1);
+
+        // Still reachable by direct ID
+        assert_eq!(client.get_course(&unlisted.id).visibility, CourseVisibility::Unlisted);
+    }
+
+    #[test]
+    fn test_private_course_readable_by_creator_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_published_course(&client, &creator, "Private Course", 1000);
+        client.set_course_visibility(&creator, &course.id, &CourseVisibility::Private);
+
+        assert!(client.try_get_course(&course.id).is_err());
+        assert!(client.try_get_course_as(&Address::generate(&env), &course.id).is_err());
+        assert_eq!(client.get_course_as(&creator, &course.id).id, course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_visibility_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_published_course(&client, &creator, "Course", 1000);
+        client.set_course_visibility(&Address::generate(&env), &course.id, &CourseVisibility::Private);
+    }
+}
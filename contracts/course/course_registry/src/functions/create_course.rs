@@ -1,13 +1,28 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::{to_lowercase, trim, u32_to_string};
+use super::category_registry::resolve_course_category;
+use super::category_stats::add_to_category_totals;
+use super::content_limits::{
+    get_content_limits, validate_description_length, validate_thumbnail_url,
+    validate_title_length,
+};
+use super::course_index::{index_category, index_language, index_level, index_price};
+use super::course_metadata::validate_metadata;
+use super::creator_stats::record_course_created;
+use super::price_limits::validate_price_range;
+use super::rate_limit::consume_creation_quota;
+use super::title_index::{hash_key, is_title_taken, reserve_title};
+use super::title_moderation::validate_title_allowed;
+use super::title_search::index_title_prefixes;
+use super::utils::{normalize_title, trim};
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, CourseLevel};
+use crate::schema::{
+    Course, CourseInput, CourseLevel, CourseStatus, CourseVisibility, MAX_BATCH_COURSES,
+};
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
-const TITLE_KEY: Symbol = symbol_short!("title");
 const COURSE_ID: Symbol = symbol_short!("course");
 
 pub fn create_course(
@@ -24,74 +39,131 @@ pub fn create_course(
 ) -> Course {
     creator.require_auth();
 
+    let input = CourseInput {
+        title,
+        description,
+        price,
+        category,
+        language,
+        thumbnail_url,
+        level: level.unwrap_or(CourseLevel::Unspecified),
+        duration_hours,
+        metadata_uri: None,
+        metadata_hash: None,
+        is_free: false,
+    };
+    store_new_course(&env, creator, input)
+}
+
+/// Creates several courses for one creator in a single call.
+///
+/// Entries are created in order and get sequential IDs. If any entry is
+/// invalid the whole call fails and none of the courses are stored.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: owner of the new courses (must authorize).
+/// - inputs: between 1 and `MAX_BATCH_COURSES` course definitions.
+///
+/// Returns:
+/// - Vec<Course>: the created courses, in input order.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the batch is empty or too large.
+/// - Any error `create_course` can return for an individual entry.
+pub fn create_courses(env: Env, creator: Address, inputs: Vec<CourseInput>) -> Vec<Course> {
+    creator.require_auth();
+
+    if inputs.is_empty() || inputs.len() > MAX_BATCH_COURSES {
+        handle_error(&env, Error::InvalidInput)
+    }
+
+    let mut courses: Vec<Course> = Vec::new(&env);
+    for input in inputs.iter() {
+        courses.push_back(store_new_course(&env, creator.clone(), input));
+    }
+    courses
+}
+
+/// Validates a course definition and stores it as a new course.
+fn store_new_course(env: &Env, creator: Address, input: CourseInput) -> Course {
+    let CourseInput {
+        title,
+        description,
+        price,
+        category,
+        language,
+        thumbnail_url,
+        level,
+        duration_hours,
+        metadata_uri,
+        metadata_hash,
+        is_free,
+    } = input;
+
     // ensure the title is not empty and not just whitespace
-    let trimmed_title = trim(&env, &title);
+    let trimmed_title = trim(env, &title);
     if title.is_empty() || trimmed_title.is_empty() {
-        handle_error(&env, Error::EmptyCourseTitle);
+        handle_error(env, Error::EmptyCourseTitle);
     }
     
-    // Additional title validation
-    if title.len() > 200 {
-        handle_error(&env, Error::InvalidInput);
-    }
+    let limits = get_content_limits(env);
+    validate_title_length(env, &limits, &title);
+    validate_title_allowed(env, &title, &creator);
 
     // Validate description - only check length, allow empty
-    if description.len() > 2000 {
-        handle_error(&env, Error::InvalidCourseDescription);
-    }
+    validate_description_length(env, &limits, &description);
 
-    // ensure the price is greater than 0
-    if price == 0 {
-        handle_error(&env, Error::InvalidPrice);
+    // ensure the price is greater than 0, or exactly 0 for free courses
+    if (price == 0) != is_free {
+        handle_error(env, Error::InvalidPrice);
     }
+    validate_price_range(env, price, is_free);
     
     // Validate optional parameters
-    if let Some(ref cat) = category {
-        if cat.is_empty() || cat.len() > 100 {
-            handle_error(&env, Error::EmptyCategory);
-        }
-    }
+    let category = resolve_course_category(env, category);
     
-    if let Some(ref lang) = language {
-        if lang.is_empty() || lang.len() > 50 {
-            handle_error(&env, Error::InvalidInput);
-        }
-    }
+    let language = normalize_language(env, language);
     
     if let Some(ref url) = thumbnail_url {
-        if url.is_empty() || url.len() > 500 {
-            handle_error(&env, Error::InvalidInput);
-        }
+        validate_thumbnail_url(env, &limits, url);
     }
+
+    validate_metadata(env, &metadata_uri, &metadata_hash);
     
     if let Some(duration) = duration_hours {
         if duration == 0 || duration > 8760 { // 8760 hours = 1 year, reasonable maximum
-            handle_error(&env, Error::InvalidInput);
+            handle_error(env, Error::InvalidInput);
         }
     }
 
-    let lowercase_title = to_lowercase(&env, &title);
-
     // to avoid duplicate title,
-    let title_key: (Symbol, String) = (TITLE_KEY, lowercase_title);
+    if is_title_taken(env, &title) {
+        handle_error(env, Error::DuplicateCourseTitle)
+    }
 
-    if env.storage().persistent().has(&title_key) {
-        handle_error(&env, Error::DuplicateCourseTitle)
+    // deterministic key derived from creator + normalized title
+    if env.storage().persistent().has(&hash_key(env, &creator, &title)) {
+        handle_error(env, Error::DuplicateCourseId)
     }
 
+    // spam prevention: per-creator creation quota
+    consume_creation_quota(env, &creator);
+
     // generate the unique id
-    let id: u128 = generate_course_id(&env);
-    let converted_id = u32_to_string(&env, id as u32);
+    let id: u128 = generate_course_id(env);
 
-    let storage_key: (Symbol, String) = (COURSE_KEY, converted_id.clone());
+    let storage_key: (Symbol, u128) = (COURSE_KEY, id);
 
     if env.storage().persistent().has(&storage_key) {
-        handle_error(&env, Error::DuplicateCourseId)
+        handle_error(env, Error::DuplicateCourseId)
     }
 
+    let now: u64 = env.ledger().timestamp();
+
     // create a new course
     let new_course: Course = Course {
-        id: converted_id.clone(),
+        id,
         title,
         description,
         creator,
@@ -99,20 +171,54 @@ pub fn create_course(
         category,
         language,
         thumbnail_url,
-        published: false,
-        prerequisites: Vec::new(&env),
-        is_archived: false,
+        status: CourseStatus::Draft,
+        prerequisites: Vec::new(env),
         level,
         duration_hours,
+        version: 1,
+        created_at: now,
+        updated_at: now,
+        slug: None,
+        visibility: CourseVisibility::Public,
+        metadata_uri,
+        metadata_hash,
+        lesson_count: 0,
+        total_duration_minutes: 0,
+        variant_group: None,
+        is_free,
+        max_students: None,
+        seats_remaining: None,
     };
 
     // save to the storage
     env.storage().persistent().set(&storage_key, &new_course);
-    env.storage().persistent().set(&title_key, &true);
+    reserve_title(env, &new_course);
+    index_level(env, id, new_course.level);
+    index_category(env, id, &new_course.category);
+    add_to_category_totals(env, &new_course.category, new_course.price, 0);
+    index_language(env, id, &new_course.language);
+    index_price(env, id, new_course.price);
+    index_title_prefixes(env, id, &new_course.title);
+    record_course_created(env, &new_course.creator);
 
     new_course
 }
 
+/// Validates a language code and returns it in its stored form.
+///
+/// Codes are trimmed and lowercased, so "EN" and " en " are both stored as
+/// "en" and land in the same language index.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the code is blank or longer than 50 bytes.
+pub fn normalize_language(env: &Env, language: Option<String>) -> Option<String> {
+    let lang: String = normalize_title(env, &language?);
+    if lang.is_empty() || lang.len() > 50 {
+        handle_error(env, Error::InvalidInput);
+    }
+    Some(lang)
+}
+
 pub fn generate_course_id(env: &Env) -> u128 {
     let current_id: u128 = env.storage().persistent().get(&COURSE_ID).unwrap_or(0);
     let new_id = current_id + 1;
@@ -156,7 +262,7 @@ mod test {
         let price = 1000_u128;
         let category = Some(String::from_str(&env, "category"));
         let language = Some(String::from_str(&env, "language"));
-        let thumbnail_url = Some(String::from_str(&env, "thumbnail_url"));
+        let thumbnail_url = Some(String::from_str(&env, "https://example.com/thumbnail.png"));
         let course: Course = client.create_course(
             &creator,
             &title,
@@ -171,12 +277,12 @@ mod test {
         let course = client.get_course(&course.id);
         assert_eq!(course.title, title);
         assert_eq!(course.description, description);
-        assert_eq!(course.id, String::from_str(&env, "1"));
+        assert_eq!(course.id, 1);
         assert_eq!(course.price, price);
         assert_eq!(course.category, category);
         assert_eq!(course.language, language);
         assert_eq!(course.thumbnail_url, thumbnail_url);
-        assert!(!course.published);
+        assert_eq!(course.status, CourseStatus::Draft);
     }
 
     #[test]
@@ -222,7 +328,7 @@ mod test {
 
         assert_eq!(stored_course.title, another_course_title);
         assert_eq!(stored_course.description, another_course_description);
-        assert_eq!(stored_course.id, String::from_str(&env, "2"));
+        assert_eq!(stored_course.id, 2);
         assert_eq!(stored_course.price, another_price);
     }
 
@@ -371,6 +477,42 @@ mod test {
         );
     }
 
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #10)")]
+    fn test_duplicate_title_whitespace_insensitive() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let title1: String = String::from_str(&env, "Rust Basics");
+        let title2: String = String::from_str(&env, "  rust   BASICS ");
+        let description: String = String::from_str(&env, "A description");
+        let price: u128 = crate::schema::DEFAULT_COURSE_PRICE;
+
+        client.create_course(
+            &Address::generate(&env),
+            &title1,
+            &description,
+            &price,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.create_course(
+            &Address::generate(&env),
+            &title2,
+            &description,
+            &price,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
     #[test]
     fn test_create_course_with_long_title() {
         let env: Env = Env::default();
@@ -394,7 +536,7 @@ mod test {
         );
         assert_eq!(course.title, long_title);
         assert_eq!(course.price, price);
-        assert_eq!(course.id, String::from_str(&env, "1"));
+        assert_eq!(course.id, 1);
     }
 
     #[test]
@@ -461,12 +603,12 @@ mod test {
         let description: String = String::from_str(&env, "Course with all fields");
         let price: u128 = 3000;
         let category: Option<String> = Some(String::from_str(&env, "Web Development"));
-        let language: Option<String> = Some(String::from_str(&env, "Spanish"));
+        let language: Option<String> = Some(String::from_str(&env, "spanish"));
         let thumbnail_url: Option<String> = Some(String::from_str(
             &env,
             "https://example.com/course-thumbnail.png",
         ));
-        let level: Option<CourseLevel> = Some(String::from_str(&env, "Intermediate"));
+        let level: Option<CourseLevel> = Some(CourseLevel::Intermediate);
         let duration_hours: Option<u32> = Some(40);
 
         let course = client.create_course(
@@ -486,9 +628,9 @@ mod test {
         assert_eq!(course.category, category);
         assert_eq!(course.language, language);
         assert_eq!(course.thumbnail_url, thumbnail_url);
-        assert_eq!(course.level, level);
+        assert_eq!(course.level, CourseLevel::Intermediate);
         assert_eq!(course.duration_hours, duration_hours);
-        assert!(!course.published);
+        assert_eq!(course.status, CourseStatus::Draft);
     }
 
     #[test]
@@ -590,9 +732,9 @@ mod test {
             &None,
         );
 
-        assert_eq!(course1.id, String::from_str(&env, "1"));
-        assert_eq!(course2.id, String::from_str(&env, "2"));
-        assert_eq!(course3.id, String::from_str(&env, "3"));
+        assert_eq!(course1.id, 1);
+        assert_eq!(course2.id, 2);
+        assert_eq!(course3.id, 3);
     }
 
     #[test]
@@ -607,7 +749,7 @@ mod test {
             "Curso de programación con caracteres especiales: áéíóú ñ",
         );
         let price: u128 = 2000;
-        let language: Option<String> = Some(String::from_str(&env, "Español"));
+        let language: Option<String> = Some(String::from_str(&env, "español"));
 
         let course = client.create_course(
             &Address::generate(&env),
@@ -624,4 +766,112 @@ mod test {
         assert_eq!(course.description, description);
         assert_eq!(course.language, language);
     }
+
+    fn course_input(env: &Env, title: &str) -> CourseInput {
+        CourseInput {
+            title: String::from_str(env, title),
+            description: String::from_str(env, "A description"),
+            price: 1000,
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            level: CourseLevel::Unspecified,
+            duration_hours: None,
+            metadata_uri: None,
+            metadata_hash: None,
+            is_free: false,
+        }
+    }
+
+    #[test]
+    fn test_create_courses_batch() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let inputs = soroban_sdk::vec![
+            &env,
+            course_input(&env, "Course A"),
+            course_input(&env, "Course B"),
+            course_input(&env, "Course C"),
+        ];
+        let courses = client.create_courses(&creator, &inputs);
+
+        assert_eq!(courses.len(), 3);
+        for (i, course) in courses.iter().enumerate() {
+            assert_eq!(course.id, i as u128 + 1);
+            assert_eq!(course.creator, creator);
+            assert_eq!(client.get_course(&course.id), course);
+        }
+    }
+
+    #[test]
+    fn test_create_courses_is_atomic() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let mut invalid = course_input(&env, "Course C");
+        invalid.price = 0;
+        let inputs = soroban_sdk::vec![
+            &env,
+            course_input(&env, "Course A"),
+            course_input(&env, "Course B"),
+            invalid,
+        ];
+
+        assert!(client.try_create_courses(&creator, &inputs).is_err());
+        assert!(client.try_get_course(&1).is_err());
+
+        // Nothing was reserved, so the same titles can still be used
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course A"),
+            &String::from_str(&env, "A description"),
+            &1000,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(course.id, 1);
+    }
+
+    #[test]
+    fn test_create_free_course() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator: Address = Address::generate(&env);
+
+        let mut free = course_input(&env, "Free Course");
+        free.price = 0;
+        free.is_free = true;
+        let courses = client.create_courses(&creator, &soroban_sdk::vec![&env, free]);
+        let course = courses.get(0).unwrap();
+        assert!(course.is_free);
+        assert_eq!(course.price, 0);
+
+        let mut priced = course_input(&env, "Priced Free Course");
+        priced.is_free = true;
+        let inputs = soroban_sdk::vec![&env, priced];
+        assert!(client.try_create_courses(&creator, &inputs).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_create_courses_empty_batch() {
+        let env: Env = Env::default();
+        env.mock_all_auths();
+        let contract_id: Address = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.create_courses(&Address::generate(&env), &Vec::new(&env));
+    }
 }
@@ -1,24 +1,37 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use crate::error::{handle_error, Error};
+use super::access_control::is_admin;
+use super::category_stats::adopt_category_totals;
+use super::course_index::adopt_category_courses;
+use super::utils::normalize_title;
+use crate::error::{handle_error, CatalogError, Error};
 use crate::schema::{CourseCategory, DataKey};
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const CREATE_CATEGORY_EVENT: Symbol = symbol_short!("newCat");
 
 /// Creates a new course category (admin-only).
 ///
+/// Once at least one category exists, courses can only be filed under a
+/// registered, active category.
+///
 /// Arguments:
 /// - env: Soroban environment.
 /// - caller: transaction caller (must be admin).
-/// - name: category name (must be non-empty).
+/// - name: category name (must be non-empty and unique, ignoring case).
 /// - description: optional category description.
 ///
 /// Returns:
 /// - u128: the newly created category ID.
 ///
-/// Storage used (replace keys if your schema differs):
-/// - ("category_seq",) -> u128                // sequence counter
-/// - (("category", id),) -> CourseCategory    // category record by id
+/// Errors:
+/// - Returns `CatalogError::CategoryExists` if a category already uses the name.
+///
+/// Storage used:
+/// - DataKey::CategorySeq -> u128                 // sequence counter
+/// - DataKey::CourseCategory(id) -> CourseCategory // category record by id
+/// - DataKey::CategoryByName(name) -> u128         // normalized name index
 pub fn create_course_category(
     env: Env,
     caller: Address,
@@ -27,19 +40,11 @@ pub fn create_course_category(
 ) -> u128 {
     // Authentication and authorization
     caller.require_auth();
-    if !is_admin(&env, caller) {
+    if !is_admin(&env, &caller) {
         handle_error(&env, Error::Unauthorized)
     }
 
-    // Basic validation
-    if name.is_empty() {
-        handle_error(&env, Error::NameRequired)
-    }
-    
-    // Additional validation for category names
-    if name.len() > 100 {
-        handle_error(&env, Error::InvalidCategoryName);
-    }
+    validate_category_name(&env, &name);
     
     // Validate description if provided
     if let Some(ref desc) = description {
@@ -48,32 +53,41 @@ pub fn create_course_category(
         }
     }
 
+    let name_key = DataKey::CategoryByName(normalize_title(&env, &name));
+    if env.storage().persistent().has(&name_key) {
+        handle_error(&env, CatalogError::CategoryExists)
+    }
+
     // Generate a new category ID
     let id = next_category_id(&env);
 
     // Build and persist the category
     let category = CourseCategory {
         id,
-        name,
+        name: name.clone(),
         description,
+        active: true,
     };
     env.storage()
         .persistent()
         .set(&DataKey::CourseCategory(id), &category);
+    env.storage().persistent().set(&name_key, &id);
+    adopt_category_courses(&env, &category.name, id);
+    adopt_category_totals(&env, &category.name, id);
+
+    env.events().publish((CREATE_CATEGORY_EVENT, id), name);
 
     // Return the new ID
     id
 }
 
-/// Checks whether who is an admin using the same pattern as user_management contract.
-/// This assumes the course_registry contract has its own admin system or uses a similar pattern.
-fn is_admin(env: &Env, who: Address) -> bool {
-    // For now, we'll use a simple storage-based admin check
-    // In a production environment, you might want to integrate with the user_management contract
-    let admins: Option<Vec<Address>> = env.storage().persistent().get(&DataKey::Admins);
-    match admins {
-        Some(list) => list.iter().any(|a| a == who),
-        None => false,
+/// Rejects category names that are empty or longer than 100 bytes.
+pub fn validate_category_name(env: &Env, name: &String) {
+    if name.is_empty() {
+        handle_error(env, Error::NameRequired)
+    }
+    if name.len() > 100 {
+        handle_error(env, Error::InvalidCategoryName);
     }
 }
 
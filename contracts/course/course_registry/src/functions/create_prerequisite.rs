@@ -1,21 +1,22 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::prerequisites::{get_prerequisites, save_prerequisites, validate_no_circular_dependency};
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, DataKey};
-use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
+use crate::schema::Course;
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 const PREREQ_CREATED_EVENT: Symbol = symbol_short!("prereqAdd");
 
-pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequisites: Vec<String>) {
+/// Adds prerequisite courses to a course.
+///
+/// New IDs are appended to the existing list; IDs already present are ignored.
+/// A course may have at most 20 prerequisites.
+pub fn add_prerequisite(env: Env, creator: Address, course_id: u128, prerequisites: Vec<u128>) {
     creator.require_auth();
     
     // Validate input parameters
-    if course_id.is_empty() {
-        handle_error(&env, Error::EmptyCourseId);
-    }
-    
-    if course_id.len() > 100 {
+    if course_id == 0 {
         handle_error(&env, Error::EmptyCourseId);
     }
     
@@ -31,11 +32,7 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
     
     // Validate each prerequisite ID
     for prerequisite_id in prerequisites.iter() {
-        if prerequisite_id.is_empty() {
-            handle_error(&env, Error::InvalidInput);
-        }
-        
-        if prerequisite_id.len() > 100 {
+        if prerequisite_id == 0 {
             handle_error(&env, Error::InvalidInput);
         }
         
@@ -45,7 +42,7 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
         }
     }
 
-    let course_key: (Symbol, String) = (symbol_short!("course"), course_id.clone());
+    let course_key: (Symbol, u128) = (symbol_short!("course"), course_id);
     let course: Course = env
         .storage()
         .persistent()
@@ -57,92 +54,29 @@ pub fn add_prerequisite(env: Env, creator: Address, course_id: String, prerequis
     }
 
     for prerequisite_id in prerequisites.iter() {
-        let prereq_course_key: (Symbol, String) =
-            (symbol_short!("course"), prerequisite_id.clone());
+        let prereq_course_key: (Symbol, u128) = (symbol_short!("course"), prerequisite_id);
         if !env.storage().persistent().has(&prereq_course_key) {
             handle_error(&env, Error::PrereqCourseNotFound)
         }
     }
 
-    validate_no_circular_dependency(&env, &course_id, &prerequisites);
-
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &prerequisites,
-    );
-
-    env.events().publish(
-        (PREREQ_CREATED_EVENT, course_id),
-        prerequisites.len() as u32,
-    );
-}
-
-fn validate_no_circular_dependency(env: &Env, course_id: &String, new_prerequisites: &Vec<String>) {
-    // Check if course_id appears in new_prerequisites (direct circular dependency)
-    for prerequisite_id in new_prerequisites.iter() {
-        if prerequisite_id.eq(course_id) {
-            handle_error(&env, Error::SelfPrerequisite)
-        }
-    }
-
-    // Check for indirect circular dependencies using DFS
-    let mut visited = Map::new(env);
-    let mut rec_stack = Map::new(env);
+    validate_no_circular_dependency(&env, course_id, &prerequisites);
 
-    for prerequisite_id in new_prerequisites.iter() {
-        if has_cycle(
-            env,
-            &prerequisite_id,
-            course_id,
-            &mut visited,
-            &mut rec_stack,
-        ) {
-            handle_error(&env, Error::CircularDependency)
+    // Append to the existing list, skipping IDs that are already required
+    let mut merged: Vec<u128> = get_prerequisites(&env, course_id);
+    for prerequisite_id in prerequisites.iter() {
+        if !merged.contains(prerequisite_id) {
+            merged.push_back(prerequisite_id);
         }
     }
-}
-
-fn has_cycle(
-    env: &Env,
-    current_course: &String,
-    target_course: &String,
-    visited: &mut Map<String, bool>,
-    rec_stack: &mut Map<String, bool>,
-) -> bool {
-    // If we've reached the target course, we found a cycle
-    if current_course.eq(target_course) {
-        return true;
-    }
-
-    // If already in recursion stack, we have a cycle
-    if rec_stack.contains_key(current_course.clone()) {
-        return true;
-    }
-
-    // If already visited and not in recursion stack, no cycle from this path
-    if visited.contains_key(current_course.clone()) {
-        return false;
+    if merged.len() > 20 {
+        handle_error(&env, Error::InvalidInput);
     }
 
-    // Mark as visited and add to recursion stack
-    visited.set(current_course.clone(), true);
-    rec_stack.set(current_course.clone(), true);
+    save_prerequisites(&env, course_id, &merged);
 
-    // Get prerequisites for current course
-    let prerequisites: Vec<String> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::CoursePrerequisites(current_course.clone()))
-        .unwrap_or(Vec::new(env));
-
-    // Recursively check all prerequisites
-    for prerequisite in prerequisites.iter() {
-        if has_cycle(env, &prerequisite, target_course, visited, rec_stack) {
-            return true;
-        }
-    }
-
-    // Remove from recursion stack before returning
-    rec_stack.remove(current_course.clone());
-    false
+    env.events().publish(
+        (PREREQ_CREATED_EVENT, course_id),
+        merged.len() as u32,
+    );
 }
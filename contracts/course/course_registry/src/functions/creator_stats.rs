@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::{CreatorStats, DataKey, InstructorSummary, MAX_PAGE_SIZE};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Returns the course counters of a creator.
+///
+/// Creators who never created a course get zeroed stats.
+///
+/// Storage used:
+/// - DataKey::CreatorStats(creator) -> CreatorStats
+pub fn get_creator_stats(env: &Env, creator: Address) -> CreatorStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorStats(creator))
+        .unwrap_or_default()
+}
+
+/// Counts a newly stored course towards its creator.
+pub fn record_course_created(env: &Env, creator: &Address) {
+    let mut stats = get_creator_stats(env, creator.clone());
+    if stats.total_created == 0 {
+        let mut instructors = instructors(env);
+        instructors.push_back(creator.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Instructors, &instructors);
+    }
+    stats.course_count += 1;
+    stats.total_created += 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorStats(creator.clone()), &stats);
+}
+
+/// Removes a deleted course from its creator's live course count.
+pub fn record_course_deleted(env: &Env, creator: &Address) {
+    let mut stats = get_creator_stats(env, creator.clone());
+    stats.course_count = stats.course_count.saturating_sub(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorStats(creator.clone()), &stats);
+}
+
+/// Counts an enrollment in one of the creator's courses.
+pub fn record_creator_enrollment(env: &Env, creator: &Address) {
+    let mut stats = get_creator_stats(env, creator.clone());
+    stats.enrollment_count += 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorStats(creator.clone()), &stats);
+}
+
+/// Adds a rating of one of the creator's courses, replacing `previous` if the
+/// student had already rated it.
+pub fn record_creator_rating(env: &Env, creator: &Address, previous: Option<u32>, rating: u32) {
+    let mut stats = get_creator_stats(env, creator.clone());
+    match previous {
+        Some(previous) => stats.rating_total -= previous as u64,
+        None => stats.rating_count += 1,
+    }
+    stats.rating_total += rating as u64;
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorStats(creator.clone()), &stats);
+}
+
+/// Returns the instructors with the most enrollments, best rated first on ties.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - limit: number of instructors to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+///
+/// Storage used:
+/// - DataKey::Instructors -> Vec<Address>
+pub fn get_top_instructors(env: &Env, limit: u32) -> Vec<InstructorSummary> {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut ranked: Vec<InstructorSummary> = Vec::new(env);
+    for instructor in instructors(env).iter() {
+        let stats = get_creator_stats(env, instructor.clone());
+        if stats.course_count == 0 {
+            continue;
+        }
+        let summary = InstructorSummary {
+            instructor,
+            course_count: stats.course_count,
+            enrollment_count: stats.enrollment_count,
+            average_rating: if stats.rating_count == 0 {
+                0
+            } else {
+                (stats.rating_total * 100 / stats.rating_count as u64) as u32
+            },
+        };
+
+        let mut i = ranked.len();
+        while i > 0 && ranks_below(&ranked.get_unchecked(i - 1), &summary) {
+            i -= 1;
+        }
+        if i < limit {
+            ranked.insert(i, summary);
+            if ranked.len() > limit {
+                ranked.pop_back();
+            }
+        }
+    }
+    ranked
+}
+
+fn ranks_below(a: &InstructorSummary, b: &InstructorSummary) -> bool {
+    (a.enrollment_count, a.average_rating) < (b.enrollment_count, b.average_rating)
+}
+
+fn instructors(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Instructors)
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_creator_stats_track_create_and_delete() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        let stats = client.get_creator_stats(&creator);
+        assert_eq!(stats.course_count, 0);
+        assert_eq!(stats.total_created, 0);
+
+        let course = create_course(&client, &creator, "Course 1");
+        create_course(&client, &creator, "Course 2");
+        create_course(&client, &other, "Course 3");
+        client.delete_course(&creator, &course.id);
+
+        let stats = client.get_creator_stats(&creator);
+        assert_eq!(stats.course_count, 1);
+        assert_eq!(stats.total_created, 2);
+        assert_eq!(client.get_creator_stats(&other).course_count, 1);
+    }
+
+    #[test]
+    fn test_creator_stats_count_clones() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Course 1");
+        client.clone_course(&creator, &course.id, &String::from_str(&env, "Course 1 copy"));
+
+        assert_eq!(client.get_creator_stats(&creator).course_count, 2);
+    }
+
+    #[test]
+    fn test_get_top_instructors() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let quiet = Address::generate(&env);
+        let popular = Address::generate(&env);
+        let params = publish_free_params();
+
+        let quiet_course = create_course(&client, &quiet, "Course 1");
+        let popular_course = create_course(&client, &popular, "Course 2");
+        client.edit_course(&quiet, &quiet_course.id, &params);
+        client.edit_course(&popular, &popular_course.id, &params);
+
+        let student = Address::generate(&env);
+        client.enroll(&student, &popular_course.id, &None, &None, &None, &None);
+        client.enroll(&Address::generate(&env), &popular_course.id, &None, &None, &None, &None);
+        client.rate_course(&student, &popular_course.id, &4);
+
+        let top = client.get_top_instructors(&1);
+        assert_eq!(top.len(), 1);
+        let first = top.get(0).unwrap();
+        assert_eq!(first.instructor, popular);
+        assert_eq!(first.enrollment_count, 2);
+        assert_eq!(first.average_rating, 400);
+        assert_eq!(client.get_top_instructors(&10).len(), 2);
+    }
+}
@@ -1,23 +1,39 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::{concat_strings, to_lowercase, u32_to_string};
+use super::assignment::delete_assignment;
+use super::category_stats::remove_from_category_totals;
+use super::course_index::{
+    enrollment_count, module_ids, unindex_category, unindex_language, unindex_level,
+    unindex_price, unrank_course,
+};
+use super::course_slug::release_slug;
+use super::course_tags::delete_course_tags;
+use super::course_variants::remove_from_variant_group;
+use super::creator_stats::record_course_deleted;
+use super::featured_courses::remove_featured;
+use super::lessons::delete_module_lessons;
+use super::live_session::delete_live_session;
+use super::module_overrides::delete_module_overrides;
+use super::module_versions::delete_module_versions;
+use super::quiz::delete_quiz;
+use super::title_index::release_title;
+use super::title_search::unindex_title_prefixes;
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, CourseModule};
-use soroban_sdk::{symbol_short, vec, Address, Env, String, Symbol, Vec};
+use crate::schema::{Course, CourseModule, CourseStatus, DataKey, EnrollmentKey, PaymentKey};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
 const MODULE_KEY: Symbol = symbol_short!("module");
-const TITLE_KEY: Symbol = symbol_short!("title");
 
-pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(), &'static str> {
+pub fn delete_course(env: &Env, creator: Address, course_id: u128) -> Result<(), &'static str> {
     creator.require_auth();
 
-    if course_id.is_empty() {
+    if course_id == 0 {
         handle_error(&env, Error::EmptyCourseId)
     }
 
-    let course_storage_key = (COURSE_KEY, course_id.clone());
+    let course_storage_key = (COURSE_KEY, course_id);
 
     if !env.storage().persistent().has(&course_storage_key) {
         handle_error(&env, Error::CourseNotFound)
@@ -33,53 +49,141 @@ pub fn delete_course(env: &Env, creator: Address, course_id: String) -> Result<(
         handle_error(&env, Error::Unauthorized)
     }
 
-    delete_course_modules(env, &course_id);
-
-    let lowercase_title = to_lowercase(env, &course.title);
+    delete_course_modules(env, course_id);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CoursePrerequisites(course_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RelatedCourses(course_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SequentialUnlock(course_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseSkills(course_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseRating(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::PayWhatYouWant(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::Sale(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::InstallmentPlan(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::PriceList(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::UsdPrice(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::VolumeDiscount(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::PriceHistory(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::UnpublishedAt(course_id));
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::RevenueSplit(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::PublicRoster(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::ActiveStudents(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::ReservedSeats(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::Deadline(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::Cohorts(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::Waitlist(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::WaitlistOffers(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::AccessTerms(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::TransfersDisabled(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::AllowlistOnly(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::TokenGate(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::PrerequisiteCheck(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::ReceiptsTransferable(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::VoucherSigner(course_id));
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::AirdropRoot(course_id));
 
-    let title_key = (TITLE_KEY, lowercase_title);
-    env.storage().persistent().remove(&title_key);
+    if course.status != CourseStatus::Archived {
+        release_title(env, &course);
+    }
+    release_slug(env, &course);
+    unindex_level(env, course_id, course.level);
+    unindex_category(env, course_id, &course.category);
+    remove_from_category_totals(
+        env,
+        &course.category,
+        course.price,
+        enrollment_count(env, course_id),
+    );
+    unindex_language(env, course_id, &course.language);
+    unindex_price(env, course_id, course.price);
+    unrank_course(env, course_id);
+    unindex_title_prefixes(env, course_id, &course.title);
+    remove_featured(env, course_id);
+    delete_course_tags(env, course_id);
+    remove_from_variant_group(env, &course);
     env.storage().persistent().remove(&course_storage_key);
+    record_course_deleted(env, &course.creator);
     env.events().publish((course_id,), "course_deleted");
 
     Ok(())
 }
 
-fn delete_course_modules(env: &Env, course_id: &String) {
-    let mut modules_to_delete: Vec<String> = Vec::new(&env);
-
-    let mut counter = 0u32;
-    loop {
-        let arr = vec![
-            &env,
-            String::from_str(&env, "module_"),
-            course_id.clone(),
-            String::from_str(&env, "_"),
-            u32_to_string(&env, counter),
-            String::from_str(&env, "_0"),
-        ];
-
-        let module_id = concat_strings(&env, arr);
-        let key = (MODULE_KEY, module_id.clone());
-        if env.storage().persistent().has(&key) {
-            if let Some(module) = env.storage().persistent().get::<_, CourseModule>(&key) {
-                if module.course_id == *course_id {
-                    modules_to_delete.push_back(module_id);
-                }
-            }
-        } else {
-            break;
-        }
-        counter += 1;
-        if counter > crate::schema::MAX_LOOP_GUARD {
-            break;
+fn delete_course_modules(env: &Env, course_id: u128) {
+    for id in module_ids(env, course_id).iter() {
+        let key = (MODULE_KEY, id.clone());
+        if let Some(module) = env.storage().persistent().get::<_, CourseModule>(&key) {
+            env.storage()
+                .persistent()
+                .remove(&(symbol_short!("pos"), course_id, module.position));
+            delete_module_versions(env, &module);
         }
-    }
-
-    for id in modules_to_delete.iter() {
-        env.storage().persistent().remove(&(MODULE_KEY, id.clone()));
+        delete_module_lessons(env, &id);
+        delete_quiz(env, &id);
+        delete_assignment(env, &id);
+        delete_live_session(env, &id);
+        delete_module_overrides(env, &id);
+        env.storage().persistent().remove(&key);
         env.events().publish((id.clone(),), "module_deleted");
     }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CourseModules(course_id));
 }
 
 #[cfg(test)]
@@ -143,12 +247,12 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
 
-        client.delete_course(&impostor, &new_course.id.clone());
+        client.delete_course(&impostor, &new_course.id);
     }
 
     #[test]
@@ -199,27 +303,50 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
 
-        assert_eq!(new_course, client.get_course(&new_course.id.clone()));
+        assert_eq!(new_course, client.get_course(&new_course.id));
         assert_eq!(
             new_course.creator,
-            client.get_course(&new_course.id.clone()).creator
+            client.get_course(&new_course.id).creator
         );
 
-        client.delete_course(&creator, &new_course.id.clone());
+        client.delete_course(&creator, &new_course.id);
 
         let exists: bool = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .has(&(COURSE_KEY, new_course.id.clone()))
+                .has(&(COURSE_KEY, new_course.id))
         });
         assert!(!exists);
     }
 
+    #[test]
+    fn test_delete_course_frees_title() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let title = String::from_str(&env, "title");
+        let description = String::from_str(&env, "description");
+
+        let course: Course = client.create_course(
+            &creator, &title, &description, &1000_u128, &None, &None, &None, &None, &None,
+        );
+        client.delete_course(&creator, &course.id);
+
+        let replacement: Course = client.create_course(
+            &creator, &title, &description, &1000_u128, &None, &None, &None, &None, &None,
+        );
+        assert_eq!(replacement.title, title);
+    }
+
     #[test]
     fn test_delete_course_with_modules() {
         let (env, contract_id, client) = setup_test_env();
@@ -233,7 +360,7 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -247,12 +374,12 @@ mod tests {
         });
         assert!(module_exists);
 
-        client.delete_course(&creator, &new_course.id.clone());
+        client.delete_course(&creator, &new_course.id);
 
         let course_exists: bool = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .has(&(COURSE_KEY, new_course.id.clone()))
+                .has(&(COURSE_KEY, new_course.id))
         });
         assert!(!course_exists);
 
@@ -274,7 +401,7 @@ mod tests {
         let client = CourseRegistryClient::new(&env, &contract_id);
 
         let creator: Address = Address::generate(&env);
-        let fake_id = String::from_str(&env, "not_found");
+        let fake_id: u128 = 999;
 
         client.delete_course(&creator, &fake_id);
     }
@@ -296,7 +423,7 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category1")),
             &Some(String::from_str(&env, "language1")),
-            &Some(String::from_str(&env, "thumbnail_url1")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail1.png")),
             &None,
             &None,
         );
@@ -308,24 +435,24 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category2")),
             &Some(String::from_str(&env, "language2")),
-            &Some(String::from_str(&env, "thumbnail_url2")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail2.png")),
             &None,
             &None,
         );
 
-        client.delete_course(&creator, &course1.id.clone());
+        client.delete_course(&creator, &course1.id);
 
         let course1_exists: bool = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .has(&(COURSE_KEY, course1.id.clone()))
+                .has(&(COURSE_KEY, course1.id))
         });
         assert!(!course1_exists);
 
         let course2_exists: bool = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .has(&(COURSE_KEY, course2.id.clone()))
+                .has(&(COURSE_KEY, course2.id))
         });
         assert!(course2_exists);
     }
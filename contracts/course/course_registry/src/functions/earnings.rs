@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::payments::to_token_amount;
+use crate::error::{handle_error, PaymentError};
+use crate::schema::PaymentKey;
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const WITHDRAW_EVENT: Symbol = symbol_short!("withdraw");
+
+/// Credits an instructor's share of a course payment to their earnings.
+///
+/// Storage used:
+/// - PaymentKey::Earnings(instructor, token) -> u128
+/// - PaymentKey::CourseEarnings(course_id) -> u128
+pub fn credit_earnings(
+    env: &Env,
+    instructor: &Address,
+    course_id: u128,
+    token: &Address,
+    amount: u128,
+) {
+    if amount == 0 {
+        return;
+    }
+    let key = PaymentKey::Earnings(instructor.clone(), token.clone());
+    let balance: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(balance + amount));
+
+    let course_key = PaymentKey::CourseEarnings(course_id);
+    let total: u128 = env.storage().persistent().get(&course_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&course_key, &(total + amount));
+}
+
+/// Returns the earnings an instructor has not withdrawn yet in a token.
+pub fn get_earnings(env: &Env, instructor: Address, token: Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Earnings(instructor, token))
+        .unwrap_or(0)
+}
+
+/// Returns the total earnings ever credited for a course, across tokens.
+pub fn get_course_earnings(env: &Env, course_id: u128) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::CourseEarnings(course_id))
+        .unwrap_or(0)
+}
+
+/// Pays out everything an instructor has earned in a token.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - instructor: the instructor withdrawing (must authorize).
+/// - token: the token to withdraw.
+///
+/// Returns:
+/// - u128: the amount transferred.
+///
+/// Errors:
+/// - Returns `PaymentError::NothingToWithdraw` if the balance is zero.
+pub fn withdraw_earnings(env: &Env, instructor: Address, token: Address) -> u128 {
+    instructor.require_auth();
+
+    let key = PaymentKey::Earnings(instructor.clone(), token.clone());
+    let amount: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if amount == 0 {
+        handle_error(env, PaymentError::NothingToWithdraw)
+    }
+    env.storage().persistent().remove(&key);
+
+    token::Client::new(env, &token).transfer(
+        &env.current_contract_address(),
+        &instructor,
+        &to_token_amount(env, amount),
+    );
+
+    env.events()
+        .publish((WITHDRAW_EVENT, instructor), (token, amount));
+
+    amount
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+    #[test]
+    fn test_withdraw_batched_earnings() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Paid Course"),
+            &String::from_str(&env, "description"),
+            &400_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+
+        for _ in 0..3 {
+            let student = Address::generate(&env);
+            token::StellarAssetClient::new(&env, &token).mint(&student, &400);
+            client.enroll(&student, &course.id, &Some(token.clone()), &None, &None, &None);
+        }
+        assert_eq!(client.get_earnings(&creator, &token), 1200);
+        assert_eq!(client.get_course_earnings(&course.id), 1200);
+
+        assert_eq!(client.withdraw_earnings(&creator, &token), 1200);
+        assert_eq!(token::Client::new(&env, &token).balance(&creator), 1200);
+        assert_eq!(client.get_earnings(&creator, &token), 0);
+        assert_eq!(client.get_course_earnings(&course.id), 1200);
+        assert!(client.try_withdraw_earnings(&creator, &token).is_err());
+    }
+}
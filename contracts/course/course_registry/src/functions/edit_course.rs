@@ -1,26 +1,45 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::{to_lowercase, trim};
+use super::category_registry::resolve_course_category;
+use super::category_stats::{
+    add_to_category_totals, remove_from_category_totals, reprice_in_category_totals,
+};
+use super::content_limits::{
+    get_content_limits, validate_description_length, validate_thumbnail_url,
+    validate_title_length,
+};
+use super::course_index::{
+    enrollment_count, index_category, index_language, index_price, unindex_category,
+    unindex_language, unindex_price,
+};
+use super::create_course::normalize_language;
+use super::get_course_version::save_course_version;
+use super::price_limits::validate_price_range;
+use super::price_history::record_price_change;
+use super::title_index::{is_title_taken, release_title, reserve_title};
+use super::title_moderation::validate_title_allowed;
+use super::title_search::{index_title_prefixes, unindex_title_prefixes};
+use super::unpublish_refunds::record_unpublished;
+use super::utils::{normalize_title, trim};
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, EditCourseParams};
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use crate::schema::{Course, CourseStatus, EditCourseParams};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
-const TITLE_KEY: Symbol = symbol_short!("title");
 
 const EDIT_COURSE_EVENT: Symbol = symbol_short!("editcours");
 
 pub fn edit_course(
     env: Env,
     creator: Address,
-    course_id: String,
+    course_id: u128,
     params: EditCourseParams,
 ) -> Course {
     creator.require_auth();
 
     // --- Load existing course ---
-    let storage_key: (Symbol, String) = (COURSE_KEY, course_id.clone());
+    let storage_key: (Symbol, u128) = (COURSE_KEY, course_id);
     let mut course: Course = env
         .storage()
         .persistent()
@@ -32,6 +51,11 @@ pub fn edit_course(
         handle_error(&env, Error::Unauthorized)
     }
 
+    // Keep the pre-edit state so it can be snapshotted as the previous version
+    let previous: Course = course.clone();
+
+    let limits = get_content_limits(&env);
+
     // --- Title update (validate + uniqueness) ---
 
     if let Some(t) = params.new_title {
@@ -42,59 +66,103 @@ pub fn edit_course(
         if t_trim.is_empty() {
             handle_error(&env, Error::EmptyCourseTitle)
         }
+        validate_title_length(&env, &limits, &t_str);
 
-        // Only check/rotate title index if it's effectively changing (case-insensitive)
-        let old_title_lc = to_lowercase(&env, &course.title);
-        let new_title_lc = to_lowercase(&env, &t_str);
+        // Only check/rotate title index if it's effectively changing (after normalization)
+        let old_title_lc = normalize_title(&env, &course.title);
+        let new_title_lc = normalize_title(&env, &t_str);
 
         if old_title_lc != new_title_lc {
-            // uniqueness index key for the *new* title
-            let new_title_key: (Symbol, String) = (TITLE_KEY, new_title_lc);
-            if env.storage().persistent().has(&new_title_key) {
+            validate_title_allowed(&env, &t_str, &course.creator);
+
+            // uniqueness check for the *new* title
+            if is_title_taken(&env, &t_trim) {
                 handle_error(&env, Error::DuplicateCourseTitle)
             }
 
-            // remove old title index and set new one
-            let old_title_key: (Symbol, String) = (TITLE_KEY, old_title_lc);
-            env.storage().persistent().remove(&old_title_key);
-            env.storage().persistent().set(&new_title_key, &true);
-
+            // free the old title and hash slots and claim the new ones;
+            // archived courses have already released theirs
+            if course.status != CourseStatus::Archived {
+                release_title(&env, &course);
+            }
+            unindex_title_prefixes(&env, course_id, &course.title);
             course.title = t_trim;
+            index_title_prefixes(&env, course_id, &course.title);
+            if course.status != CourseStatus::Archived {
+                reserve_title(&env, &course);
+            }
         }
     }
 
     // --- Description ---
     if let Some(d) = params.new_description {
+        validate_description_length(&env, &limits, &d);
         course.description = d;
     }
 
-    // --- Price (>0) ---
-    if let Some(p) = params.new_price {
-        if p == 0 {
-            handle_error(&env, Error::InvalidPrice);
-        }
-        course.price = p;
+    // --- Price (>0, or exactly 0 for free courses) ---
+    let is_free = params.new_is_free.unwrap_or(course.is_free);
+    let price = match params.new_price {
+        Some(p) => p,
+        None if is_free => 0,
+        None => course.price,
+    };
+    if (price == 0) != is_free {
+        handle_error(&env, Error::InvalidPrice);
+    }
+    if price != course.price {
+        validate_price_range(&env, price, is_free);
+        unindex_price(&env, course_id, course.price);
+        index_price(&env, course_id, price);
+        reprice_in_category_totals(&env, &course.category, course.price, price);
+        record_price_change(&env, course_id, course.price);
     }
+    course.price = price;
+    course.is_free = is_free;
 
     // --- Optional fields: category / language / thumbnail ---
     if let Some(cat) = params.new_category {
-        course.category = cat; // Some(value) sets; None clears
+        let cat = resolve_course_category(&env, cat); // Some(value) sets; None clears
+        if cat != course.category {
+            unindex_category(&env, course_id, &course.category);
+            index_category(&env, course_id, &cat);
+            let enrollments = enrollment_count(&env, course_id);
+            remove_from_category_totals(&env, &course.category, course.price, enrollments);
+            add_to_category_totals(&env, &cat, course.price, enrollments);
+        }
+        course.category = cat;
     }
     if let Some(lang) = params.new_language {
+        // An empty code clears the language
+        let lang = normalize_language(&env, lang.filter(|l| !l.is_empty()));
+        if lang != course.language {
+            unindex_language(&env, course_id, &course.language);
+            index_language(&env, course_id, &lang);
+        }
         course.language = lang;
     }
     if let Some(url) = params.new_thumbnail_url {
+        if let Some(ref u) = url {
+            validate_thumbnail_url(&env, &limits, u);
+        }
         course.thumbnail_url = url;
     }
 
-    // --- Published flag ---
+    // --- Publish / unpublish (Draft <-> Published only) ---
     if let Some(p) = params.new_published {
-        course.published = p;
-    }
-
-    // --- Level field ---
-    if let Some(level) = params.new_level {
-        course.level = level; // Some(value) sets; None clears
+        match course.status {
+            CourseStatus::Archived => handle_error(&env, Error::CourseAlreadyArchived),
+            CourseStatus::Suspended => handle_error(&env, Error::CourseSuspended),
+            CourseStatus::Draft | CourseStatus::Published => {}
+        }
+        course.status = if p {
+            CourseStatus::Published
+        } else {
+            CourseStatus::Draft
+        };
+        if previous.status == CourseStatus::Published && !p {
+            record_unpublished(&env, course_id);
+        }
     }
 
     // --- Duration hours field ---
@@ -102,21 +170,26 @@ pub fn edit_course(
         course.duration_hours = duration; // Some(value) sets; None clears
     }
 
+    // --- Version history: snapshot previous state and bump version ---
+    save_course_version(&env, &previous);
+    course.version = previous.version + 1;
+    course.updated_at = env.ledger().timestamp();
+
     // --- Persist updated course ---
     env.storage().persistent().set(&storage_key, &course);
 
     // --- Emit event ---
     env.events()
-        .publish((EDIT_COURSE_EVENT, course_id.clone()), course.clone());
+        .publish((EDIT_COURSE_EVENT, course_id), course.clone());
 
     course
 }
 
 #[cfg(test)]
 mod test {
-    use crate::schema::{Course, EditCourseParams};
+    use crate::schema::{Course, CourseStatus, EditCourseParams};
     use crate::{CourseRegistry, CourseRegistryClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
 
     #[test]
     fn test_edit_course_success() {
@@ -135,7 +208,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "original_category")),
             &Some(String::from_str(&env, "original_language")),
-            &Some(String::from_str(&env, "original_thumbnail")),
+            &Some(String::from_str(&env, "https://example.com/original_thumbnail.png")),
             &None,
             &None,
         );
@@ -146,10 +219,10 @@ mod test {
             new_price: Some(2000_u128),
             new_category: Some(Some(String::from_str(&env, "new_category"))),
             new_language: Some(Some(String::from_str(&env, "new_language"))),
-            new_thumbnail_url: Some(Some(String::from_str(&env, "new_thumbnail"))),
+            new_thumbnail_url: Some(Some(String::from_str(&env, "https://example.com/new_thumbnail.png"))),
             new_published: Some(true),
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         let edited_course = client.edit_course(&creator, &course.id, &params);
 
@@ -169,9 +242,9 @@ mod test {
         );
         assert_eq!(
             edited_course.thumbnail_url,
-            Some(String::from_str(&env, "new_thumbnail"))
+            Some(String::from_str(&env, "https://example.com/new_thumbnail.png"))
         );
-        assert_eq!(edited_course.published, true);
+        assert_eq!(edited_course.status, CourseStatus::Published);
         assert_eq!(edited_course.creator, creator);
 
         let retrieved_course = client.get_course(&course.id);
@@ -191,9 +264,9 @@ mod test {
         );
         assert_eq!(
             retrieved_course.thumbnail_url,
-            Some(String::from_str(&env, "new_thumbnail"))
+            Some(String::from_str(&env, "https://example.com/new_thumbnail.png"))
         );
-        assert_eq!(retrieved_course.published, true);
+        assert_eq!(retrieved_course.status, CourseStatus::Published);
     }
 
     #[test]
@@ -228,8 +301,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         client.edit_course(&impostor, &course.id, &params);
     }
@@ -244,7 +317,7 @@ mod test {
         let client = CourseRegistryClient::new(&env, &contract_id);
 
         let creator: Address = Address::generate(&env);
-        let fake_course_id = String::from_str(&env, "nonexistent_course");
+        let fake_course_id: u128 = 999;
 
         let params = EditCourseParams {
             new_title: Some(String::from_str(&env, "New Title")),
@@ -254,8 +327,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         client.edit_course(&creator, &fake_course_id, &params);
     }
@@ -291,8 +364,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         client.edit_course(&creator, &course.id, &params);
     }
@@ -328,8 +401,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         client.edit_course(&creator, &course.id, &params);
     }
@@ -377,8 +450,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         client.edit_course(&creator, &course2.id, &params);
     }
@@ -401,7 +474,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "original_category")),
             &Some(String::from_str(&env, "original_language")),
-            &Some(String::from_str(&env, "original_thumbnail")),
+            &Some(String::from_str(&env, "https://example.com/original_thumbnail.png")),
             &None,
             &None,
         );
@@ -414,8 +487,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         let edited_course = client.edit_course(&creator, &course.id, &params);
 
@@ -435,9 +508,9 @@ mod test {
         );
         assert_eq!(
             edited_course.thumbnail_url,
-            Some(String::from_str(&env, "original_thumbnail"))
+            Some(String::from_str(&env, "https://example.com/original_thumbnail.png"))
         );
-        assert_eq!(edited_course.published, false); // Default value, unchanged
+        assert_eq!(edited_course.status, CourseStatus::Draft); // Default value, unchanged
     }
 
     #[test]
@@ -470,8 +543,8 @@ mod test {
             new_language: None,
             new_thumbnail_url: None,
             new_published: None,
-            new_level: None,
             new_duration_hours: None,
+            new_is_free: None,
         };
         let edited_course = client.edit_course(&creator, &course.id, &params);
 
@@ -484,4 +557,83 @@ mod test {
             String::from_str(&env, "New Description")
         );
     }
+
+    #[test]
+    fn test_edit_course_updates_timestamps() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Original Title"),
+            &String::from_str(&env, "Original Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(course.created_at, 1_000);
+        assert_eq!(course.updated_at, 1_000);
+
+        env.ledger().set_timestamp(5_000);
+
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: Some(String::from_str(&env, "New Description")),
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        let edited_course = client.edit_course(&creator, &course.id, &params);
+
+        assert_eq!(edited_course.created_at, 1_000);
+        assert_eq!(edited_course.updated_at, 5_000);
+    }
+
+    #[test]
+    fn test_edit_course_title_frees_old_title() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator: Address = Address::generate(&env);
+        let old_title = String::from_str(&env, "Original Title");
+        let description = String::from_str(&env, "Original Description");
+
+        let course: Course = client.create_course(
+            &creator, &old_title, &description, &1000_u128, &None, &None, &None, &None, &None,
+        );
+
+        let params = EditCourseParams {
+            new_title: Some(String::from_str(&env, "Renamed Title")),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+
+        let reused: Course = client.create_course(
+            &creator, &old_title, &description, &1000_u128, &None, &None, &None, &None, &None,
+        );
+        assert_eq!(reused.title, old_title);
+    }
 }
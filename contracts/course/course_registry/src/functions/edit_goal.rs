@@ -12,13 +12,13 @@ const GOAL_EDITED_EVENT: Symbol = symbol_short!("goaledit");
 pub fn edit_goal(
     env: Env,
     creator: Address,
-    course_id: String,
+    course_id: u128,
     goal_id: String,
     new_content: String,
 ) -> CourseGoal {
     creator.require_auth();
     // Validate input
-    if course_id.is_empty() {
+    if course_id == 0 {
         handle_error(&env, Error::InvalidInput)
     }
     if goal_id.is_empty() {
@@ -30,7 +30,7 @@ pub fn edit_goal(
     }
 
     // Load course
-    let storage_key = (symbol_short!("course"), course_id.clone());
+    let storage_key = (symbol_short!("course"), course_id);
     let course: Course = env
         .storage()
         .persistent()
@@ -38,11 +38,11 @@ pub fn edit_goal(
         .expect("Course not found");
 
     // Only creator can edit goal (or later: check admin)
-    if !is_course_creator(&env, course.id.clone(), creator) {
+    if !is_course_creator(&env, course.id, creator) {
         handle_error(&env, Error::Unauthorized)
     }
 
-    let goal_key = DataKey::CourseGoal(course_id.clone(), goal_id.clone());
+    let goal_key = DataKey::CourseGoal(course_id, goal_id.clone());
     let mut goal: CourseGoal = env
         .storage()
         .persistent()
@@ -57,7 +57,7 @@ pub fn edit_goal(
 
     // Emit event
     env.events().publish(
-        (GOAL_EDITED_EVENT, course_id.clone(), goal_id.clone()),
+        (GOAL_EDITED_EVENT, course_id, goal_id.clone()),
         new_content.clone(),
     );
 
@@ -82,7 +82,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(env, "category")),
             &Some(String::from_str(env, "language")),
-            &Some(String::from_str(env, "thumbnail_url")),
+            &Some(String::from_str(env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -108,7 +108,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -169,7 +169,7 @@ mod test {
 
         client.edit_goal(
             &creator,
-            &String::from_str(&env, "nonexistent_course"),
+            &999_u128,
             &String::from_str(&env, "goal1"),
             &String::from_str(&env, "Some content"),
         );
@@ -1,22 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::prerequisites::{save_prerequisites, validate_no_circular_dependency};
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, DataKey};
-use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol, Vec};
+use crate::schema::Course;
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 const PREREQ_UPDATED_EVENT: Symbol = symbol_short!("preqedit");
 
 pub fn edit_prerequisite(
     env: Env,
     creator: Address,
-    course_id: String,
-    new_prerequisites: Vec<String>,
+    course_id: u128,
+    new_prerequisites: Vec<u128>,
 ) {
     creator.require_auth();
 
     // Load course to verify it exists and check authorization
-    let course_key = (symbol_short!("course"), course_id.clone());
+    let course_key = (symbol_short!("course"), course_id);
     let course: Course = env
         .storage()
         .persistent()
@@ -30,21 +31,17 @@ pub fn edit_prerequisite(
 
     // Validate that all prerequisite courses exist
     for prerequisite_id in new_prerequisites.iter() {
-        let prereq_course_key = (symbol_short!("course"), prerequisite_id.clone());
+        let prereq_course_key = (symbol_short!("course"), prerequisite_id);
         if !env.storage().persistent().has(&prereq_course_key) {
             handle_error(&env, Error::PrereqCourseNotFound)
         }
     }
 
-    // TODO: Implement advanced prerequisite validation (depth limits, cyclic dependencies)
     // Prevent circular dependencies
-    validate_no_circular_dependency(&env, &course_id, &new_prerequisites);
+    validate_no_circular_dependency(&env, course_id, &new_prerequisites);
 
     // Save updated prerequisites
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &new_prerequisites,
-    );
+    save_prerequisites(&env, course_id, &new_prerequisites);
 
     // Emit event
     env.events().publish(
@@ -53,79 +50,10 @@ pub fn edit_prerequisite(
     );
 }
 
-fn validate_no_circular_dependency(env: &Env, course_id: &String, new_prerequisites: &Vec<String>) {
-    // Check if course_id appears in new_prerequisites (direct circular dependency)
-    for prerequisite_id in new_prerequisites.iter() {
-        if prerequisite_id.eq(course_id) {
-            handle_error(&env, Error::SelfPrerequisite)
-        }
-    }
-
-    // Check for indirect circular dependencies using DFS
-    let mut visited = Map::new(env);
-    let mut rec_stack = Map::new(env);
-
-    for prerequisite_id in new_prerequisites.iter() {
-        if has_cycle(
-            env,
-            &prerequisite_id,
-            course_id,
-            &mut visited,
-            &mut rec_stack,
-        ) {
-            handle_error(&env, Error::CircularDependency);
-        }
-    }
-}
-
-fn has_cycle(
-    env: &Env,
-    current_course: &String,
-    target_course: &String,
-    visited: &mut Map<String, bool>,
-    rec_stack: &mut Map<String, bool>,
-) -> bool {
-    // If we've reached the target course, we found a cycle
-    if current_course.eq(target_course) {
-        return true;
-    }
-
-    // If already in recursion stack, we have a cycle
-    if rec_stack.contains_key(current_course.clone()) {
-        return true;
-    }
-
-    // If already visited and not in recursion stack, no cycle from this path
-    if visited.contains_key(current_course.clone()) {
-        return false;
-    }
-
-    // Mark as visited and add to recursion stack
-    visited.set(current_course.clone(), true);
-    rec_stack.set(current_course.clone(), true);
-
-    // Get prerequisites for current course
-    let prerequisites: Vec<String> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::CoursePrerequisites(current_course.clone()))
-        .unwrap_or(Vec::new(env));
-
-    // Recursively check all prerequisites
-    for prerequisite in prerequisites.iter() {
-        if has_cycle(env, &prerequisite, target_course, visited, rec_stack) {
-            return true;
-        }
-    }
-
-    // Remove from recursion stack before returning
-    rec_stack.remove(current_course.clone());
-    false
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::schema::DataKey;
     use crate::CourseRegistry;
     use crate::CourseRegistryClient;
     use soroban_sdk::{
@@ -177,18 +105,18 @@ mod tests {
         );
 
         let mut prerequisites = Vec::new(&env);
-        prerequisites.push_back(course2.id.clone());
-        prerequisites.push_back(course3.id.clone());
+        prerequisites.push_back(course2.id);
+        prerequisites.push_back(course3.id);
 
-        client.edit_prerequisite(&creator, &course1.id.clone(), &prerequisites.clone());
+        client.edit_prerequisite(&creator, &course1.id, &prerequisites.clone());
 
         let events = env.events().all();
         assert!(!events.is_empty());
 
-        let stored_prerequisites: Vec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: Vec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap()
         });
 
@@ -252,18 +180,18 @@ mod tests {
         );
 
         let mut initial_prerequisites = Vec::new(&env);
-        initial_prerequisites.push_back(course2.id.clone());
+        initial_prerequisites.push_back(course2.id);
         client.edit_prerequisite(&creator, &course1.id, &initial_prerequisites);
 
         let mut new_prerequisites = Vec::new(&env);
-        new_prerequisites.push_back(course3.id.clone());
-        new_prerequisites.push_back(course4.id.clone());
+        new_prerequisites.push_back(course3.id);
+        new_prerequisites.push_back(course4.id);
         client.edit_prerequisite(&creator, &course1.id, &new_prerequisites);
 
-        let stored_prerequisites: Vec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: Vec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap()
         });
 
@@ -305,16 +233,16 @@ mod tests {
         );
 
         let mut initial_prerequisites = Vec::new(&env);
-        initial_prerequisites.push_back(course2.id.clone());
+        initial_prerequisites.push_back(course2.id);
         client.edit_prerequisite(&creator, &course1.id, &initial_prerequisites);
 
         let empty_prerequisites = Vec::new(&env);
-        client.edit_prerequisite(&creator, &course1.id.clone(), &empty_prerequisites);
+        client.edit_prerequisite(&creator, &course1.id, &empty_prerequisites);
 
-        let stored_prerequisites: Vec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: Vec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap()
         });
 
@@ -332,7 +260,7 @@ mod tests {
 
         client.edit_prerequisite(
             &Address::generate(&env),
-            &String::from_str(&env, "404"),
+            &404_u128,
             &Vec::new(&env),
         );
     }
@@ -360,7 +288,7 @@ mod tests {
         );
 
         let mut prerequisites = Vec::new(&env);
-        prerequisites.push_back(String::from_str(&env, "404"));
+        prerequisites.push_back(404_u128);
 
         client.edit_prerequisite(&creator, &course1.id, &prerequisites);
     }
@@ -388,7 +316,7 @@ mod tests {
         );
 
         let mut prerequisites = Vec::new(&env);
-        prerequisites.push_back(course1.id.clone());
+        prerequisites.push_back(course1.id);
 
         client.edit_prerequisite(&creator, &course1.id, &prerequisites);
     }
@@ -438,15 +366,15 @@ mod tests {
         );
 
         let mut prerequisites2 = Vec::new(&env);
-        prerequisites2.push_back(course3.id.clone());
+        prerequisites2.push_back(course3.id);
         client.edit_prerequisite(&creator, &course2.id, &prerequisites2);
 
         let mut prerequisites1 = Vec::new(&env);
-        prerequisites1.push_back(course2.id.clone());
+        prerequisites1.push_back(course2.id);
         client.edit_prerequisite(&creator, &course1.id, &prerequisites1);
 
         let mut prerequisites3 = Vec::new(&env);
-        prerequisites3.push_back(course1.id.clone());
+        prerequisites3.push_back(course1.id);
         client.edit_prerequisite(&creator, &course3.id, &prerequisites3);
     }
 
@@ -484,11 +412,11 @@ mod tests {
         );
 
         let mut prerequisites = Vec::new(&env);
-        prerequisites.push_back(course2.id.clone());
+        prerequisites.push_back(course2.id);
 
         client.edit_prerequisite(&creator, &course1.id, &prerequisites);
 
-        let stored_prerequisites: Vec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: Vec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
                 .get(&DataKey::CoursePrerequisites(course1.id))
@@ -563,19 +491,19 @@ mod tests {
         );
 
         let mut prerequisites2 = Vec::new(&env);
-        prerequisites2.push_back(course4.id.clone());
+        prerequisites2.push_back(course4.id);
         client.edit_prerequisite(&creator, &course2.id, &prerequisites2);
 
         let mut prerequisites3 = Vec::new(&env);
-        prerequisites3.push_back(course5.id.clone());
+        prerequisites3.push_back(course5.id);
         client.edit_prerequisite(&creator, &course3.id, &prerequisites3);
 
         let mut prerequisites1 = Vec::new(&env);
-        prerequisites1.push_back(course2.id.clone());
-        prerequisites1.push_back(course3.id.clone());
+        prerequisites1.push_back(course2.id);
+        prerequisites1.push_back(course3.id);
         client.edit_prerequisite(&creator, &course1.id, &prerequisites1);
 
-        let stored_prerequisites: Vec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: Vec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
                 .get(&DataKey::CoursePrerequisites(course1.id))
@@ -0,0 +1,412 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_terms::get_access_terms;
+use super::allowlist::require_allowlisted;
+use super::category_stats::record_category_enrollment;
+use super::cohorts::leave_cohort;
+use super::course_bans::is_banned;
+use super::course_capacity::{free_seat, take_seat};
+use super::course_index::record_enrollment;
+use super::creator_stats::record_creator_enrollment;
+use super::enrollment_deadlines::require_enrollment_open;
+use super::enrollment_events::publish_transition;
+use super::payments::collect_payment;
+use super::receipts::issue_receipt;
+use super::prerequisite_checks::require_prerequisites;
+use super::token_gates::require_token_gate;
+use super::trending_courses::record_daily_enrollment;
+use crate::error::{handle_error, EnrollmentError};
+use crate::schema::{
+    Course, CourseStatus, DataKey, Enrollment, EnrollmentKey, EnrollmentSource, EnrollmentStatus,
+    EnrollmentTransition,
+};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const ENROLL_EVENT: Symbol = symbol_short!("enroll");
+
+/// Enrolls a student in a published course, charging its price.
+///
+/// Once a payment token is configured the price is transferred from the
+/// student before the enrollment is recorded; see `payments::collect_payment`.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolling student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - token: the token to pay with; the payment token or one on the course
+///   price list.
+/// - amount: amount to pay, for pay-what-you-want courses; defaults to the price.
+/// - coupon: optional coupon code lowering the price.
+/// - referrer: optional affiliate who referred the student; earns their
+///   commission if registered for the course.
+///
+/// Returns:
+/// - Enrollment: the new enrollment record.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Returns `EnrollmentError::StudentBanned` if the student is banned from the course.
+/// - Returns `EnrollmentError::CourseFull` if the course has no seat left.
+/// - Returns `EnrollmentError::EnrollmentClosed` if the enrollment deadline passed.
+/// - Returns `EnrollmentError::NotAllowlisted` if the course only takes
+///   allowlisted students and the student is not one of them.
+/// - Returns `EnrollmentError::TokenGateNotMet` if the course is token-gated
+///   and the student does not hold the required token or NFT.
+/// - Returns `EnrollmentError::PrerequisitesNotMet` if the student lacks a
+///   prerequisite; `get_missing_prerequisites` lists which.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is not accepted for the course.
+/// - Returns `PaymentError::AmountBelowMinimum` if `amount` is below the price.
+/// - Returns `PaymentError::CouponNotFound`, `CouponExpired` or `CouponUsedUp`
+///   if the coupon cannot be used.
+///
+/// Storage used:
+/// - DataKey::Enrollment(course_id, student) -> Enrollment
+/// - DataKey::EnrollmentCount(course_id) -> u32
+/// - EnrollmentKey::StudentCourses(student) -> Vec<u128>
+/// - EnrollmentKey::CourseStudents(course_id) -> Vec<Address>
+pub fn enroll(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    token: Option<Address>,
+    amount: Option<u128>,
+    coupon: Option<String>,
+    referrer: Option<Address>,
+) -> Enrollment {
+    student.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+    let payment = collect_payment(env, &course, &student, &student, token, amount, coupon, referrer);
+
+    let amount_paid: u128 = payment.map(|payment| payment.amount).unwrap_or(0);
+    record_student_enrollment(
+        env,
+        &course,
+        &student,
+        amount_paid,
+        EnrollmentSource::Purchase,
+    )
+}
+
+/// Stores a new enrollment, updates the enrollment indexes, counters and
+/// stats, and issues the student a receipt.
+///
+/// Every path enrolling a student goes through here. The caller has checked
+/// that the course is open and the student is not enrolled yet, and has
+/// collected any payment.
+///
+/// A student whose access was revoked can only be enrolled again by a grant
+/// from the creator; any other path fails with `EnrollmentError::AccessRevoked`,
+/// which reverts the payment. Banned students cannot be enrolled at all and
+/// fail with `EnrollmentError::StudentBanned`. Courses with a `max_students` limit fail with
+/// `EnrollmentError::CourseFull` once every seat is taken, and courses past
+/// their enrollment deadline with `EnrollmentError::EnrollmentClosed`, or
+/// restricted to an allowlist without the student with
+/// `EnrollmentError::NotAllowlisted`, or gated on a token the student does
+/// not hold with `EnrollmentError::TokenGateNotMet`. Students missing a
+/// prerequisite fail with `EnrollmentError::PrerequisitesNotMet`. None of
+/// these apply when the creator grants access.
+pub fn record_student_enrollment(
+    env: &Env,
+    course: &Course,
+    student: &Address,
+    amount_paid: u128,
+    source: EnrollmentSource,
+) -> Enrollment {
+    if is_banned(env, course.id, student.clone()) {
+        handle_error(env, EnrollmentError::StudentBanned)
+    }
+    if let Some(previous) = get_enrollment(env, course.id, student.clone()) {
+        if previous.status == EnrollmentStatus::Revoked && source != EnrollmentSource::Granted {
+            handle_error(env, EnrollmentError::AccessRevoked)
+        }
+    }
+    if source != EnrollmentSource::Granted {
+        require_enrollment_open(env, course.id);
+        require_allowlisted(env, course.id, student);
+        require_token_gate(env, course.id, student);
+        require_prerequisites(env, course.id, student);
+    }
+    take_seat(env, course.id);
+    let now: u64 = env.ledger().timestamp();
+    let enrollment = Enrollment {
+        course_id: course.id,
+        student: student.clone(),
+        enrolled_at: now,
+        amount_paid,
+        source,
+        status: EnrollmentStatus::Active,
+        expires_at: get_access_terms(env, course.id).map(|terms| now + terms.duration),
+    };
+    env.storage().persistent().set(
+        &DataKey::Enrollment(course.id, student.clone()),
+        &enrollment,
+    );
+
+    let mut course_ids: Vec<u128> = get_student_course_ids(env, student);
+    if !course_ids.contains(course.id) {
+        course_ids.push_back(course.id);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::StudentCourses(student.clone()), &course_ids);
+    }
+    let mut students: Vec<Address> = get_course_student_ids(env, course.id);
+    if !students.contains(student) {
+        students.push_back(student.clone());
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::CourseStudents(course.id), &students);
+    }
+    record_enrollment(env, course.id);
+    record_daily_enrollment(env, course.id);
+    record_creator_enrollment(env, &course.creator);
+    record_category_enrollment(env, &course.category);
+    issue_receipt(env, &enrollment);
+    publish_transition(
+        env,
+        course.id,
+        student,
+        EnrollmentTransition::Created(enrollment.source.clone()),
+    );
+
+    env.events()
+        .publish((ENROLL_EVENT, course.id), student.clone());
+
+    enrollment
+}
+
+/// Removes a student's enrollment, for instance after a refund, and takes it
+/// out of the enrollment indexes.
+///
+/// Enrollment counters and statistics are not rolled back.
+pub fn remove_enrollment(env: &Env, course_id: u128, student: &Address) {
+    if is_enrolled(env, course_id, student) {
+        free_seat(env, course_id);
+    }
+    leave_cohort(env, course_id, student);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Enrollment(course_id, student.clone()));
+
+    let mut course_ids: Vec<u128> = get_student_course_ids(env, student);
+    if let Some(index) = course_ids.first_index_of(course_id) {
+        course_ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::StudentCourses(student.clone()), &course_ids);
+    }
+    let mut students: Vec<Address> = get_course_student_ids(env, course_id);
+    if let Some(index) = students.first_index_of(student) {
+        students.remove(index);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::CourseStudents(course_id), &students);
+    }
+}
+
+/// Moves an enrollment to another student, keeping its place in the course
+/// roster.
+///
+/// The caller has checked that `to` has no enrollment in the course.
+pub fn reassign_enrollment(env: &Env, course_id: u128, from: &Address, to: &Address) -> Enrollment {
+    let mut enrollment: Enrollment = match get_enrollment(env, course_id, from.clone()) {
+        Some(enrollment) => enrollment,
+        None => handle_error(env, EnrollmentError::NotEnrolled),
+    };
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Enrollment(course_id, from.clone()));
+    enrollment.student = to.clone();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Enrollment(course_id, to.clone()), &enrollment);
+
+    let mut from_courses: Vec<u128> = get_student_course_ids(env, from);
+    if let Some(index) = from_courses.first_index_of(course_id) {
+        from_courses.remove(index);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::StudentCourses(from.clone()), &from_courses);
+    }
+    let mut to_courses: Vec<u128> = get_student_course_ids(env, to);
+    to_courses.push_back(course_id);
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::StudentCourses(to.clone()), &to_courses);
+    let mut students: Vec<Address> = get_course_student_ids(env, course_id);
+    match students.first_index_of(from) {
+        Some(index) => students.set(index, to.clone()),
+        None => students.push_back(to.clone()),
+    }
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::CourseStudents(course_id), &students);
+
+    enrollment
+}
+
+/// Whether a student is enrolled in a course with active access.
+///
+/// This is the check every feature gating on enrollment should use.
+pub fn is_enrolled(env: &Env, course_id: u128, student: &Address) -> bool {
+    match get_enrollment(env, course_id, student.clone()) {
+        Some(enrollment) => enrollment.status == EnrollmentStatus::Active,
+        None => false,
+    }
+}
+
+/// Returns the enrollment of a student in a course, if any.
+pub fn get_enrollment(env: &Env, course_id: u128, student: Address) -> Option<Enrollment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Enrollment(course_id, student))
+}
+
+/// Returns the IDs of the courses a student is enrolled in, in enrollment order.
+pub fn get_student_course_ids(env: &Env, student: &Address) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::StudentCourses(student.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the students enrolled in a course, in enrollment order.
+pub fn get_course_student_ids(env: &Env, course_id: u128) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::CourseStudents(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::{Course, EnrollmentSource, EnrollmentStatus};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec, Address, Env, String};
+
+    fn create_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        published: bool,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        if !published {
+            return course;
+        }
+        client.edit_course(creator, &course.id, &publish_free_params())
+    }
+
+    #[test]
+    fn test_enroll() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let student = Address::generate(&env);
+
+        let course = create_course(&client, &Address::generate(&env), "Course", true);
+        assert_eq!(client.get_enrollment(&course.id, &student), None);
+
+        assert!(!client.is_enrolled(&student, &course.id));
+
+        let enrollment = client.enroll(&student, &course.id, &None, &None, &None, &None);
+        assert_eq!(enrollment.enrolled_at, 1_000);
+        assert_eq!(enrollment.source, EnrollmentSource::Purchase);
+        assert_eq!(enrollment.status, EnrollmentStatus::Active);
+        assert_eq!(
+            client.get_enrollment(&course.id, &student),
+            Some(enrollment)
+        );
+        assert!(client.is_enrolled(&student, &course.id));
+    }
+
+    #[test]
+    fn test_enrollment_indexes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        let first = create_course(&client, &creator, "Course 1", true);
+        let second = create_course(&client, &creator, "Course 2", true);
+        client.enroll(&student, &first.id, &None, &None, &None, &None);
+        client.enroll(&student, &second.id, &None, &None, &None, &None);
+        client.enroll(&other, &first.id, &None, &None, &None, &None);
+
+        env.as_contract(&contract_id, || {
+            let course_ids = super::get_student_course_ids(&env, &student);
+            assert_eq!(course_ids, vec![&env, first.id, second.id]);
+            let students = super::get_course_student_ids(&env, first.id);
+            assert_eq!(students, vec![&env, student.clone(), other.clone()]);
+
+            super::remove_enrollment(&env, first.id, &student);
+            assert_eq!(
+                super::get_student_course_ids(&env, &student),
+                vec![&env, second.id]
+            );
+            assert_eq!(
+                super::get_course_student_ids(&env, first.id),
+                vec![&env, other.clone()]
+            );
+        });
+        assert!(!client.is_enrolled(&student, &first.id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #501)")]
+    fn test_enroll_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let student = Address::generate(&env);
+
+        let course = create_course(&client, &Address::generate(&env), "Course", true);
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #502)")]
+    fn test_enroll_in_draft_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let course = create_course(&client, &Address::generate(&env), "Course", false);
+        client.enroll(&Address::generate(&env), &course.id, &None, &None, &None, &None);
+    }
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, EnrollmentError};
+use crate::schema::EnrollmentKey;
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const SET_DEADLINE_EVENT: Symbol = symbol_short!("setDeadln");
+
+/// Sets the time after which a course takes no new students, or removes it.
+///
+/// Students enrolled before the deadline keep their access. The creator can
+/// still grant access afterwards.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - deadline: ledger timestamp of the deadline, or `None` to remove it.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::Deadline(course_id) -> u64
+pub fn set_enrollment_deadline(env: &Env, caller: Address, course_id: u128, deadline: Option<u64>) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::Deadline(course_id);
+    match deadline {
+        Some(deadline) => env.storage().persistent().set(&key, &deadline),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_DEADLINE_EVENT, course_id), deadline);
+}
+
+/// Returns the enrollment deadline of a course, if any.
+pub fn get_enrollment_deadline(env: &Env, course_id: u128) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::Deadline(course_id))
+}
+
+/// Fails with `EnrollmentError::EnrollmentClosed` once the enrollment
+/// deadline of a course has passed.
+pub fn require_enrollment_open(env: &Env, course_id: u128) {
+    if let Some(deadline) = get_enrollment_deadline(env, course_id) {
+        if env.ledger().timestamp() > deadline {
+            handle_error(env, EnrollmentError::EnrollmentClosed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env, String,
+    };
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        client.set_enrollment_deadline(&creator, &course.id, &Some(1_000));
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_students_keep_access_after_deadline() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        assert_eq!(client.get_enrollment_deadline(&course_id), Some(1_000));
+        let student = Address::generate(&env);
+        env.ledger().set_timestamp(1_000);
+        client.enroll(&student, &course_id, &None, &None, &None, &None);
+
+        env.ledger().set_timestamp(1_001);
+        assert!(client.is_enrolled(&student, &course_id));
+        let assistant = Address::generate(&env);
+        client.grant_access(&creator, &course_id, &assistant);
+        assert!(client.is_enrolled(&assistant, &course_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #510)")]
+    fn test_enroll_after_deadline() {
+        let env = Env::default();
+        let (client, _creator, course_id) = setup(&env);
+        env.ledger().set_timestamp(1_001);
+
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::schema::EnrollmentTransition;
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const LIFECYCLE_EVENT: Symbol = symbol_short!("lifecycle");
+
+/// Publishes a step in the lifecycle of an enrollment.
+///
+/// Every transition is published under the same topics,
+/// `("lifecycle", course_id, student)`, so indexers and notification services
+/// can follow a course or a student with a single filter instead of polling
+/// storage. The feature-specific events stay as they are.
+pub fn publish_transition(
+    env: &Env,
+    course_id: u128,
+    student: &Address,
+    transition: EnrollmentTransition,
+) {
+    env.events()
+        .publish((LIFECYCLE_EVENT, course_id, student.clone()), transition);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{EnrollmentSource, EnrollmentTransition};
+
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Events},
+        token, Address, Env, String, Symbol, TryFromVal, Vec,
+    };
+
+    fn transitions(env: &Env, student: &Address) -> Vec<EnrollmentTransition> {
+        let mut found: Vec<EnrollmentTransition> = Vec::new(env);
+        for (_contract, topics, data) in env.events().all().iter() {
+            let name = Symbol::try_from_val(env, &topics.get_unchecked(0));
+            let who = topics
+                .get(2)
+                .and_then(|topic| Address::try_from_val(env, &topic).ok());
+            if name.is_ok_and(|name| name == symbol_short!("lifecycle"))
+                && who.as_ref() == Some(student)
+            {
+                found.push_back(EnrollmentTransition::try_from_val(env, &data).unwrap());
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn test_lifecycle_events() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        client.set_refund_window(&admin, &86_400);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        let found = transitions(&env, &student);
+        assert!(found.contains(EnrollmentTransition::Paid(token.clone(), 1000)));
+        assert!(found.contains(EnrollmentTransition::Created(EnrollmentSource::Purchase)));
+
+        client.request_refund(&student, &course.id, &None);
+        assert!(transitions(&env, &student).contains(EnrollmentTransition::Refunded(1000)));
+
+        let tester = Address::generate(&env);
+        client.grant_access(&creator, &course.id, &tester);
+        assert!(transitions(&env, &tester).contains(EnrollmentTransition::Granted));
+        let reason = String::from_str(&env, "Beta is over");
+        client.revoke_access(&creator, &course.id, &tester, &reason);
+        assert!(transitions(&env, &tester).contains(EnrollmentTransition::Revoked));
+    }
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::access_log::append_access_log;
+use super::allowlist::require_allowlisted;
+use super::course_bans::is_banned;
+use super::enrollment::{get_enrollment, is_enrolled, reassign_enrollment};
+use super::prerequisite_checks::require_prerequisites;
+use super::receipts::reassign_receipt;
+use super::reorder_modules::get_modules;
+use super::token_gates::require_token_gate;
+use crate::error::{handle_error, EnrollmentError};
+use crate::schema::{
+    AccessChange, AccessLogEntry, DataKey, Enrollment, EnrollmentKey, InstallmentSchedule, Payment,
+    PaymentKey,
+};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const SET_TRANSFERS_EVENT: Symbol = symbol_short!("setXfers");
+const TRANSFER_EVENT: Symbol = symbol_short!("transfer");
+
+/// Allows or forbids moving enrollments in a course to other addresses.
+///
+/// Transfers are allowed by default.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::TransfersDisabled(course_id) -> bool
+pub fn set_transfers_enabled(env: &Env, caller: Address, course_id: u128, enabled: bool) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::TransfersDisabled(course_id);
+    if enabled {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &true);
+    }
+
+    env.events()
+        .publish((SET_TRANSFERS_EVENT, course_id), enabled);
+}
+
+/// Whether enrollments in a course may be moved to other addresses.
+pub fn transfers_enabled(env: &Env, course_id: u128) -> bool {
+    !env.storage()
+        .persistent()
+        .has(&EnrollmentKey::TransfersDisabled(course_id))
+}
+
+/// Moves a student's enrollment in a course to another address, for instance
+/// after buying on the wrong wallet.
+///
+/// Access, module progress, the payment record, any installment schedule and
+/// cohort place all move to `to`; refunds still go to whoever paid. Both
+/// addresses must authorize, unless the course creator or an admin approves
+/// the transfer. Without that approval `to` must be allowed to enroll in the
+/// course: allowlisted, holding any gating token and meeting the
+/// prerequisites, as for `enrollment::enroll`.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - course_id: unique identifier of the course.
+/// - from: the enrolled address.
+/// - to: the address taking over the enrollment.
+/// - approver: the course creator or an admin approving the transfer in
+///   place of both students (must authorize), or `None`.
+///
+/// Returns:
+/// - Enrollment: the enrollment, now held by `to`.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the approver may not manage the course.
+/// - Returns `EnrollmentError::TransfersDisabled` if the creator forbade transfers.
+/// - Returns `EnrollmentError::NotEnrolled` if `from` has no active enrollment.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if `to` has an enrollment in
+///   the course.
+/// - Returns `EnrollmentError::StudentBanned` if `to` is banned from the course.
+/// - Returns `EnrollmentError::NotAllowlisted`,
+///   `EnrollmentError::TokenGateNotMet` or
+///   `EnrollmentError::PrerequisitesNotMet` if the transfer is not approved
+///   and `to` could not enroll in the course.
+pub fn transfer_enrollment(
+    env: &Env,
+    course_id: u128,
+    from: Address,
+    to: Address,
+    approver: Option<Address>,
+) -> Enrollment {
+    match &approver {
+        Some(approver) => require_course_management_auth(env, approver, course_id),
+        None => {
+            from.require_auth();
+            to.require_auth();
+        }
+    }
+    if !transfers_enabled(env, course_id) {
+        handle_error(env, EnrollmentError::TransfersDisabled)
+    }
+    if !is_enrolled(env, course_id, &from) {
+        handle_error(env, EnrollmentError::NotEnrolled)
+    }
+    if get_enrollment(env, course_id, to.clone()).is_some() {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+    if is_banned(env, course_id, to.clone()) {
+        handle_error(env, EnrollmentError::StudentBanned)
+    }
+    if approver.is_none() {
+        require_allowlisted(env, course_id, &to);
+        require_token_gate(env, course_id, &to);
+        require_prerequisites(env, course_id, &to);
+    }
+
+    let enrollment: Enrollment = reassign_enrollment(env, course_id, &from, &to);
+
+    let payment_key = PaymentKey::Payment(course_id, from.clone());
+    if let Some(payment) = env.storage().persistent().get::<_, Payment>(&payment_key) {
+        env.storage().persistent().remove(&payment_key);
+        env.storage()
+            .persistent()
+            .set(&PaymentKey::Payment(course_id, to.clone()), &payment);
+    }
+    let schedule_key = PaymentKey::Installments(course_id, from.clone());
+    if let Some(mut schedule) = env
+        .storage()
+        .persistent()
+        .get::<_, InstallmentSchedule>(&schedule_key)
+    {
+        env.storage().persistent().remove(&schedule_key);
+        schedule.student = to.clone();
+        env.storage()
+            .persistent()
+            .set(&PaymentKey::Installments(course_id, to.clone()), &schedule);
+    }
+    reassign_receipt(env, course_id, &from, &to);
+    let cohort_key = EnrollmentKey::StudentCohort(course_id, from.clone());
+    if let Some(cohort_id) = env.storage().persistent().get::<_, u32>(&cohort_key) {
+        env.storage().persistent().remove(&cohort_key);
+        env.storage().persistent().set(
+            &EnrollmentKey::StudentCohort(course_id, to.clone()),
+            &cohort_id,
+        );
+    }
+    for module in get_modules(env, course_id).iter() {
+        let key = DataKey::ModuleCompleted(module.id.clone(), from.clone());
+        if let Some(completed_at) = env.storage().persistent().get::<_, u64>(&key) {
+            env.storage().persistent().remove(&key);
+            env.storage().persistent().set(
+                &DataKey::ModuleCompleted(module.id, to.clone()),
+                &completed_at,
+            );
+        }
+    }
+    append_access_log(
+        env,
+        course_id,
+        AccessLogEntry {
+            student: from.clone(),
+            change: AccessChange::Transferred(to.clone()),
+            actor: Some(approver.clone().unwrap_or(from.clone())),
+            reason: None,
+            at: env.ledger().timestamp(),
+        },
+    );
+
+    env.events()
+        .publish((TRANSFER_EVENT, course_id), (from, to, approver));
+
+    enrollment
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_transfer_enrollment_with_progress() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let module = client.add_module(&creator, &course_id, &0, &String::from_str(&env, "Intro"));
+        let from = Address::generate(&env);
+        let to = Address::generate(&env);
+        client.enroll(&from, &course_id, &None, &None, &None, &None);
+        let completed_at = client.complete_module(&from, &module.id);
+
+        let enrollment = client.transfer_enrollment(&course_id, &from, &to, &None);
+        assert_eq!(enrollment.student, to);
+        assert!(!client.is_enrolled(&from, &course_id));
+        assert!(client.is_enrolled(&to, &course_id));
+        assert_eq!(client.get_module_completion(&module.id, &from), None);
+        assert_eq!(
+            client.get_module_completion(&module.id, &to),
+            Some(completed_at)
+        );
+        assert!(client.list_user_courses(&from).is_empty());
+        assert_eq!(client.list_user_courses(&to).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #517)")]
+    fn test_transfer_when_disabled() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let from = Address::generate(&env);
+        client.enroll(&from, &course_id, &None, &None, &None, &None);
+        client.set_transfers_enabled(&creator, &course_id, &false);
+
+        client.transfer_enrollment(&course_id, &from, &Address::generate(&env), &Some(creator));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #521)")]
+    fn test_transfer_to_banned_student() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let from = Address::generate(&env);
+        let to = Address::generate(&env);
+        client.enroll(&from, &course_id, &None, &None, &None, &None);
+        client.ban_student(&creator, &course_id, &to, &String::from_str(&env, "spam"));
+
+        client.transfer_enrollment(&course_id, &from, &to, &None);
+    }
+
+    #[test]
+    fn test_transfer_to_address_outside_allowlist() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let from = Address::generate(&env);
+        client.set_allowlist_mode(&creator, &course_id, &true);
+        client.add_to_allowlist(&creator, &course_id, &vec![&env, from.clone()]);
+        client.enroll(&from, &course_id, &None, &None, &None, &None);
+
+        let to = Address::generate(&env);
+        assert!(client
+            .try_transfer_enrollment(&course_id, &from, &to, &None)
+            .is_err());
+        let enrollment = client.transfer_enrollment(&course_id, &from, &to, &Some(creator));
+        assert_eq!(enrollment.student, to);
+    }
+}
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::enrollment::remove_enrollment;
+use super::enrollment_events::publish_transition;
+use super::payments::{settle_payment, to_token_amount};
+use super::refund_requests::{close_refund_request, open_refund_request};
+use crate::error::{handle_error, PaymentError};
+use crate::schema::{EnrollmentTransition, Payment, PaymentKey, PaymentStatus};
+use soroban_sdk::{symbol_short, token, Address, Env, String, Symbol};
+
+const REFUND_EVENT: Symbol = symbol_short!("refund");
+const RELEASE_EVENT: Symbol = symbol_short!("release");
+
+/// Refunds an escrowed payment to its payer and cancels the enrollment.
+///
+/// The refund goes to whoever paid, so a gifted course refunds the giver.
+/// While the refund window of the payment is open the refund is immediate.
+/// After that, a student giving a reason opens a refund request for the
+/// instructor to approve or reject; see `refund_requests::approve_refund`.
+/// Enrollment counters and statistics are not rolled back.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolled student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - reason: why the student wants a refund; required after the window.
+///
+/// Returns:
+/// - Payment: the refunded payment record, or the released one when a
+///   request was opened.
+///
+/// Errors:
+/// - Returns `PaymentError::PaymentNotFound` if the student did not pay for the course.
+/// - Returns `PaymentError::RefundWindowClosed` if the payment is no longer in
+///   escrow and no reason is given, or it was already refunded.
+/// - Returns `PaymentError::RefundRequestExists` if the student already
+///   requested a refund for the course.
+pub fn request_refund(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    reason: Option<String>,
+) -> Payment {
+    student.require_auth();
+
+    let key = PaymentKey::Payment(course_id, student.clone());
+    let mut payment: Payment = load_payment(env, &key);
+    if payment.status != PaymentStatus::Escrowed || env.ledger().timestamp() >= payment.escrow_until
+    {
+        match reason {
+            Some(reason) if payment.status != PaymentStatus::Refunded => {
+                if payment.status == PaymentStatus::Escrowed {
+                    settle_payment(env, &mut payment);
+                    env.storage().persistent().set(&key, &payment);
+                }
+                open_refund_request(env, &student, course_id, reason);
+                return payment;
+            }
+            _ => handle_error(env, PaymentError::RefundWindowClosed),
+        }
+    }
+
+    refund_escrowed_payment(env, student, payment)
+}
+
+/// Returns an escrowed payment to its payer in full and cancels the
+/// enrollment. The caller has checked that the refund window is open.
+pub fn refund_escrowed_payment(env: &Env, student: Address, mut payment: Payment) -> Payment {
+    token::Client::new(env, &payment.token).transfer(
+        &env.current_contract_address(),
+        &payment.payer,
+        &to_token_amount(env, payment.amount),
+    );
+    payment.status = PaymentStatus::Refunded;
+    env.storage().persistent().set(
+        &PaymentKey::Payment(payment.course_id, student.clone()),
+        &payment,
+    );
+    close_refund_request(env, payment.course_id, &student);
+    remove_enrollment(env, payment.course_id, &student);
+    publish_transition(
+        env,
+        payment.course_id,
+        &student,
+        EnrollmentTransition::Refunded(payment.amount),
+    );
+
+    env.events()
+        .publish((REFUND_EVENT, payment.course_id), (student, payment.amount));
+
+    payment
+}
+
+/// Releases an escrowed payment once its refund window has ended.
+///
+/// Anyone may call this; the platform fee goes to the treasury and the rest
+/// is credited to the creator's earnings.
+///
+/// Errors:
+/// - Returns `PaymentError::PaymentNotFound` if the student did not pay for the course.
+/// - Returns `PaymentError::EscrowActive` if the refund window is still open.
+/// - Returns `PaymentError::RefundWindowClosed` if the payment was already
+///   released or refunded.
+pub fn release_payment(env: &Env, course_id: u128, student: Address) -> Payment {
+    let key = PaymentKey::Payment(course_id, student.clone());
+    let mut payment: Payment = load_payment(env, &key);
+    if payment.status != PaymentStatus::Escrowed {
+        handle_error(env, PaymentError::RefundWindowClosed)
+    }
+    if env.ledger().timestamp() < payment.escrow_until {
+        handle_error(env, PaymentError::EscrowActive)
+    }
+
+    settle_payment(env, &mut payment);
+    env.storage().persistent().set(&key, &payment);
+
+    env.events()
+        .publish((RELEASE_EVENT, course_id), (student, payment.amount));
+
+    payment
+}
+
+/// Releases the payment of an earlier enrollment of a student in a course, so
+/// a new payment can replace its record.
+///
+/// A payment stays in escrow after its refund window until it is released,
+/// for instance when the student unenrolled without a refund; overwriting it
+/// would strand its funds in the contract.
+///
+/// Errors:
+/// - Returns `PaymentError::EscrowActive` if the refund window of the earlier
+///   payment is still open.
+pub fn release_previous_payment(env: &Env, course_id: u128, student: &Address) {
+    let key = PaymentKey::Payment(course_id, student.clone());
+    if let Some(payment) = env.storage().persistent().get::<_, Payment>(&key) {
+        if payment.status == PaymentStatus::Escrowed {
+            release_payment(env, course_id, student.clone());
+        }
+    }
+}
+
+fn load_payment(env: &Env, key: &PaymentKey) -> Payment {
+    match env.storage().persistent().get(key) {
+        Some(payment) => payment,
+        None => handle_error(env, PaymentError::PaymentNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::{Course, PaymentStatus};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Course) {
+        let (client, admin, token) = setup_registry_with_token(env);
+        client.set_refund_window(&admin, &(14 * DAY));
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Paid Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.edit_course(&creator, &course.id, &publish_params());
+        (client, token, course)
+    }
+
+    fn enroll_paying<'a>(
+        env: &Env,
+        client: &CourseRegistryClient<'a>,
+        token: &Address,
+        course_id: u128,
+    ) -> Address {
+        let student = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&student, &1000);
+        client.enroll(&student, &course_id, &Some(token.clone()), &None, &None, &None);
+        student
+    }
+
+    #[test]
+    fn test_refund_within_window() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let student = enroll_paying(&env, &client, &token, course.id);
+        assert_eq!(client.get_earnings(&course.creator, &token), 0);
+
+        env.ledger().set_timestamp(13 * DAY);
+        let payment = client.request_refund(&student, &course.id, &None);
+        assert_eq!(payment.status, PaymentStatus::Refunded);
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 1000);
+        assert_eq!(client.get_enrollment(&course.id, &student), None);
+    }
+
+    #[test]
+    fn test_release_after_window() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let student = enroll_paying(&env, &client, &token, course.id);
+        assert!(client.try_release_payment(&course.id, &student).is_err());
+
+        env.ledger().set_timestamp(14 * DAY);
+        assert!(client
+            .try_request_refund(&student, &course.id, &None)
+            .is_err());
+        let payment = client.release_payment(&course.id, &student);
+        assert_eq!(payment.status, PaymentStatus::Released);
+        assert_eq!(client.get_earnings(&course.creator, &token), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #705)")]
+    fn test_refund_without_payment() {
+        let env = Env::default();
+        let (client, _token, course) = setup(&env);
+
+        client.request_refund(&Address::generate(&env), &course.id, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_refund_window_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin, _token) = setup_registry_with_token(&env);
+
+        client.set_refund_window(&Address::generate(&env), &86_400);
+    }
+
+    #[test]
+    fn test_reenroll_releases_expired_escrow() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let student = enroll_paying(&env, &client, &token, course.id);
+
+        env.ledger().set_timestamp(15 * DAY);
+        assert!(!client.unenroll(&student, &course.id));
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        client.enroll(&student, &course.id, &Some(token.clone()), &None, &None, &None);
+        assert_eq!(client.get_earnings(&course.creator, &token), 1000);
+        let payment = client.get_payment(&course.id, &student).unwrap();
+        assert_eq!(payment.status, PaymentStatus::Escrowed);
+        assert_eq!(payment.escrow_until, 29 * DAY);
+
+        env.ledger().set_timestamp(29 * DAY);
+        client.release_payment(&course.id, &student);
+        assert_eq!(client.get_earnings(&course.creator, &token), 2000);
+        assert_eq!(token::Client::new(&env, &token).balance(&client.address), 2000);
+    }
+}
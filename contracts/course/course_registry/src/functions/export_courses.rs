@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseExportPage, CourseSummary, MAX_EXPORT_SCAN, MAX_PAGE_SIZE};
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID_COUNTER: Symbol = symbol_short!("course");
+
+/// Exports the catalog in ID order, one page at a time, for off-chain indexers.
+///
+/// Every stored course is exported regardless of status or visibility; the
+/// summary carries both so the indexer can filter. Start with a cursor of 0
+/// and pass back `next_cursor` until it is `None`. At most `MAX_EXPORT_SCAN`
+/// IDs are examined per call, so a page may hold fewer than `limit` courses
+/// (or none) while `next_cursor` is still set.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - cursor: the last course ID already exported, or 0 to start.
+/// - limit: maximum number of courses to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn export_courses(env: &Env, cursor: u128, limit: u32) -> CourseExportPage {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let last_id: u128 = env
+        .storage()
+        .persistent()
+        .get(&COURSE_ID_COUNTER)
+        .unwrap_or(0);
+
+    let mut courses: Vec<CourseSummary> = Vec::new(env);
+    let mut id: u128 = cursor;
+    let mut scanned: u32 = 0;
+    while id < last_id && courses.len() < limit && scanned < MAX_EXPORT_SCAN {
+        id += 1;
+        scanned += 1;
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, id)) {
+            Some(course) => course,
+            None => continue,
+        };
+        courses.push_back(CourseSummary {
+            id: course.id,
+            title: course.title,
+            creator: course.creator,
+            price: course.price,
+            category: course.category,
+            status: course.status,
+            visibility: course.visibility,
+            version: course.version,
+            updated_at: course.updated_at,
+        });
+    }
+
+    CourseExportPage {
+        courses,
+        next_cursor: if id < last_id { Some(id) } else { None },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::CourseStatus;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    #[test]
+    fn test_export_courses_walks_catalog() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let titles = ["Course 1", "Course 2", "Course 3"];
+        for title in titles.iter() {
+            client.create_course(
+                &creator,
+                &String::from_str(&env, title),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            );
+        }
+        client.delete_course(&creator, &2);
+
+        let page = client.export_courses(&0, &1);
+        assert_eq!(page.courses.len(), 1);
+        assert_eq!(page.courses.get(0).unwrap().id, 1);
+        assert_eq!(page.courses.get(0).unwrap().status, CourseStatus::Draft);
+        assert_eq!(page.next_cursor, Some(1));
+
+        let page = client.export_courses(&1, &20);
+        assert_eq!(page.courses.len(), 1);
+        assert_eq!(page.courses.get(0).unwrap().id, 3);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_export_courses_invalid_limit() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.export_courses(&0, &21);
+    }
+}
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use crate::error::{handle_error, CatalogError, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility, DataKey, MAX_FEATURED_COURSES};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const PIN_EVENT: Symbol = symbol_short!("pinFeat");
+const UNPIN_EVENT: Symbol = symbol_short!("unpinFeat");
+
+/// Adds a published course to the end of the featured list (admin-only).
+///
+/// Pinning a course that is already featured does nothing.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - course_id: the course to feature.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::InvalidInput` if the course is not published.
+/// - Returns `CatalogError::FeaturedListFull` if `MAX_FEATURED_COURSES` are
+///   already featured.
+///
+/// Storage used:
+/// - DataKey::FeaturedCourses -> Vec<u128>
+pub fn pin_featured_course(env: &Env, caller: Address, course_id: u128) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    if course.status != CourseStatus::Published {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut featured: Vec<u128> = featured_course_ids(env);
+    if featured.contains(course_id) {
+        return;
+    }
+    if featured.len() >= MAX_FEATURED_COURSES {
+        handle_error(env, CatalogError::FeaturedListFull)
+    }
+    featured.push_back(course_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeaturedCourses, &featured);
+
+    env.events().publish((PIN_EVENT,), course_id);
+}
+
+/// Removes a course from the featured list (admin-only).
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `CatalogError::NotFeatured` if the course is not featured.
+pub fn unpin_featured_course(env: &Env, caller: Address, course_id: u128) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if !remove_featured(env, course_id) {
+        handle_error(env, CatalogError::NotFeatured)
+    }
+
+    env.events().publish((UNPIN_EVENT,), course_id);
+}
+
+/// Returns the featured courses that are still published and public, in
+/// display order.
+pub fn get_featured_courses(env: &Env) -> Vec<Course> {
+    let mut results: Vec<Course> = Vec::new(env);
+    for course_id in featured_course_ids(env).iter() {
+        let course: Option<Course> = env.storage().persistent().get(&(COURSE_KEY, course_id));
+        if let Some(course) = course {
+            if course.status == CourseStatus::Published
+                && course.visibility == CourseVisibility::Public
+            {
+                results.push_back(course);
+            }
+        }
+    }
+    results
+}
+
+/// Drops a course from the featured list, returning whether it was featured.
+/// Also used when the course is deleted.
+pub fn remove_featured(env: &Env, course_id: u128) -> bool {
+    let mut featured: Vec<u128> = featured_course_ids(env);
+    match featured.first_index_of(course_id) {
+        Some(i) => {
+            featured.remove(i);
+            env.storage()
+                .persistent()
+                .set(&DataKey::FeaturedCourses, &featured);
+            true
+        }
+        None => false,
+    }
+}
+
+fn featured_course_ids(env: &Env) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeaturedCourses)
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{create_published_course, setup_registry};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+
+    #[test]
+    fn test_pin_and_unpin_featured_courses() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let first = create_published_course(&client, &creator, "Course 1", 1000);
+        let second = create_published_course(&client, &creator, "Course 2", 1000);
+
+        client.pin_featured_course(&admin, &second.id);
+        client.pin_featured_course(&admin, &first.id);
+        client.pin_featured_course(&admin, &second.id);
+        let featured = client.get_featured_courses();
+        assert_eq!(featured.len(), 2);
+        assert_eq!(featured.get(0).unwrap().id, second.id);
+
+        client.unpin_featured_course(&admin, &second.id);
+        assert_eq!(client.get_featured_courses().len(), 1);
+
+        client.delete_course(&creator, &first.id);
+        assert!(client.get_featured_courses().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #609)")]
+    fn test_unpin_course_not_featured() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let course = create_published_course(&client, &Address::generate(&env), "Course 1", 1000);
+
+        client.unpin_featured_course(&admin, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_pin_featured_course_without_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Course 1", 1000);
+
+        client.pin_featured_course(&creator, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_pin_featured_course_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Course 1", 1000);
+
+        client.pin_featured_course(&creator, &course.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_unpin_featured_course_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Course 1", 1000);
+        client.pin_featured_course(&admin, &course.id);
+
+        client.unpin_featured_course(&creator, &course.id);
+    }
+}
@@ -1,10 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::sales::effective_price;
 use crate::error::{handle_error, Error};
-use soroban_sdk::{Env, String, Symbol};
+use soroban_sdk::{Env, Symbol};
 
-use crate::schema::Course;
+use crate::schema::{Course, CourseStatus, CourseVisibility};
 
 /// Retrieves a course by its ID.
 ///
@@ -13,29 +14,41 @@ use crate::schema::Course;
 /// - course_id: unique identifier of the course.
 ///
 /// Returns:
-/// - Course: the course record associated with the given ID.
+/// - Course: the course record associated with the given ID, with `price`
+///   set to the sale price while a sale is running.
 ///
 /// Errors:
 /// - Panics with `"Course not found"` if the course does not exist.
 /// - Returns `Error::CourseAlreadyArchived` if the course is archived.
+/// - Returns `Error::CourseSuspended` if the course was suspended by an admin.
+/// - Returns `Error::CoursePrivate` if the course is private.
 ///
 /// Storage used (replace keys if your schema differs):
 /// - (("course", id),) -> Course    // course record by id
-pub fn get_course(env: &Env, course_id: String) -> Course {
+pub fn get_course(env: &Env, course_id: u128) -> Course {
     // Create the storage key for the course
     let key = Symbol::new(env, "course");
 
     // Get the course from storage
-    let course: Course = env
+    let mut course: Course = env
         .storage()
         .persistent()
-        .get(&(key, course_id.clone()))
+        .get(&(key, course_id))
         .expect("Course not found");
 
-    match course.is_archived {
-        true => handle_error(&env, Error::CourseAlreadyArchived),
-        false => course,
+    match course.status {
+        CourseStatus::Archived => handle_error(&env, Error::CourseAlreadyArchived),
+        CourseStatus::Suspended => handle_error(&env, Error::CourseSuspended),
+        CourseStatus::Draft | CourseStatus::Published => {}
     }
+
+    // Private course metadata is only served through `get_course_as`
+    if course.visibility == CourseVisibility::Private {
+        handle_error(&env, Error::CoursePrivate)
+    }
+
+    course.price = effective_price(env, &course);
+    course
 }
 
 #[cfg(test)]
@@ -62,7 +75,7 @@ mod test {
         env.mock_all_auths();
         let contract_id = env.register(CourseRegistry, {});
         let client = CourseRegistryClient::new(&env, &contract_id);
-        client.get_course(&String::from_str(&env, "1"));
+        client.get_course(&1);
     }
 
     #[test]
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::get_course::get_course;
+use super::utils::course_hash;
+use crate::error::{handle_error, Error};
+use crate::schema::Course;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
+
+const COURSE_HASH_KEY: Symbol = symbol_short!("crs_hash");
+
+/// Computes the deterministic hash for a creator and title.
+///
+/// The hash lets off-chain systems find a course without knowing its ID; it
+/// is not an ID itself. Course IDs stay sequential `u128` values assigned at
+/// creation, and all storage is keyed by them.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: address of the course creator.
+/// - title: course title (normalized with `normalize_title` before hashing).
+///
+/// Returns:
+/// - BytesN<32>: `sha256(xdr(creator) || normalized title)`.
+pub fn compute_course_hash(env: &Env, creator: Address, title: String) -> BytesN<32> {
+    course_hash(env, &creator, &title)
+}
+
+/// Retrieves a course by its deterministic creator + title hash.
+///
+/// The hash index is kept up to date as courses are renamed, archived and
+/// deleted, so a hash only resolves while a live course has that creator and
+/// title.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - hash: the hash returned by `compute_course_hash`.
+///
+/// Returns:
+/// - Course: the course currently indexed under the hash.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if no course is indexed under the hash.
+///
+/// Storage used:
+/// - (("crs_hash", hash),) -> u128    // course id by deterministic hash
+pub fn get_course_by_hash(env: &Env, hash: BytesN<32>) -> Course {
+    let course_id: u128 = match env.storage().persistent().get(&(COURSE_HASH_KEY, hash)) {
+        Some(id) => id,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    get_course(env, course_id)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_get_course_by_hash_success() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Intro to Soroban");
+
+        // Hash is stable under case and surrounding whitespace
+        let hash = client.compute_course_hash(&creator, &String::from_str(&env, "  INTRO TO SOROBAN "));
+        assert_eq!(client.get_course_by_hash(&hash), course);
+    }
+
+    #[test]
+    fn test_course_hash_depends_on_creator() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let title = String::from_str(&env, "Intro to Soroban");
+
+        let hash1 = client.compute_course_hash(&Address::generate(&env), &title);
+        let hash2 = client.compute_course_hash(&Address::generate(&env), &title);
+        assert_ne!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_course_hash_follows_title_edit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Old Title");
+        let params = EditCourseParams {
+            new_title: Some(String::from_str(&env, "New Title")),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        client.edit_course(&creator, &course.id, &params);
+
+        let new_hash = client.compute_course_hash(&creator, &String::from_str(&env, "New Title"));
+        assert_eq!(client.get_course_by_hash(&new_hash).id, course.id);
+
+        let old_hash = client.compute_course_hash(&creator, &String::from_str(&env, "Old Title"));
+        assert!(client.try_get_course_by_hash(&old_hash).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_get_course_by_hash_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let hash = client.compute_course_hash(&Address::generate(&env), &String::from_str(&env, "Missing"));
+        client.get_course_by_hash(&hash);
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::get_course::get_course;
+use super::title_index::title_key;
+use crate::error::{handle_error, Error};
+use crate::schema::Course;
+use soroban_sdk::{Env, String};
+
+/// Retrieves a live course by its title.
+///
+/// The title is normalized the same way as on creation, so differences in
+/// case or whitespace do not matter. Archived and deleted courses no longer
+/// hold their title and are not returned.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - title: the human-entered course title.
+///
+/// Returns:
+/// - Course: the course currently holding the title.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if no live course holds the title.
+///
+/// Storage used:
+/// - (("title", normalized_title),) -> u128    // course id by title
+pub fn get_course_by_title(env: &Env, title: String) -> Course {
+    let course_id: u128 = match env.storage().persistent().get(&title_key(env, &title)) {
+        Some(id) => id,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    get_course(env, course_id)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_get_course_by_title_success() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        create_course(&client, &creator, "Intro to Rust");
+        let course = create_course(&client, &creator, "Intro to Soroban");
+
+        let found = client.get_course_by_title(&String::from_str(&env, " intro  TO soroban"));
+        assert_eq!(found, course);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_get_course_by_title_archived() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Intro to Soroban");
+        client.archive_course(&creator, &course.id);
+
+        client.get_course_by_title(&String::from_str(&env, "Intro to Soroban"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_get_course_by_title_not_found() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.get_course_by_title(&String::from_str(&env, "Missing"));
+    }
+}
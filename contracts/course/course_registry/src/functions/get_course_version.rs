@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::Course;
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_VERSION_KEY: Symbol = symbol_short!("course_v");
+
+/// Stores an immutable snapshot of a course under its current version.
+///
+/// Called before a course is modified so the previous state stays readable.
+///
+/// Storage used:
+/// - (("course_v", id, version),) -> Course    // snapshot of a past version
+pub fn save_course_version(env: &Env, course: &Course) {
+    let key: (Symbol, u128, u32) = (COURSE_VERSION_KEY, course.id, course.version);
+    env.storage().persistent().set(&key, course);
+}
+
+/// Retrieves a course as it looked at a given version.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - course_id: unique identifier of the course.
+/// - version: the version number to retrieve (the first version is 1).
+///
+/// Returns:
+/// - Course: the course record at the requested version.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::CourseVersionNotFound` if the version was never recorded.
+pub fn get_course_version(env: &Env, course_id: u128, version: u32) -> Course {
+    let course: Course = match env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+    {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+
+    if version == course.version {
+        return course;
+    }
+
+    let key: (Symbol, u128, u32) = (COURSE_VERSION_KEY, course_id, version);
+    match env.storage().persistent().get(&key) {
+        Some(snapshot) => snapshot,
+        None => handle_error(env, Error::CourseVersionNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, "Original Title"),
+            &String::from_str(&client.env, "Original Description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    fn price_params(price: u128) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: Some(price),
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_new_course_starts_at_version_one() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        assert_eq!(course.version, 1);
+
+        let fetched = client.get_course_version(&course.id, &1);
+        assert_eq!(fetched, course);
+    }
+
+    #[test]
+    fn test_edit_course_keeps_previous_versions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let original = create_course(&client, &creator);
+        let second = client.edit_course(&creator, &original.id, &price_params(2000));
+        let third = client.edit_course(&creator, &original.id, &price_params(3000));
+
+        assert_eq!(second.version, 2);
+        assert_eq!(third.version, 3);
+
+        assert_eq!(client.get_course_version(&original.id, &1), original);
+        assert_eq!(client.get_course_version(&original.id, &2), second);
+        assert_eq!(client.get_course_version(&original.id, &3), third);
+        assert_eq!(client.get_course_version(&original.id, &1).price, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #32)")]
+    fn test_get_course_version_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        client.get_course_version(&course.id, &2);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_get_course_version_course_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.get_course_version(&1, &1);
+    }
+}
@@ -1,8 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use super::utils::u32_to_string;
-use crate::schema::Course;
+use crate::schema::{Course, CourseStatus, CourseVisibility};
 use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -12,8 +11,7 @@ pub fn get_courses_by_instructor(env: &Env, instructor: Address) -> Vec<Course>
     let mut id: u128 = 1;
 
     loop {
-        let course_id = u32_to_string(env, id as u32);
-        let key = (COURSE_KEY, course_id.clone());
+        let key = (COURSE_KEY, id);
 
         if !env.storage().persistent().has(&key) {
             break;
@@ -21,7 +19,10 @@ pub fn get_courses_by_instructor(env: &Env, instructor: Address) -> Vec<Course>
 
         let course: Course = env.storage().persistent().get(&key).unwrap();
 
-        if course.creator == instructor && !course.is_archived {
+        if course.creator == instructor
+            && matches!(course.status, CourseStatus::Draft | CourseStatus::Published)
+            && course.visibility == CourseVisibility::Public
+        {
             results.push_back(course);
         }
 
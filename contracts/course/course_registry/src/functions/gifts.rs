@@ -0,0 +1,369 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::{collect_payment, quote_payment, record_payment, to_token_amount};
+use crate::error::{handle_error, EnrollmentError, Error, PaymentError};
+use crate::schema::{
+    Course, CourseStatus, Enrollment, EnrollmentSource, PaymentKey, PendingGift,
+    MAX_GIFT_CLAIM_PERIOD,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const GIFT_EVENT: Symbol = symbol_short!("gift");
+const PENDING_GIFT_EVENT: Symbol = symbol_short!("giftPend");
+const CLAIM_GIFT_EVENT: Symbol = symbol_short!("giftClaim");
+const RECLAIM_GIFT_EVENT: Symbol = symbol_short!("giftBack");
+
+/// Buys a course for someone else.
+///
+/// The payer is charged as on `enroll` and the enrollment, with its access,
+/// belongs to the recipient. The payment record keeps the payer, so a refund
+/// during the refund window goes back to them. The event is published under
+/// the recipient's address so their wallet can show the gift. For recipients
+/// who cannot act on the course yet, see `create_pending_gift`.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - payer: the address paying for the course (must authorize).
+/// - recipient: the address being enrolled.
+/// - course_id: unique identifier of the course.
+/// - token: the token to pay with; the payment token or one on the course
+///   price list.
+///
+/// Returns:
+/// - Enrollment: the recipient's new enrollment record.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the payer gifts the course to themselves.
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the recipient is already enrolled.
+/// - Any error `payments::collect_payment` can return for the token.
+pub fn gift_course(
+    env: &Env,
+    payer: Address,
+    recipient: Address,
+    course_id: u128,
+    token: Option<Address>,
+) -> Enrollment {
+    payer.require_auth();
+    if payer == recipient {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let course: Course = load_open_course(env, course_id);
+    if is_enrolled(env, course_id, &recipient) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    let payment = collect_payment(env, &course, &payer, &recipient, token, None, None, None);
+    let amount_paid: u128 = payment.map(|payment| payment.amount).unwrap_or(0);
+    let enrollment = record_student_enrollment(
+        env,
+        &course,
+        &recipient,
+        amount_paid,
+        EnrollmentSource::Gift,
+    );
+
+    env.events()
+        .publish((GIFT_EVENT, recipient), (payer, course_id, amount_paid));
+
+    enrollment
+}
+
+/// Buys a course for an address that has not registered yet.
+///
+/// The payer is charged the current price, which is held by the contract as
+/// a pending gift for the recipient. The recipient enrolls with `claim_gift`
+/// before the gift expires; after that the payer can take the funds back with
+/// `reclaim_gift`. Each recipient has at most one pending gift per course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - payer: the address paying for the course (must authorize).
+/// - recipient: the address the gift is for.
+/// - course_id: unique identifier of the course.
+/// - token: the token to pay with; the payment token or one on the course
+///   price list.
+/// - claim_period: seconds the recipient has to claim the gift, up to
+///   `MAX_GIFT_CLAIM_PERIOD`.
+///
+/// Returns:
+/// - PendingGift: the stored gift.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the payer gifts the course to themselves,
+///   the claim period is out of range or the course costs nothing.
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the recipient is already enrolled.
+/// - Returns `PaymentError::GiftPending` if the recipient already has a pending
+///   gift for the course.
+/// - Any error `payments::quote_payment` can return for the token.
+///
+/// Storage used:
+/// - PaymentKey::PendingGift(course_id, recipient) -> PendingGift
+pub fn create_pending_gift(
+    env: &Env,
+    payer: Address,
+    recipient: Address,
+    course_id: u128,
+    token: Option<Address>,
+    claim_period: u64,
+) -> PendingGift {
+    payer.require_auth();
+    if payer == recipient || claim_period == 0 || claim_period > MAX_GIFT_CLAIM_PERIOD {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let course: Course = load_open_course(env, course_id);
+    if is_enrolled(env, course_id, &recipient) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+    let key = PaymentKey::PendingGift(course_id, recipient.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(env, PaymentError::GiftPending)
+    }
+
+    let (accepted, amount): (Address, u128) = match quote_payment(env, &course, token, None, None) {
+        Some(quote) => quote,
+        None => handle_error(env, Error::InvalidInput),
+    };
+    if amount > 0 {
+        token::Client::new(env, &accepted).transfer(
+            &payer,
+            &env.current_contract_address(),
+            &to_token_amount(env, amount),
+        );
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let gift = PendingGift {
+        course_id,
+        payer: payer.clone(),
+        recipient: recipient.clone(),
+        token: accepted,
+        amount,
+        created_at: now,
+        expires_at: now + claim_period,
+    };
+    env.storage().persistent().set(&key, &gift);
+
+    env.events().publish(
+        (PENDING_GIFT_EVENT, recipient),
+        (payer, course_id, amount, gift.expires_at),
+    );
+
+    gift
+}
+
+/// Claims a pending gift, enrolling the recipient in the course.
+///
+/// The held funds become a regular payment by the giver, so the refund window
+/// starts now and a refund goes back to the giver.
+///
+/// Errors:
+/// - Returns `PaymentError::GiftNotFound` if there is no pending gift.
+/// - Returns `PaymentError::GiftExpired` if the claim period has ended.
+/// - Returns `Error::CourseNotFound` if the course was deleted.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the recipient enrolled meanwhile.
+pub fn claim_gift(env: &Env, recipient: Address, course_id: u128) -> Enrollment {
+    recipient.require_auth();
+
+    let key = PaymentKey::PendingGift(course_id, recipient.clone());
+    let gift: PendingGift = load_gift(env, &key);
+    if env.ledger().timestamp() >= gift.expires_at {
+        handle_error(env, PaymentError::GiftExpired)
+    }
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    if is_enrolled(env, course_id, &recipient) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    env.storage().persistent().remove(&key);
+    record_payment(
+        env,
+        &course,
+        &gift.payer,
+        &recipient,
+        &gift.token,
+        gift.amount,
+        None,
+    );
+    let enrollment = record_student_enrollment(
+        env,
+        &course,
+        &recipient,
+        gift.amount,
+        EnrollmentSource::Gift,
+    );
+
+    env.events()
+        .publish((CLAIM_GIFT_EVENT, recipient), (gift.payer, course_id));
+
+    enrollment
+}
+
+/// Returns an expired, unclaimed gift to its payer.
+///
+/// Errors:
+/// - Returns `PaymentError::GiftNotFound` if there is no pending gift.
+/// - Returns `Error::Unauthorized` if the caller did not pay for the gift.
+/// - Returns `PaymentError::GiftNotExpired` if the recipient can still claim it.
+pub fn reclaim_gift(env: &Env, payer: Address, course_id: u128, recipient: Address) -> PendingGift {
+    payer.require_auth();
+
+    let key = PaymentKey::PendingGift(course_id, recipient.clone());
+    let gift: PendingGift = load_gift(env, &key);
+    if gift.payer != payer {
+        handle_error(env, Error::Unauthorized)
+    }
+    if env.ledger().timestamp() < gift.expires_at {
+        handle_error(env, PaymentError::GiftNotExpired)
+    }
+
+    env.storage().persistent().remove(&key);
+    if gift.amount > 0 {
+        token::Client::new(env, &gift.token).transfer(
+            &env.current_contract_address(),
+            &payer,
+            &to_token_amount(env, gift.amount),
+        );
+    }
+
+    env.events().publish(
+        (RECLAIM_GIFT_EVENT, course_id),
+        (payer, recipient, gift.amount),
+    );
+
+    gift
+}
+
+/// Returns the pending gift of a course for a recipient, if any.
+pub fn get_pending_gift(env: &Env, course_id: u128, recipient: Address) -> Option<PendingGift> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::PendingGift(course_id, recipient))
+}
+
+fn load_open_course(env: &Env, course_id: u128) -> Course {
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    course
+}
+
+fn load_gift(env: &Env, key: &PaymentKey) -> PendingGift {
+    match env.storage().persistent().get(key) {
+        Some(gift) => gift,
+        None => handle_error(env, PaymentError::GiftNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::Course;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Course) {
+        let (client, admin, token) = setup_registry_with_token(env);
+        client.set_refund_window(&admin, &DAY);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.edit_course(&creator, &course.id, &publish_params());
+        (client, token, course)
+    }
+
+    #[test]
+    fn test_gift_enrolls_recipient_and_refunds_payer() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+
+        let payer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+        let enrollment = client.gift_course(&payer, &recipient, &course.id, &Some(token.clone()));
+
+        assert_eq!(enrollment.student, recipient);
+        assert_eq!(enrollment.amount_paid, 1000);
+        assert!(client.get_enrollment(&course.id, &payer).is_none());
+        let payment = client.get_payment(&course.id, &recipient).unwrap();
+        assert_eq!(payment.payer, payer);
+
+        client.request_refund(&recipient, &course.id, &None);
+        let balances = token::Client::new(&env, &token);
+        assert_eq!(balances.balance(&payer), 1000);
+        assert_eq!(balances.balance(&recipient), 0);
+    }
+
+    #[test]
+    fn test_pending_gift_claimed_by_recipient() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let payer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+
+        let gift =
+            client.create_pending_gift(&payer, &recipient, &course.id, &Some(token.clone()), &DAY);
+        assert_eq!(gift.amount, 1000);
+        assert!(client.get_enrollment(&course.id, &recipient).is_none());
+
+        let enrollment = client.claim_gift(&recipient, &course.id);
+        assert_eq!(enrollment.amount_paid, 1000);
+        assert!(client.get_pending_gift(&course.id, &recipient).is_none());
+        assert_eq!(
+            client.get_payment(&course.id, &recipient).unwrap().payer,
+            payer
+        );
+    }
+
+    #[test]
+    fn test_expired_gift_reclaimed_by_payer() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env);
+        let payer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&payer, &1000);
+        client.create_pending_gift(&payer, &recipient, &course.id, &Some(token.clone()), &DAY);
+
+        assert!(client
+            .try_reclaim_gift(&payer, &course.id, &recipient)
+            .is_err());
+        env.ledger().with_mut(|li| li.timestamp += DAY);
+        assert!(client.try_claim_gift(&recipient, &course.id).is_err());
+
+        client.reclaim_gift(&payer, &course.id, &recipient);
+        assert_eq!(token::Client::new(&env, &token).balance(&payer), 1000);
+        assert!(client.get_pending_gift(&course.id, &recipient).is_none());
+    }
+}
@@ -0,0 +1,426 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::{get_enrollment, is_enrolled, record_student_enrollment};
+use super::enrollment_events::publish_transition;
+use super::escrow::release_previous_payment;
+use super::payments::{
+    bps_of, get_payment, get_payment_token, get_platform_fee, settle_payment, to_token_amount,
+};
+use super::sales::effective_price;
+use crate::error::{handle_error, EnrollmentError, Error, PaymentError};
+use crate::schema::{
+    Course, CourseStatus, DataKey, EnrollmentSource, EnrollmentTransition, InstallmentPlan,
+    InstallmentSchedule, Payment, PaymentKey, PaymentStatus, MAX_INSTALLMENTS,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_INSTALLMENT_PLAN_EVENT: Symbol = symbol_short!("setInstPl");
+const INSTALLMENT_EVENT: Symbol = symbol_short!("instPaid");
+
+/// Offers a course in installments, or stops offering it.
+///
+/// Students already paying in installments keep their schedule.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - plan: number of installments, time between them and grace period, or
+///   `None` to stop offering installments.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the course is free, the count is not
+///   between 2 and `MAX_INSTALLMENTS`, or the interval is 0.
+///
+/// Storage used:
+/// - PaymentKey::InstallmentPlan(course_id) -> InstallmentPlan
+pub fn set_installment_plan(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    plan: Option<InstallmentPlan>,
+) {
+    let course: Course = load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::InstallmentPlan(course_id);
+    match &plan {
+        Some(plan) => {
+            if course.is_free
+                || plan.count < 2
+                || plan.count > MAX_INSTALLMENTS
+                || plan.interval == 0
+            {
+                handle_error(env, Error::InvalidInput)
+            }
+            env.storage().persistent().set(&key, plan);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_INSTALLMENT_PLAN_EVENT, course_id), plan);
+}
+
+/// Returns the installment plan offered for a course, if any.
+pub fn get_installment_plan(env: &Env, course_id: u128) -> Option<InstallmentPlan> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::InstallmentPlan(course_id))
+}
+
+/// Enrolls a student who pays the course price in installments.
+///
+/// The current price is split into equal installments, the first one taking
+/// any rounding remainder, and the first installment is charged right away.
+/// The student has full access from then on. Installments are released to the
+/// creator as they are paid, so the refund window does not apply to them.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolling student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - token: the token to pay with; must be the accepted payment token.
+///
+/// Returns:
+/// - InstallmentSchedule: what is paid and when the next installment is due.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Returns `PaymentError::InstallmentsNotOffered` if the course has no
+///   installment plan.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is not accepted or no
+///   payment token is set.
+/// - Returns `PaymentError::EscrowActive` if the refund window of an earlier
+///   payment of the student for the course is still open.
+///
+/// Storage used:
+/// - PaymentKey::Installments(course_id, student) -> InstallmentSchedule
+pub fn enroll_with_installments(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    token: Option<Address>,
+) -> InstallmentSchedule {
+    student.require_auth();
+
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+    let plan: InstallmentPlan = match get_installment_plan(env, course_id) {
+        Some(plan) if !course.is_free => plan,
+        _ => handle_error(env, PaymentError::InstallmentsNotOffered),
+    };
+    let accepted: Address = match get_payment_token(env) {
+        Some(accepted) if token.as_ref() == Some(&accepted) => accepted,
+        _ => handle_error(env, PaymentError::UnsupportedToken),
+    };
+
+    release_previous_payment(env, course_id, &student);
+
+    let total: u128 = effective_price(env, &course);
+    let now: u64 = env.ledger().timestamp();
+    let mut schedule = InstallmentSchedule {
+        course_id,
+        student: student.clone(),
+        token: accepted,
+        total,
+        paid: 0,
+        count: plan.count,
+        paid_count: 0,
+        interval: plan.interval,
+        grace_period: plan.grace_period,
+        started_at: now,
+        next_due_at: Some(now),
+    };
+    let amount: u128 = charge_installment(env, &course, &mut schedule);
+    record_student_enrollment(
+        env,
+        &course,
+        &student,
+        amount,
+        EnrollmentSource::Installments,
+    );
+
+    schedule
+}
+
+/// Pays the next installment of a course.
+///
+/// Paying an overdue installment lifts the suspension of the student's access.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course was deleted.
+/// - Returns `PaymentError::NothingDue` if the student has no installments
+///   left to pay for the course.
+pub fn pay_installment(env: &Env, student: Address, course_id: u128) -> InstallmentSchedule {
+    student.require_auth();
+
+    let mut schedule: InstallmentSchedule =
+        match get_installment_schedule(env, course_id, student.clone()) {
+            Some(schedule) if schedule.next_due_at.is_some() => schedule,
+            _ => handle_error(env, PaymentError::NothingDue),
+        };
+    let course: Course = load_course(env, course_id);
+    let amount: u128 = charge_installment(env, &course, &mut schedule);
+
+    if let Some(mut enrollment) = get_enrollment(env, course_id, student.clone()) {
+        enrollment.amount_paid += amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Enrollment(course_id, student), &enrollment);
+    }
+
+    schedule
+}
+
+/// Returns a student's installment schedule for a course, if they pay in installments.
+pub fn get_installment_schedule(
+    env: &Env,
+    course_id: u128,
+    student: Address,
+) -> Option<InstallmentSchedule> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Installments(course_id, student))
+}
+
+/// Whether a student's access to a course is suspended because an
+/// installment is overdue past its grace period.
+pub fn is_access_suspended(env: &Env, course_id: u128, student: &Address) -> bool {
+    match get_installment_schedule(env, course_id, student.clone()) {
+        Some(InstallmentSchedule {
+            next_due_at: Some(due_at),
+            grace_period,
+            ..
+        }) => env.ledger().timestamp() > due_at.saturating_add(grace_period),
+        _ => false,
+    }
+}
+
+/// Charges the next installment of a schedule and saves the schedule.
+///
+/// The installment is settled at once. The first one replaces the student's
+/// payment record for the course, which an earlier enrollment may have left;
+/// later ones are added to it.
+fn charge_installment(env: &Env, course: &Course, schedule: &mut InstallmentSchedule) -> u128 {
+    let base: u128 = schedule.total / schedule.count as u128;
+    let amount: u128 = if schedule.paid_count == 0 {
+        schedule.total - base * (schedule.count as u128 - 1)
+    } else {
+        base
+    };
+    if amount > 0 {
+        token::Client::new(env, &schedule.token).transfer(
+            &schedule.student,
+            &env.current_contract_address(),
+            &to_token_amount(env, amount),
+        );
+    }
+
+    let platform_fee: u128 = match get_platform_fee(env) {
+        Some(fee) => bps_of(amount, fee.fee_bps),
+        None => 0,
+    };
+    let now: u64 = env.ledger().timestamp();
+    let mut installment = Payment {
+        course_id: course.id,
+        payer: schedule.student.clone(),
+        creator: course.creator.clone(),
+        token: schedule.token.clone(),
+        amount,
+        platform_fee,
+        creator_amount: amount - platform_fee,
+        referrer: None,
+        referral_commission: 0,
+        paid_at: now,
+        status: PaymentStatus::Escrowed,
+        escrow_until: now,
+    };
+    settle_payment(env, &mut installment);
+    publish_transition(
+        env,
+        course.id,
+        &schedule.student,
+        EnrollmentTransition::Paid(schedule.token.clone(), amount),
+    );
+    let payment: Payment = match get_payment(env, course.id, schedule.student.clone()) {
+        Some(mut payment) if schedule.paid_count > 0 => {
+            payment.amount += installment.amount;
+            payment.platform_fee += installment.platform_fee;
+            payment.creator_amount += installment.creator_amount;
+            payment.paid_at = now;
+            payment
+        }
+        _ => installment,
+    };
+    env.storage().persistent().set(
+        &PaymentKey::Payment(course.id, schedule.student.clone()),
+        &payment,
+    );
+
+    schedule.paid += amount;
+    schedule.paid_count += 1;
+    schedule.next_due_at = if schedule.paid_count < schedule.count {
+        Some(
+            schedule
+                .started_at
+                .saturating_add(schedule.interval.saturating_mul(schedule.paid_count as u64)),
+        )
+    } else {
+        None
+    };
+    env.storage().persistent().set(
+        &PaymentKey::Installments(course.id, schedule.student.clone()),
+        schedule,
+    );
+
+    env.events().publish(
+        (INSTALLMENT_EVENT, course.id),
+        (schedule.student.clone(), amount, schedule.paid_count),
+    );
+
+    amount
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{InstallmentPlan, ModuleAccess, PaymentStatus};
+
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    #[test]
+    fn test_installments_suspend_and_restore_access() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        let plan = InstallmentPlan {
+            count: 3,
+            interval: 30 * DAY,
+            grace_period: 5 * DAY,
+        };
+        client.set_installment_plan(&creator, &course.id, &Some(plan));
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        let schedule = client.enroll_with_installments(&student, &course.id, &Some(token.clone()));
+        assert_eq!(schedule.paid, 334);
+        assert_eq!(schedule.next_due_at, Some(30 * DAY));
+        assert_eq!(
+            client.check_module_access(&student, &module.id),
+            ModuleAccess::Granted
+        );
+
+        env.ledger().set_timestamp(36 * DAY);
+        assert_eq!(
+            client.check_module_access(&student, &module.id),
+            ModuleAccess::Suspended
+        );
+        assert!(!client.has_course_access(&course.id, &student));
+
+        client.pay_installment(&student, &course.id);
+        assert_eq!(
+            client.check_module_access(&student, &module.id),
+            ModuleAccess::Granted
+        );
+        let schedule = client.pay_installment(&student, &course.id);
+        assert_eq!(schedule.paid, 1000);
+        assert_eq!(schedule.next_due_at, None);
+        assert_eq!(client.get_earnings(&creator, &token), 1000);
+        assert_eq!(
+            client
+                .get_enrollment(&course.id, &student)
+                .unwrap()
+                .amount_paid,
+            1000
+        );
+        assert!(client.try_pay_installment(&student, &course.id).is_err());
+    }
+
+    #[test]
+    fn test_installments_after_unenrolling_from_escrowed_payment() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        client.set_refund_window(&admin, &(14 * DAY));
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &900_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let plan = InstallmentPlan {
+            count: 3,
+            interval: 30 * DAY,
+            grace_period: 5 * DAY,
+        };
+        client.set_installment_plan(&creator, &course.id, &Some(plan));
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1800);
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        env.ledger().set_timestamp(15 * DAY);
+        assert!(!client.unenroll(&student, &course.id));
+
+        client.enroll_with_installments(&student, &course.id, &Some(token.clone()));
+        assert_eq!(client.get_earnings(&creator, &token), 1200);
+        let payment = client.get_payment(&course.id, &student).unwrap();
+        assert_eq!(payment.amount, 300);
+        assert_eq!(payment.status, PaymentStatus::Released);
+        assert!(client.try_release_payment(&course.id, &student).is_err());
+        assert_eq!(client.get_earnings(&creator, &token), 1200);
+    }
+}
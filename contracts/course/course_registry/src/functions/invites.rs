@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::coupons::{apply_discount, hash_code};
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::{quote_payment, record_payment, to_token_amount};
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{
+    Course, CourseStatus, Discount, Enrollment, EnrollmentKey, EnrollmentSource, Invite,
+    MAX_INVITE_BATCH,
+};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CREATE_INVITES_EVENT: Symbol = symbol_short!("invites");
+const REVOKE_INVITE_EVENT: Symbol = symbol_short!("rmInvite");
+const USE_INVITE_EVENT: Symbol = symbol_short!("useInvite");
+
+/// Creates single-use invite codes for a course.
+///
+/// Only the SHA-256 hashes of the codes are stored, as for coupons. Each code
+/// enrolls one student, free with `Discount::Percent(100)` or at the
+/// discounted price otherwise.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - code_hashes: SHA-256 hashes of the codes, at most `MAX_INVITE_BATCH`.
+/// - discount: the price reduction each code grants.
+/// - expires_at: ledger timestamp from which the codes are rejected.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the batch is empty or too large, the
+///   discount is out of range, `expires_at` has passed or a code already
+///   exists.
+///
+/// Storage used:
+/// - EnrollmentKey::Invite(course_id, code_hash) -> Invite
+pub fn create_invites(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    code_hashes: Vec<BytesN<32>>,
+    discount: Discount,
+    expires_at: u64,
+) {
+    load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let valid_discount = match discount {
+        Discount::Percent(percent) => (1..=100).contains(&percent),
+        Discount::Fixed(amount) => amount > 0,
+    };
+    if !valid_discount
+        || code_hashes.is_empty()
+        || code_hashes.len() > MAX_INVITE_BATCH
+        || expires_at <= env.ledger().timestamp()
+    {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let invite = Invite {
+        course_id,
+        discount,
+        expires_at,
+        used_by: None,
+    };
+    for code_hash in code_hashes.iter() {
+        let key = EnrollmentKey::Invite(course_id, code_hash);
+        if env.storage().persistent().has(&key) {
+            handle_error(env, Error::InvalidInput)
+        }
+        env.storage().persistent().set(&key, &invite);
+    }
+
+    env.events()
+        .publish((CREATE_INVITES_EVENT, course_id), code_hashes.len());
+}
+
+/// Deletes an invite code nobody used yet.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `EnrollmentError::InviteNotFound` if the code does not exist.
+/// - Returns `EnrollmentError::InviteUsed` if the code was already used.
+pub fn revoke_invite(env: &Env, caller: Address, course_id: u128, code_hash: BytesN<32>) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::Invite(course_id, code_hash.clone());
+    match env.storage().persistent().get::<_, Invite>(&key) {
+        Some(invite) if invite.used_by.is_some() => handle_error(env, EnrollmentError::InviteUsed),
+        Some(_) => env.storage().persistent().remove(&key),
+        None => handle_error(env, EnrollmentError::InviteNotFound),
+    }
+
+    env.events()
+        .publish((REVOKE_INVITE_EVENT, course_id), code_hash);
+}
+
+/// Enrolls a student with an invite code.
+///
+/// The code is hashed and checked against the course's invites, then marked
+/// as used by the student, who pays the discounted price of the course in
+/// `token`, if anything. The discount applies to the amount quoted for the
+/// token, so it also covers sale, price list and USD prices. An invite for
+/// 100% off needs no token. The hash of the code used is kept with the
+/// enrollment.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the student enrolling (must authorize).
+/// - course_id: unique identifier of the course.
+/// - code: the invite code.
+/// - token: the token to pay any remaining price with.
+///
+/// Errors:
+/// - Returns `EnrollmentError::InviteNotFound` if no invite matches the code.
+/// - Returns `EnrollmentError::InviteUsed` if the code was already used.
+/// - Returns `EnrollmentError::InviteExpired` if the code has expired.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Any error `payments::quote_payment` can return for the token.
+///
+/// Storage used:
+/// - EnrollmentKey::StudentInvite(course_id, student) -> BytesN<32>
+pub fn enroll_with_invite(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    code: String,
+    token: Option<Address>,
+) -> Enrollment {
+    student.require_auth();
+
+    let code_hash: BytesN<32> = match hash_code(env, &code) {
+        Some(code_hash) => code_hash,
+        None => handle_error(env, EnrollmentError::InviteNotFound),
+    };
+    let key = EnrollmentKey::Invite(course_id, code_hash.clone());
+    let mut invite: Invite = match env.storage().persistent().get(&key) {
+        Some(invite) => invite,
+        None => handle_error(env, EnrollmentError::InviteNotFound),
+    };
+    if invite.used_by.is_some() {
+        handle_error(env, EnrollmentError::InviteUsed)
+    }
+    if env.ledger().timestamp() >= invite.expires_at {
+        handle_error(env, EnrollmentError::InviteExpired)
+    }
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    invite.used_by = Some(student.clone());
+    env.storage().persistent().set(&key, &invite);
+    env.storage().persistent().set(
+        &EnrollmentKey::StudentInvite(course_id, student.clone()),
+        &code_hash,
+    );
+
+    let quote: Option<(Address, u128)> = match invite.discount {
+        Discount::Percent(100) => None,
+        _ => quote_payment(env, &course, token, None, None),
+    };
+    let amount_paid: u128 = match quote {
+        Some((accepted, price)) => match apply_discount(price, &invite.discount) {
+            0 => 0,
+            amount => {
+                token::Client::new(env, &accepted).transfer(
+                    &student,
+                    &env.current_contract_address(),
+                    &to_token_amount(env, amount),
+                );
+                record_payment(env, &course, &student, &student, &accepted, amount, None).amount
+            }
+        },
+        None => 0,
+    };
+
+    env.events()
+        .publish((USE_INVITE_EVENT, course_id), (student.clone(), code_hash));
+
+    record_student_enrollment(
+        env,
+        &course,
+        &student,
+        amount_paid,
+        EnrollmentSource::Invite,
+    )
+}
+
+/// Returns the invite behind a code hash, if it exists.
+pub fn get_invite(env: &Env, course_id: u128, code_hash: BytesN<32>) -> Option<Invite> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::Invite(course_id, code_hash))
+}
+
+/// Returns the hash of the invite code a student enrolled with, if any.
+pub fn get_student_invite(env: &Env, course_id: u128, student: Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::StudentInvite(course_id, student))
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::{Discount, EnrollmentSource};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        map,
+        testutils::{Address as _, Ledger},
+        token, vec, Address, Bytes, Env, String,
+    };
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Address, u128) {
+        let (client, _admin, token) = setup_registry_with_token(env);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        (client, token, creator, course.id)
+    }
+
+    #[test]
+    fn test_invites_are_single_use() {
+        let env = Env::default();
+        let (client, token, creator, course_id) = setup(&env);
+        let free = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"FREE-1"))
+            .to_bytes();
+        let half = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"HALF-1"))
+            .to_bytes();
+        client.create_invites(
+            &creator,
+            &course_id,
+            &vec![&env, free.clone()],
+            &Discount::Percent(100),
+            &1000,
+        );
+        client.create_invites(
+            &creator,
+            &course_id,
+            &vec![&env, half.clone()],
+            &Discount::Percent(50),
+            &1000,
+        );
+
+        let guest = Address::generate(&env);
+        let enrollment =
+            client.enroll_with_invite(&guest, &course_id, &String::from_str(&env, "FREE-1"), &None);
+        assert_eq!(enrollment.amount_paid, 0);
+        assert_eq!(enrollment.source, EnrollmentSource::Invite);
+        assert_eq!(
+            client.get_student_invite(&course_id, &guest),
+            Some(free.clone())
+        );
+        assert_eq!(
+            client.get_invite(&course_id, &free).unwrap().used_by,
+            Some(guest)
+        );
+        assert!(client
+            .try_enroll_with_invite(
+                &Address::generate(&env),
+                &course_id,
+                &String::from_str(&env, "FREE-1"),
+                &None,
+            )
+            .is_err());
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &500);
+        let enrollment = client.enroll_with_invite(
+            &student,
+            &course_id,
+            &String::from_str(&env, "HALF-1"),
+            &Some(token.clone()),
+        );
+        assert_eq!(enrollment.amount_paid, 500);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #526)")]
+    fn test_expired_invite() {
+        let env = Env::default();
+        let (client, _token, creator, course_id) = setup(&env);
+        let code_hash = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"LATE"))
+            .to_bytes();
+        client.create_invites(
+            &creator,
+            &course_id,
+            &vec![&env, code_hash],
+            &Discount::Percent(100),
+            &1000,
+        );
+        env.ledger().set_timestamp(1000);
+
+        client.enroll_with_invite(
+            &Address::generate(&env),
+            &course_id,
+            &String::from_str(&env, "LATE"),
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_invite_discount_applies_to_price_list() {
+        let env = Env::default();
+        let (client, _token, creator, course_id) = setup(&env);
+        let other = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        client.set_course_prices(&creator, &course_id, &map![&env, (other.clone(), 4000)]);
+        let half = env
+            .crypto()
+            .sha256(&Bytes::from_slice(&env, b"HALF-1"))
+            .to_bytes();
+        client.create_invites(
+            &creator,
+            &course_id,
+            &vec![&env, half],
+            &Discount::Percent(50),
+            &1000,
+        );
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &other).mint(&student, &4000);
+        let enrollment = client.enroll_with_invite(
+            &student,
+            &course_id,
+            &String::from_str(&env, "HALF-1"),
+            &Some(other.clone()),
+        );
+        assert_eq!(enrollment.amount_paid, 2000);
+        assert_eq!(token::Client::new(&env, &other).balance(&student), 2000);
+        assert_eq!(
+            client.get_payment(&course_id, &student).unwrap().token,
+            other
+        );
+    }
+}
@@ -2,11 +2,11 @@
 // Copyright (c) 2025 SkillCert
 
 use crate::schema::Course;
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
 const COURSE_KEY: Symbol = symbol_short!("course");
 
-pub fn is_course_creator(env: &Env, course_id: String, user: Address) -> bool {
-    let key = (COURSE_KEY, course_id.clone());
+pub fn is_course_creator(env: &Env, course_id: u128, user: Address) -> bool {
+    let key = (COURSE_KEY, course_id);
     let course: Course = env.storage().persistent().get(&key).unwrap();
 
     if course.creator == user {
@@ -17,10 +17,9 @@ pub fn is_course_creator(env: &Env, course_id: String, user: Address) -> bool {
 
 #[cfg(test)]
 mod test {
-    use super::*;
     use crate::CourseRegistry;
     use crate::{schema::Course, CourseRegistryClient};
-    use soroban_sdk::{testutils::Address as _, Address, Env};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
     #[test]
     fn test_is_cource_creator_success() {
@@ -39,7 +38,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -67,7 +66,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::content_limits::validate_metadata_uri;
+use super::lessons::get_lesson;
+use super::utils::trim;
+use crate::error::{handle_error, Error};
+use crate::schema::{DataKey, Lesson, ResourceLink, MAX_LESSON_RESOURCES};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const ADD_RESOURCE_EVENT: Symbol = symbol_short!("addRsrc");
+const REMOVE_RESOURCE_EVENT: Symbol = symbol_short!("rmRsrc");
+
+/// Longest resource label accepted, in bytes.
+const MAX_RESOURCE_LABEL_LEN: u32 = 100;
+
+/// Attaches a downloadable resource to a lesson.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - lesson_id: unique identifier of the lesson.
+/// - resource: label, `ipfs://`, `ar://` or `https://` URI and optional hash.
+///
+/// Returns:
+/// - Lesson: the updated lesson.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the label is empty or too long, or the
+///   lesson already has `MAX_LESSON_RESOURCES` resources.
+pub fn add_lesson_resource(env: &Env, caller: Address, lesson_id: u128, resource: ResourceLink) -> Lesson {
+    let mut lesson: Lesson = get_lesson(env, lesson_id);
+    require_course_management_auth(env, &caller, lesson.course_id);
+
+    if trim(env, &resource.label).is_empty() || resource.label.len() > MAX_RESOURCE_LABEL_LEN {
+        handle_error(env, Error::InvalidInput)
+    }
+    validate_metadata_uri(env, &resource.uri);
+    if lesson.resources.len() >= MAX_LESSON_RESOURCES {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    lesson.resources.push_back(resource);
+    save_lesson(env, &mut lesson);
+
+    env.events()
+        .publish((ADD_RESOURCE_EVENT, lesson_id), lesson.resources.len());
+
+    lesson
+}
+
+/// Removes the resource at `index` from a lesson.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if `index` is out of range.
+pub fn remove_lesson_resource(env: &Env, caller: Address, lesson_id: u128, index: u32) -> Lesson {
+    let mut lesson: Lesson = get_lesson(env, lesson_id);
+    require_course_management_auth(env, &caller, lesson.course_id);
+
+    if index >= lesson.resources.len() {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    lesson.resources.remove(index);
+    save_lesson(env, &mut lesson);
+
+    env.events()
+        .publish((REMOVE_RESOURCE_EVENT, lesson_id), index);
+
+    lesson
+}
+
+fn save_lesson(env: &Env, lesson: &mut Lesson) {
+    lesson.updated_at = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lesson(lesson.id), &*lesson);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Lesson, LessonContentType, ResourceLink};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Lesson) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Module"));
+        let lesson = client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(env, "Lesson"),
+            &LessonContentType::Text,
+            &None,
+            &15,
+        );
+        (client, creator, lesson)
+    }
+
+    fn resource(env: &Env, label: &str) -> ResourceLink {
+        ResourceLink {
+            label: String::from_str(env, label),
+            uri: String::from_str(env, "https://example.com/slides.pdf"),
+            hash: Some(BytesN::from_array(env, &[1; 32])),
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_resources() {
+        let env = Env::default();
+        let (client, creator, lesson) = setup(&env);
+
+        client.add_lesson_resource(&creator, &lesson.id, &resource(&env, "Slides"));
+        let updated = client.add_lesson_resource(&creator, &lesson.id, &resource(&env, "Worksheet"));
+        assert_eq!(updated.resources.len(), 2);
+
+        let updated = client.remove_lesson_resource(&creator, &lesson.id, &0);
+        assert_eq!(updated.resources.len(), 1);
+        assert_eq!(
+            client.get_lesson(&lesson.id).resources.get(0).unwrap().label,
+            String::from_str(&env, "Worksheet")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_resource_limit() {
+        let env = Env::default();
+        let (client, creator, lesson) = setup(&env);
+
+        for _ in 0..11 {
+            client.add_lesson_resource(&creator, &lesson.id, &resource(&env, "Extra"));
+        }
+    }
+}
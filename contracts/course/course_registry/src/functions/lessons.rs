@@ -0,0 +1,535 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::content_limits::validate_metadata_uri;
+use super::course_totals::recompute_course_totals;
+use super::utils::trim;
+use crate::error::{handle_error, Error};
+use crate::schema::{
+    CourseModule, DataKey, EditLessonParams, Lesson, LessonContentType, MAX_LESSONS_PER_MODULE,
+};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const ADD_LESSON_EVENT: Symbol = symbol_short!("addLesson");
+const EDIT_LESSON_EVENT: Symbol = symbol_short!("editLesn");
+const REMOVE_LESSON_EVENT: Symbol = symbol_short!("rmLesson");
+const REORDER_LESSONS_EVENT: Symbol = symbol_short!("reordLesn");
+const LESSON_HASH_EVENT: Symbol = symbol_short!("lesnHash");
+
+/// Longest lesson title accepted, in bytes.
+const MAX_LESSON_TITLE_LEN: u32 = 200;
+/// Longest lesson duration accepted (one week), in minutes.
+const MAX_LESSON_DURATION_MINUTES: u32 = 10_080;
+
+/// Appends a lesson to a module.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: the module the lesson belongs to.
+/// - title: lesson title.
+/// - content_type: kind of content the lesson delivers.
+/// - content_uri: optional `ipfs://`, `ar://` or `https://` URI of the content.
+/// - duration_minutes: expected time to complete the lesson.
+///
+/// Returns:
+/// - Lesson: the created lesson.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if a field is invalid or the module is full.
+///
+/// Storage used:
+/// - DataKey::LessonSeq -> u128
+/// - DataKey::Lesson(lesson_id) -> Lesson
+/// - DataKey::ModuleLessons(module_id) -> Vec<u128>
+pub fn add_lesson(
+    env: &Env,
+    caller: Address,
+    module_id: String,
+    title: String,
+    content_type: LessonContentType,
+    content_uri: Option<String>,
+    duration_minutes: u32,
+) -> Lesson {
+    let module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    validate_lesson(env, &title, &content_uri, duration_minutes);
+
+    let mut ids: Vec<u128> = lesson_ids(env, &module_id);
+    if ids.len() >= MAX_LESSONS_PER_MODULE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    let lesson = Lesson {
+        id: next_lesson_id(env),
+        module_id: module_id.clone(),
+        course_id: module.course_id,
+        title,
+        content_type,
+        content_uri,
+        content_hash: None,
+        duration_minutes,
+        created_at: now,
+        updated_at: now,
+        resources: Vec::new(env),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lesson(lesson.id), &lesson);
+    ids.push_back(lesson.id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleLessons(module_id.clone()), &ids);
+    refresh_module_totals(env, &module_id);
+
+    env.events()
+        .publish((ADD_LESSON_EVENT, lesson.course_id), lesson.id);
+
+    lesson
+}
+
+/// Updates the title, content URI or duration of a lesson.
+///
+/// The content type is changed with `set_lesson_content_type`.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if a new value is invalid.
+pub fn edit_lesson(env: &Env, caller: Address, lesson_id: u128, params: EditLessonParams) -> Lesson {
+    let mut lesson: Lesson = get_lesson(env, lesson_id);
+    require_course_management_auth(env, &caller, lesson.course_id);
+
+    if let Some(title) = params.new_title {
+        lesson.title = title;
+    }
+    if let Some(content_uri) = params.new_content_uri {
+        lesson.content_uri = content_uri;
+    }
+    if let Some(duration_minutes) = params.new_duration_minutes {
+        lesson.duration_minutes = duration_minutes;
+    }
+    validate_lesson(env, &lesson.title, &lesson.content_uri, lesson.duration_minutes);
+
+    lesson.updated_at = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lesson(lesson_id), &lesson);
+    refresh_module_totals(env, &lesson.module_id);
+
+    env.events()
+        .publish((EDIT_LESSON_EVENT, lesson.course_id), lesson_id);
+
+    lesson
+}
+
+/// Changes the kind of content a lesson delivers.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+pub fn set_lesson_content_type(
+    env: &Env,
+    caller: Address,
+    lesson_id: u128,
+    content_type: LessonContentType,
+) -> Lesson {
+    let mut lesson: Lesson = get_lesson(env, lesson_id);
+    require_course_management_auth(env, &caller, lesson.course_id);
+
+    lesson.content_type = content_type;
+    lesson.updated_at = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lesson(lesson_id), &lesson);
+
+    env.events()
+        .publish((EDIT_LESSON_EVENT, lesson.course_id), lesson_id);
+
+    lesson
+}
+
+/// Sets or clears the content hash of a lesson.
+///
+/// Emits a `lesnHash` event with the previous and new hash whenever the hash
+/// changes, so content swapped after enrollment can be detected.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+pub fn set_lesson_content_hash(
+    env: &Env,
+    caller: Address,
+    lesson_id: u128,
+    content_hash: Option<BytesN<32>>,
+) -> Lesson {
+    let mut lesson: Lesson = get_lesson(env, lesson_id);
+    require_course_management_auth(env, &caller, lesson.course_id);
+
+    if lesson.content_hash == content_hash {
+        return lesson;
+    }
+
+    let previous: Option<BytesN<32>> = lesson.content_hash.clone();
+    lesson.content_hash = content_hash.clone();
+    lesson.updated_at = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::Lesson(lesson_id), &lesson);
+
+    env.events()
+        .publish((LESSON_HASH_EVENT, lesson_id), (previous, content_hash));
+
+    lesson
+}
+
+/// Deletes a lesson from its module.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+pub fn remove_lesson(env: &Env, caller: Address, lesson_id: u128) {
+    let lesson: Lesson = get_lesson(env, lesson_id);
+    require_course_management_auth(env, &caller, lesson.course_id);
+
+    let mut ids: Vec<u128> = lesson_ids(env, &lesson.module_id);
+    if let Some(i) = ids.first_index_of(lesson_id) {
+        ids.remove(i);
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleLessons(lesson.module_id.clone()), &ids);
+    env.storage().persistent().remove(&DataKey::Lesson(lesson_id));
+    refresh_module_totals(env, &lesson.module_id);
+
+    env.events()
+        .publish((REMOVE_LESSON_EVENT, lesson.course_id), lesson_id);
+}
+
+/// Reorders the lessons of a module.
+///
+/// `new_order` must list every lesson of the module exactly once.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if `new_order` is not a permutation of the
+///   module's lesson IDs.
+pub fn reorder_lessons(env: &Env, caller: Address, module_id: String, new_order: Vec<u128>) -> Vec<Lesson> {
+    let module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    let current: Vec<u128> = lesson_ids(env, &module_id);
+    if new_order.len() != current.len() {
+        handle_error(env, Error::InvalidInput)
+    }
+    for (i, lesson_id) in new_order.iter().enumerate() {
+        if !current.contains(lesson_id) || new_order.first_index_of(lesson_id) != Some(i as u32) {
+            handle_error(env, Error::InvalidInput)
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleLessons(module_id.clone()), &new_order);
+
+    env.events()
+        .publish((REORDER_LESSONS_EVENT, module.course_id), module_id.clone());
+
+    get_module_lessons(env, module_id)
+}
+
+/// Retrieves a lesson by its ID.
+///
+/// Errors:
+/// - Returns `Error::LessonNotFound` if the lesson does not exist.
+pub fn get_lesson(env: &Env, lesson_id: u128) -> Lesson {
+    match env.storage().persistent().get(&DataKey::Lesson(lesson_id)) {
+        Some(lesson) => lesson,
+        None => handle_error(env, Error::LessonNotFound),
+    }
+}
+
+/// Returns the lessons of a module in display order.
+pub fn get_module_lessons(env: &Env, module_id: String) -> Vec<Lesson> {
+    let mut lessons: Vec<Lesson> = Vec::new(env);
+    for lesson_id in lesson_ids(env, &module_id).iter() {
+        let lesson: Option<Lesson> = env.storage().persistent().get(&DataKey::Lesson(lesson_id));
+        if let Some(lesson) = lesson {
+            lessons.push_back(lesson);
+        }
+    }
+    lessons
+}
+
+/// Returns the lesson IDs of a module in display order.
+pub fn lesson_ids(env: &Env, module_id: &String) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ModuleLessons(module_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Deletes every lesson of a module. Used when the module itself is removed.
+pub fn delete_module_lessons(env: &Env, module_id: &String) {
+    for lesson_id in lesson_ids(env, module_id).iter() {
+        env.storage().persistent().remove(&DataKey::Lesson(lesson_id));
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ModuleLessons(module_id.clone()));
+}
+
+/// Copies every lesson of a module into another module under new IDs.
+pub fn copy_module_lessons(env: &Env, from_module: &String, to_module: &String, to_course: u128) {
+    let now: u64 = env.ledger().timestamp();
+    let mut ids: Vec<u128> = Vec::new(env);
+    for lesson in get_module_lessons(env, from_module.clone()).iter() {
+        let copy = Lesson {
+            id: next_lesson_id(env),
+            module_id: to_module.clone(),
+            course_id: to_course,
+            created_at: now,
+            updated_at: now,
+            ..lesson
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Lesson(copy.id), &copy);
+        ids.push_back(copy.id);
+    }
+    if !ids.is_empty() {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ModuleLessons(to_module.clone()), &ids);
+    }
+}
+
+/// Recomputes the lesson count and duration of a module from its lessons,
+/// then the totals of its course.
+fn refresh_module_totals(env: &Env, module_id: &String) {
+    let mut module: CourseModule = load_module(env, module_id);
+
+    let lessons = get_module_lessons(env, module_id.clone());
+    let mut duration_minutes: u32 = 0;
+    for lesson in lessons.iter() {
+        duration_minutes = duration_minutes.saturating_add(lesson.duration_minutes);
+    }
+    module.lesson_count = lessons.len();
+    module.duration_minutes = duration_minutes;
+
+    env.storage()
+        .persistent()
+        .set(&(MODULE_KEY, module_id.clone()), &module);
+    recompute_course_totals(env, module.course_id);
+}
+
+fn validate_lesson(env: &Env, title: &String, content_uri: &Option<String>, duration_minutes: u32) {
+    if trim(env, title).is_empty() || title.len() > MAX_LESSON_TITLE_LEN {
+        handle_error(env, Error::InvalidInput)
+    }
+    if duration_minutes > MAX_LESSON_DURATION_MINUTES {
+        handle_error(env, Error::InvalidInput)
+    }
+    if let Some(uri) = content_uri {
+        validate_metadata_uri(env, uri);
+    }
+}
+
+fn load_module(env: &Env, module_id: &String) -> CourseModule {
+    match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    }
+}
+
+fn next_lesson_id(env: &Env) -> u128 {
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::LessonSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::LessonSeq, &id);
+    id
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{CourseModule, EditLessonParams, LessonContentType};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Events, vec, Address, BytesN, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, CourseModule) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Module"));
+        (client, creator, module)
+    }
+
+    #[test]
+    fn test_add_lessons_updates_totals() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        let video = client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Welcome"),
+            &LessonContentType::Video,
+            &Some(String::from_str(&env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi")),
+            &15,
+        );
+        client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Reading"),
+            &LessonContentType::Text,
+            &None,
+            &25,
+        );
+
+        assert_eq!(client.get_lesson(&video.id).title, String::from_str(&env, "Welcome"));
+        assert_eq!(client.get_module_lessons(&module.id).len(), 2);
+
+        let course = client.get_course(&module.course_id);
+        assert_eq!(course.lesson_count, 2);
+        assert_eq!(course.total_duration_minutes, 40);
+
+        client.remove_lesson(&creator, &video.id);
+        let course = client.get_course(&module.course_id);
+        assert_eq!(course.lesson_count, 1);
+        assert_eq!(course.total_duration_minutes, 25);
+    }
+
+    #[test]
+    fn test_edit_and_reorder_lessons() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        let first = client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "First"),
+            &LessonContentType::Text,
+            &None,
+            &10,
+        );
+        let second = client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Second"),
+            &LessonContentType::Quiz,
+            &None,
+            &5,
+        );
+
+        let edited = client.edit_lesson(
+            &creator,
+            &first.id,
+            &EditLessonParams {
+                new_title: Some(String::from_str(&env, "Intro")),
+                new_content_uri: None,
+                new_duration_minutes: Some(20),
+            },
+        );
+        assert_eq!(edited.title, String::from_str(&env, "Intro"));
+        let edited = client.set_lesson_content_type(&creator, &first.id, &LessonContentType::Video);
+        assert_eq!(edited.content_type, LessonContentType::Video);
+        assert_eq!(edited.title, String::from_str(&env, "Intro"));
+        assert_eq!(client.get_course(&module.course_id).total_duration_minutes, 25);
+
+        let lessons = client.reorder_lessons(&creator, &module.id, &vec![&env, second.id, first.id]);
+        assert_eq!(lessons.get(0).unwrap().id, second.id);
+        assert_eq!(lessons.get(1).unwrap().id, first.id);
+    }
+
+    #[test]
+    fn test_set_lesson_content_hash() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        let lesson = client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Lecture"),
+            &LessonContentType::Video,
+            &None,
+            &45,
+        );
+        assert_eq!(lesson.content_hash, None);
+
+        let hash = BytesN::from_array(&env, &[7; 32]);
+        let updated = client.set_lesson_content_hash(&creator, &lesson.id, &Some(hash.clone()));
+        assert_eq!(updated.content_hash, Some(hash.clone()));
+        assert_eq!(client.get_lesson(&lesson.id).content_hash, Some(hash));
+
+        client.set_lesson_content_hash(&creator, &lesson.id, &Some(BytesN::from_array(&env, &[8; 32])));
+        assert!(!env.events().all().is_empty());
+    }
+
+    #[test]
+    fn test_remove_module_deletes_lessons() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        let lesson = client.add_lesson(
+            &creator,
+            &module.id,
+            &String::from_str(&env, "Lesson"),
+            &LessonContentType::Assignment,
+            &None,
+            &30,
+        );
+        client.remove_module(&creator, &module.id);
+
+        assert!(client.try_get_lesson(&lesson.id).is_err());
+        assert_eq!(client.get_course(&module.course_id).lesson_count, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_add_lesson_unauthorized() {
+        let env = Env::default();
+        let (client, _creator, module) = setup(&env);
+
+        client.add_lesson(
+            &Address::generate(&env),
+            &module.id,
+            &String::from_str(&env, "Lesson"),
+            &LessonContentType::Text,
+            &None,
+            &10,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #42)")]
+    fn test_get_lesson_not_found() {
+        let env = Env::default();
+        let (client, _creator, _module) = setup(&env);
+
+        client.get_lesson(&404);
+    }
+}
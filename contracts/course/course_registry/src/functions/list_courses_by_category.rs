@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::{course_ids_by_category, page_listed_courses};
+use crate::schema::Course;
+use soroban_sdk::{Env, String, Vec};
+
+/// Lists published, public courses filed under a category.
+///
+/// Reads the per-category index instead of scanning every course. The
+/// category is matched ignoring case and spacing, and a registered category
+/// is also found by any of its previous names.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - category: the category name.
+/// - offset: number of matching courses to skip.
+/// - limit: maximum number of courses to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn get_courses_by_category(env: &Env, category: String, offset: u32, limit: u32) -> Vec<Course> {
+    page_listed_courses(env, &course_ids_by_category(env, &category), offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_published_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        category: &str,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &Some(String::from_str(&client.env, category)),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &edit_params(Some(true), None))
+    }
+
+    fn edit_params(published: Option<bool>, category: Option<Option<String>>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: category,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_get_courses_by_category_paginates() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let first = create_published_course(&client, &creator, "Course 1", "Programming");
+        create_published_course(&client, &creator, "Course 2", "Design");
+        let third = create_published_course(&client, &creator, "Course 3", "programming");
+
+        let programming = String::from_str(&env, "Programming");
+        let page = client.get_courses_by_category(&programming, &0, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, first.id);
+        let page = client.get_courses_by_category(&programming, &1, &1);
+        assert_eq!(page.get(0).unwrap().id, third.id);
+        assert!(client.try_get_courses_by_category(&programming, &0, &0).is_err());
+    }
+
+    #[test]
+    fn test_get_courses_by_category_follows_edits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_published_course(&client, &creator, "Course 1", "Programming");
+        client.edit_course(
+            &creator,
+            &course.id,
+            &edit_params(None, Some(Some(String::from_str(&env, "Design")))),
+        );
+
+        let programming = String::from_str(&env, "Programming");
+        let design = String::from_str(&env, "Design");
+        assert_eq!(client.get_courses_by_category(&programming, &0, &10).len(), 0);
+        assert_eq!(client.get_courses_by_category(&design, &0, &10).len(), 1);
+
+        client.delete_course(&creator, &course.id);
+        assert_eq!(client.get_courses_by_category(&design, &0, &10).len(), 0);
+    }
+}
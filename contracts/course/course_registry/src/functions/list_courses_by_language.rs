@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::{course_ids_by_language, page_listed_courses};
+use crate::schema::Course;
+use soroban_sdk::{Env, String, Vec};
+
+/// Lists published, public courses taught in a language.
+///
+/// Reads the per-language index instead of scanning every course. Language
+/// codes are normalized on write, so "EN" finds courses created with "en".
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - language: the language code, e.g. "en" or "es".
+/// - offset: number of matching courses to skip.
+/// - limit: maximum number of courses to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn get_courses_by_language(env: &Env, language: String, offset: u32, limit: u32) -> Vec<Course> {
+    page_listed_courses(env, &course_ids_by_language(env, &language), offset, limit)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_published_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        language: &str,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &Some(String::from_str(&client.env, language)),
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &edit_params(Some(true), None))
+    }
+
+    fn edit_params(published: Option<bool>, language: Option<Option<String>>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: language,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_get_courses_by_language_normalizes_codes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let english = create_published_course(&client, &creator, "Course 1", "EN");
+        assert_eq!(english.language, Some(String::from_str(&env, "en")));
+        create_published_course(&client, &creator, "Course 2", " en ");
+        create_published_course(&client, &creator, "Course 3", "es");
+
+        assert_eq!(client.get_courses_by_language(&String::from_str(&env, "en"), &0, &10).len(), 2);
+        assert_eq!(client.get_courses_by_language(&String::from_str(&env, "ES"), &0, &10).len(), 1);
+    }
+
+    #[test]
+    fn test_get_courses_by_language_follows_edits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_published_course(&client, &creator, "Course 1", "en");
+        let clear = Some(Some(String::from_str(&env, "")));
+        let edited = client.edit_course(&creator, &course.id, &edit_params(None, clear));
+
+        assert_eq!(edited.language, None);
+        assert!(client
+            .get_courses_by_language(&String::from_str(&env, "en"), &0, &10)
+            .is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::course_ids_by_level;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseLevel, CourseStatus, CourseVisibility};
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Lists published, public courses of a given difficulty level.
+///
+/// Reads the per-level index instead of scanning every course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - level: the difficulty level to list.
+/// - limit: maximum number of courses to return (default 10, capped at 20).
+/// - offset: number of matching courses to skip.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `level` is `Unspecified`, `limit` exceeds 100
+///   or `offset` exceeds 10000.
+pub fn list_courses_by_level(
+    env: &Env,
+    level: CourseLevel,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Vec<Course> {
+    if level == CourseLevel::Unspecified
+        || limit.is_some_and(|l| l > 100)
+        || offset.is_some_and(|o| o > 10000)
+    {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let offset_value = offset.unwrap_or(0);
+    let max_limit = limit.unwrap_or(10).min(20);
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut matched: u32 = 0;
+
+    for id in course_ids_by_level(env, level).iter() {
+        if results.len() >= max_limit {
+            break;
+        }
+
+        let course: Option<Course> = env.storage().persistent().get(&(COURSE_KEY, id));
+        let course = match course {
+            Some(course) => course,
+            None => continue,
+        };
+        if course.status != CourseStatus::Published || course.visibility != CourseVisibility::Public {
+            continue;
+        }
+
+        if matched >= offset_value {
+            results.push_back(course);
+        }
+        matched += 1;
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_params;
+    use crate::schema::{Course, CourseLevel};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_published_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        level: Option<CourseLevel>,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &level,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &publish_params())
+    }
+
+    #[test]
+    fn test_list_courses_by_level() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let beginner = create_published_course(&client, &creator, "Course 1", Some(CourseLevel::Beginner));
+        create_published_course(&client, &creator, "Course 2", Some(CourseLevel::Advanced));
+        create_published_course(&client, &creator, "Course 3", None);
+        client.create_course(
+            &creator,
+            &String::from_str(&env, "Draft Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &Some(CourseLevel::Beginner),
+            &None,
+        );
+
+        let listed = client.list_courses_by_level(&CourseLevel::Beginner, &None, &None);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed.get(0).unwrap().id, beginner.id);
+        assert_eq!(client.list_courses_by_level(&CourseLevel::Intermediate, &None, &None).len(), 0);
+    }
+
+    #[test]
+    fn test_list_courses_by_level_follows_edits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_published_course(&client, &creator, "Course 1", Some(CourseLevel::Beginner));
+        client.set_course_level(&creator, &course.id, &CourseLevel::Intermediate);
+
+        assert_eq!(client.list_courses_by_level(&CourseLevel::Beginner, &None, &None).len(), 0);
+        assert_eq!(client.list_courses_by_level(&CourseLevel::Intermediate, &None, &None).len(), 1);
+
+        client.delete_course(&creator, &course.id);
+        assert_eq!(client.list_courses_by_level(&CourseLevel::Intermediate, &None, &None).len(), 0);
+    }
+
+    #[test]
+    fn test_list_courses_by_level_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        create_published_course(&client, &creator, "Course 1", Some(CourseLevel::Advanced));
+        let second = create_published_course(&client, &creator, "Course 2", Some(CourseLevel::Advanced));
+        create_published_course(&client, &creator, "Course 3", Some(CourseLevel::Advanced));
+
+        let page = client.list_courses_by_level(&CourseLevel::Advanced, &Some(1), &Some(1));
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, second.id);
+    }
+}
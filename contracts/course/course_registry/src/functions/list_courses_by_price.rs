@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::{course_ids_by_price_bucket, price_bucket};
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility, MAX_PAGE_SIZE};
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Lists published, public courses priced between `min` and `max`, inclusive.
+///
+/// Only the price buckets overlapping the range are read, so the cost grows
+/// with the number of courses in that price band rather than the whole
+/// catalog. Results come bucket by bucket from the cheapest band up, and in
+/// creation order within a bucket.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - min: lowest price included.
+/// - max: highest price included.
+/// - offset: number of matching courses to skip.
+/// - limit: maximum number of courses to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `min` exceeds `max`, or `limit` is 0 or
+///   exceeds `MAX_PAGE_SIZE`.
+pub fn get_courses_by_price_range(
+    env: &Env,
+    min: u128,
+    max: u128,
+    offset: u32,
+    limit: u32,
+) -> Vec<Course> {
+    if min > max || limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut matched: u32 = 0;
+    for bucket in price_bucket(min)..=price_bucket(max) {
+        for id in course_ids_by_price_bucket(env, bucket).iter() {
+            if results.len() >= limit {
+                return results;
+            }
+
+            let course: Course = match env.storage().persistent().get(&(COURSE_KEY, id)) {
+                Some(course) => course,
+                None => continue,
+            };
+            if course.price < min
+                || course.price > max
+                || course.status != CourseStatus::Published
+                || course.visibility != CourseVisibility::Public
+            {
+                continue;
+            }
+
+            if matched >= offset {
+                results.push_back(course);
+            }
+            matched += 1;
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_published_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        price: u128,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &price,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &edit_params(Some(true), None))
+    }
+
+    fn edit_params(published: Option<bool>, price: Option<u128>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: price,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_get_courses_by_price_range() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        create_published_course(&client, &creator, "Course 1", 90);
+        let mid = create_published_course(&client, &creator, "Course 2", 150);
+        let upper = create_published_course(&client, &creator, "Course 3", 200);
+        create_published_course(&client, &creator, "Course 4", 201);
+
+        let listed = client.get_courses_by_price_range(&100, &200, &0, &10);
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed.get(0).unwrap().id, mid.id);
+        assert_eq!(listed.get(1).unwrap().id, upper.id);
+
+        client.edit_course(&creator, &mid.id, &edit_params(None, Some(5_000)));
+        assert_eq!(client.get_courses_by_price_range(&100, &200, &0, &10).len(), 1);
+        assert_eq!(client.get_courses_by_price_range(&5_000, &5_000, &0, &10).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_get_courses_by_price_range_inverted() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.get_courses_by_price_range(&200, &100, &0, &10);
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_index::{enrollment_ranking, page_listed_courses};
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility, SortKey, MAX_PAGE_SIZE};
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const COURSE_ID_COUNTER: Symbol = symbol_short!("course");
+
+/// Lists published, public courses in one of the storefront orderings.
+///
+/// - `SortKey::Newest` walks course IDs from the most recent down, since IDs
+///   are handed out in creation order.
+/// - `SortKey::MostEnrolled` reads the enrollment ranking, highest count
+///   first. Only the `MAX_RANKED_COURSES` most-enrolled courses are ranked,
+///   so courses without enrollments are not listed.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - sort: the ordering to use.
+/// - offset: number of matching courses to skip.
+/// - limit: maximum number of courses to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `limit` is 0 or exceeds `MAX_PAGE_SIZE`.
+pub fn list_courses_sorted(env: &Env, sort: SortKey, offset: u32, limit: u32) -> Vec<Course> {
+    match sort {
+        SortKey::Newest => list_newest(env, offset, limit),
+        SortKey::MostEnrolled => page_listed_courses(env, &enrollment_ranking(env), offset, limit),
+    }
+}
+
+fn list_newest(env: &Env, offset: u32, limit: u32) -> Vec<Course> {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut id: u128 = env
+        .storage()
+        .persistent()
+        .get(&COURSE_ID_COUNTER)
+        .unwrap_or(0);
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut matched: u32 = 0;
+    while id > 0 && results.len() < limit {
+        let course: Option<Course> = env.storage().persistent().get(&(COURSE_KEY, id));
+        id -= 1;
+        let course = match course {
+            Some(course) => course,
+            None => continue,
+        };
+        if course.status != CourseStatus::Published || course.visibility != CourseVisibility::Public {
+            continue;
+        }
+
+        if matched >= offset {
+            results.push_back(course);
+        }
+        matched += 1;
+    }
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_free_course;
+    use crate::schema::SortKey;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+
+    #[test]
+    fn test_list_courses_sorted() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let first = create_free_course(&client, &creator, "Course 1");
+        let second = create_free_course(&client, &creator, "Course 2");
+        let third = create_free_course(&client, &creator, "Course 3");
+
+        let newest = client.list_courses_sorted(&SortKey::Newest, &0, &2);
+        assert_eq!(newest.len(), 2);
+        assert_eq!(newest.get(0).unwrap().id, third.id);
+        assert_eq!(newest.get(1).unwrap().id, second.id);
+
+        client.enroll(&Address::generate(&env), &first.id, &None, &None, &None, &None);
+        client.enroll(&Address::generate(&env), &second.id, &None, &None, &None, &None);
+        client.enroll(&Address::generate(&env), &second.id, &None, &None, &None, &None);
+        assert_eq!(client.get_enrollment_count(&second.id), 2);
+
+        let popular = client.list_courses_sorted(&SortKey::MostEnrolled, &0, &10);
+        assert_eq!(popular.len(), 2);
+        assert_eq!(popular.get(0).unwrap().id, second.id);
+        assert_eq!(popular.get(1).unwrap().id, first.id);
+
+        client.enroll(&Address::generate(&env), &first.id, &None, &None, &None, &None);
+        client.enroll(&Address::generate(&env), &first.id, &None, &None, &None, &None);
+        let popular = client.list_courses_sorted(&SortKey::MostEnrolled, &0, &1);
+        assert_eq!(popular.get(0).unwrap().id, first.id);
+    }
+}
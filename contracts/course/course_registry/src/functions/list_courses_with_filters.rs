@@ -1,7 +1,6 @@
-use crate::functions::utils::u32_to_string;
 use crate::error::{handle_error, Error};
 
-use crate::schema::{Course, CourseFilters};
+use crate::schema::{Course, CourseFilters, CourseLevel, CourseStatus, CourseVisibility};
 use soroban_sdk::{symbol_short, Env, Symbol, Vec};
 
 const COURSE_KEY: Symbol = symbol_short!("course");
@@ -44,9 +43,7 @@ pub fn list_courses_with_filters(
             break;
         }
 
-        // Use the utility function instead of to_string()
-        let course_id = u32_to_string(env, id as u32);
-        let key = (COURSE_KEY, course_id.clone());
+        let key = (COURSE_KEY, id);
 
         if !env.storage().persistent().has(&key) {
             empty_checks += 1;
@@ -59,8 +56,8 @@ pub fn list_courses_with_filters(
 
         let course: Course = env.storage().persistent().get(&key).unwrap();
 
-        // Skip archived or unpublished courses
-        if course.is_archived || !course.published {
+        // Only published, public courses are listed
+        if course.status != CourseStatus::Published || course.visibility != CourseVisibility::Public {
             id += 1;
             continue;
         }
@@ -77,10 +74,7 @@ pub fn list_courses_with_filters(
                 .category
                 .as_ref()
                 .map_or(true, |cat| course.category.as_ref() == Some(cat))
-            && filters
-                .level
-                .as_ref()
-                .map_or(true, |lvl| course.level.as_ref() == Some(lvl))
+            && (filters.level == CourseLevel::Unspecified || course.level == filters.level)
             && filters.min_duration.map_or(true, |min| {
                 course.duration_hours.map_or(false, |d| d >= min)
             })
@@ -112,6 +106,7 @@ pub fn list_courses_with_filters(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::functions::test_utils::publish_params;
     use crate::{CourseRegistry, CourseRegistryClient};
     use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
@@ -128,7 +123,7 @@ mod test {
             min_price: None,
             max_price: None,
             category: None,
-            level: None,
+            level: CourseLevel::Unspecified,
             min_duration: None,
             max_duration: None,
         };
@@ -160,26 +155,14 @@ mod test {
         );
 
         // Publish the course so it appears in filtered results
-        use crate::schema::EditCourseParams;
-        let params = EditCourseParams {
-            new_title: None,
-            new_description: None,
-            new_price: None,
-            new_category: None,
-            new_language: None,
-            new_thumbnail_url: None,
-            new_published: Some(true),
-            new_level: None,
-            new_duration_hours: None,
-        };
-        client.edit_course(&creator, &course.id, &params);
+        client.edit_course(&creator, &course.id, &publish_params());
 
         // No filters - should return the course
         let filters = CourseFilters {
             min_price: None,
             max_price: None,
             category: None,
-            level: None,
+            level: CourseLevel::Unspecified,
             min_duration: None,
             max_duration: None,
         };
@@ -216,7 +199,7 @@ mod test {
             min_price: Some(crate::schema::FILTER_MIN_PRICE),
             max_price: Some(crate::schema::DEFAULT_COURSE_PRICE),
             category: None,
-            level: None,
+            level: CourseLevel::Unspecified,
             min_duration: None,
             max_duration: None,
         };
@@ -252,7 +235,7 @@ mod test {
             min_price: None,
             max_price: None,
             category: None,
-            level: None,
+            level: CourseLevel::Unspecified,
             min_duration: None,
             max_duration: None,
         };
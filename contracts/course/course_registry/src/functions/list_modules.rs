@@ -25,6 +25,7 @@ pub fn course_registry_list_modules(env: &Env, course_id: String) -> CourseModul
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::schema::ModuleRelease;
     use crate::CourseRegistry;
     use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env, String};
 
@@ -40,18 +41,24 @@ mod test {
         // Create a test course first
         let course: CourseModule = CourseModule {
             id: String::from_str(&env, "test_module_123"),
-            course_id: String::from_str(&env, "test_course_123"),
+            course_id: 123,
             position: 0,
             title: String::from_str(&env, "Introduction to Blockchain"),
             created_at: 0,
+            lesson_count: 0,
+            duration_minutes: 0,
+            release: ModuleRelease::Immediate,
+            is_preview: false,
+            version: 1,
+            content_hash: None,
         };
 
         // Set up initial course data and perform test within contract context
         env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .set(&(MODULE_KEY, course.course_id.clone()), &course);
-            course_registry_list_modules(&env, course.course_id)
+                .set(&(MODULE_KEY, course.id.clone()), &course);
+            course_registry_list_modules(&env, course.id)
         });
     }
 
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::module_access::check_module_access;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{CourseModule, DataKey, LiveSession, ModuleAccess};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_SESSION_EVENT: Symbol = symbol_short!("setLive");
+
+/// Schedules a live session for a module, or reschedules it.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: the module the session belongs to.
+/// - start_time: ledger timestamp the session opens at.
+/// - end_time: ledger timestamp the session closes at.
+/// - join_uri_hash: hash of the join URI shared with attendees.
+///
+/// Returns:
+/// - LiveSession: the session schedule.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if `end_time` is not after `start_time`.
+///
+/// Storage used:
+/// - DataKey::LiveSession(module_id) -> LiveSession
+/// - DataKey::LiveSessionJoin(module_id) -> BytesN<32>
+pub fn set_live_session(
+    env: &Env,
+    caller: Address,
+    module_id: String,
+    start_time: u64,
+    end_time: u64,
+    join_uri_hash: BytesN<32>,
+) -> LiveSession {
+    let module: CourseModule = match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
+    require_course_management_auth(env, &caller, module.course_id);
+
+    if end_time <= start_time {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let session = LiveSession {
+        module_id: module_id.clone(),
+        start_time,
+        end_time,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::LiveSession(module_id.clone()), &session);
+    env.storage()
+        .persistent()
+        .set(&DataKey::LiveSessionJoin(module_id.clone()), &join_uri_hash);
+
+    env.events()
+        .publish((SET_SESSION_EVENT, module.course_id), (module_id, start_time, end_time));
+
+    session
+}
+
+/// Returns the schedule of the live session of a module.
+///
+/// Errors:
+/// - Returns `Error::LiveSessionNotFound` if the module has no live session.
+pub fn get_live_session(env: &Env, module_id: String) -> LiveSession {
+    match env.storage().persistent().get(&DataKey::LiveSession(module_id)) {
+        Some(session) => session,
+        None => handle_error(env, Error::LiveSessionNotFound),
+    }
+}
+
+/// Returns the join information of a live session to a student who may read
+/// the module, while the session is running.
+///
+/// Errors:
+/// - Returns `Error::LiveSessionNotFound` if the module has no live session.
+/// - Returns `EnrollmentError::ModuleLocked` if the student may not read the module.
+/// - Returns `EnrollmentError::SessionNotLive` outside the session window.
+pub fn join_live_session(env: &Env, student: Address, module_id: String) -> BytesN<32> {
+    student.require_auth();
+
+    let session: LiveSession = get_live_session(env, module_id.clone());
+    if check_module_access(env, student, module_id.clone()) != ModuleAccess::Granted {
+        handle_error(env, EnrollmentError::ModuleLocked)
+    }
+
+    let now: u64 = env.ledger().timestamp();
+    if now < session.start_time || now > session.end_time {
+        handle_error(env, EnrollmentError::SessionNotLive)
+    }
+
+    env.storage()
+        .persistent()
+        .get(&DataKey::LiveSessionJoin(module_id))
+        .expect("Live session join info not found")
+}
+
+/// Deletes the live session of a module, if any. Used when the module itself is removed.
+pub fn delete_live_session(env: &Env, module_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LiveSession(module_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::LiveSessionJoin(module_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::CourseModule;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, BytesN, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, CourseModule) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Office hours"));
+        (client, creator, module)
+    }
+
+    #[test]
+    fn test_join_live_session_during_window() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+        let student = Address::generate(&env);
+        client.enroll(&student, &module.course_id, &None, &None, &None, &None);
+
+        let join = BytesN::from_array(&env, &[9; 32]);
+        client.set_live_session(&creator, &module.id, &1_000, &4_600, &join);
+        assert_eq!(client.get_live_session(&module.id).end_time, 4_600);
+
+        env.ledger().set_timestamp(999);
+        assert!(client.try_join_live_session(&student, &module.id).is_err());
+
+        env.ledger().set_timestamp(2_000);
+        assert_eq!(client.join_live_session(&student, &module.id), join);
+
+        env.ledger().set_timestamp(4_601);
+        assert!(client.try_join_live_session(&student, &module.id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #503)")]
+    fn test_join_live_session_not_enrolled() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        client.set_live_session(&creator, &module.id, &0, &4_600, &BytesN::from_array(&env, &[9; 32]));
+        env.ledger().set_timestamp(10);
+        client.join_live_session(&Address::generate(&env), &module.id);
+    }
+}
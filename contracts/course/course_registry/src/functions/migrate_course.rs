@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use super::category_stats::add_to_category_totals;
+use super::course_index::{index_category, index_language, index_level, index_price};
+use super::creator_stats::record_course_created;
+use super::prerequisites::save_prerequisites;
+use super::title_index::reserve_title;
+use super::title_search::index_title_prefixes;
+use super::utils::{parse_u128, to_lowercase};
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseLevel, CourseStatus, CourseVisibility, LegacyCourse};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const MIGRATE_COURSE_EVENT: Symbol = symbol_short!("migCourse");
+
+/// Migrates a course stored under a legacy `String` ID to the native `u128` key.
+///
+/// Courses created before IDs moved to `u128` were stored under
+/// `("course", String)`. This reads such an entry, rewrites it under
+/// `("course", u128)` and removes the legacy key. Legacy prerequisite IDs are
+/// converted as well; entries that are not numeric are dropped.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: admin performing the migration.
+/// - legacy_id: the decimal string ID the course was stored under.
+///
+/// Returns:
+/// - Course: the migrated course record.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if `legacy_id` is not a decimal number.
+/// - Returns `Error::CourseNotFound` if no legacy course exists under `legacy_id`.
+/// - Returns `Error::DuplicateCourseId` if a course already exists under the new key.
+pub fn migrate_course(env: Env, caller: Address, legacy_id: String) -> Course {
+    caller.require_auth();
+
+    if !is_admin(&env, &caller) {
+        handle_error(&env, Error::Unauthorized)
+    }
+
+    let course_id: u128 = match parse_u128(&legacy_id) {
+        Some(id) if id > 0 => id,
+        _ => handle_error(&env, Error::InvalidInput),
+    };
+
+    let legacy_key: (Symbol, String) = (COURSE_KEY, legacy_id.clone());
+    let legacy: LegacyCourse = match env.storage().persistent().get(&legacy_key) {
+        Some(course) => course,
+        None => handle_error(&env, Error::CourseNotFound),
+    };
+
+    let new_key: (Symbol, u128) = (COURSE_KEY, course_id);
+    if env.storage().persistent().has(&new_key) {
+        handle_error(&env, Error::DuplicateCourseId)
+    }
+
+    let mut prerequisites: Vec<u128> = Vec::new(&env);
+    for prerequisite in legacy.prerequisites.iter() {
+        if let Some(id) = parse_u128(&prerequisite.id) {
+            prerequisites.push_back(id);
+        }
+    }
+
+    let status = if legacy.is_archived {
+        CourseStatus::Archived
+    } else if legacy.published {
+        CourseStatus::Published
+    } else {
+        CourseStatus::Draft
+    };
+
+    let now: u64 = env.ledger().timestamp();
+    let course: Course = Course {
+        id: course_id,
+        title: legacy.title,
+        description: legacy.description,
+        creator: legacy.creator,
+        price: legacy.price,
+        category: legacy.category,
+        language: legacy.language,
+        thumbnail_url: legacy.thumbnail_url,
+        status,
+        prerequisites,
+        level: legacy
+            .level
+            .map_or(CourseLevel::Unspecified, |level| parse_level(&env, &level)),
+        duration_hours: legacy.duration_hours,
+        version: 1,
+        created_at: now,
+        updated_at: now,
+        slug: None,
+        visibility: CourseVisibility::Public,
+        metadata_uri: None,
+        metadata_hash: None,
+        lesson_count: 0,
+        total_duration_minutes: 0,
+        variant_group: None,
+        is_free: false,
+        max_students: None,
+        seats_remaining: None,
+    };
+
+    env.storage().persistent().set(&new_key, &course);
+    if !course.prerequisites.is_empty() {
+        save_prerequisites(&env, course_id, &course.prerequisites);
+    }
+    env.storage().persistent().remove(&legacy_key);
+    index_level(&env, course_id, course.level);
+    index_category(&env, course_id, &course.category);
+    add_to_category_totals(&env, &course.category, course.price, 0);
+    index_language(&env, course_id, &course.language);
+    index_price(&env, course_id, course.price);
+    index_title_prefixes(&env, course_id, &course.title);
+    record_course_created(&env, &course.creator);
+
+    if course.status != CourseStatus::Archived {
+        reserve_title(&env, &course);
+    }
+
+    env.events()
+        .publish((MIGRATE_COURSE_EVENT, legacy_id), course_id);
+
+    course
+}
+
+/// Maps a legacy free-form level string to `CourseLevel`; unknown values are dropped.
+fn parse_level(env: &Env, level: &String) -> CourseLevel {
+    let level = to_lowercase(env, level);
+    if level == String::from_str(env, "beginner") {
+        CourseLevel::Beginner
+    } else if level == String::from_str(env, "intermediate") {
+        CourseLevel::Intermediate
+    } else if level == String::from_str(env, "advanced") {
+        CourseLevel::Advanced
+    } else {
+        CourseLevel::Unspecified
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::{CourseId, CourseLevel, CourseStatus, LegacyCourse};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        symbol_short, testutils::Address as _, testutils::Ledger, vec, Address, Env, String,
+    };
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn legacy_course(env: &Env, id: &str, creator: &Address) -> LegacyCourse {
+        LegacyCourse {
+            id: String::from_str(env, id),
+            title: String::from_str(env, "Legacy Course"),
+            description: String::from_str(env, "Stored before the u128 migration"),
+            creator: creator.clone(),
+            price: 500,
+            category: None,
+            language: None,
+            thumbnail_url: None,
+            published: true,
+            prerequisites: vec![
+                env,
+                CourseId {
+                    id: String::from_str(env, "1"),
+                    count: 1,
+                },
+            ],
+            is_archived: false,
+            level: Some(String::from_str(env, "Advanced")),
+            duration_hours: Some(3),
+        }
+    }
+
+    #[test]
+    fn test_migrate_course_success() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(42);
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let legacy_key = (symbol_short!("course"), String::from_str(&env, "7"));
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+            env.storage()
+                .persistent()
+                .set(&legacy_key, &legacy_course(&env, "7", &creator));
+        });
+
+        let migrated = client.migrate_course(&admin, &String::from_str(&env, "7"));
+
+        assert_eq!(migrated.id, 7);
+        assert_eq!(migrated.price, 500);
+        assert_eq!(migrated.prerequisites, vec![&env, 1_u128]);
+        assert_eq!(client.get_prerequisites(&7), vec![&env, 1_u128]);
+        assert_eq!(migrated.status, CourseStatus::Published);
+        assert_eq!(migrated.level, CourseLevel::Advanced);
+        assert_eq!(migrated.version, 1);
+        assert_eq!(migrated.created_at, 42);
+        assert_eq!(client.get_course(&7), migrated);
+
+        let legacy_exists = env.as_contract(&contract_id, || {
+            env.storage().persistent().has(&legacy_key)
+        });
+        assert!(!legacy_exists);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_migrate_course_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.migrate_course(&Address::generate(&env), &String::from_str(&env, "7"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_migrate_course_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+
+        client.migrate_course(&Address::generate(&env), &String::from_str(&env, "7"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_migrate_course_invalid_id() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(&env, &admin, &user_mgmt_id);
+        });
+
+        client.migrate_course(&admin, &String::from_str(&env, "abc"));
+    }
+}
@@ -2,25 +2,114 @@
 // Copyright (c) 2025 SkillCert
 
 pub mod access_control;
+pub mod access_grants;
+pub mod access_log;
+pub mod access_terms;
+pub mod affiliates;
+pub mod airdrops;
+pub mod allowlist;
 pub mod add_goal;
 pub mod add_module;
 pub mod archive_course;
+pub mod assignment;
+pub mod bundles;
+pub mod category_registry;
+pub mod category_stats;
+pub mod clone_course;
+pub mod cohorts;
+pub mod course_bans;
+pub mod course_capacity;
+pub mod content_limits;
+pub mod coupons;
+pub mod course_index;
+pub mod course_level;
+pub mod course_metadata;
+pub mod course_outline;
+pub mod course_ratings;
+pub mod course_roster;
+pub mod course_slug;
+pub mod course_status;
+pub mod course_tags;
+pub mod course_totals;
+pub mod course_variants;
+pub mod course_visibility;
 pub mod create_course;
 pub mod create_course_category;
 pub mod create_prerequisite;
+pub mod creator_stats;
 pub mod delete_course;
+pub mod earnings;
 pub mod edit_course;
 pub mod edit_goal;
 pub mod edit_prerequisite;
+pub mod enrollment;
+pub mod enrollment_deadlines;
+pub mod enrollment_events;
+pub mod enrollment_transfers;
+pub mod escrow;
+pub mod export_courses;
+pub mod featured_courses;
 pub mod get_course;
+pub mod get_course_by_hash;
+pub mod get_course_by_title;
 pub mod get_course_category;
+pub mod get_course_version;
 pub mod get_courses_by_instructor;
 pub mod get_prerequisites_by_course;
+pub mod gifts;
+pub mod installments;
+pub mod invites;
 pub mod is_course_creator;
+pub mod lesson_resources;
+pub mod lessons;
 pub mod list_categories;
+pub mod list_courses_by_category;
+pub mod list_courses_by_language;
+pub mod list_courses_by_level;
+pub mod list_courses_by_price;
+pub mod list_courses_sorted;
 pub mod list_courses_with_filters;
 pub mod list_modules;
+pub mod live_session;
+pub mod migrate_course;
+pub mod module_access;
+pub mod module_overrides;
+pub mod module_progress;
+pub mod module_versions;
+pub mod payments;
+pub mod prerequisite_checks;
+pub mod prerequisites;
+pub mod price_history;
+pub mod price_limits;
+pub mod price_list;
+pub mod quiz;
+pub mod refund_requests;
+pub mod related_courses;
+pub mod rate_limit;
+pub mod receipts;
 pub mod remove_goal;
 pub mod remove_module;
 pub mod remove_prerequisite;
+pub mod reorder_modules;
+pub mod royalty_splits;
+pub mod sales;
+pub mod scholarships;
+pub mod seats;
+pub mod skills;
+pub mod student_courses;
+pub mod subscriptions;
+#[cfg(test)]
+pub mod test_utils;
+pub mod tips;
+pub mod title_index;
+pub mod title_moderation;
+pub mod title_search;
+pub mod token_gates;
+pub mod treasury;
+pub mod trending_courses;
+pub mod unenrollment;
+pub mod unpublish_refunds;
+pub mod usd_pricing;
 pub mod utils;
+pub mod vouchers;
+pub mod waitlist;
@@ -0,0 +1,334 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::{is_admin, require_course_management_auth};
+use super::module_overrides::get_module_override;
+use super::module_progress::get_module_completion;
+use super::installments::is_access_suspended;
+use super::reorder_modules::get_modules;
+use super::subscriptions::access_started_at;
+use crate::error::{handle_error, Error};
+use crate::schema::{
+    Course, CourseModule, DataKey, ModuleAccess, ModuleOverride, ModuleRelease, ModuleUnlock,
+};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_RELEASE_EVENT: Symbol = symbol_short!("setRelse");
+const SET_PREVIEW_EVENT: Symbol = symbol_short!("setPrevw");
+const SET_SEQUENTIAL_EVENT: Symbol = symbol_short!("setSeqUnl");
+
+const SECONDS_PER_DAY: u64 = 86_400;
+/// Longest drip delay accepted, in days.
+const MAX_RELEASE_DAYS: u32 = 3_650;
+
+/// Sets when the content of a module opens to enrolled students.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: unique identifier of the module.
+/// - release: a fixed timestamp, a delay after enrollment, or `Immediate`.
+///
+/// Returns:
+/// - CourseModule: the updated module.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the delay exceeds ten years.
+pub fn set_module_release(env: &Env, caller: Address, module_id: String, release: ModuleRelease) -> CourseModule {
+    let mut module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    if let ModuleRelease::DaysAfterEnrollment(days) = release {
+        if days > MAX_RELEASE_DAYS {
+            handle_error(env, Error::InvalidInput)
+        }
+    }
+
+    module.release = release;
+    env.storage()
+        .persistent()
+        .set(&(MODULE_KEY, module_id.clone()), &module);
+
+    env.events()
+        .publish((SET_RELEASE_EVENT, module.course_id), module_id);
+
+    module
+}
+
+/// Marks a module as a free preview, or makes it enrollment-only again.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+pub fn set_module_preview(env: &Env, caller: Address, module_id: String, is_preview: bool) -> CourseModule {
+    let mut module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    module.is_preview = is_preview;
+    env.storage()
+        .persistent()
+        .set(&(MODULE_KEY, module_id.clone()), &module);
+
+    env.events()
+        .publish((SET_PREVIEW_EVENT, module.course_id), (module_id, is_preview));
+
+    module
+}
+
+/// Turns sequential unlock on or off for a course. When on, each module
+/// stays blocked until the student completed the module before it.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - DataKey::SequentialUnlock(course_id) -> bool
+pub fn set_sequential_unlock(env: &Env, caller: Address, course_id: u128, enabled: bool) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = DataKey::SequentialUnlock(course_id);
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((SET_SEQUENTIAL_EVENT, course_id), enabled);
+}
+
+/// Returns whether modules of a course must be completed in order.
+pub fn is_sequential_unlock(env: &Env, course_id: u128) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SequentialUnlock(course_id))
+        .unwrap_or(false)
+}
+
+/// Checks whether `student` may read the content of a module.
+///
+/// The course creator and admins can read every module. Per-address
+/// overrides come next: a granted address can read the module, a denied one
+/// cannot. Preview modules are then open to everyone. Other students must be
+/// enrolled in the course or hold a subscription covering it, be past the
+/// module's release point and, with sequential unlock, be done with the
+/// previous module. Subscribers count from the start of their subscription.
+/// Students behind on their installments are suspended until they pay.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+pub fn check_module_access(env: &Env, student: Address, module_id: String) -> ModuleAccess {
+    let module: CourseModule = load_module(env, &module_id);
+
+    if is_course_staff(env, module.course_id, &student) {
+        return ModuleAccess::Granted;
+    }
+
+    match get_module_override(env, module_id, student.clone()) {
+        Some(ModuleOverride::Grant) => return ModuleAccess::Granted,
+        Some(ModuleOverride::Deny) => return ModuleAccess::Denied,
+        None => {}
+    }
+
+    if module.is_preview {
+        return ModuleAccess::Granted;
+    }
+
+    let enrolled_at: u64 = match access_started_at(env, module.course_id, &student) {
+        Some(enrolled_at) => enrolled_at,
+        None if is_access_suspended(env, module.course_id, &student) => {
+            return ModuleAccess::Suspended
+        }
+        None => return ModuleAccess::NotEnrolled,
+    };
+
+    let unlock_at: u64 = unlock_time(&module.release, enrolled_at);
+    if env.ledger().timestamp() < unlock_at {
+        return ModuleAccess::Locked(unlock_at);
+    }
+
+    if is_sequential_unlock(env, module.course_id) {
+        if let Some(previous) = previous_module(env, &module) {
+            if get_module_completion(env, previous.clone(), student).is_none() {
+                return ModuleAccess::BlockedBy(previous);
+            }
+        }
+    }
+
+    ModuleAccess::Granted
+}
+
+/// Returns when each module of a course opens for `student`, in module order.
+///
+/// Preview modules are always open. Modules released relative to enrollment
+/// have no unlock time while the student is not enrolled.
+pub fn get_unlock_schedule(env: &Env, course_id: u128, student: Address) -> Vec<ModuleUnlock> {
+    let enrolled_at: Option<u64> = access_started_at(env, course_id, &student);
+
+    let mut schedule: Vec<ModuleUnlock> = Vec::new(env);
+    for module in get_modules(env, course_id).iter() {
+        let unlock_at: Option<u64> = match module.release {
+            _ if module.is_preview => Some(0),
+            ModuleRelease::Immediate => Some(0),
+            ModuleRelease::At(timestamp) => Some(timestamp),
+            ModuleRelease::DaysAfterEnrollment(_) => {
+                enrolled_at.map(|enrolled_at| unlock_time(&module.release, enrolled_at))
+            }
+        };
+        schedule.push_back(ModuleUnlock {
+            module_id: module.id,
+            position: module.position,
+            unlock_at,
+        });
+    }
+    schedule
+}
+
+fn unlock_time(release: &ModuleRelease, enrolled_at: u64) -> u64 {
+    match release {
+        ModuleRelease::Immediate => 0,
+        ModuleRelease::At(timestamp) => *timestamp,
+        ModuleRelease::DaysAfterEnrollment(days) => {
+            enrolled_at.saturating_add(*days as u64 * SECONDS_PER_DAY)
+        }
+    }
+}
+
+/// Returns the ID of the module right before `module` in the course order.
+fn previous_module(env: &Env, module: &CourseModule) -> Option<String> {
+    let modules: Vec<CourseModule> = get_modules(env, module.course_id);
+    let mut previous: Option<String> = None;
+    for candidate in modules.iter() {
+        if candidate.id == module.id {
+            return previous;
+        }
+        previous = Some(candidate.id);
+    }
+    None
+}
+
+fn is_course_staff(env: &Env, course_id: u128, who: &Address) -> bool {
+    let creator: Option<Address> = env
+        .storage()
+        .persistent()
+        .get::<_, Course>(&(COURSE_KEY, course_id))
+        .map(|course| course.creator);
+    creator.as_ref() == Some(who) || is_admin(env, who)
+}
+
+fn load_module(env: &Env, module_id: &String) -> CourseModule {
+    match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_free_course;
+    use crate::schema::{ModuleAccess, ModuleRelease};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+    const DAY: u64 = 86_400;
+
+
+    #[test]
+    fn test_drip_release_after_enrollment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(10 * DAY);
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+
+        let course = create_free_course(&client, &creator, "Course");
+        let week1 = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Week 1"));
+        let week2 = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Week 2"));
+        client.set_module_release(&creator, &week2.id, &ModuleRelease::DaysAfterEnrollment(7));
+
+        assert_eq!(client.check_module_access(&student, &week1.id), ModuleAccess::NotEnrolled);
+        let schedule = client.get_unlock_schedule(&course.id, &student);
+        assert_eq!(schedule.get(0).unwrap().unlock_at, Some(0));
+        assert_eq!(schedule.get(1).unwrap().unlock_at, None);
+
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+        assert_eq!(client.check_module_access(&student, &week1.id), ModuleAccess::Granted);
+        assert_eq!(
+            client.check_module_access(&student, &week2.id),
+            ModuleAccess::Locked(17 * DAY)
+        );
+        assert_eq!(
+            client.get_unlock_schedule(&course.id, &student).get(1).unwrap().unlock_at,
+            Some(17 * DAY)
+        );
+
+        env.ledger().set_timestamp(17 * DAY);
+        assert_eq!(client.check_module_access(&student, &week2.id), ModuleAccess::Granted);
+
+        // The creator is never locked out
+        assert_eq!(client.check_module_access(&creator, &week2.id), ModuleAccess::Granted);
+    }
+
+    #[test]
+    fn test_release_at_fixed_time() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+
+        let course = create_free_course(&client, &creator, "Course");
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Finale"));
+        client.set_module_release(&creator, &module.id, &ModuleRelease::At(5 * DAY));
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+
+        assert_eq!(client.check_module_access(&student, &module.id), ModuleAccess::Locked(5 * DAY));
+        env.ledger().set_timestamp(5 * DAY);
+        assert_eq!(client.check_module_access(&student, &module.id), ModuleAccess::Granted);
+    }
+
+    #[test]
+    fn test_preview_module_open_without_enrollment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let visitor = Address::generate(&env);
+
+        let course = create_free_course(&client, &creator, "Course");
+        let intro = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+        let paid = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Deep dive"));
+        client.set_module_release(&creator, &intro.id, &ModuleRelease::DaysAfterEnrollment(3));
+        assert!(client.set_module_preview(&creator, &intro.id, &true).is_preview);
+
+        assert_eq!(client.check_module_access(&visitor, &intro.id), ModuleAccess::Granted);
+        assert_eq!(client.check_module_access(&visitor, &paid.id), ModuleAccess::NotEnrolled);
+        assert_eq!(client.get_unlock_schedule(&course.id, &visitor).get(0).unwrap().unlock_at, Some(0));
+
+        client.set_module_preview(&creator, &intro.id, &false);
+        assert_eq!(client.check_module_access(&visitor, &intro.id), ModuleAccess::NotEnrolled);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_module_release_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_free_course(&client, &creator, "Course");
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Week 1"));
+        client.set_module_release(&Address::generate(&env), &module.id, &ModuleRelease::At(DAY));
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseModule, DataKey, ModuleOverride, MAX_MODULE_OVERRIDES};
+use soroban_sdk::{symbol_short, Address, Env, Map, String, Symbol};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_OVERRIDE_EVENT: Symbol = symbol_short!("setOvrd");
+
+/// Grants or denies a module to a specific address, or clears the override.
+///
+/// Overrides take precedence over course-level access: a granted address can
+/// read the module without enrolling, a denied one cannot read it at all.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: unique identifier of the module.
+/// - user: the address the override applies to.
+/// - access: `Some(Grant)`, `Some(Deny)`, or `None` to clear the override.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the module already has
+///   `MAX_MODULE_OVERRIDES` overrides.
+///
+/// Storage used:
+/// - DataKey::ModuleOverrides(module_id) -> Map<Address, ModuleOverride>
+pub fn set_module_override(
+    env: &Env,
+    caller: Address,
+    module_id: String,
+    user: Address,
+    access: Option<ModuleOverride>,
+) {
+    let module: CourseModule = match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
+    require_course_management_auth(env, &caller, module.course_id);
+
+    let mut overrides: Map<Address, ModuleOverride> = get_module_overrides(env, module_id.clone());
+    match access {
+        Some(access) => {
+            if !overrides.contains_key(user.clone()) && overrides.len() >= MAX_MODULE_OVERRIDES {
+                handle_error(env, Error::InvalidInput)
+            }
+            overrides.set(user.clone(), access);
+        }
+        None => {
+            overrides.remove(user.clone());
+        }
+    }
+
+    let key = DataKey::ModuleOverrides(module_id.clone());
+    if overrides.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &overrides);
+    }
+
+    env.events()
+        .publish((SET_OVERRIDE_EVENT, module.course_id), (module_id, user, access));
+}
+
+/// Returns the override of a module for one address, if any.
+pub fn get_module_override(env: &Env, module_id: String, user: Address) -> Option<ModuleOverride> {
+    get_module_overrides(env, module_id).get(user)
+}
+
+/// Returns every override of a module.
+pub fn get_module_overrides(env: &Env, module_id: String) -> Map<Address, ModuleOverride> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ModuleOverrides(module_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Deletes every override of a module. Used when the module itself is removed.
+pub fn delete_module_overrides(env: &Env, module_id: &String) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ModuleOverrides(module_id.clone()));
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::{CourseModule, ModuleAccess, ModuleOverride};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, CourseModule) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Bonus"));
+        (client, creator, module)
+    }
+
+    #[test]
+    fn test_grant_module_without_enrollment() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+        let early_buyer = Address::generate(&env);
+
+        assert_eq!(client.check_module_access(&early_buyer, &module.id), ModuleAccess::NotEnrolled);
+
+        client.set_module_override(&creator, &module.id, &early_buyer, &Some(ModuleOverride::Grant));
+        assert_eq!(client.check_module_access(&early_buyer, &module.id), ModuleAccess::Granted);
+        assert_eq!(
+            client.get_module_override(&module.id, &early_buyer),
+            Some(ModuleOverride::Grant)
+        );
+        assert_eq!(client.get_module_overrides(&module.id).len(), 1);
+
+        client.set_module_override(&creator, &module.id, &early_buyer, &None);
+        assert_eq!(client.get_module_override(&module.id, &early_buyer), None);
+        assert_eq!(client.check_module_access(&early_buyer, &module.id), ModuleAccess::NotEnrolled);
+    }
+
+    #[test]
+    fn test_deny_module_to_enrolled_student() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+        let student = Address::generate(&env);
+        client.enroll(&student, &module.course_id, &None, &None, &None, &None);
+        client.set_module_preview(&creator, &module.id, &true);
+
+        client.set_module_override(&creator, &module.id, &student, &Some(ModuleOverride::Deny));
+        assert_eq!(client.check_module_access(&student, &module.id), ModuleAccess::Denied);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_module_override_unauthorized() {
+        let env = Env::default();
+        let (client, _creator, module) = setup(&env);
+        let stranger = Address::generate(&env);
+
+        client.set_module_override(&stranger, &module.id, &stranger, &Some(ModuleOverride::Grant));
+    }
+}
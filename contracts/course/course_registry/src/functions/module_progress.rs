@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::module_access::check_module_access;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{CourseModule, DataKey, ModuleAccess};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const COMPLETE_MODULE_EVENT: Symbol = symbol_short!("modDone");
+
+/// Records that a student completed a module.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the student (must authorize).
+/// - module_id: unique identifier of the module.
+///
+/// Returns:
+/// - u64: the completion timestamp. Completing a module again keeps the
+///   first timestamp.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `EnrollmentError::ModuleLocked` if the student may not read the module yet.
+///
+/// Storage used:
+/// - DataKey::ModuleCompleted(module_id, student) -> u64
+pub fn complete_module(env: &Env, student: Address, module_id: String) -> u64 {
+    student.require_auth();
+
+    if let Some(completed_at) = get_module_completion(env, module_id.clone(), student.clone()) {
+        return completed_at;
+    }
+
+    if check_module_access(env, student.clone(), module_id.clone()) != ModuleAccess::Granted {
+        handle_error(env, EnrollmentError::ModuleLocked)
+    }
+
+    let module: CourseModule = match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
+
+    let completed_at: u64 = env.ledger().timestamp();
+    env.storage().persistent().set(
+        &DataKey::ModuleCompleted(module_id.clone(), student.clone()),
+        &completed_at,
+    );
+
+    env.events()
+        .publish((COMPLETE_MODULE_EVENT, module.course_id), (module_id, student));
+
+    completed_at
+}
+
+/// Returns when a student completed a module, or `None` if they have not.
+pub fn get_module_completion(env: &Env, module_id: String, student: Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ModuleCompleted(module_id, student))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_free_course;
+    use crate::schema::ModuleAccess;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+
+    #[test]
+    fn test_sequential_unlock() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+
+        let course = create_free_course(&client, &creator, "Course");
+        let first = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "First"));
+        let second = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Second"));
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+
+        // Without the setting every module is open
+        assert_eq!(client.check_module_access(&student, &second.id), ModuleAccess::Granted);
+
+        client.set_sequential_unlock(&creator, &course.id, &true);
+        assert!(client.is_sequential_unlock(&course.id));
+        assert_eq!(
+            client.check_module_access(&student, &second.id),
+            ModuleAccess::BlockedBy(first.id.clone())
+        );
+
+        client.complete_module(&student, &first.id);
+        assert!(client.get_module_completion(&first.id, &student).is_some());
+        assert_eq!(client.check_module_access(&student, &second.id), ModuleAccess::Granted);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #503)")]
+    fn test_complete_blocked_module() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+
+        let course = create_free_course(&client, &creator, "Course");
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "First"));
+        let second = client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Second"));
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+        client.set_sequential_unlock(&creator, &course.id, &true);
+
+        client.complete_module(&student, &second.id);
+    }
+}
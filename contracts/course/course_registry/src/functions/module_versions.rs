@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseModule, DataKey, UpdateModuleParams};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+/// Replaces the title or content hash of a module and bumps its version.
+///
+/// The previous state of the module stays readable through
+/// `get_module_version`, and a `module_updated` event is emitted so enrolled
+/// students can be told the content changed.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: unique identifier of the module.
+/// - params: the fields to replace.
+///
+/// Returns:
+/// - CourseModule: the module at its new version.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidModuleTitle` if the new title is empty or too long.
+///
+/// Storage used:
+/// - DataKey::ModuleVersion(module_id, version) -> CourseModule
+pub fn update_module(env: &Env, caller: Address, module_id: String, params: UpdateModuleParams) -> CourseModule {
+    let key = (MODULE_KEY, module_id.clone());
+    let mut module: CourseModule = match env.storage().persistent().get(&key) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    };
+    require_course_management_auth(env, &caller, module.course_id);
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::ModuleVersion(module_id.clone(), module.version), &module);
+
+    if let Some(title) = params.new_title {
+        if title.is_empty() || title.len() > 500 {
+            handle_error(env, Error::InvalidModuleTitle)
+        }
+        module.title = title;
+    }
+    if let Some(content_hash) = params.new_content_hash {
+        module.content_hash = content_hash;
+    }
+    module.version += 1;
+    env.storage().persistent().set(&key, &module);
+
+    env.events()
+        .publish((module_id, module.version), "module_updated");
+
+    module
+}
+
+/// Returns a module as it was at `version`.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module or the version does not exist.
+pub fn get_module_version(env: &Env, module_id: String, version: u32) -> CourseModule {
+    let current: Option<CourseModule> = env.storage().persistent().get(&(MODULE_KEY, module_id.clone()));
+    match current {
+        Some(module) if module.version == version => module,
+        Some(_) => match env
+            .storage()
+            .persistent()
+            .get(&DataKey::ModuleVersion(module_id, version))
+        {
+            Some(module) => module,
+            None => handle_error(env, Error::ModuleNotFound),
+        },
+        None => handle_error(env, Error::ModuleNotFound),
+    }
+}
+
+/// Deletes the superseded versions of a module. Used when the module itself is removed.
+pub fn delete_module_versions(env: &Env, module: &CourseModule) {
+    for version in 1..module.version {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ModuleVersion(module.id.clone(), version));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::UpdateModuleParams;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+    #[test]
+    fn test_update_module_keeps_previous_version() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Draft"));
+        assert_eq!(module.version, 1);
+
+        let params = UpdateModuleParams {
+            new_title: Some(String::from_str(&env, "Final")),
+            new_content_hash: Some(Some(BytesN::from_array(&env, &[3; 32]))),
+        };
+        let updated = client.update_module(&creator, &module.id, &params);
+        assert_eq!(updated.version, 2);
+
+        let previous = client.get_module_version(&module.id, &1);
+        assert_eq!(previous.title, String::from_str(&env, "Draft"));
+        assert_eq!(previous.content_hash, None);
+        assert_eq!(client.get_module_version(&module.id, &2).title, String::from_str(&env, "Final"));
+        assert!(client.try_get_module_version(&module.id, &3).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_update_module_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Draft"));
+        let params = UpdateModuleParams {
+            new_title: Some(String::from_str(&env, "Hijacked")),
+            new_content_hash: None,
+        };
+        client.update_module(&Address::generate(&env), &module.id, &params);
+    }
+}
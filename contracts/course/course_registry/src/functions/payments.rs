@@ -0,0 +1,635 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::{is_admin, require_course_management_auth};
+use super::affiliates::{credit_affiliate, referral_commission};
+use super::coupons::{apply_discount, redeem_coupon};
+use super::enrollment_events::publish_transition;
+use super::escrow::release_previous_payment;
+use super::price_list::get_course_prices;
+use super::royalty_splits::credit_course_revenue;
+use super::sales::effective_price;
+use super::treasury::credit_treasury;
+use super::usd_pricing::{get_usd_price, usd_to_token};
+use crate::error::{handle_error, Error, PaymentError};
+use crate::schema::{
+    Course, EnrollmentTransition, Payment, PaymentKey, PaymentStatus, PlatformFee, MAX_FEE_BPS,
+    MAX_REFUND_WINDOW,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, String, Symbol};
+
+const SET_PAYMENT_TOKEN_EVENT: Symbol = symbol_short!("payToken");
+const PAYMENT_EVENT: Symbol = symbol_short!("payment");
+const SET_PLATFORM_FEE_EVENT: Symbol = symbol_short!("setFee");
+const SET_REFUND_WINDOW_EVENT: Symbol = symbol_short!("refundWin");
+const SET_PWYW_EVENT: Symbol = symbol_short!("setPwyw");
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Sets the token accepted for course payments (admin-only).
+///
+/// Until a token is set, only free courses and courses with a price list can
+/// be enrolled in.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - token: address of the Soroban token contract to accept.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+///
+/// Storage used:
+/// - PaymentKey::PaymentToken -> Address
+pub fn set_payment_token(env: &Env, caller: Address, token: Address) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::PaymentToken, &token);
+
+    env.events().publish((SET_PAYMENT_TOKEN_EVENT,), token);
+}
+
+/// Returns the token accepted for course payments, if one is set.
+pub fn get_payment_token(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&PaymentKey::PaymentToken)
+}
+
+/// Sets the platform fee taken from course payments (admin-only).
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - fee_bps: fee rate in basis points, from 0 to `MAX_FEE_BPS`.
+/// - treasury: address receiving the fees withdrawn from the treasury; see
+///   `treasury::withdraw_treasury`.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if `fee_bps` exceeds `MAX_FEE_BPS`.
+///
+/// Storage used:
+/// - PaymentKey::PlatformFee -> PlatformFee
+pub fn set_platform_fee(env: &Env, caller: Address, fee_bps: u32, treasury: Address) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if fee_bps > MAX_FEE_BPS {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let fee = PlatformFee { fee_bps, treasury };
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::PlatformFee, &fee);
+
+    env.events().publish((SET_PLATFORM_FEE_EVENT,), fee);
+}
+
+/// Returns the platform fee, if one is set.
+pub fn get_platform_fee(env: &Env) -> Option<PlatformFee> {
+    env.storage().persistent().get(&PaymentKey::PlatformFee)
+}
+
+/// Sets how long payments stay in escrow, refundable by the student (admin-only).
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - seconds: length of the refund window, up to `MAX_REFUND_WINDOW`; 0
+///   releases payments immediately.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if `seconds` exceeds `MAX_REFUND_WINDOW`.
+///
+/// Storage used:
+/// - PaymentKey::RefundWindow -> u64
+pub fn set_refund_window(env: &Env, caller: Address, seconds: u64) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+    if seconds > MAX_REFUND_WINDOW {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::RefundWindow, &seconds);
+
+    env.events().publish((SET_REFUND_WINDOW_EVENT,), seconds);
+}
+
+/// Returns the refund window in seconds; 0 if payments are not escrowed.
+pub fn get_refund_window(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::RefundWindow)
+        .unwrap_or(0)
+}
+
+/// Turns pay-what-you-want pricing on or off for a course.
+///
+/// With pay-what-you-want pricing the course price is the minimum a student
+/// pays; they may choose to pay more when enrolling.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - enabled: whether students may pay more than the price.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the course is free.
+///
+/// Storage used:
+/// - PaymentKey::PayWhatYouWant(course_id) -> bool
+pub fn set_pay_what_you_want(env: &Env, caller: Address, course_id: u128, enabled: bool) {
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    require_course_management_auth(env, &caller, course_id);
+    if course.is_free {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let key = PaymentKey::PayWhatYouWant(course_id);
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events().publish((SET_PWYW_EVENT, course_id), enabled);
+}
+
+/// Whether a course uses pay-what-you-want pricing.
+pub fn is_pay_what_you_want(env: &Env, course_id: u128) -> bool {
+    env.storage()
+        .persistent()
+        .has(&PaymentKey::PayWhatYouWant(course_id))
+}
+
+/// Charges the price of a course a student is enrolling in.
+///
+/// The price is transferred from `payer`, usually the student, to the contract
+/// and the platform fee, if any, is computed; the split is kept in the payment
+/// record. During the refund window the funds stay in escrow, after that they
+/// are released: the fee goes to the treasury and the rest is credited to the
+/// creator's earnings. See `quote_payment` for what is charged.
+///
+/// Errors:
+/// - Any error `quote_payment` can return.
+/// - Returns `Error::InvalidInput` if the price does not fit a token amount.
+///
+/// Storage used:
+/// - PaymentKey::Payment(course_id, student) -> Payment
+#[allow(clippy::too_many_arguments)]
+pub fn collect_payment(
+    env: &Env,
+    course: &Course,
+    payer: &Address,
+    student: &Address,
+    token: Option<Address>,
+    amount: Option<u128>,
+    coupon: Option<String>,
+    referrer: Option<Address>,
+) -> Option<Payment> {
+    let (accepted, amount): (Address, u128) = quote_payment(env, course, token, amount, coupon)?;
+    if amount > 0 {
+        token::Client::new(env, &accepted).transfer(
+            payer,
+            &env.current_contract_address(),
+            &to_token_amount(env, amount),
+        );
+    }
+
+    Some(record_payment(
+        env, course, payer, student, &accepted, amount, referrer,
+    ))
+}
+
+/// Works out the token and amount an enrollment in a course costs.
+///
+/// Nothing is charged for free courses; `None` is returned then.
+///
+/// Paying with the payment token costs `Course::price`, or the sale price
+/// while a sale is running; any other token must be on the course price list
+/// and costs the amount listed there. A course priced in USD costs the value
+/// of that price in the chosen token at the oracle rate. A coupon code, if
+/// given, is redeemed and lowers it further. `amount`
+/// defaults to the price. Only pay-what-you-want courses accept an amount
+/// other than the price, and never one below it.
+///
+/// Errors:
+/// - Returns `PaymentError::PaymentTokenNotSet` if the course is not free but
+///   no payment token is set and the course has no price list.
+/// - Any error `coupons::redeem_coupon` can return for the coupon code.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is neither the payment
+///   token nor on the course price list.
+/// - Returns `PaymentError::OracleUnavailable` if the course is priced in USD
+///   and the oracle cannot convert the price.
+/// - Returns `PaymentError::AmountBelowMinimum` if `amount` is below the price.
+/// - Returns `Error::InvalidInput` if `amount` differs from the price of a
+///   fixed-price course.
+pub fn quote_payment(
+    env: &Env,
+    course: &Course,
+    token: Option<Address>,
+    amount: Option<u128>,
+    coupon: Option<String>,
+) -> Option<(Address, u128)> {
+    if course.is_free {
+        return None;
+    }
+    let payment_token: Option<Address> = get_payment_token(env);
+    let prices = get_course_prices(env, course.id);
+    if payment_token.is_none() && prices.is_empty() {
+        handle_error(env, PaymentError::PaymentTokenNotSet)
+    }
+    let (accepted, price): (Address, u128) = match token {
+        Some(token) if payment_token.as_ref() == Some(&token) => {
+            (token, effective_price(env, course))
+        }
+        Some(token) => match prices.get(token.clone()) {
+            Some(price) => (token, price),
+            None => handle_error(env, PaymentError::UnsupportedToken),
+        },
+        None => handle_error(env, PaymentError::UnsupportedToken),
+    };
+    let price: u128 = match get_usd_price(env, course.id) {
+        Some(usd_cents) => usd_to_token(env, &accepted, usd_cents),
+        None => price,
+    };
+    let price: u128 = match coupon {
+        Some(code) => apply_discount(price, &redeem_coupon(env, course.id, &code).discount),
+        None => price,
+    };
+    let amount: u128 = match amount {
+        None => price,
+        Some(amount) if amount < price => handle_error(env, PaymentError::AmountBelowMinimum),
+        Some(amount) if amount == price || is_pay_what_you_want(env, course.id) => amount,
+        Some(_) => handle_error(env, Error::InvalidInput),
+    };
+
+    Some((accepted, amount))
+}
+
+/// Records a payment already transferred to the contract by `payer` for the
+/// enrollment of `student`.
+///
+/// The platform fee and the referral commission of `referrer`, if they are an
+/// affiliate of the course, are computed and the payment is held in escrow
+/// for the refund window, or released at once when there is none. A payment
+/// left in escrow by an earlier enrollment of the student is released first.
+///
+/// Errors:
+/// - Returns `PaymentError::EscrowActive` if the refund window of an earlier
+///   payment of the student for the course is still open.
+///
+/// Storage used:
+/// - PaymentKey::Payment(course_id, student) -> Payment
+pub fn record_payment(
+    env: &Env,
+    course: &Course,
+    payer: &Address,
+    student: &Address,
+    token: &Address,
+    amount: u128,
+    referrer: Option<Address>,
+) -> Payment {
+    release_previous_payment(env, course.id, student);
+    let platform_fee: u128 = match get_platform_fee(env) {
+        Some(fee) => bps_of(amount, fee.fee_bps),
+        None => 0,
+    };
+    let referral_commission: u128 = match &referrer {
+        Some(referrer) => {
+            referral_commission(env, course.id, referrer, student, amount, platform_fee)
+        }
+        None => 0,
+    };
+    let referrer: Option<Address> = referrer.filter(|_| referral_commission > 0);
+    let now: u64 = env.ledger().timestamp();
+    let mut payment = Payment {
+        course_id: course.id,
+        payer: payer.clone(),
+        creator: course.creator.clone(),
+        token: token.clone(),
+        amount,
+        platform_fee,
+        creator_amount: amount - platform_fee - referral_commission,
+        referrer,
+        referral_commission,
+        paid_at: now,
+        status: PaymentStatus::Escrowed,
+        escrow_until: now + get_refund_window(env),
+    };
+    if payment.escrow_until == now {
+        settle_payment(env, &mut payment);
+    }
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::Payment(course.id, student.clone()), &payment);
+
+    env.events()
+        .publish((PAYMENT_EVENT, course.id), (student.clone(), amount));
+    publish_transition(
+        env,
+        course.id,
+        student,
+        EnrollmentTransition::Paid(token.clone(), amount),
+    );
+
+    payment
+}
+
+/// Returns the payment a student made for a course, if any.
+pub fn get_payment(env: &Env, course_id: u128, student: Address) -> Option<Payment> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Payment(course_id, student))
+}
+
+/// Releases an escrowed payment: credits the platform fee to the treasury,
+/// credits the referral commission to the referrer and the rest to the
+/// creator's earnings. The caller saves the record.
+pub fn settle_payment(env: &Env, payment: &mut Payment) {
+    credit_treasury(env, &payment.token, payment.platform_fee);
+    if let Some(referrer) = &payment.referrer {
+        credit_affiliate(
+            env,
+            referrer,
+            payment.course_id,
+            &payment.token,
+            payment.referral_commission,
+        );
+    }
+    credit_course_revenue(
+        env,
+        payment.course_id,
+        &payment.creator,
+        &payment.token,
+        payment.creator_amount,
+    );
+    payment.status = PaymentStatus::Released;
+}
+
+/// Returns `bps` basis points of an amount, rounded down, without overflowing.
+pub fn bps_of(amount: u128, bps: u32) -> u128 {
+    let (bps, scale) = (bps as u128, MAX_FEE_BPS as u128);
+    amount / scale * bps + amount % scale * bps / scale
+}
+
+/// Converts a price to the `i128` amount used by the token interface.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the price exceeds `i128::MAX`.
+pub fn to_token_amount(env: &Env, price: u128) -> i128 {
+    match i128::try_from(price) {
+        Ok(amount) => amount,
+        Err(_) => handle_error(env, Error::InvalidInput),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{
+        create_published_course, setup_registry, setup_registry_with_token,
+    };
+    use crate::schema::EditCourseParams;
+    use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+    #[test]
+    fn test_enroll_credits_creator_earnings() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1500);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+
+        let balances = token::Client::new(&env, &token);
+        assert_eq!(balances.balance(&student), 500);
+        assert_eq!(balances.balance(&client.address), 1000);
+        assert_eq!(client.get_earnings(&creator, &token), 1000);
+        let payment = client.get_payment(&course.id, &student).unwrap();
+        assert_eq!(payment.amount, 1000);
+        assert_eq!(payment.token, token);
+    }
+
+    #[test]
+    fn test_enroll_in_free_course_without_payment() {
+        let env = Env::default();
+        let (client, _admin, _token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+        let params = EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: Some(true),
+        };
+        let course = client.edit_course(&creator, &course.id, &params);
+        assert_eq!(course.price, 0);
+
+        let student = Address::generate(&env);
+        client.enroll(&student, &course.id, &None, &None, &None, &None);
+        assert!(client.get_enrollment(&course.id, &student).is_some());
+        assert_eq!(client.get_payment(&course.id, &student), None);
+    }
+
+    #[test]
+    fn test_pay_what_you_want() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &5000);
+
+        // Fixed-price courses only accept their price
+        assert!(client
+            .try_enroll(
+                &student,
+                &course.id,
+                &Some(token.clone()),
+                &Some(1500),
+                &None,
+                &None
+            )
+            .is_err());
+
+        client.set_pay_what_you_want(&creator, &course.id, &true);
+        assert!(client
+            .try_enroll(
+                &student,
+                &course.id,
+                &Some(token.clone()),
+                &Some(999),
+                &None,
+                &None
+            )
+            .is_err());
+        let enrollment = client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &Some(1500),
+            &None,
+            &None,
+        );
+        assert_eq!(enrollment.amount_paid, 1500);
+        assert_eq!(client.get_earnings(&creator, &token), 1500);
+    }
+
+    #[test]
+    fn test_platform_fee_split() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        let treasury = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        client.set_platform_fee(&admin, &250, &treasury);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+
+        assert_eq!(client.get_treasury_account(&token).income, 25);
+        assert_eq!(client.get_earnings(&creator, &token), 975);
+        let payment = client.get_payment(&course.id, &student).unwrap();
+        assert_eq!(payment.platform_fee, 25);
+        assert_eq!(payment.creator_amount, 975);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_platform_fee_above_maximum() {
+        let env = Env::default();
+        let (client, admin, _token) = setup_registry_with_token(&env);
+
+        client.set_platform_fee(&admin, &10_001, &Address::generate(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #701)")]
+    fn test_enroll_with_unsupported_token() {
+        let env = Env::default();
+        let (client, _admin, _token) = setup_registry_with_token(&env);
+        let other = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+
+        client.enroll(
+            &Address::generate(&env),
+            &course.id,
+            &Some(other),
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #731)")]
+    fn test_enroll_in_paid_course_without_payment_token() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+
+        client.enroll(
+            &Address::generate(&env),
+            &course.id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_enroll_twice_charges_once() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &3000);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator, "Paid Course", 1000);
+
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        assert!(client
+            .try_enroll(
+                &student,
+                &course.id,
+                &Some(token.clone()),
+                &None,
+                &None,
+                &None
+            )
+            .is_err());
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 2000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_payment_token_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+
+        client.set_payment_token(&Address::generate(&env), &token);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_platform_fee_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let caller = Address::generate(&env);
+
+        client.set_platform_fee(&caller, &500, &caller);
+    }
+}
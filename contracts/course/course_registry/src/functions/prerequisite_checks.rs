@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::is_enrolled;
+use super::module_progress::get_module_completion;
+use super::prerequisites::get_prerequisites;
+use super::reorder_modules::get_modules;
+use crate::error::{handle_error, EnrollmentError};
+use crate::schema::{EnrollmentKey, PrerequisiteCheck};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, Vec};
+
+const SET_PREREQUISITE_CHECK_EVENT: Symbol = symbol_short!("prereqChk");
+
+/// Sets how the prerequisites of a course are checked when a student enrolls.
+///
+/// By default each prerequisite must be completed in this contract. Courses
+/// whose prerequisites are proven by certificates name the certificate
+/// contract to ask instead.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::PrerequisiteCheck(course_id) -> PrerequisiteCheck
+pub fn set_prerequisite_check(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    check: PrerequisiteCheck,
+) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::PrerequisiteCheck(course_id);
+    match &check {
+        PrerequisiteCheck::Completion => env.storage().persistent().remove(&key),
+        PrerequisiteCheck::Certificate(_) => env.storage().persistent().set(&key, &check),
+    }
+
+    env.events()
+        .publish((SET_PREREQUISITE_CHECK_EVENT, course_id), check);
+}
+
+/// Returns how the prerequisites of a course are checked.
+pub fn get_prerequisite_check(env: &Env, course_id: u128) -> PrerequisiteCheck {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::PrerequisiteCheck(course_id))
+        .unwrap_or(PrerequisiteCheck::Completion)
+}
+
+/// Whether a student completed every module of a course.
+///
+/// A course without modules counts as completed once the student is enrolled.
+pub fn is_course_completed(env: &Env, course_id: u128, student: &Address) -> bool {
+    let modules = get_modules(env, course_id);
+    if modules.is_empty() {
+        return is_enrolled(env, course_id, student);
+    }
+    modules
+        .iter()
+        .all(|module| get_module_completion(env, module.id, student.clone()).is_some())
+}
+
+/// Returns the prerequisites of a course the student does not meet, in the
+/// order they were added.
+pub fn get_missing_prerequisites(env: &Env, course_id: u128, student: &Address) -> Vec<u128> {
+    let check: PrerequisiteCheck = get_prerequisite_check(env, course_id);
+    let mut missing: Vec<u128> = Vec::new(env);
+    for prerequisite_id in get_prerequisites(env, course_id).iter() {
+        let met: bool = match &check {
+            PrerequisiteCheck::Completion => is_course_completed(env, prerequisite_id, student),
+            PrerequisiteCheck::Certificate(certificates) => env.invoke_contract(
+                certificates,
+                &Symbol::new(env, "has_certificate"),
+                (student.clone(), prerequisite_id).into_val(env),
+            ),
+        };
+        if !met {
+            missing.push_back(prerequisite_id);
+        }
+    }
+    missing
+}
+
+/// Fails with `EnrollmentError::PrerequisitesNotMet` if the student lacks
+/// any prerequisite of the course.
+///
+/// Contract errors cannot carry data, so clients call
+/// `get_missing_prerequisites` to show which ones are missing.
+pub fn require_prerequisites(env: &Env, course_id: u128, student: &Address) {
+    if !get_missing_prerequisites(env, course_id, student).is_empty() {
+        handle_error(env, EnrollmentError::PrerequisitesNotMet)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_free_course;
+    use crate::schema::PrerequisiteCheck;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, vec, Address, Env, String};
+
+    #[contract]
+    pub struct Certificates;
+
+    #[contractimpl]
+    impl Certificates {
+        pub fn has_certificate(_env: Env, _student: Address, course_id: u128) -> bool {
+            course_id == 1
+        }
+    }
+
+    #[test]
+    fn test_enroll_after_completing_prerequisites() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+
+        let basics = create_free_course(&client, &creator, "Basics");
+        let intro = create_free_course(&client, &creator, "Intro");
+        let advanced = create_free_course(&client, &creator, "Advanced");
+        client.add_prerequisite(&creator, &advanced.id, &vec![&env, basics.id, intro.id]);
+        let module = client.add_module(&creator, &basics.id, &0, &String::from_str(&env, "First"));
+
+        client.enroll(&student, &basics.id, &None, &None, &None, &None);
+        assert!(!client.is_course_completed(&basics.id, &student));
+        assert_eq!(
+            client.get_missing_prerequisites(&advanced.id, &student),
+            vec![&env, basics.id, intro.id]
+        );
+        assert!(client
+            .try_enroll(&student, &advanced.id, &None, &None, &None, &None)
+            .is_err());
+
+        client.complete_module(&student, &module.id);
+        client.enroll(&student, &intro.id, &None, &None, &None, &None);
+        assert_eq!(
+            client
+                .get_missing_prerequisites(&advanced.id, &student)
+                .len(),
+            0
+        );
+        client.enroll(&student, &advanced.id, &None, &None, &None, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #520)")]
+    fn test_certificate_check() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let certificates = env.register(Certificates, ());
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+
+        let first = create_free_course(&client, &creator, "First");
+        let second = create_free_course(&client, &creator, "Second");
+        let advanced = create_free_course(&client, &creator, "Advanced");
+        let check = PrerequisiteCheck::Certificate(certificates);
+        client.set_prerequisite_check(&creator, &advanced.id, &check);
+        assert_eq!(client.get_prerequisite_check(&advanced.id), check);
+
+        client.add_prerequisite(&creator, &advanced.id, &vec![&env, first.id]);
+        client.enroll(&student, &advanced.id, &None, &None, &None, &None);
+
+        client.add_prerequisite(&creator, &advanced.id, &vec![&env, second.id]);
+        client.enroll(
+            &Address::generate(&env),
+            &advanced.id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+}
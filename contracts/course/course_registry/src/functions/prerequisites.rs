@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, DataKey};
+use soroban_sdk::{symbol_short, Env, Map, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Returns the prerequisite course IDs of a course, in the order they were added.
+///
+/// Returns an empty list if the course has no prerequisites or does not exist.
+///
+/// Storage used:
+/// - DataKey::CoursePrerequisites(course_id) -> Vec<u128>
+pub fn get_prerequisites(env: &Env, course_id: u128) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CoursePrerequisites(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the prerequisite list of a course and mirrors it on `Course::prerequisites`.
+pub fn save_prerequisites(env: &Env, course_id: u128, prerequisites: &Vec<u128>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CoursePrerequisites(course_id), prerequisites);
+
+    let key = (COURSE_KEY, course_id);
+    if let Some(mut course) = env.storage().persistent().get::<_, Course>(&key) {
+        course.prerequisites = prerequisites.clone();
+        env.storage().persistent().set(&key, &course);
+    }
+}
+
+/// Rejects prerequisite lists that would make `course_id` depend on itself,
+/// directly or through other courses.
+///
+/// Errors:
+/// - Returns `Error::SelfPrerequisite` if `course_id` is in `new_prerequisites`.
+/// - Returns `Error::CircularDependency` if a prerequisite already depends on `course_id`.
+pub fn validate_no_circular_dependency(env: &Env, course_id: u128, new_prerequisites: &Vec<u128>) {
+    // Check if course_id appears in new_prerequisites (direct circular dependency)
+    for prerequisite_id in new_prerequisites.iter() {
+        if prerequisite_id == course_id {
+            handle_error(env, Error::SelfPrerequisite)
+        }
+    }
+
+    // Check for indirect circular dependencies using DFS
+    let mut visited = Map::new(env);
+    let mut rec_stack = Map::new(env);
+
+    for prerequisite_id in new_prerequisites.iter() {
+        if has_cycle(
+            env,
+            prerequisite_id,
+            course_id,
+            &mut visited,
+            &mut rec_stack,
+        ) {
+            handle_error(env, Error::CircularDependency)
+        }
+    }
+}
+
+fn has_cycle(
+    env: &Env,
+    current_course: u128,
+    target_course: u128,
+    visited: &mut Map<u128, bool>,
+    rec_stack: &mut Map<u128, bool>,
+) -> bool {
+    // If we've reached the target course, we found a cycle
+    if current_course == target_course {
+        return true;
+    }
+
+    // If already in recursion stack, we have a cycle
+    if rec_stack.contains_key(current_course) {
+        return true;
+    }
+
+    // If already visited and not in recursion stack, no cycle from this path
+    if visited.contains_key(current_course) {
+        return false;
+    }
+
+    // Mark as visited and add to recursion stack
+    visited.set(current_course, true);
+    rec_stack.set(current_course, true);
+
+    // Recursively check all prerequisites
+    for prerequisite in get_prerequisites(env, current_course).iter() {
+        if has_cycle(env, prerequisite, target_course, visited, rec_stack) {
+            return true;
+        }
+    }
+
+    // Remove from recursion stack before returning
+    rec_stack.remove(current_course);
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_add_prerequisite_appends_and_syncs_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course1 = create_course(&client, &creator, "Course 1");
+        let course2 = create_course(&client, &creator, "Course 2");
+        let course3 = create_course(&client, &creator, "Course 3");
+
+        client.add_prerequisite(&creator, &course1.id, &vec![&env, course2.id]);
+        client.add_prerequisite(&creator, &course1.id, &vec![&env, course3.id, course2.id]);
+
+        let expected = vec![&env, course2.id, course3.id];
+        assert_eq!(client.get_prerequisites(&course1.id), expected);
+        assert_eq!(client.get_course(&course1.id).prerequisites, expected);
+
+        client.remove_prerequisite(&creator, &course1.id, &course2.id);
+        assert_eq!(client.get_course(&course1.id).prerequisites, vec![&env, course3.id]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #15)")]
+    fn test_add_prerequisite_indirect_cycle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course1 = create_course(&client, &creator, "Course 1");
+        let course2 = create_course(&client, &creator, "Course 2");
+        let course3 = create_course(&client, &creator, "Course 3");
+
+        client.add_prerequisite(&creator, &course2.id, &vec![&env, course1.id]);
+        client.add_prerequisite(&creator, &course3.id, &vec![&env, course2.id]);
+        client.add_prerequisite(&creator, &course1.id, &vec![&env, course3.id]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #13)")]
+    fn test_add_prerequisite_missing_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course1 = create_course(&client, &creator, "Course 1");
+        client.add_prerequisite(&creator, &course1.id, &vec![&env, 404_u128]);
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::schema::{PaymentKey, PriceChange, MAX_PRICE_HISTORY};
+use soroban_sdk::{Env, Vec};
+
+/// Records the price a course had until now, when its price changes.
+///
+/// Only the last `MAX_PRICE_HISTORY` changes are kept. Purchases already
+/// under way keep the price they started at: pending gifts and seats are paid
+/// up front, and installment schedules fix their total at enrollment.
+///
+/// Storage used:
+/// - PaymentKey::PriceHistory(course_id) -> Vec<PriceChange>
+pub fn record_price_change(env: &Env, course_id: u128, old_price: u128) {
+    let mut history: Vec<PriceChange> = get_price_history(env, course_id);
+    if history.len() >= MAX_PRICE_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(PriceChange {
+        price: old_price,
+        changed_at: env.ledger().timestamp(),
+    });
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::PriceHistory(course_id), &history);
+}
+
+/// Returns the former prices of a course, oldest first.
+///
+/// Each entry is a price the course had and when it was replaced; the
+/// current price is `Course::price`.
+pub fn get_price_history(env: &Env, course_id: u128) -> Vec<PriceChange> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::PriceHistory(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry_with_token;
+    use crate::schema::{Course, EditCourseParams, InstallmentPlan};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    fn params(price: Option<u128>, published: Option<bool>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: price,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Address, Course) {
+        let (client, _admin, token) = setup_registry_with_token(env);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.edit_course(&creator, &course.id, &params(None, Some(true)));
+        (client, token, creator, course)
+    }
+
+    #[test]
+    fn test_price_changes_are_recorded() {
+        let env = Env::default();
+        let (client, _token, creator, course) = setup(&env);
+
+        env.ledger().with_mut(|li| li.timestamp = 100);
+        client.edit_course(&creator, &course.id, &params(Some(1500), None));
+        client.edit_course(&creator, &course.id, &params(None, Some(false)));
+        env.ledger().with_mut(|li| li.timestamp = 200);
+        client.edit_course(&creator, &course.id, &params(Some(2000), None));
+
+        let history = client.get_price_history(&course.id);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0).unwrap().price, 1000);
+        assert_eq!(history.get(0).unwrap().changed_at, 100);
+        assert_eq!(history.get(1).unwrap().price, 1500);
+        assert_eq!(history.get(1).unwrap().changed_at, 200);
+    }
+
+    #[test]
+    fn test_started_purchases_keep_their_price() {
+        let env = Env::default();
+        let (client, token, creator, course) = setup(&env);
+        let plan = InstallmentPlan {
+            count: 2,
+            interval: 86_400,
+            grace_period: 0,
+        };
+        client.set_installment_plan(&creator, &course.id, &Some(plan));
+
+        let payer = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let student = Address::generate(&env);
+        let minter = token::StellarAssetClient::new(&env, &token);
+        minter.mint(&payer, &1000);
+        minter.mint(&student, &1000);
+        client.create_pending_gift(
+            &payer,
+            &recipient,
+            &course.id,
+            &Some(token.clone()),
+            &86_400,
+        );
+        client.enroll_with_installments(&student, &course.id, &Some(token.clone()));
+
+        client.edit_course(&creator, &course.id, &params(Some(3000), None));
+
+        assert_eq!(client.claim_gift(&recipient, &course.id).amount_paid, 1000);
+        let schedule = client.pay_installment(&student, &course.id);
+        assert_eq!(schedule.total, 1000);
+        assert_eq!(schedule.paid, 1000);
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use crate::error::{handle_error, Error};
+use crate::schema::PriceLimits;
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const PRICE_LIMITS_KEY: Symbol = symbol_short!("prc_lim");
+
+const SET_PRICE_LIMITS_EVENT: Symbol = symbol_short!("setPrcLim");
+
+/// Returns the price range allowed for paid courses.
+///
+/// Any price above 0 is allowed when no admin override has been stored.
+pub fn get_price_limits(env: &Env) -> PriceLimits {
+    env.storage()
+        .instance()
+        .get(&PRICE_LIMITS_KEY)
+        .unwrap_or(PriceLimits {
+            min_price: 1,
+            max_price: u128::MAX,
+        })
+}
+
+/// Sets the lowest and highest price of paid courses (admin-only).
+///
+/// The range is in units of the payment token. It applies to `create_course`,
+/// to `edit_course` calls that change the price, and to sale prices when they
+/// are set; existing prices are left as they are. USD prices and price list
+/// amounts are in other units and are not checked. Free courses are not
+/// affected.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - limits: the new range; `min_price` must be above 0 and not above
+///   `max_price`.
+///
+/// Storage used:
+/// - ("prc_lim",) -> PriceLimits    // instance storage
+pub fn set_price_limits(env: &Env, caller: Address, limits: PriceLimits) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if limits.min_price == 0 || limits.min_price > limits.max_price {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    env.storage().instance().set(&PRICE_LIMITS_KEY, &limits);
+
+    env.events().publish((SET_PRICE_LIMITS_EVENT,), limits);
+}
+
+/// Rejects prices of paid courses outside the configured range.
+pub fn validate_price_range(env: &Env, price: u128, is_free: bool) {
+    if is_free {
+        return;
+    }
+    let limits: PriceLimits = get_price_limits(env);
+    if price < limits.min_price || price > limits.max_price {
+        handle_error(env, Error::InvalidPrice)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::{EditCourseParams, PriceLimits};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{map, testutils::Address as _, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> CourseRegistryClient<'a> {
+        let (client, admin) = setup_registry(env);
+        let limits = PriceLimits {
+            min_price: 100,
+            max_price: 10_000,
+        };
+        client.set_price_limits(&admin, &limits);
+        client
+    }
+
+    fn create(client: &CourseRegistryClient, creator: &Address, title: &str, price: u128) -> u128 {
+        client
+            .create_course(
+                creator,
+                &String::from_str(&client.env, title),
+                &String::from_str(&client.env, "description"),
+                &price,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .id
+    }
+
+    fn price_edit(price: Option<u128>, is_free: Option<bool>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: price,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: is_free,
+        }
+    }
+
+    #[test]
+    fn test_prices_within_limits_and_free_courses() {
+        let env = Env::default();
+        let client = setup(&env);
+        let creator = Address::generate(&env);
+
+        create(&client, &creator, "Cheapest", 100);
+        create(&client, &creator, "Dearest", 10_000);
+        let course_id = create(&client, &creator, "Free", 1000);
+        let course = client.edit_course(&creator, &course_id, &price_edit(None, Some(true)));
+        assert!(course.is_free);
+        assert_eq!(client.get_price_limits().min_price, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #9)")]
+    fn test_dust_price_rejected() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        create(&client, &Address::generate(&env), "Dust", 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #9)")]
+    fn test_edit_above_ceiling_rejected() {
+        let env = Env::default();
+        let client = setup(&env);
+        let creator = Address::generate(&env);
+        let course_id = create(&client, &creator, "Course", 1000);
+
+        client.edit_course(&creator, &course_id, &price_edit(Some(u128::MAX), None));
+    }
+
+    #[test]
+    fn test_sale_price_respects_limits() {
+        let env = Env::default();
+        let client = setup(&env);
+        let creator = Address::generate(&env);
+        let course_id = create(&client, &creator, "Course", 1000);
+
+        assert!(client.try_set_sale(&creator, &course_id, &50, &0, &100).is_err());
+        client.set_sale(&creator, &course_id, &500, &0, &100);
+    }
+
+    #[test]
+    fn test_usd_and_price_list_prices_ignore_limits() {
+        let env = Env::default();
+        let client = setup(&env);
+        let creator = Address::generate(&env);
+        let course_id = create(&client, &creator, "Course", 1000);
+        let token = Address::generate(&env);
+
+        client.set_usd_price(&creator, &course_id, &Some(50));
+        client.set_course_prices(&creator, &course_id, &map![&env, (token, 50_000_000)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_price_limits_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let limits = PriceLimits {
+            min_price: 100,
+            max_price: 10_000,
+        };
+
+        client.set_price_limits(&Address::generate(&env), &limits);
+    }
+}
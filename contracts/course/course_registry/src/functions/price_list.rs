@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, PaymentKey, MAX_PRICE_LIST_TOKENS};
+use soroban_sdk::{symbol_short, Address, Env, Map, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_PRICES_EVENT: Symbol = symbol_short!("setPrices");
+
+/// Sets the prices of a course in tokens other than the payment token.
+///
+/// `Course::price` stays the price in the payment token; the price list adds
+/// other tokens a student may pay with, each at its own amount. An empty list
+/// removes them.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - prices: amount per token contract address, up to `MAX_PRICE_LIST_TOKENS`.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the course is free or the list is too long.
+/// - Returns `Error::InvalidPrice` if an amount is 0.
+///
+/// Storage used:
+/// - PaymentKey::PriceList(course_id) -> Map<Address, u128>
+pub fn set_course_prices(env: &Env, caller: Address, course_id: u128, prices: Map<Address, u128>) {
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::PriceList(course_id);
+    if prices.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        if course.is_free || prices.len() > MAX_PRICE_LIST_TOKENS {
+            handle_error(env, Error::InvalidInput)
+        }
+        if prices.values().contains(0_u128) {
+            handle_error(env, Error::InvalidPrice)
+        }
+        env.storage().persistent().set(&key, &prices);
+    }
+
+    env.events()
+        .publish((SET_PRICES_EVENT, course_id), prices.len());
+}
+
+/// Returns the prices of a course in tokens other than the payment token.
+pub fn get_course_prices(env: &Env, course_id: u128) -> Map<Address, u128> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::PriceList(course_id))
+        .unwrap_or(Map::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry};
+    use soroban_sdk::{map, testutils::Address as _, token, Address, Env, String};
+
+    #[test]
+    fn test_enroll_in_listed_token() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let xlm = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let usdc = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        client.set_payment_token(&admin, &xlm);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        client.set_course_prices(&creator, &course.id, &map![&env, (usdc.clone(), 120_u128)]);
+        assert_eq!(
+            client.get_course_prices(&course.id).get(usdc.clone()),
+            Some(120)
+        );
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &usdc).mint(&student, &120);
+        client.enroll(&student, &course.id, &Some(usdc.clone()), &None, &None, &None);
+        assert_eq!(client.get_earnings(&creator, &usdc), 120);
+        assert_eq!(
+            client
+                .get_enrollment(&course.id, &student)
+                .unwrap()
+                .amount_paid,
+            120
+        );
+
+        let other = Address::generate(&env);
+        let unlisted = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        assert!(client
+            .try_enroll(&other, &course.id, &Some(unlisted), &None, &None, &None)
+            .is_err());
+    }
+}
@@ -0,0 +1,263 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseModule, DataKey, Quiz, QuizQuestion, MAX_QUIZ_QUESTIONS};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+
+const SET_QUIZ_EVENT: Symbol = symbol_short!("setQuiz");
+const ADD_QUESTION_EVENT: Symbol = symbol_short!("addQuest");
+const REMOVE_QUESTION_EVENT: Symbol = symbol_short!("rmQuest");
+
+/// Attaches a quiz to a module, or updates its grading rules.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - module_id: the module holding the quiz.
+/// - passing_score: percentage of points needed to pass, from 1 to 100.
+/// - max_attempts: attempts allowed per student, at least 1.
+///
+/// Returns:
+/// - Quiz: the quiz configuration. Existing questions are kept.
+///
+/// Errors:
+/// - Returns `Error::ModuleNotFound` if the module does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the score or attempt limit is out of range.
+///
+/// Storage used:
+/// - DataKey::Quiz(module_id) -> Quiz
+pub fn set_quiz(env: &Env, caller: Address, module_id: String, passing_score: u32, max_attempts: u32) -> Quiz {
+    let module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    if passing_score == 0 || passing_score > 100 || max_attempts == 0 {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let quiz: Quiz = match env
+        .storage()
+        .persistent()
+        .get::<_, Quiz>(&DataKey::Quiz(module_id.clone()))
+    {
+        Some(quiz) => Quiz {
+            passing_score,
+            max_attempts,
+            ..quiz
+        },
+        None => Quiz {
+            module_id: module_id.clone(),
+            question_ids: Vec::new(env),
+            next_question_id: 1,
+            passing_score,
+            max_attempts,
+        },
+    };
+    save_quiz(env, &quiz);
+
+    env.events()
+        .publish((SET_QUIZ_EVENT, module.course_id), module_id);
+
+    quiz
+}
+
+/// Adds a question to the quiz of a module.
+///
+/// Only a hash of the question is stored; the question itself and its answer
+/// key stay off-chain.
+///
+/// Returns:
+/// - u32: the ID of the new question within the quiz.
+///
+/// Errors:
+/// - Returns `Error::QuizNotFound` if the module has no quiz.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if `points` is 0 or the quiz already has
+///   `MAX_QUIZ_QUESTIONS` questions.
+///
+/// Storage used:
+/// - DataKey::QuizQuestion(module_id, question_id) -> QuizQuestion
+pub fn add_quiz_question(
+    env: &Env,
+    caller: Address,
+    module_id: String,
+    content_hash: BytesN<32>,
+    points: u32,
+) -> u32 {
+    let mut quiz: Quiz = get_quiz(env, module_id.clone());
+    let module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    if points == 0 || quiz.question_ids.len() >= MAX_QUIZ_QUESTIONS {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let question = QuizQuestion {
+        id: quiz.next_question_id,
+        content_hash,
+        points,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::QuizQuestion(module_id.clone(), question.id), &question);
+
+    quiz.question_ids.push_back(question.id);
+    quiz.next_question_id += 1;
+    save_quiz(env, &quiz);
+
+    env.events()
+        .publish((ADD_QUESTION_EVENT, module.course_id), (module_id, question.id));
+
+    question.id
+}
+
+/// Removes a question from the quiz of a module.
+///
+/// Errors:
+/// - Returns `Error::QuizNotFound` if the module has no quiz or the question
+///   is not part of it.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+pub fn remove_quiz_question(env: &Env, caller: Address, module_id: String, question_id: u32) {
+    let mut quiz: Quiz = get_quiz(env, module_id.clone());
+    let module: CourseModule = load_module(env, &module_id);
+    require_course_management_auth(env, &caller, module.course_id);
+
+    let index = match quiz.question_ids.first_index_of(question_id) {
+        Some(index) => index,
+        None => handle_error(env, Error::QuizNotFound),
+    };
+    quiz.question_ids.remove(index);
+    save_quiz(env, &quiz);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QuizQuestion(module_id.clone(), question_id));
+
+    env.events()
+        .publish((REMOVE_QUESTION_EVENT, module.course_id), (module_id, question_id));
+}
+
+/// Returns the quiz configuration of a module.
+///
+/// Errors:
+/// - Returns `Error::QuizNotFound` if the module has no quiz.
+pub fn get_quiz(env: &Env, module_id: String) -> Quiz {
+    match env.storage().persistent().get(&DataKey::Quiz(module_id)) {
+        Some(quiz) => quiz,
+        None => handle_error(env, Error::QuizNotFound),
+    }
+}
+
+/// Returns one question of the quiz of a module.
+///
+/// Errors:
+/// - Returns `Error::QuizNotFound` if the question does not exist.
+pub fn get_quiz_question(env: &Env, module_id: String, question_id: u32) -> QuizQuestion {
+    match env
+        .storage()
+        .persistent()
+        .get(&DataKey::QuizQuestion(module_id, question_id))
+    {
+        Some(question) => question,
+        None => handle_error(env, Error::QuizNotFound),
+    }
+}
+
+/// Deletes the quiz of a module and its questions, if any. Used when the
+/// module itself is removed.
+pub fn delete_quiz(env: &Env, module_id: &String) {
+    let key = DataKey::Quiz(module_id.clone());
+    if let Some(quiz) = env.storage().persistent().get::<_, Quiz>(&key) {
+        for question_id in quiz.question_ids.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::QuizQuestion(module_id.clone(), question_id));
+        }
+        env.storage().persistent().remove(&key);
+    }
+}
+
+fn save_quiz(env: &Env, quiz: &Quiz) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Quiz(quiz.module_id.clone()), quiz);
+}
+
+fn load_module(env: &Env, module_id: &String) -> CourseModule {
+    match env.storage().persistent().get(&(MODULE_KEY, module_id.clone())) {
+        Some(module) => module,
+        None => handle_error(env, Error::ModuleNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::CourseModule;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, CourseModule) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(env, "Checkpoint"));
+        (client, creator, module)
+    }
+
+    #[test]
+    fn test_quiz_questions() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        let quiz = client.set_quiz(&creator, &module.id, &70, &3);
+        assert_eq!(quiz.passing_score, 70);
+        assert_eq!(quiz.max_attempts, 3);
+
+        let q1 = client.add_quiz_question(&creator, &module.id, &BytesN::from_array(&env, &[1; 32]), &5);
+        let q2 = client.add_quiz_question(&creator, &module.id, &BytesN::from_array(&env, &[2; 32]), &10);
+        assert_eq!(client.get_quiz(&module.id).question_ids, vec![&env, q1, q2]);
+        assert_eq!(client.get_quiz_question(&module.id, &q2).points, 10);
+
+        // Updating the grading rules keeps the questions
+        client.set_quiz(&creator, &module.id, &80, &1);
+        client.remove_quiz_question(&creator, &module.id, &q1);
+        let quiz = client.get_quiz(&module.id);
+        assert_eq!(quiz.passing_score, 80);
+        assert_eq!(quiz.question_ids, vec![&env, q2]);
+        assert!(client.try_get_quiz_question(&module.id, &q1).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #43)")]
+    fn test_add_question_without_quiz() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        client.add_quiz_question(&creator, &module.id, &BytesN::from_array(&env, &[1; 32]), &5);
+    }
+
+    #[test]
+    fn test_remove_module_deletes_quiz() {
+        let env = Env::default();
+        let (client, creator, module) = setup(&env);
+
+        client.set_quiz(&creator, &module.id, &50, &2);
+        client.remove_module(&creator, &module.id);
+        assert!(client.try_get_quiz(&module.id).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use crate::error::{handle_error, Error};
+use crate::schema::{CreationWindow, DataKey, RateLimit};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const RATE_LIMIT_KEY: Symbol = symbol_short!("rate_lim");
+
+/// Returns the course creation rate limit.
+///
+/// Defaults to a disabled limit when the admin never configured one.
+pub fn get_rate_limit(env: &Env) -> RateLimit {
+    env.storage()
+        .instance()
+        .get(&RATE_LIMIT_KEY)
+        .unwrap_or(RateLimit {
+            max_courses: 0,
+            window_seconds: 0,
+        })
+}
+
+/// Configures how many courses one address may create per time window (admin-only).
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - limit: the new limit; `max_courses == 0` disables rate limiting,
+///   otherwise `window_seconds` must be non-zero.
+///
+/// Storage used:
+/// - ("rate_lim",) -> RateLimit    // instance storage
+pub fn set_rate_limit(env: &Env, caller: Address, limit: RateLimit) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if limit.max_courses > 0 && limit.window_seconds == 0 {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    env.storage().instance().set(&RATE_LIMIT_KEY, &limit);
+}
+
+/// Counts one course creation against the creator's quota.
+///
+/// The window starts with the first creation after the previous one expired;
+/// once `window_seconds` have passed the counter starts over.
+///
+/// Errors:
+/// - Returns `Error::RateLimitExceeded` if the creator already used the quota
+///   of the current window.
+///
+/// Storage used:
+/// - DataKey::CreationWindow(creator) -> CreationWindow
+pub fn consume_creation_quota(env: &Env, creator: &Address) {
+    let limit = get_rate_limit(env);
+    if limit.max_courses == 0 {
+        return;
+    }
+
+    let key = DataKey::CreationWindow(creator.clone());
+    let now: u64 = env.ledger().timestamp();
+    let mut window: CreationWindow = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(CreationWindow {
+            window_start: now,
+            count: 0,
+        });
+
+    if now.saturating_sub(window.window_start) >= limit.window_seconds {
+        window = CreationWindow {
+            window_start: now,
+            count: 0,
+        };
+    }
+
+    if window.count >= limit.max_courses {
+        handle_error(env, Error::RateLimitExceeded)
+    }
+
+    window.count += 1;
+    env.storage().persistent().set(&key, &window);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::{Course, RateLimit};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env, String};
+
+    mod mock_user_management {
+        use soroban_sdk::{contract, contractimpl, Address, Env};
+
+        #[contract]
+        pub struct UserManagement;
+
+        #[contractimpl]
+        impl UserManagement {
+            pub fn is_admin(_env: Env, _who: Address) -> bool {
+                true
+            }
+        }
+    }
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address) {
+        env.mock_all_auths();
+        let user_mgmt_id = env.register(mock_user_management::UserManagement, ());
+        let contract_id = env.register(CourseRegistry, ());
+        let admin = Address::generate(env);
+        env.as_contract(&contract_id, || {
+            crate::functions::access_control::initialize(env, &admin, &user_mgmt_id);
+        });
+        (CourseRegistryClient::new(env, &contract_id), admin)
+    }
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_rate_limit_window_resets() {
+        let env = Env::default();
+        let (client, admin) = setup(&env);
+        let creator = Address::generate(&env);
+        client.set_rate_limit(
+            &admin,
+            &RateLimit {
+                max_courses: 2,
+                window_seconds: 3600,
+            },
+        );
+
+        env.ledger().set_timestamp(1000);
+        create_course(&client, &creator, "Course 1");
+        create_course(&client, &creator, "Course 2");
+        assert!(client
+            .try_create_course(
+                &creator,
+                &String::from_str(&env, "Course 3"),
+                &String::from_str(&env, "description"),
+                &1000_u128,
+                &None,
+                &None,
+                &None,
+                &None,
+                &None,
+            )
+            .is_err());
+
+        // Other creators have their own quota
+        create_course(&client, &Address::generate(&env), "Course 4");
+
+        env.ledger().set_timestamp(1000 + 3600);
+        create_course(&client, &creator, "Course 3");
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #41)")]
+    fn test_rate_limit_exceeded() {
+        let env = Env::default();
+        let (client, admin) = setup(&env);
+        let creator = Address::generate(&env);
+        client.set_rate_limit(
+            &admin,
+            &RateLimit {
+                max_courses: 1,
+                window_seconds: 60,
+            },
+        );
+
+        create_course(&client, &creator, "Course 1");
+        create_course(&client, &creator, "Course 2");
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_set_rate_limit_zero_window() {
+        let env = Env::default();
+        let (client, admin) = setup(&env);
+        client.set_rate_limit(
+            &admin,
+            &RateLimit {
+                max_courses: 5,
+                window_seconds: 0,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_rate_limit_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+
+        client.set_rate_limit(
+            &Address::generate(&env),
+            &RateLimit {
+                max_courses: 5,
+                window_seconds: 3_600,
+            },
+        );
+    }
+}
@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{Enrollment, EnrollmentKey, Receipt};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const SET_RECEIPTS_TRANSFERABLE_EVENT: Symbol = symbol_short!("setRcptTx");
+const ISSUE_RECEIPT_EVENT: Symbol = symbol_short!("receipt");
+const TRANSFER_RECEIPT_EVENT: Symbol = symbol_short!("rcptXfer");
+
+/// Allows or forbids transferring the enrollment receipts of a course.
+///
+/// Receipts are not transferable by default. Transferring a receipt moves
+/// the proof of purchase only; access stays with the enrollment, see
+/// `enrollment_transfers::transfer_enrollment`.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::ReceiptsTransferable(course_id) -> bool
+pub fn set_receipts_transferable(env: &Env, caller: Address, course_id: u128, transferable: bool) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::ReceiptsTransferable(course_id);
+    if transferable {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((SET_RECEIPTS_TRANSFERABLE_EVENT, course_id), transferable);
+}
+
+/// Whether the enrollment receipts of a course may be transferred.
+pub fn receipts_transferable(env: &Env, course_id: u128) -> bool {
+    env.storage()
+        .persistent()
+        .has(&EnrollmentKey::ReceiptsTransferable(course_id))
+}
+
+/// Issues a receipt for a new enrollment to the student.
+///
+/// Called for every enrollment; enrolling again issues a new receipt.
+///
+/// Storage used:
+/// - EnrollmentKey::ReceiptSeq -> u128
+/// - EnrollmentKey::Receipt(id) -> Receipt
+/// - EnrollmentKey::EnrollmentReceipt(course_id, student) -> u128
+/// - EnrollmentKey::OwnerReceipts(owner) -> Vec<u128>
+pub fn issue_receipt(env: &Env, enrollment: &Enrollment) -> Receipt {
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get::<_, u128>(&EnrollmentKey::ReceiptSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::ReceiptSeq, &id);
+
+    let receipt = Receipt {
+        id,
+        course_id: enrollment.course_id,
+        student: enrollment.student.clone(),
+        owner: enrollment.student.clone(),
+        amount_paid: enrollment.amount_paid,
+        source: enrollment.source.clone(),
+        issued_at: enrollment.enrolled_at,
+    };
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::Receipt(id), &receipt);
+    env.storage().persistent().set(
+        &EnrollmentKey::EnrollmentReceipt(enrollment.course_id, enrollment.student.clone()),
+        &id,
+    );
+    add_owned(env, &receipt.owner, id);
+
+    env.events().publish(
+        (ISSUE_RECEIPT_EVENT, enrollment.course_id),
+        (enrollment.student.clone(), id),
+    );
+
+    receipt
+}
+
+/// Transfers a receipt to another address.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - from: the receipt holder (must authorize).
+/// - to: the new holder.
+/// - receipt_id: unique identifier of the receipt.
+///
+/// Errors:
+/// - Returns `EnrollmentError::ReceiptNotFound` if there is no such receipt.
+/// - Returns `Error::Unauthorized` if `from` does not hold the receipt.
+/// - Returns `EnrollmentError::ReceiptNotTransferable` if receipts of the
+///   course cannot be transferred.
+pub fn transfer_receipt(env: &Env, from: Address, to: Address, receipt_id: u128) -> Receipt {
+    from.require_auth();
+
+    let mut receipt: Receipt = match get_receipt(env, receipt_id) {
+        Some(receipt) => receipt,
+        None => handle_error(env, EnrollmentError::ReceiptNotFound),
+    };
+    if receipt.owner != from {
+        handle_error(env, Error::Unauthorized)
+    }
+    if !receipts_transferable(env, receipt.course_id) {
+        handle_error(env, EnrollmentError::ReceiptNotTransferable)
+    }
+
+    move_receipt(env, &mut receipt, &to);
+
+    env.events()
+        .publish((TRANSFER_RECEIPT_EVENT, receipt_id), (from, to));
+
+    receipt
+}
+
+/// Moves the receipt of an enrollment along with it, when the enrollment is
+/// transferred and its student still holds the receipt.
+pub fn reassign_receipt(env: &Env, course_id: u128, from: &Address, to: &Address) {
+    let key = EnrollmentKey::EnrollmentReceipt(course_id, from.clone());
+    let id: u128 = match env.storage().persistent().get(&key) {
+        Some(id) => id,
+        None => return,
+    };
+    env.storage().persistent().remove(&key);
+    env.storage().persistent().set(
+        &EnrollmentKey::EnrollmentReceipt(course_id, to.clone()),
+        &id,
+    );
+    if let Some(mut receipt) = get_receipt(env, id) {
+        if receipt.owner == *from {
+            move_receipt(env, &mut receipt, to);
+        }
+    }
+}
+
+/// Returns a receipt, if it exists.
+pub fn get_receipt(env: &Env, receipt_id: u128) -> Option<Receipt> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::Receipt(receipt_id))
+}
+
+/// Returns the receipt of a student's latest enrollment in a course, if any.
+pub fn get_enrollment_receipt(env: &Env, course_id: u128, student: Address) -> Option<Receipt> {
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&EnrollmentKey::EnrollmentReceipt(course_id, student))?;
+    get_receipt(env, id)
+}
+
+/// Returns the receipts an address holds, oldest first.
+pub fn list_receipts(env: &Env, owner: Address) -> Vec<Receipt> {
+    let mut receipts: Vec<Receipt> = Vec::new(env);
+    for id in owned_ids(env, &owner).iter() {
+        if let Some(receipt) = get_receipt(env, id) {
+            receipts.push_back(receipt);
+        }
+    }
+    receipts
+}
+
+fn move_receipt(env: &Env, receipt: &mut Receipt, to: &Address) {
+    let mut ids: Vec<u128> = owned_ids(env, &receipt.owner);
+    if let Some(index) = ids.first_index_of(receipt.id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::OwnerReceipts(receipt.owner.clone()), &ids);
+    }
+    receipt.owner = to.clone();
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::Receipt(receipt.id), receipt);
+    add_owned(env, to, receipt.id);
+}
+
+fn add_owned(env: &Env, owner: &Address, id: u128) {
+    let mut ids: Vec<u128> = owned_ids(env, owner);
+    ids.push_back(id);
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::OwnerReceipts(owner.clone()), &ids);
+}
+
+fn owned_ids(env: &Env, owner: &Address) -> Vec<u128> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::OwnerReceipts(owner.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::EnrollmentSource;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_receipt_issued_and_transferred() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let student = Address::generate(&env);
+        let collector = Address::generate(&env);
+        client.enroll(&student, &course_id, &None, &None, &None, &None);
+
+        let receipt = client.get_enrollment_receipt(&course_id, &student).unwrap();
+        assert_eq!(receipt.owner, student);
+        assert_eq!(receipt.source, EnrollmentSource::Purchase);
+        assert_eq!(client.list_receipts(&student).len(), 1);
+        assert!(client
+            .try_transfer_receipt(&student, &collector, &receipt.id)
+            .is_err());
+
+        client.set_receipts_transferable(&creator, &course_id, &true);
+        let receipt = client.transfer_receipt(&student, &collector, &receipt.id);
+        assert_eq!(receipt.owner, collector);
+        assert_eq!(receipt.student, student);
+        assert!(client.list_receipts(&student).is_empty());
+        assert_eq!(client.list_receipts(&collector).len(), 1);
+        assert!(client.is_enrolled(&student, &course_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #523)")]
+    fn test_receipts_not_transferable_by_default() {
+        let env = Env::default();
+        let (client, _creator, course_id) = setup(&env);
+        let student = Address::generate(&env);
+        client.enroll(&student, &course_id, &None, &None, &None, &None);
+        let receipt = client.get_enrollment_receipt(&course_id, &student).unwrap();
+
+        client.transfer_receipt(&student, &Address::generate(&env), &receipt.id);
+    }
+}
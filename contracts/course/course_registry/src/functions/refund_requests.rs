@@ -0,0 +1,370 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::remove_enrollment;
+use super::enrollment_events::publish_transition;
+use super::payments::to_token_amount;
+use super::royalty_splits::revenue_shares;
+use crate::error::{handle_error, PaymentError};
+use crate::schema::{
+    EnrollmentTransition, Payment, PaymentKey, PaymentStatus, RefundRequest, RefundRequestStatus,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, String, Symbol, Vec};
+
+const REFUND_REQUEST_EVENT: Symbol = symbol_short!("refundReq");
+const APPROVE_REFUND_EVENT: Symbol = symbol_short!("refundOk");
+const REJECT_REFUND_EVENT: Symbol = symbol_short!("refundNo");
+
+/// Opens a refund request for a released payment.
+///
+/// Called by `escrow::request_refund` once the refund window is over. A
+/// student can request a refund for a course only once.
+///
+/// Errors:
+/// - Returns `PaymentError::RefundRequestExists` if the student already
+///   requested a refund for the course.
+///
+/// Storage used:
+/// - PaymentKey::RefundRequest(course_id, student) -> RefundRequest
+pub fn open_refund_request(env: &Env, student: &Address, course_id: u128, reason: String) {
+    let key = PaymentKey::RefundRequest(course_id, student.clone());
+    if env.storage().persistent().has(&key) {
+        handle_error(env, PaymentError::RefundRequestExists)
+    }
+
+    let request = RefundRequest {
+        course_id,
+        student: student.clone(),
+        reason,
+        requested_at: env.ledger().timestamp(),
+        status: RefundRequestStatus::Pending,
+        rejection_reason: None,
+        decided_at: None,
+    };
+    env.storage().persistent().set(&key, &request);
+
+    env.events()
+        .publish((REFUND_REQUEST_EVENT, course_id), student.clone());
+}
+
+/// Approves a pending refund request and cancels the enrollment.
+///
+/// The creator's share of the payment is taken from the instructor's
+/// unwithdrawn earnings and returned to whoever paid. The platform fee and
+/// any referral commission are not returned.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - student: the student who requested the refund.
+///
+/// Returns:
+/// - RefundRequest: the approved request.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `PaymentError::RefundRequestNotFound` if there is no request.
+/// - Returns `PaymentError::RefundRequestClosed` if it was already decided.
+/// - Returns `PaymentError::NotRefundable` if the payment is not released.
+/// - Returns `PaymentError::InsufficientEarnings` if the instructor has
+///   withdrawn too much to cover the refund.
+pub fn approve_refund(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    student: Address,
+) -> RefundRequest {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::RefundRequest(course_id, student.clone());
+    let mut request: RefundRequest = load_pending_request(env, &key);
+    let payment_key = PaymentKey::Payment(course_id, student.clone());
+    let mut payment: Payment = match env.storage().persistent().get(&payment_key) {
+        Some(payment) => payment,
+        None => handle_error(env, PaymentError::PaymentNotFound),
+    };
+    if payment.status != PaymentStatus::Released {
+        handle_error(env, PaymentError::NotRefundable)
+    }
+
+    refund_from_earnings(env, &payment);
+    payment.status = PaymentStatus::Refunded;
+    env.storage().persistent().set(&payment_key, &payment);
+    remove_enrollment(env, course_id, &student);
+    publish_transition(
+        env,
+        course_id,
+        &student,
+        EnrollmentTransition::Refunded(payment.creator_amount),
+    );
+
+    request.status = RefundRequestStatus::Approved;
+    request.decided_at = Some(env.ledger().timestamp());
+    env.storage().persistent().set(&key, &request);
+
+    env.events().publish(
+        (APPROVE_REFUND_EVENT, course_id),
+        (student, payment.creator_amount),
+    );
+
+    request
+}
+
+/// Rejects a pending refund request, keeping the reason on-chain.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `PaymentError::RefundRequestNotFound` if there is no request.
+/// - Returns `PaymentError::RefundRequestClosed` if it was already decided.
+pub fn reject_refund(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    student: Address,
+    reason: String,
+) -> RefundRequest {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::RefundRequest(course_id, student.clone());
+    let mut request: RefundRequest = load_pending_request(env, &key);
+    request.status = RefundRequestStatus::Rejected;
+    request.rejection_reason = Some(reason);
+    request.decided_at = Some(env.ledger().timestamp());
+    env.storage().persistent().set(&key, &request);
+
+    env.events()
+        .publish((REJECT_REFUND_EVENT, course_id), student);
+
+    request
+}
+
+/// Closes the pending refund request of a student, if any, once their
+/// payment was refunded another way.
+///
+/// Storage used:
+/// - PaymentKey::RefundRequest(course_id, student) -> RefundRequest
+pub fn close_refund_request(env: &Env, course_id: u128, student: &Address) {
+    let key = PaymentKey::RefundRequest(course_id, student.clone());
+    if let Some(mut request) = env.storage().persistent().get::<_, RefundRequest>(&key) {
+        if request.status == RefundRequestStatus::Pending {
+            request.status = RefundRequestStatus::Closed;
+            request.decided_at = Some(env.ledger().timestamp());
+            env.storage().persistent().set(&key, &request);
+        }
+    }
+}
+
+/// Returns the creator's share of a released payment to its payer, out of
+/// the instructor's unwithdrawn earnings. The caller updates the record.
+///
+/// When the course revenue is split, each co-instructor gives back their
+/// share under the current split.
+///
+/// Errors:
+/// - Returns `PaymentError::InsufficientEarnings` if an instructor has
+///   withdrawn too much to cover their part of the refund.
+pub fn refund_from_earnings(env: &Env, payment: &Payment) {
+    let shares: Vec<(Address, u128)> = revenue_shares(
+        env,
+        payment.course_id,
+        &payment.creator,
+        payment.creator_amount,
+    );
+    let mut balances: Vec<u128> = Vec::new(env);
+    for (instructor, part) in shares.iter() {
+        let balance: u128 = env
+            .storage()
+            .persistent()
+            .get(&PaymentKey::Earnings(instructor, payment.token.clone()))
+            .unwrap_or(0);
+        if balance < part {
+            handle_error(env, PaymentError::InsufficientEarnings)
+        }
+        balances.push_back(balance);
+    }
+    for (i, (instructor, part)) in shares.iter().enumerate() {
+        let balance: u128 = balances.get(i as u32).unwrap_or(0);
+        env.storage().persistent().set(
+            &PaymentKey::Earnings(instructor, payment.token.clone()),
+            &(balance - part),
+        );
+    }
+    if payment.creator_amount > 0 {
+        token::Client::new(env, &payment.token).transfer(
+            &env.current_contract_address(),
+            &payment.payer,
+            &to_token_amount(env, payment.creator_amount),
+        );
+    }
+}
+
+/// Returns the refund request of a student for a course, if any.
+pub fn get_refund_request(env: &Env, course_id: u128, student: Address) -> Option<RefundRequest> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::RefundRequest(course_id, student))
+}
+
+fn load_pending_request(env: &Env, key: &PaymentKey) -> RefundRequest {
+    let request: RefundRequest = match env.storage().persistent().get(key) {
+        Some(request) => request,
+        None => handle_error(env, PaymentError::RefundRequestNotFound),
+    };
+    if request.status != RefundRequestStatus::Pending {
+        handle_error(env, PaymentError::RefundRequestClosed)
+    }
+    request
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::{Course, PaymentStatus, RefundRequestStatus};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Course, Address) {
+        let (client, admin, token) = setup_registry_with_token(env);
+        client.set_refund_window(&admin, &(14 * DAY));
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.edit_course(&creator, &course.id, &publish_params());
+
+        let student = Address::generate(env);
+        token::StellarAssetClient::new(env, &token).mint(&student, &1000);
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        env.ledger().set_timestamp(30 * DAY);
+        (client, token, course, student)
+    }
+
+    #[test]
+    fn test_approved_refund_paid_from_earnings() {
+        let env = Env::default();
+        let (client, token, course, student) = setup(&env);
+
+        let reason = String::from_str(&env, "Content does not match the syllabus");
+        let payment = client.request_refund(&student, &course.id, &Some(reason.clone()));
+        assert_eq!(payment.status, PaymentStatus::Released);
+        assert_eq!(client.get_earnings(&course.creator, &token), 1000);
+        let request = client.get_refund_request(&course.id, &student).unwrap();
+        assert_eq!(request.reason, reason);
+        assert_eq!(request.status, RefundRequestStatus::Pending);
+
+        let request = client.approve_refund(&course.creator, &course.id, &student);
+        assert_eq!(request.status, RefundRequestStatus::Approved);
+        assert_eq!(client.get_earnings(&course.creator, &token), 0);
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 1000);
+        assert_eq!(client.get_enrollment(&course.id, &student), None);
+        assert_eq!(
+            client.get_payment(&course.id, &student).unwrap().status,
+            PaymentStatus::Refunded
+        );
+    }
+
+    #[test]
+    fn test_rejected_refund_keeps_reason() {
+        let env = Env::default();
+        let (client, _token, course, student) = setup(&env);
+        client.request_refund(
+            &student,
+            &course.id,
+            &Some(String::from_str(&env, "Changed my mind")),
+        );
+
+        let reason = String::from_str(&env, "Course was completed");
+        let request = client.reject_refund(&course.creator, &course.id, &student, &reason);
+        assert_eq!(request.status, RefundRequestStatus::Rejected);
+        assert_eq!(request.rejection_reason, Some(reason));
+        assert!(client
+            .try_approve_refund(&course.creator, &course.id, &student)
+            .is_err());
+        assert!(client.get_enrollment(&course.id, &student).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #729)")]
+    fn test_refund_after_withdrawal() {
+        let env = Env::default();
+        let (client, token, course, student) = setup(&env);
+        client.request_refund(
+            &student,
+            &course.id,
+            &Some(String::from_str(&env, "Broken")),
+        );
+        client.withdraw_earnings(&course.creator, &token);
+
+        client.approve_refund(&course.creator, &course.id, &student);
+    }
+
+    #[test]
+    fn test_unpublish_refund_closes_pending_request() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        client.set_refund_window(&admin, &DAY);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        client.enroll(
+            &student,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+
+        env.ledger().set_timestamp(2 * DAY);
+        client.request_refund(
+            &student,
+            &course.id,
+            &Some(String::from_str(&env, "Broken")),
+        );
+        client.archive_course(&creator, &course.id);
+        client.claim_unpublish_refund(&student, &course.id);
+
+        let request = client.get_refund_request(&course.id, &student).unwrap();
+        assert_eq!(request.status, RefundRequestStatus::Closed);
+        assert!(client
+            .try_approve_refund(&creator, &course.id, &student)
+            .is_err());
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 1000);
+        assert_eq!(client.get_earnings(&creator, &token), 0);
+    }
+}
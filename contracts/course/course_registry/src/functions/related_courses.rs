@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility, DataKey, MAX_RELATED_COURSES};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_RELATED_EVENT: Symbol = symbol_short!("setRelatd");
+
+/// Replaces the curated list of courses related to a course.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course creator (must authorize).
+/// - course_id: unique identifier of the course.
+/// - related: up to `MAX_RELATED_COURSES` distinct, published course IDs;
+///   an empty list clears the suggestions.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not the course creator.
+/// - Returns `Error::InvalidInput` if the list is too long, contains duplicates,
+///   the course itself or a course that is not published.
+/// - Returns `Error::CourseNotFound` if a related course does not exist.
+///
+/// Storage used:
+/// - DataKey::RelatedCourses(course_id) -> Vec<u128>
+pub fn set_related_courses(env: &Env, creator: Address, course_id: u128, related: Vec<u128>) {
+    creator.require_auth();
+
+    let course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if related.len() > MAX_RELATED_COURSES {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    for (i, related_id) in related.iter().enumerate() {
+        if related_id == course_id || related.first_index_of(related_id) != Some(i as u32) {
+            handle_error(env, Error::InvalidInput)
+        }
+
+        let target: Course = match env.storage().persistent().get(&(COURSE_KEY, related_id)) {
+            Some(target) => target,
+            None => handle_error(env, Error::CourseNotFound),
+        };
+        if target.status != CourseStatus::Published {
+            handle_error(env, Error::InvalidInput)
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::RelatedCourses(course_id), &related);
+
+    env.events()
+        .publish((SET_RELATED_EVENT, course_id), related.len());
+}
+
+/// Returns the related courses of a course that are still published and public.
+///
+/// Courses that were unpublished, archived, hidden or deleted after being
+/// linked are skipped.
+pub fn get_related_courses(env: &Env, course_id: u128) -> Vec<Course> {
+    let related: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RelatedCourses(course_id))
+        .unwrap_or(Vec::new(env));
+
+    let mut results: Vec<Course> = Vec::new(env);
+    for related_id in related.iter() {
+        let course: Option<Course> = env.storage().persistent().get(&(COURSE_KEY, related_id));
+        if let Some(course) = course {
+            if course.status == CourseStatus::Published
+                && course.visibility == CourseVisibility::Public
+            {
+                results.push_back(course);
+            }
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_params;
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    fn create_course<'a>(
+        client: &CourseRegistryClient<'a>,
+        creator: &Address,
+        title: &str,
+        published: bool,
+    ) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        if !published {
+            return course;
+        }
+        client.edit_course(creator, &course.id, &publish_params())
+    }
+
+    #[test]
+    fn test_set_and_get_related_courses() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Rust Basics", true);
+        let related1 = create_course(&client, &creator, "Rust Advanced", true);
+        let related2 = create_course(&client, &creator, "Soroban 101", true);
+
+        client.set_related_courses(&creator, &course.id, &vec![&env, related1.id, related2.id]);
+        assert_eq!(client.get_related_courses(&course.id).len(), 2);
+
+        // Archived courses drop out of the suggestions
+        client.archive_course(&creator, &related1.id);
+        let related = client.get_related_courses(&course.id);
+        assert_eq!(related.len(), 1);
+        assert_eq!(related.get(0).unwrap().id, related2.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_related_course_must_be_published() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Rust Basics", true);
+        let draft = create_course(&client, &creator, "Rust Draft", false);
+        client.set_related_courses(&creator, &course.id, &vec![&env, draft.id]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #17)")]
+    fn test_related_course_must_exist() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator, "Rust Basics", true);
+        client.set_related_courses(&creator, &course.id, &vec![&env, 404_u128]);
+    }
+}
@@ -1,17 +1,18 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::course_index::unindex_goal;
 use crate::error::{handle_error, Error};
 use crate::schema::{Course, CourseGoal, DataKey};
 use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
 
 const GOAL_REMOVED_EVENT: Symbol = symbol_short!("goalrem");
 
-pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String) -> () {
+pub fn remove_goal(env: Env, caller: Address, course_id: u128, goal_id: String) -> () {
     caller.require_auth();
 
     // Validate input
-    if course_id.is_empty() {
+    if course_id == 0 {
         handle_error(&env, Error::InvalidInput)
     }
     if goal_id.is_empty() {
@@ -19,7 +20,7 @@ pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String
     }
 
     // Load course to verify it exists and check permissions
-    let storage_key = (symbol_short!("course"), course_id.clone());
+    let storage_key = (symbol_short!("course"), course_id);
     let course: Course = env
         .storage()
         .persistent()
@@ -33,7 +34,7 @@ pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String
     }
 
     // Check if the goal exists
-    let goal_storage_key = DataKey::CourseGoal(course_id.clone(), goal_id.clone());
+    let goal_storage_key = DataKey::CourseGoal(course_id, goal_id.clone());
     let goal: CourseGoal = env
         .storage()
         .persistent()
@@ -47,10 +48,11 @@ pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String
 
     // Remove the goal from storage
     env.storage().persistent().remove(&goal_storage_key);
+    unindex_goal(&env, course_id, &goal_id);
 
     // Emits an event for successful goal removal.
     env.events().publish(
-        (GOAL_REMOVED_EVENT, course_id.clone(), goal_id.clone()),
+        (GOAL_REMOVED_EVENT, course_id, goal_id.clone()),
         goal.content.clone(),
     );
 }
@@ -79,7 +81,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -112,7 +114,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -135,7 +137,7 @@ mod test {
         let client = CourseRegistryClient::new(&env, &contract_id);
 
         let creator: Address = Address::generate(&env);
-        let fake_course_id = String::from_str(&env, "nonexistent_course");
+        let fake_course_id = 999_u128;
         let fake_goal_id = String::from_str(&env, "fake_goal_id");
 
         client.remove_goal(&creator, &fake_course_id, &fake_goal_id);
@@ -160,7 +162,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -188,7 +190,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -215,7 +217,7 @@ mod test {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -1,11 +1,20 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::access_control::require_course_management_auth;
+use super::assignment::delete_assignment;
+use super::course_index::unindex_module;
+use super::course_totals::recompute_course_totals;
+use super::lessons::delete_module_lessons;
+use super::live_session::delete_live_session;
+use super::module_overrides::delete_module_overrides;
+use super::module_versions::delete_module_versions;
+use super::quiz::delete_quiz;
 use crate::error::{handle_error, Error};
 use crate::schema::CourseModule;
-use soroban_sdk::{symbol_short, Env, String};
+use soroban_sdk::{symbol_short, Address, Env, String};
 
-pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
+pub fn remove_module(env: &Env, caller: Address, module_id: String) -> Result<(), &'static str> {
     if module_id.len() == 0 {
         handle_error(&env, Error::EmptyModuleId)
     }
@@ -17,14 +26,30 @@ pub fn remove_module(env: &Env, module_id: String) -> Result<(), &'static str> {
         .get(&(symbol_short!("module"), module_id.clone()));
 
     // Validate that the module exists and is a valid CourseModule
-    if module.is_none() {
-        handle_error(&env, Error::ModuleNotFound)
-    }
-
-    // Delete the CourseModule directly from persistent storage using its key.
+    let module: CourseModule = match module {
+        Some(module) => module,
+        None => handle_error(&env, Error::ModuleNotFound),
+    };
+
+    // Only the course creator or an admin may remove its modules
+    require_course_management_auth(env, &caller, module.course_id);
+
+    // Delete the module's lessons, past versions, quiz, assignment, live
+    // session and access overrides, then the CourseModule itself.
+    delete_module_lessons(env, &module_id);
+    delete_module_versions(env, &module);
+    delete_quiz(env, &module_id);
+    delete_assignment(env, &module_id);
+    delete_live_session(env, &module_id);
+    delete_module_overrides(env, &module_id);
     env.storage()
         .persistent()
         .remove(&(symbol_short!("module"), module_id.clone()));
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("pos"), module.course_id, module.position));
+    unindex_module(env, module.course_id, &module_id);
+    recompute_course_totals(env, module.course_id);
 
     // Emits an event to indicate the module has been removed.
     env.events().publish((module_id,), "module_removed");
@@ -84,7 +109,7 @@ mod tests {
             &1000_u128,
             &Some(String::from_str(&env, "category")),
             &Some(String::from_str(&env, "language")),
-            &Some(String::from_str(&env, "thumbnail_url")),
+            &Some(String::from_str(&env, "https://example.com/thumbnail.png")),
             &None,
             &None,
         );
@@ -97,7 +122,7 @@ mod tests {
         });
         assert!(exists);
 
-        client.remove_module(&new_module.id);
+        client.remove_module(&creator, &new_module.id);
         let exists: bool = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
@@ -115,7 +140,7 @@ mod tests {
         let contract_id = env.register(CourseRegistry, {});
         let client = CourseRegistryClient::new(&env, &contract_id);
 
-        client.remove_module(&String::from_str(&env, ""));
+        client.remove_module(&Address::generate(&env), &String::from_str(&env, ""));
     }
 
     #[test]
@@ -127,6 +152,35 @@ mod tests {
         let contract_id = env.register(CourseRegistry, {});
         let client = CourseRegistryClient::new(&env, &contract_id);
 
-        client.remove_module(&String::from_str(&env, "non_existent_module"));
+        client.remove_module(
+            &Address::generate(&env),
+            &String::from_str(&env, "non_existent_module"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_remove_module_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        let creator = Address::generate(&env);
+        let course: Course = client.create_course(
+            &creator,
+            &String::from_str(&env, "title"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Module Title"));
+
+        client.remove_module(&Address::generate(&env), &module.id);
     }
 }
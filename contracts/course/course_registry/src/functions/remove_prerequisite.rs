@@ -1,22 +1,23 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
+use super::prerequisites::{get_prerequisites, save_prerequisites};
 use crate::error::{handle_error, Error};
-use crate::schema::{Course, DataKey};
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+use crate::schema::Course;
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 const PREREQ_REMOVED_EVENT: Symbol = symbol_short!("prereqrmv");
 
 pub fn remove_prerequisite(
     env: Env,
     creator: Address,
-    course_id: String,
-    prerequisite_course_id: String,
+    course_id: u128,
+    prerequisite_course_id: u128,
 ) {
     creator.require_auth();
 
     // Load course
-    let course_key = (symbol_short!("course"), course_id.clone());
+    let course_key = (symbol_short!("course"), course_id);
     let course: Course = env
         .storage()
         .persistent()
@@ -29,16 +30,12 @@ pub fn remove_prerequisite(
     }
 
     // Load current list of prerequisites
-    let mut prerequisites: Vec<String> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::CoursePrerequisites(course_id.clone()))
-        .unwrap_or(Vec::new(&env));
+    let mut prerequisites: Vec<u128> = get_prerequisites(&env, course_id);
 
     // Find and remove the prerequisite
     let index = prerequisites
         .iter()
-        .position(|id| id.eq(&prerequisite_course_id));
+        .position(|id| id == prerequisite_course_id);
 
     match index {
         Some(i) => {
@@ -48,10 +45,7 @@ pub fn remove_prerequisite(
     }
 
     // Save updated prerequisites
-    env.storage().persistent().set(
-        &DataKey::CoursePrerequisites(course_id.clone()),
-        &prerequisites,
-    );
+    save_prerequisites(&env, course_id, &prerequisites);
 
     // Emits an event for successful prerequisite removal.
     env.events()
@@ -60,8 +54,7 @@ pub fn remove_prerequisite(
 
 #[cfg(test)]
 mod test {
-    use super::*;
-    use crate::schema::Course;
+    use crate::schema::{Course, DataKey};
     use crate::{CourseRegistry, CourseRegistryClient};
     use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec as SdkVec};
 
@@ -99,13 +92,13 @@ mod test {
             &None,
         );
 
-        let prerequisites = SdkVec::from_array(&env, [course2.id.clone()]);
+        let prerequisites = SdkVec::from_array(&env, [course2.id]);
         client.edit_prerequisite(&creator, &course1.id, &prerequisites);
 
-        let stored_prerequisites: SdkVec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: SdkVec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap_or(SdkVec::new(&env))
         });
         assert_eq!(stored_prerequisites.len(), 1);
@@ -113,10 +106,10 @@ mod test {
 
         client.remove_prerequisite(&creator, &course1.id, &course2.id);
 
-        let stored_prerequisites: SdkVec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: SdkVec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap_or(SdkVec::new(&env))
         });
         assert_eq!(stored_prerequisites.len(), 0);
@@ -158,7 +151,7 @@ mod test {
             &None,
         );
 
-        let prerequisites = SdkVec::from_array(&env, [course2.id.clone()]);
+        let prerequisites = SdkVec::from_array(&env, [course2.id]);
         client.edit_prerequisite(&creator, &course1.id, &prerequisites);
 
         client.remove_prerequisite(&impostor, &course1.id, &course2.id);
@@ -174,8 +167,8 @@ mod test {
         let client = CourseRegistryClient::new(&env, &contract_id);
 
         let creator: Address = Address::generate(&env);
-        let fake_course_id = String::from_str(&env, "nonexistent_course");
-        let fake_prereq_id = String::from_str(&env, "nonexistent_prereq");
+        let fake_course_id: u128 = 999;
+        let fake_prereq_id: u128 = 1000;
 
         client.remove_prerequisite(&creator, &fake_course_id, &fake_prereq_id);
     }
@@ -264,13 +257,13 @@ mod test {
             &None,
         );
 
-        let prerequisites = SdkVec::from_array(&env, [course2.id.clone(), course3.id.clone()]);
+        let prerequisites = SdkVec::from_array(&env, [course2.id, course3.id]);
         client.edit_prerequisite(&creator, &course1.id, &prerequisites);
 
-        let stored_prerequisites: SdkVec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: SdkVec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap_or(SdkVec::new(&env))
         });
         assert_eq!(stored_prerequisites.len(), 2);
@@ -279,10 +272,10 @@ mod test {
 
         client.remove_prerequisite(&creator, &course1.id, &course2.id);
 
-        let stored_prerequisites: SdkVec<String> = env.as_contract(&contract_id, || {
+        let stored_prerequisites: SdkVec<u128> = env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
-                .get(&DataKey::CoursePrerequisites(course1.id.clone()))
+                .get(&DataKey::CoursePrerequisites(course1.id))
                 .unwrap_or(SdkVec::new(&env))
         });
         assert_eq!(stored_prerequisites.len(), 1);
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::course_index::module_ids;
+use crate::error::{handle_error, Error};
+use crate::schema::{CourseModule, DataKey};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const MODULE_KEY: Symbol = symbol_short!("module");
+const POSITION_KEY: Symbol = symbol_short!("pos");
+
+const REORDER_MODULES_EVENT: Symbol = symbol_short!("reordMods");
+
+/// Reorders the modules of a course.
+///
+/// `new_order` must list every module of the course exactly once. Modules
+/// are renumbered to positions `0..n` in that order.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - new_order: the module IDs in their new order.
+///
+/// Returns:
+/// - Vec<CourseModule>: the modules in their new order.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if `new_order` is not a permutation of the
+///   course's module IDs.
+pub fn reorder_modules(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    new_order: Vec<String>,
+) -> Vec<CourseModule> {
+    require_course_management_auth(env, &caller, course_id);
+
+    let current: Vec<String> = module_ids(env, course_id);
+    if new_order.len() != current.len() {
+        handle_error(env, Error::InvalidInput)
+    }
+    for (i, module_id) in new_order.iter().enumerate() {
+        if !current.contains(&module_id) || new_order.first_index_of(&module_id) != Some(i as u32) {
+            handle_error(env, Error::InvalidInput)
+        }
+    }
+
+    let mut modules: Vec<CourseModule> = Vec::new(env);
+    for module_id in new_order.iter() {
+        let module: CourseModule = env
+            .storage()
+            .persistent()
+            .get(&(MODULE_KEY, module_id))
+            .expect("Module not found");
+        env.storage()
+            .persistent()
+            .remove(&(POSITION_KEY, course_id, module.position));
+        modules.push_back(module);
+    }
+
+    let mut reordered: Vec<CourseModule> = Vec::new(env);
+    for (position, mut module) in modules.iter().enumerate() {
+        module.position = position as u32;
+        env.storage()
+            .persistent()
+            .set(&(MODULE_KEY, module.id.clone()), &module);
+        env.storage()
+            .persistent()
+            .set(&(POSITION_KEY, course_id, module.position), &true);
+        reordered.push_back(module);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::CourseModules(course_id), &new_order);
+
+    env.events()
+        .publish((REORDER_MODULES_EVENT, course_id), new_order.len());
+
+    reordered
+}
+
+/// Returns all modules of a course, sorted by position.
+pub fn get_modules(env: &Env, course_id: u128) -> Vec<CourseModule> {
+    let mut modules: Vec<CourseModule> = Vec::new(env);
+    for module_id in module_ids(env, course_id).iter() {
+        let module: Option<CourseModule> = env.storage().persistent().get(&(MODULE_KEY, module_id));
+        if let Some(module) = module {
+            // insertion sort; courses hold few modules
+            let mut index = modules.len();
+            while index > 0 && modules.get_unchecked(index - 1).position > module.position {
+                index -= 1;
+            }
+            modules.insert(index, module);
+        }
+    }
+    modules
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::Course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, vec, Address, Env, String};
+
+    fn create_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address) -> Course {
+        client.create_course(
+            creator,
+            &String::from_str(&client.env, "Course"),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_get_modules_sorted_by_position() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        client.add_module(&creator, &course.id, &2, &String::from_str(&env, "Third"));
+        client.add_module(&creator, &course.id, &0, &String::from_str(&env, "First"));
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "Second"));
+
+        let modules = client.get_modules(&course.id);
+        assert_eq!(modules.len(), 3);
+        assert_eq!(modules.get(0).unwrap().title, String::from_str(&env, "First"));
+        assert_eq!(modules.get(2).unwrap().title, String::from_str(&env, "Third"));
+    }
+
+    #[test]
+    fn test_reorder_modules() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        let a = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "A"));
+        let b = client.add_module(&creator, &course.id, &5, &String::from_str(&env, "B"));
+        let c = client.add_module(&creator, &course.id, &9, &String::from_str(&env, "C"));
+
+        client.reorder_modules(&creator, &course.id, &vec![&env, c.id.clone(), a.id.clone(), b.id.clone()]);
+
+        let modules = client.get_modules(&course.id);
+        assert_eq!(modules.get(0).unwrap().id, c.id);
+        assert_eq!(modules.get(0).unwrap().position, 0);
+        assert_eq!(modules.get(1).unwrap().id, a.id);
+        assert_eq!(modules.get(2).unwrap().id, b.id);
+        assert_eq!(modules.get(2).unwrap().position, 2);
+
+        // The freed position can be used again
+        env.ledger().set_sequence_number(env.ledger().sequence() + 1);
+        client.add_module(&creator, &course.id, &9, &String::from_str(&env, "D"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_reorder_modules_missing_module() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        let a = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "A"));
+        client.add_module(&creator, &course.id, &1, &String::from_str(&env, "B"));
+
+        client.reorder_modules(&creator, &course.id, &vec![&env, a.id.clone(), a.id]);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_reorder_modules_unauthorized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_course(&client, &creator);
+        let a = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "A"));
+        client.reorder_modules(&Address::generate(&env), &course.id, &vec![&env, a.id]);
+    }
+}
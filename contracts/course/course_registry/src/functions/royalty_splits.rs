@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::earnings::credit_earnings;
+use super::payments::bps_of;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, PaymentKey, MAX_FEE_BPS, MAX_SPLIT_RECIPIENTS};
+use soroban_sdk::{symbol_short, Address, Env, Map, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_SPLIT_EVENT: Symbol = symbol_short!("setSplit");
+
+/// Splits the revenue of a course among its co-instructors.
+///
+/// From then on the creator's share of every payment released for the course
+/// is credited to the earnings of each co-instructor according to the split,
+/// instead of to the creator alone. The creator must list themselves to keep
+/// a share. An empty split gives all revenue back to the creator.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - creator: the course owner (must authorize).
+/// - course_id: unique identifier of the course.
+/// - shares: share of each co-instructor in basis points; the shares must add
+///   up to `MAX_FEE_BPS` (100%).
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller is not the course owner.
+/// - Returns `Error::InvalidInput` if there are more than
+///   `MAX_SPLIT_RECIPIENTS` co-instructors, a share is 0 or the shares do not
+///   add up to 100%.
+///
+/// Storage used:
+/// - PaymentKey::RevenueSplit(course_id) -> Map<Address, u32>
+pub fn set_revenue_split(env: &Env, creator: Address, course_id: u128, shares: Map<Address, u32>) {
+    creator.require_auth();
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    if course.creator != creator {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let key = PaymentKey::RevenueSplit(course_id);
+    if shares.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        if shares.len() > MAX_SPLIT_RECIPIENTS {
+            handle_error(env, Error::InvalidInput)
+        }
+        let mut total: u32 = 0;
+        for share in shares.values().iter() {
+            if share == 0 || share > MAX_FEE_BPS {
+                handle_error(env, Error::InvalidInput)
+            }
+            total += share;
+        }
+        if total != MAX_FEE_BPS {
+            handle_error(env, Error::InvalidInput)
+        }
+        env.storage().persistent().set(&key, &shares);
+    }
+
+    env.events()
+        .publish((SET_SPLIT_EVENT, course_id), shares.len());
+}
+
+/// Returns the revenue split of a course; empty if the creator gets it all.
+pub fn get_revenue_split(env: &Env, course_id: u128) -> Map<Address, u32> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::RevenueSplit(course_id))
+        .unwrap_or(Map::new(env))
+}
+
+/// Divides an amount of course revenue according to the current split.
+///
+/// Shares are rounded down and the last co-instructor gets the remainder,
+/// so the parts always add up to `amount`. Without a split the creator gets
+/// everything.
+pub fn revenue_shares(
+    env: &Env,
+    course_id: u128,
+    creator: &Address,
+    amount: u128,
+) -> Vec<(Address, u128)> {
+    let split: Map<Address, u32> = get_revenue_split(env, course_id);
+    let mut parts: Vec<(Address, u128)> = Vec::new(env);
+    if split.is_empty() {
+        parts.push_back((creator.clone(), amount));
+        return parts;
+    }
+    let mut remaining: u128 = amount;
+    for (i, (instructor, bps)) in split.iter().enumerate() {
+        let part: u128 = if i as u32 + 1 == split.len() {
+            remaining
+        } else {
+            bps_of(amount, bps)
+        };
+        remaining -= part;
+        parts.push_back((instructor, part));
+    }
+    parts
+}
+
+/// Credits the creator's share of a payment to the earnings of the course's
+/// co-instructors, or of the creator when there is no split.
+pub fn credit_course_revenue(
+    env: &Env,
+    course_id: u128,
+    creator: &Address,
+    token: &Address,
+    amount: u128,
+) {
+    for (instructor, part) in revenue_shares(env, course_id, creator, amount).iter() {
+        credit_earnings(env, &instructor, course_id, token, part);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{map, testutils::Address as _, token, Address, Env, String};
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Address, u128) {
+        let (client, _admin, token) = setup_registry_with_token(env);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        (client, token, creator, course.id)
+    }
+
+    #[test]
+    fn test_revenue_split_among_co_instructors() {
+        let env = Env::default();
+        let (client, token, creator, course_id) = setup(&env);
+        let co_instructor = Address::generate(&env);
+        let shares = map![
+            &env,
+            (creator.clone(), 7_000_u32),
+            (co_instructor.clone(), 3_000_u32)
+        ];
+        client.set_revenue_split(&creator, &course_id, &shares);
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+        client.enroll(
+            &student,
+            &course_id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+
+        assert_eq!(client.get_earnings(&creator, &token), 700);
+        assert_eq!(client.get_earnings(&co_instructor, &token), 300);
+        assert_eq!(client.get_course_earnings(&course_id), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_shares_must_add_up_to_100_percent() {
+        let env = Env::default();
+        let (client, _token, creator, course_id) = setup(&env);
+        let shares = map![
+            &env,
+            (creator.clone(), 6_000_u32),
+            (Address::generate(&env), 3_000_u32)
+        ];
+
+        client.set_revenue_split(&creator, &course_id, &shares);
+    }
+}
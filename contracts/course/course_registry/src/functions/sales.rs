@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::price_limits::validate_price_range;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, PaymentKey, Sale};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_SALE_EVENT: Symbol = symbol_short!("setSale");
+const CLEAR_SALE_EVENT: Symbol = symbol_short!("clearSale");
+
+/// Schedules a sale price for a course, replacing any previous sale.
+///
+/// Between `starts_at` and `ends_at` the sale price is what `get_course`
+/// reports and what `enroll` charges; the stored price is left untouched.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - price: the discounted price, above 0 and below the regular price.
+/// - starts_at: ledger timestamp the sale starts at.
+/// - ends_at: ledger timestamp the sale ends at, exclusive.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidPrice` if the sale price is 0, not below the price
+///   or outside the price limits.
+/// - Returns `Error::InvalidInput` if the window is empty or already over, or
+///   the course is free.
+///
+/// Storage used:
+/// - PaymentKey::Sale(course_id) -> Sale
+pub fn set_sale(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    price: u128,
+    starts_at: u64,
+    ends_at: u64,
+) -> Sale {
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    require_course_management_auth(env, &caller, course_id);
+
+    if course.is_free || starts_at >= ends_at || ends_at <= env.ledger().timestamp() {
+        handle_error(env, Error::InvalidInput)
+    }
+    if price == 0 || price >= course.price {
+        handle_error(env, Error::InvalidPrice)
+    }
+    validate_price_range(env, price, false);
+
+    let sale = Sale {
+        price,
+        starts_at,
+        ends_at,
+    };
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::Sale(course_id), &sale);
+
+    env.events()
+        .publish((SET_SALE_EVENT, course_id), sale.clone());
+
+    sale
+}
+
+/// Cancels the sale of a course, if any.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+pub fn clear_sale(env: &Env, caller: Address, course_id: u128) {
+    require_course_management_auth(env, &caller, course_id);
+    env.storage()
+        .persistent()
+        .remove(&PaymentKey::Sale(course_id));
+
+    env.events().publish((CLEAR_SALE_EVENT, course_id), ());
+}
+
+/// Returns the sale scheduled for a course, whether or not it is running.
+pub fn get_sale(env: &Env, course_id: u128) -> Option<Sale> {
+    env.storage().persistent().get(&PaymentKey::Sale(course_id))
+}
+
+/// Returns the price a course sells for right now.
+///
+/// This is the sale price while a sale is running, and the regular price
+/// otherwise. A sale is ignored if the regular price has since dropped below it.
+pub fn effective_price(env: &Env, course: &Course) -> u128 {
+    match get_sale(env, course.id) {
+        Some(sale) if is_running(env, &sale) && sale.price < course.price => sale.price,
+        _ => course.price,
+    }
+}
+
+fn is_running(env: &Env, sale: &Sale) -> bool {
+    let now = env.ledger().timestamp();
+    sale.starts_at <= now && now < sale.ends_at
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_params;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env, String,
+    };
+
+    #[test]
+    fn test_sale_window_sets_effective_price() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+
+        client.set_sale(&creator, &course.id, &600, &100, &200);
+        assert_eq!(client.get_course(&course.id).price, 1000);
+
+        env.ledger().set_timestamp(100);
+        assert_eq!(client.get_course(&course.id).price, 600);
+
+        env.ledger().set_timestamp(200);
+        assert_eq!(client.get_course(&course.id).price, 1000);
+        assert!(client.get_sale(&course.id).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #9)")]
+    fn test_sale_price_must_be_lower() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.set_sale(&creator, &course.id, &1000, &0, &100);
+    }
+}
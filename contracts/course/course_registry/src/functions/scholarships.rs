@@ -0,0 +1,381 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::{is_admin, require_course_management_auth};
+use super::course_index::category_key;
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::{get_payment_token, record_payment, settle_payment, to_token_amount};
+use super::sales::effective_price;
+use crate::error::{handle_error, EnrollmentError, Error, PaymentError};
+use crate::schema::{
+    Course, CourseStatus, Enrollment, EnrollmentSource, Payment, PaymentKey, PaymentStatus,
+    ScholarshipPool, ScholarshipTarget,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CREATE_POOL_EVENT: Symbol = symbol_short!("newPool");
+const FUND_POOL_EVENT: Symbol = symbol_short!("fundPool");
+const SET_ELIGIBLE_EVENT: Symbol = symbol_short!("setElig");
+const SCHOLARSHIP_EVENT: Symbol = symbol_short!("scholar");
+
+/// Opens a scholarship pool for a course or a category.
+///
+/// The pool holds the payment token set at creation. Anyone may open and fund
+/// pools; only the course creator or an admin decides who benefits from them.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - sponsor: the address opening the pool (must authorize).
+/// - target: the course, or the category of courses, the pool pays for.
+///
+/// Returns:
+/// - ScholarshipPool: the new, empty pool.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the target course does not exist.
+/// - Returns `Error::EmptyCategory` if the target category is empty.
+/// - Returns `PaymentError::UnsupportedToken` if no payment token is set.
+///
+/// Storage used:
+/// - PaymentKey::PoolSeq -> u128
+/// - PaymentKey::ScholarshipPool(id) -> ScholarshipPool
+pub fn create_scholarship_pool(
+    env: &Env,
+    sponsor: Address,
+    target: ScholarshipTarget,
+) -> ScholarshipPool {
+    sponsor.require_auth();
+
+    match &target {
+        ScholarshipTarget::Course(course_id) => {
+            load_course(env, *course_id);
+        }
+        ScholarshipTarget::Category(name) if name.is_empty() => {
+            handle_error(env, Error::EmptyCategory)
+        }
+        ScholarshipTarget::Category(_) => {}
+    }
+    let token: Address = match get_payment_token(env) {
+        Some(token) => token,
+        None => handle_error(env, PaymentError::UnsupportedToken),
+    };
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get::<_, u128>(&PaymentKey::PoolSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&PaymentKey::PoolSeq, &id);
+
+    let pool = ScholarshipPool {
+        id,
+        target,
+        token,
+        balance: 0,
+        total_contributed: 0,
+        total_awarded: 0,
+        scholarships: 0,
+    };
+    save_pool(env, &pool);
+
+    env.events()
+        .publish((CREATE_POOL_EVENT, id), (sponsor, pool.target.clone()));
+
+    pool
+}
+
+/// Deposits tokens into a scholarship pool.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the amount is 0.
+/// - Returns `PaymentError::PoolNotFound` if the pool does not exist.
+///
+/// Storage used:
+/// - PaymentKey::SponsorContribution(pool_id, sponsor) -> u128
+pub fn fund_scholarship_pool(
+    env: &Env,
+    sponsor: Address,
+    pool_id: u128,
+    amount: u128,
+) -> ScholarshipPool {
+    sponsor.require_auth();
+    if amount == 0 {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut pool: ScholarshipPool = get_scholarship_pool(env, pool_id);
+    token::Client::new(env, &pool.token).transfer(
+        &sponsor,
+        &env.current_contract_address(),
+        &to_token_amount(env, amount),
+    );
+    pool.balance += amount;
+    pool.total_contributed += amount;
+    save_pool(env, &pool);
+
+    let key = PaymentKey::SponsorContribution(pool_id, sponsor.clone());
+    let contributed: u128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &(contributed + amount));
+
+    env.events()
+        .publish((FUND_POOL_EVENT, pool_id), (sponsor, amount));
+
+    pool
+}
+
+/// Allow-lists a student for one scholarship from a pool, or removes them.
+///
+/// Course pools are managed by the course creator or an admin, category
+/// pools by an admin.
+///
+/// Errors:
+/// - Returns `PaymentError::PoolNotFound` if the pool does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the pool.
+///
+/// Storage used:
+/// - PaymentKey::ScholarshipEligible(pool_id, student) -> bool
+pub fn set_scholarship_eligible(
+    env: &Env,
+    caller: Address,
+    pool_id: u128,
+    student: Address,
+    eligible: bool,
+) {
+    let pool: ScholarshipPool = get_scholarship_pool(env, pool_id);
+    match pool.target {
+        ScholarshipTarget::Course(course_id) => {
+            require_course_management_auth(env, &caller, course_id)
+        }
+        ScholarshipTarget::Category(_) => {
+            caller.require_auth();
+            if !is_admin(env, &caller) {
+                handle_error(env, Error::Unauthorized)
+            }
+        }
+    }
+
+    let key = PaymentKey::ScholarshipEligible(pool_id, student.clone());
+    if eligible {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events()
+        .publish((SET_ELIGIBLE_EVENT, pool_id), (student, eligible));
+}
+
+/// Whether a student is allow-listed for a scholarship from a pool.
+pub fn is_scholarship_eligible(env: &Env, pool_id: u128, student: Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&PaymentKey::ScholarshipEligible(pool_id, student))
+}
+
+/// Enrolls an allow-listed student with the pool paying the course price.
+///
+/// The pool pays the current price, or the sale price while a sale is running,
+/// and the payment is released to the creator at once, as it is not the
+/// student's to refund. The student's place on the allow-list is used up.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolling student (must authorize).
+/// - course_id: unique identifier of the course.
+/// - pool_id: the pool paying for the enrollment.
+///
+/// Returns:
+/// - Enrollment: the new enrollment record.
+///
+/// Errors:
+/// - Returns `PaymentError::PoolNotFound` if the pool does not exist.
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::InvalidInput` if the pool is not for the course.
+/// - Returns `PaymentError::NotEligible` if the student is not allow-listed.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Returns `PaymentError::PoolExhausted` if the pool cannot cover the price.
+pub fn enroll_with_scholarship(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    pool_id: u128,
+) -> Enrollment {
+    student.require_auth();
+
+    let mut pool: ScholarshipPool = get_scholarship_pool(env, pool_id);
+    let course: Course = load_course(env, course_id);
+    let covered: bool = match &pool.target {
+        ScholarshipTarget::Course(id) => *id == course_id,
+        ScholarshipTarget::Category(name) => course
+            .category
+            .as_ref()
+            .is_some_and(|category| category_key(env, category) == category_key(env, name)),
+    };
+    if !covered {
+        handle_error(env, Error::InvalidInput)
+    }
+    let eligible_key = PaymentKey::ScholarshipEligible(pool_id, student.clone());
+    if !env.storage().persistent().has(&eligible_key) {
+        handle_error(env, PaymentError::NotEligible)
+    }
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    let price: u128 = if course.is_free {
+        0
+    } else {
+        effective_price(env, &course)
+    };
+    if price > pool.balance {
+        handle_error(env, PaymentError::PoolExhausted)
+    }
+    pool.balance -= price;
+    pool.total_awarded += price;
+    pool.scholarships += 1;
+    save_pool(env, &pool);
+    env.storage().persistent().remove(&eligible_key);
+
+    if price > 0 {
+        let mut payment: Payment =
+            record_payment(env, &course, &student, &student, &pool.token, price, None);
+        if payment.status == PaymentStatus::Escrowed {
+            settle_payment(env, &mut payment);
+            env.storage()
+                .persistent()
+                .set(&PaymentKey::Payment(course_id, student.clone()), &payment);
+        }
+    }
+
+    env.events().publish(
+        (SCHOLARSHIP_EVENT, pool_id),
+        (student.clone(), course_id, price),
+    );
+
+    record_student_enrollment(env, &course, &student, price, EnrollmentSource::Scholarship)
+}
+
+/// Returns a scholarship pool by ID.
+///
+/// Errors:
+/// - Returns `PaymentError::PoolNotFound` if the pool does not exist.
+pub fn get_scholarship_pool(env: &Env, pool_id: u128) -> ScholarshipPool {
+    match env
+        .storage()
+        .persistent()
+        .get(&PaymentKey::ScholarshipPool(pool_id))
+    {
+        Some(pool) => pool,
+        None => handle_error(env, PaymentError::PoolNotFound),
+    }
+}
+
+/// Returns how much a sponsor has deposited into a pool in total.
+pub fn get_sponsor_contribution(env: &Env, pool_id: u128, sponsor: Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::SponsorContribution(pool_id, sponsor))
+        .unwrap_or(0)
+}
+
+fn save_pool(env: &Env, pool: &ScholarshipPool) {
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::ScholarshipPool(pool.id), pool);
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::{Course, ScholarshipTarget};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+    fn create_published_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, "Course"),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &Some(String::from_str(&client.env, "Blockchain")),
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &publish_params())
+    }
+
+    #[test]
+    fn test_category_pool_pays_for_eligible_student() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator);
+
+        let sponsor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&sponsor, &1500);
+        let target = ScholarshipTarget::Category(String::from_str(&env, "blockchain"));
+        let pool = client.create_scholarship_pool(&sponsor, &target);
+        client.fund_scholarship_pool(&sponsor, &pool.id, &1500);
+
+        let student = Address::generate(&env);
+        client.set_scholarship_eligible(&admin, &pool.id, &student, &true);
+        let enrollment = client.enroll_with_scholarship(&student, &course.id, &pool.id);
+
+        assert_eq!(enrollment.amount_paid, 1000);
+        assert_eq!(client.get_earnings(&creator, &token), 1000);
+        let pool = client.get_scholarship_pool(&pool.id);
+        assert_eq!(pool.balance, 500);
+        assert_eq!(pool.total_awarded, 1000);
+        assert_eq!(pool.scholarships, 1);
+        assert_eq!(client.get_sponsor_contribution(&pool.id, &sponsor), 1500);
+        assert!(!client.is_scholarship_eligible(&pool.id, &student));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #719)")]
+    fn test_pool_cannot_cover_price() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let course = create_published_course(&client, &creator);
+
+        let sponsor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&sponsor, &500);
+        let pool = client.create_scholarship_pool(&sponsor, &ScholarshipTarget::Course(course.id));
+        client.fund_scholarship_pool(&sponsor, &pool.id, &500);
+
+        let student = Address::generate(&env);
+        client.set_scholarship_eligible(&creator, &pool.id, &student, &true);
+        client.enroll_with_scholarship(&student, &course.id, &pool.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_category_pool_eligibility_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin, _token) = setup_registry_with_token(&env);
+        let sponsor = Address::generate(&env);
+        let target = ScholarshipTarget::Category(String::from_str(&env, "blockchain"));
+        let pool = client.create_scholarship_pool(&sponsor, &target);
+
+        client.set_scholarship_eligible(&sponsor, &pool.id, &Address::generate(&env), &true);
+    }
+}
@@ -0,0 +1,341 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::coupons::apply_discount;
+use super::course_capacity::{release_reserved_seat, reserve_seats};
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::{bps_of, get_platform_fee, quote_payment, settle_payment, to_token_amount};
+use crate::error::{handle_error, EnrollmentError, Error, PaymentError};
+use crate::schema::{
+    Course, CourseStatus, Discount, Enrollment, EnrollmentSource, Payment, PaymentKey,
+    PaymentStatus, SeatPool, VolumeDiscount, MAX_SEATS_PER_PURCHASE,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_VOLUME_DISCOUNT_EVENT: Symbol = symbol_short!("setVolDsc");
+const BUY_SEATS_EVENT: Symbol = symbol_short!("buySeats");
+const ASSIGN_SEAT_EVENT: Symbol = symbol_short!("seat");
+
+/// Sets the discount organizations get when buying many seats, or removes it.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - discount: percentage off for purchases of at least `min_seats` seats, or
+///   `None` to remove the discount.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the course is free, `min_seats` is below
+///   2 or the percentage is not between 1 and 99.
+///
+/// Storage used:
+/// - PaymentKey::VolumeDiscount(course_id) -> VolumeDiscount
+pub fn set_volume_discount(
+    env: &Env,
+    caller: Address,
+    course_id: u128,
+    discount: Option<VolumeDiscount>,
+) {
+    let course: Course = load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::VolumeDiscount(course_id);
+    match &discount {
+        Some(discount) => {
+            if course.is_free
+                || discount.min_seats < 2
+                || discount.percent == 0
+                || discount.percent >= 100
+            {
+                handle_error(env, Error::InvalidInput)
+            }
+            env.storage().persistent().set(&key, discount);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_VOLUME_DISCOUNT_EVENT, course_id), discount);
+}
+
+/// Returns the volume discount of a course, if any.
+pub fn get_volume_discount(env: &Env, course_id: u128) -> Option<VolumeDiscount> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::VolumeDiscount(course_id))
+}
+
+/// Buys seats of a course for an organization to hand out to its members.
+///
+/// Each seat costs what one enrollment would cost in the token, minus the
+/// volume discount when enough seats are bought at once. The payment is
+/// released to the creator right away: seats are not refundable. Buying
+/// again adds seats to the organization's pool, paid in the same token.
+/// Bought seats count against the course capacity until they are assigned.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - org: the buying organization (must authorize).
+/// - course_id: unique identifier of the course.
+/// - seat_count: number of seats, from 1 to `MAX_SEATS_PER_PURCHASE`.
+/// - token: the token to pay with; the payment token or one on the course
+///   price list.
+///
+/// Returns:
+/// - SeatPool: the organization's seats for the course.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the seat count is out of range, the
+///   course costs nothing or the total overflows.
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::CourseFull` if fewer seats are left in the
+///   course.
+/// - Any error `payments::quote_payment` can return for the token.
+/// - Returns `PaymentError::UnsupportedToken` if earlier seats were paid in
+///   another token.
+///
+/// Storage used:
+/// - PaymentKey::SeatPool(course_id, org) -> SeatPool
+/// - EnrollmentKey::ReservedSeats(course_id) -> u32
+pub fn purchase_seats(
+    env: &Env,
+    org: Address,
+    course_id: u128,
+    seat_count: u32,
+    token: Option<Address>,
+) -> SeatPool {
+    org.require_auth();
+    if seat_count == 0 || seat_count > MAX_SEATS_PER_PURCHASE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    let (accepted, seat_price): (Address, u128) =
+        match quote_payment(env, &course, token, None, None) {
+            Some(quote) => quote,
+            None => handle_error(env, Error::InvalidInput),
+        };
+    let key = PaymentKey::SeatPool(course_id, org.clone());
+    let mut pool: SeatPool = env.storage().persistent().get(&key).unwrap_or(SeatPool {
+        course_id,
+        org: org.clone(),
+        token: accepted.clone(),
+        seats: 0,
+        assigned: 0,
+        amount_paid: 0,
+    });
+    if pool.token != accepted {
+        handle_error(env, PaymentError::UnsupportedToken)
+    }
+    let total: u128 = match seat_price.checked_mul(seat_count as u128) {
+        Some(total) => total,
+        None => handle_error(env, Error::InvalidInput),
+    };
+    let total: u128 = match get_volume_discount(env, course_id) {
+        Some(discount) if seat_count >= discount.min_seats => {
+            apply_discount(total, &Discount::Percent(discount.percent))
+        }
+        _ => total,
+    };
+    reserve_seats(env, course_id, seat_count);
+
+    if total > 0 {
+        token::Client::new(env, &accepted).transfer(
+            &org,
+            &env.current_contract_address(),
+            &to_token_amount(env, total),
+        );
+        let platform_fee: u128 = match get_platform_fee(env) {
+            Some(fee) => bps_of(total, fee.fee_bps),
+            None => 0,
+        };
+        let now: u64 = env.ledger().timestamp();
+        let mut payment = Payment {
+            course_id,
+            payer: org.clone(),
+            creator: course.creator.clone(),
+            token: accepted.clone(),
+            amount: total,
+            platform_fee,
+            creator_amount: total - platform_fee,
+            referrer: None,
+            referral_commission: 0,
+            paid_at: now,
+            status: PaymentStatus::Escrowed,
+            escrow_until: now,
+        };
+        settle_payment(env, &mut payment);
+    }
+
+    pool.seats += seat_count;
+    pool.amount_paid += total;
+    env.storage().persistent().set(&key, &pool);
+
+    env.events()
+        .publish((BUY_SEATS_EVENT, course_id), (org, seat_count, total));
+
+    pool
+}
+
+/// Enrolls a member of an organization on one of its seats.
+///
+/// The enrollment records the average price the organization paid per seat.
+///
+/// Errors:
+/// - Returns `PaymentError::NoSeatsLeft` if the organization has no free seat.
+/// - Returns `Error::CourseNotFound` if the course was deleted.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the member is already enrolled.
+pub fn assign_seat(env: &Env, org: Address, course_id: u128, member: Address) -> Enrollment {
+    org.require_auth();
+
+    let key = PaymentKey::SeatPool(course_id, org.clone());
+    let mut pool: SeatPool = match env.storage().persistent().get(&key) {
+        Some(pool) => pool,
+        None => handle_error(env, PaymentError::NoSeatsLeft),
+    };
+    if pool.assigned >= pool.seats {
+        handle_error(env, PaymentError::NoSeatsLeft)
+    }
+    let course: Course = load_course(env, course_id);
+    if is_enrolled(env, course_id, &member) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    pool.assigned += 1;
+    env.storage().persistent().set(&key, &pool);
+    // Hand the reserved seat back so the enrollment below takes it.
+    release_reserved_seat(env, course_id);
+
+    env.events()
+        .publish((ASSIGN_SEAT_EVENT, course_id), (org, member.clone()));
+
+    record_student_enrollment(
+        env,
+        &course,
+        &member,
+        pool.amount_paid / pool.seats as u128,
+        EnrollmentSource::Seat,
+    )
+}
+
+/// Returns the seats an organization bought for a course, if any.
+pub fn get_seat_pool(env: &Env, course_id: u128, org: Address) -> Option<SeatPool> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::SeatPool(course_id, org))
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::VolumeDiscount;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+    #[test]
+    fn test_purchase_and_assign_seats() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let discount = VolumeDiscount {
+            min_seats: 10,
+            percent: 20,
+        };
+        client.set_volume_discount(&creator, &course.id, &Some(discount));
+
+        let org = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&org, &8000);
+        let pool = client.purchase_seats(&org, &course.id, &10, &Some(token.clone()));
+        assert_eq!(pool.seats, 10);
+        assert_eq!(pool.amount_paid, 8000);
+        assert_eq!(client.get_earnings(&creator, &token), 8000);
+
+        let member = Address::generate(&env);
+        let enrollment = client.assign_seat(&org, &course.id, &member);
+        assert_eq!(enrollment.student, member);
+        assert_eq!(enrollment.amount_paid, 800);
+        assert_eq!(client.get_seat_pool(&course.id, &org).unwrap().assigned, 1);
+        assert!(client
+            .try_assign_seat(&org, &course.id, &Address::generate(&env))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_purchased_seats_take_capacity() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        client.set_max_students(&creator, &course.id, &Some(3));
+
+        let org = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&org, &4000);
+        assert!(client
+            .try_purchase_seats(&org, &course.id, &4, &Some(token.clone()))
+            .is_err());
+        client.purchase_seats(&org, &course.id, &2, &Some(token.clone()));
+        assert_eq!(client.get_course(&course.id).seats_remaining, Some(1));
+        assert!(client
+            .try_set_max_students(&creator, &course.id, &Some(1))
+            .is_err());
+
+        client.assign_seat(&org, &course.id, &Address::generate(&env));
+        assert_eq!(client.get_course(&course.id).seats_remaining, Some(1));
+        assert!(client
+            .try_purchase_seats(&org, &course.id, &2, &Some(token))
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #725)")]
+    fn test_assign_seat_without_seats() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.assign_seat(&Address::generate(&env), &1, &Address::generate(&env));
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::{is_admin, require_course_management_auth};
+use super::utils::normalize_title;
+use crate::error::{handle_error, CatalogError, Error};
+use crate::schema::{Course, CourseLevel, CourseSkill, DataKey, Skill, MAX_COURSE_SKILLS};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const CREATE_SKILL_EVENT: Symbol = symbol_short!("newSkill");
+const SET_COURSE_SKILLS_EVENT: Symbol = symbol_short!("setSkills");
+
+/// Adds a skill to the shared taxonomy (admin-only).
+///
+/// Skills give courses and certificates a canonical on-chain reference, so a
+/// certificate can assert "Rust: Intermediate" against skill IDs rather than
+/// free text.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - name: skill name, unique ignoring case and spacing.
+/// - parent_id: optional broader skill this one is nested under.
+///
+/// Returns:
+/// - u128: the newly created skill ID.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the name is empty or longer than 100 bytes.
+/// - Returns `CatalogError::SkillExists` if a skill already uses the name.
+/// - Returns `CatalogError::SkillNotFound` if the parent skill does not exist.
+///
+/// Storage used:
+/// - DataKey::SkillSeq -> u128
+/// - DataKey::Skill(id) -> Skill
+/// - DataKey::SkillByName(name) -> u128
+pub fn create_skill(env: &Env, caller: Address, name: String, parent_id: Option<u128>) -> u128 {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if name.is_empty() || name.len() > 100 {
+        handle_error(env, Error::InvalidInput)
+    }
+    let name_key = DataKey::SkillByName(normalize_title(env, &name));
+    if env.storage().persistent().has(&name_key) {
+        handle_error(env, CatalogError::SkillExists)
+    }
+    if let Some(parent_id) = parent_id {
+        get_skill(env, parent_id);
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get::<_, u128>(&DataKey::SkillSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&DataKey::SkillSeq, &id);
+
+    let skill = Skill {
+        id,
+        name: name.clone(),
+        parent_id,
+    };
+    env.storage().persistent().set(&DataKey::Skill(id), &skill);
+    env.storage().persistent().set(&name_key, &id);
+
+    env.events().publish((CREATE_SKILL_EVENT, id), name);
+
+    id
+}
+
+/// Returns a skill by ID.
+///
+/// Errors:
+/// - Returns `CatalogError::SkillNotFound` if the skill does not exist.
+pub fn get_skill(env: &Env, skill_id: u128) -> Skill {
+    match env.storage().persistent().get(&DataKey::Skill(skill_id)) {
+        Some(skill) => skill,
+        None => handle_error(env, CatalogError::SkillNotFound),
+    }
+}
+
+/// Looks a skill up by name, ignoring case and spacing.
+pub fn find_skill(env: &Env, name: String) -> Option<Skill> {
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SkillByName(normalize_title(env, &name)))?;
+    env.storage().persistent().get(&DataKey::Skill(id))
+}
+
+/// Replaces the skills a course teaches.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - skills: up to `MAX_COURSE_SKILLS` distinct skills with the level taught;
+///   an empty list clears them.
+///
+/// Errors:
+/// - Panics with `"Course not found"` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the list is too long, repeats a skill or
+///   leaves a level `Unspecified`.
+/// - Returns `CatalogError::SkillNotFound` if a skill does not exist.
+///
+/// Storage used:
+/// - DataKey::CourseSkills(course_id) -> Vec<CourseSkill>
+pub fn set_course_skills(env: &Env, caller: Address, course_id: u128, skills: Vec<CourseSkill>) {
+    let _course: Course = env
+        .storage()
+        .persistent()
+        .get(&(COURSE_KEY, course_id))
+        .expect("Course not found");
+    require_course_management_auth(env, &caller, course_id);
+
+    if skills.len() > MAX_COURSE_SKILLS {
+        handle_error(env, Error::InvalidInput)
+    }
+    for (i, skill) in skills.iter().enumerate() {
+        if skill.level == CourseLevel::Unspecified {
+            handle_error(env, Error::InvalidInput)
+        }
+        get_skill(env, skill.skill_id);
+        for earlier in skills.slice(0..i as u32).iter() {
+            if earlier.skill_id == skill.skill_id {
+                handle_error(env, Error::InvalidInput)
+            }
+        }
+    }
+
+    let key = DataKey::CourseSkills(course_id);
+    if skills.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &skills);
+    }
+
+    env.events()
+        .publish((SET_COURSE_SKILLS_EVENT, course_id), skills.len());
+}
+
+/// Returns the skills a course teaches.
+pub fn get_course_skills(env: &Env, course_id: u128) -> Vec<CourseSkill> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CourseSkills(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::{CourseLevel, CourseSkill};
+    use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+    #[test]
+    fn test_skill_taxonomy_and_course_skills() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+
+        let programming = client.create_skill(&admin, &String::from_str(&env, "Programming"), &None);
+        let rust = client.create_skill(&admin, &String::from_str(&env, "Rust"), &Some(programming));
+        assert_eq!(client.get_skill(&rust).parent_id, Some(programming));
+        assert_eq!(client.find_skill(&String::from_str(&env, "rust")).unwrap().id, rust);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Rust for Soroban"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let skills = vec![
+            &env,
+            CourseSkill {
+                skill_id: rust,
+                level: CourseLevel::Intermediate,
+            },
+        ];
+        client.set_course_skills(&creator, &course.id, &skills);
+        assert_eq!(client.get_course_skills(&course.id), skills);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #606)")]
+    fn test_create_skill_unknown_parent() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+
+        client.create_skill(&admin, &String::from_str(&env, "Rust"), &Some(42));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #607)")]
+    fn test_create_skill_duplicate_name() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+
+        client.create_skill(&admin, &String::from_str(&env, "Rust"), &None);
+        client.create_skill(&admin, &String::from_str(&env, "RUST"), &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_create_skill_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+
+        client.create_skill(&Address::generate(&env), &String::from_str(&env, "Rust"), &None);
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::enrollment::{get_enrollment, get_student_course_ids};
+use crate::schema::EnrollmentSummary;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Lists the courses a student is enrolled in, in enrollment order.
+///
+/// Every enrollment path, paid or not, adds the course to the student's
+/// index; refunded enrollments are taken out of it, revoked ones stay with
+/// their status.
+///
+/// Storage used:
+/// - EnrollmentKey::StudentCourses(student) -> Vec<u128>
+pub fn list_user_courses(env: &Env, student: Address) -> Vec<EnrollmentSummary> {
+    let mut summaries: Vec<EnrollmentSummary> = Vec::new(env);
+    for course_id in get_student_course_ids(env, &student).iter() {
+        if let Some(enrollment) = get_enrollment(env, course_id, student.clone()) {
+            summaries.push_back(EnrollmentSummary {
+                course_id,
+                status: enrollment.status,
+                source: enrollment.source,
+                enrolled_at: enrollment.enrolled_at,
+            });
+        }
+    }
+    summaries
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::{EnrollmentSource, EnrollmentStatus};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        Address, Env, String,
+    };
+
+    #[test]
+    fn test_list_user_courses() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let student = Address::generate(&env);
+        assert!(client.list_user_courses(&student).is_empty());
+
+        let mut course_ids = [0_u128; 2];
+        for (course_id, title) in course_ids.iter_mut().zip(["Course 1", "Course 2"]) {
+            *course_id = client
+                .create_course(
+                    &creator,
+                    &String::from_str(&env, title),
+                    &String::from_str(&env, "description"),
+                    &1000_u128,
+                    &None,
+                    &None,
+                    &None,
+                    &None,
+                    &None,
+                )
+                .id;
+        }
+        client.edit_course(&creator, &course_ids[0], &publish_free_params());
+
+        env.ledger().set_timestamp(100);
+        client.enroll(&student, &course_ids[0], &None, &None, &None, &None);
+        env.ledger().set_timestamp(200);
+        client.grant_access(&creator, &course_ids[1], &student);
+        client.revoke_access(
+            &creator,
+            &course_ids[0],
+            &student,
+            &String::from_str(&env, "Chargeback"),
+        );
+
+        let courses = client.list_user_courses(&student);
+        assert_eq!(courses.len(), 2);
+        let first = courses.get(0).unwrap();
+        assert_eq!(first.course_id, course_ids[0]);
+        assert_eq!(first.status, EnrollmentStatus::Revoked);
+        assert_eq!(first.enrolled_at, 100);
+        let second = courses.get(1).unwrap();
+        assert_eq!(second.course_id, course_ids[1]);
+        assert_eq!(second.source, EnrollmentSource::Granted);
+        assert_eq!(second.status, EnrollmentStatus::Active);
+    }
+}
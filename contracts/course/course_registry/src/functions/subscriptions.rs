@@ -0,0 +1,471 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use super::cohorts::get_student_cohort;
+use super::course_bans::is_banned;
+use super::enrollment::get_enrollment;
+use super::installments::is_access_suspended;
+use super::payments::{get_payment_token, to_token_amount};
+use super::treasury::credit_treasury;
+use crate::error::{handle_error, Error, PaymentError};
+use crate::schema::{
+    EnrollmentStatus, PaymentKey, Subscription, SubscriptionPlan, MAX_PLAN_COURSES,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, String, Symbol, Vec};
+
+const CREATE_PLAN_EVENT: Symbol = symbol_short!("newPlan");
+const SET_PLAN_ACTIVE_EVENT: Symbol = symbol_short!("planActve");
+const SUBSCRIBE_EVENT: Symbol = symbol_short!("subscribe");
+const RENEW_EVENT: Symbol = symbol_short!("renew");
+
+/// Creates a subscription plan (admin-only).
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - name: plan name shown to students.
+/// - price: price charged per period.
+/// - period: length of one period in seconds of ledger time.
+/// - course_ids: courses the plan gives access to, up to `MAX_PLAN_COURSES`;
+///   an empty list covers the whole catalog.
+///
+/// Returns:
+/// - SubscriptionPlan: the new plan.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the name is empty or longer than 100
+///   bytes, the period is 0 or the course list is too long.
+/// - Returns `Error::InvalidPrice` if the price is 0.
+///
+/// Storage used:
+/// - PaymentKey::PlanSeq -> u128
+/// - PaymentKey::Plan(id) -> SubscriptionPlan
+pub fn create_plan(
+    env: &Env,
+    caller: Address,
+    name: String,
+    price: u128,
+    period: u64,
+    course_ids: Vec<u128>,
+) -> SubscriptionPlan {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    if name.is_empty() || name.len() > 100 || period == 0 || course_ids.len() > MAX_PLAN_COURSES {
+        handle_error(env, Error::InvalidInput)
+    }
+    if price == 0 {
+        handle_error(env, Error::InvalidPrice)
+    }
+
+    let id: u128 = env
+        .storage()
+        .persistent()
+        .get::<_, u128>(&PaymentKey::PlanSeq)
+        .unwrap_or(0)
+        + 1;
+    env.storage().persistent().set(&PaymentKey::PlanSeq, &id);
+
+    let plan = SubscriptionPlan {
+        id,
+        name,
+        price,
+        period,
+        course_ids,
+        active: true,
+    };
+    env.storage().persistent().set(&PaymentKey::Plan(id), &plan);
+
+    env.events().publish((CREATE_PLAN_EVENT, id), plan.price);
+
+    plan
+}
+
+/// Opens or closes a plan to new subscriptions and renewals (admin-only).
+///
+/// Running subscriptions keep their access until they expire.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `PaymentError::PlanNotFound` if the plan does not exist.
+pub fn set_plan_active(env: &Env, caller: Address, plan_id: u128, active: bool) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let mut plan: SubscriptionPlan = get_plan(env, plan_id);
+    plan.active = active;
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::Plan(plan_id), &plan);
+
+    env.events()
+        .publish((SET_PLAN_ACTIVE_EVENT, plan_id), active);
+}
+
+/// Returns a subscription plan by ID.
+///
+/// Errors:
+/// - Returns `PaymentError::PlanNotFound` if the plan does not exist.
+pub fn get_plan(env: &Env, plan_id: u128) -> SubscriptionPlan {
+    match env.storage().persistent().get(&PaymentKey::Plan(plan_id)) {
+        Some(plan) => plan,
+        None => handle_error(env, PaymentError::PlanNotFound),
+    }
+}
+
+/// Subscribes to a plan for one period, starting now.
+///
+/// The price is credited to the platform treasury. Nothing is charged while
+/// no payment token is set.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - subscriber: the subscribing student (must authorize).
+/// - plan_id: unique identifier of the plan.
+/// - token: the token to pay with; must be the accepted payment token.
+///
+/// Returns:
+/// - Subscription: the new subscription.
+///
+/// Errors:
+/// - Returns `PaymentError::PlanNotFound` if the plan does not exist.
+/// - Returns `PaymentError::PlanInactive` if the plan is closed.
+/// - Returns `PaymentError::SubscriptionActive` if the subscriber already has
+///   a running subscription to the plan; use `renew` instead.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is not accepted.
+///
+/// Storage used:
+/// - PaymentKey::Subscription(subscriber, plan_id) -> Subscription
+/// - PaymentKey::SubscriberPlans(subscriber) -> Vec<u128>
+pub fn subscribe(
+    env: &Env,
+    subscriber: Address,
+    plan_id: u128,
+    token: Option<Address>,
+) -> Subscription {
+    subscriber.require_auth();
+
+    let plan: SubscriptionPlan = get_open_plan(env, plan_id);
+    let now: u64 = env.ledger().timestamp();
+    let previous: Option<Subscription> = get_subscription(env, subscriber.clone(), plan_id);
+    if matches!(&previous, Some(subscription) if subscription.expires_at > now) {
+        handle_error(env, PaymentError::SubscriptionActive)
+    }
+    charge_period(env, &subscriber, &plan, token);
+
+    let subscription = Subscription {
+        plan_id,
+        subscriber: subscriber.clone(),
+        started_at: now,
+        expires_at: now.saturating_add(plan.period),
+    };
+    env.storage().persistent().set(
+        &PaymentKey::Subscription(subscriber.clone(), plan_id),
+        &subscription,
+    );
+    if previous.is_none() {
+        let key = PaymentKey::SubscriberPlans(subscriber.clone());
+        let mut plans: Vec<u128> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        plans.push_back(plan_id);
+        env.storage().persistent().set(&key, &plans);
+    }
+
+    env.events().publish(
+        (SUBSCRIBE_EVENT, plan_id),
+        (subscriber, subscription.expires_at),
+    );
+
+    subscription
+}
+
+/// Extends a subscription by one period.
+///
+/// A running subscription is extended from its expiry, a lapsed one from now.
+///
+/// Errors:
+/// - Returns `PaymentError::SubscriptionNotFound` if the subscriber never
+///   subscribed to the plan.
+/// - Returns `PaymentError::PlanInactive` if the plan is closed.
+/// - Returns `PaymentError::UnsupportedToken` if `token` is not accepted.
+pub fn renew(
+    env: &Env,
+    subscriber: Address,
+    plan_id: u128,
+    token: Option<Address>,
+) -> Subscription {
+    subscriber.require_auth();
+
+    let mut subscription: Subscription = match get_subscription(env, subscriber.clone(), plan_id) {
+        Some(subscription) => subscription,
+        None => handle_error(env, PaymentError::SubscriptionNotFound),
+    };
+    let plan: SubscriptionPlan = get_open_plan(env, plan_id);
+    charge_period(env, &subscriber, &plan, token);
+
+    let now: u64 = env.ledger().timestamp();
+    if subscription.expires_at <= now {
+        subscription.started_at = now;
+        subscription.expires_at = now;
+    }
+    subscription.expires_at = subscription.expires_at.saturating_add(plan.period);
+    env.storage().persistent().set(
+        &PaymentKey::Subscription(subscriber.clone(), plan_id),
+        &subscription,
+    );
+
+    env.events().publish(
+        (RENEW_EVENT, plan_id),
+        (subscriber, subscription.expires_at),
+    );
+
+    subscription
+}
+
+/// Returns a subscriber's subscription to a plan, running or lapsed.
+pub fn get_subscription(env: &Env, subscriber: Address, plan_id: u128) -> Option<Subscription> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Subscription(subscriber, plan_id))
+}
+
+/// Returns since when a student may study a course, if they may.
+///
+/// That is the enrollment time for enrolled students, unless their access is
+/// suspended over a missed installment, or else the start of the earliest
+/// running subscription to a plan covering the course. A student whose access
+/// to the course was revoked or who is banned from it has none, subscription
+/// or not, and one who left the course only has subscription access. Students
+/// of a cohort study from the start of the cohort until it ends, and
+/// enrollments sold for a limited time give no access once expired.
+pub fn access_started_at(env: &Env, course_id: u128, student: &Address) -> Option<u64> {
+    if is_banned(env, course_id, student.clone()) {
+        return None;
+    }
+    let now: u64 = env.ledger().timestamp();
+    if let Some(enrollment) = get_enrollment(env, course_id, student.clone()) {
+        if enrollment.status == EnrollmentStatus::Revoked {
+            return None;
+        }
+        let expired: bool = enrollment
+            .expires_at
+            .is_some_and(|expires_at| now >= expires_at);
+        if enrollment.status == EnrollmentStatus::Active
+            && !expired
+            && !is_access_suspended(env, course_id, student)
+        {
+            match get_student_cohort(env, course_id, student.clone()) {
+                Some(cohort) => {
+                    if now >= cohort.starts_at && now < cohort.ends_at {
+                        return Some(cohort.starts_at);
+                    }
+                }
+                None => return Some(enrollment.enrolled_at),
+            }
+        }
+    }
+
+    let plans: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&PaymentKey::SubscriberPlans(student.clone()))
+        .unwrap_or(Vec::new(env));
+    let mut started_at: Option<u64> = None;
+    for plan_id in plans.iter() {
+        let subscription: Subscription = match get_subscription(env, student.clone(), plan_id) {
+            Some(subscription) if subscription.expires_at > now => subscription,
+            _ => continue,
+        };
+        let plan: SubscriptionPlan = get_plan(env, plan_id);
+        if !plan.course_ids.is_empty() && !plan.course_ids.contains(course_id) {
+            continue;
+        }
+        started_at = Some(match started_at {
+            Some(earliest) => earliest.min(subscription.started_at),
+            None => subscription.started_at,
+        });
+    }
+    started_at
+}
+
+/// Whether a student is enrolled in a course or holds a running subscription
+/// covering it.
+pub fn has_course_access(env: &Env, course_id: u128, student: Address) -> bool {
+    access_started_at(env, course_id, &student).is_some()
+}
+
+fn get_open_plan(env: &Env, plan_id: u128) -> SubscriptionPlan {
+    let plan: SubscriptionPlan = get_plan(env, plan_id);
+    if !plan.active {
+        handle_error(env, PaymentError::PlanInactive)
+    }
+    plan
+}
+
+fn charge_period(env: &Env, subscriber: &Address, plan: &SubscriptionPlan, token: Option<Address>) {
+    let accepted: Address = match get_payment_token(env) {
+        Some(accepted) => accepted,
+        None => return,
+    };
+    if token.as_ref() != Some(&accepted) {
+        handle_error(env, PaymentError::UnsupportedToken)
+    }
+    token::Client::new(env, &accepted).transfer(
+        subscriber,
+        &env.current_contract_address(),
+        &to_token_amount(env, plan.price),
+    );
+    credit_treasury(env, &accepted, plan.price);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::ModuleAccess;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, vec, Address, Env, String,
+    };
+
+    #[test]
+    fn test_subscription_grants_access_until_expiry() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let module = client.add_module(&creator, &course.id, &0, &String::from_str(&env, "Intro"));
+
+        let plan = client.create_plan(
+            &admin,
+            &String::from_str(&env, "All access"),
+            &300,
+            &1_000,
+            &vec![&env],
+        );
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &600);
+        assert!(!client.has_course_access(&course.id, &student));
+
+        client.subscribe(&student, &plan.id, &Some(token.clone()));
+        assert!(client.has_course_access(&course.id, &student));
+        assert_eq!(
+            client.check_module_access(&student, &module.id),
+            ModuleAccess::Granted
+        );
+
+        env.ledger().set_timestamp(500);
+        let renewed = client.renew(&student, &plan.id, &Some(token.clone()));
+        assert_eq!(renewed.expires_at, 2_000);
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 0);
+
+        env.ledger().set_timestamp(2_000);
+        assert!(!client.has_course_access(&course.id, &student));
+        assert_eq!(
+            client.check_module_access(&student, &module.id),
+            ModuleAccess::NotEnrolled
+        );
+    }
+
+    #[test]
+    fn test_banned_subscriber_has_no_access() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        let plan = client.create_plan(
+            &admin,
+            &String::from_str(&env, "All access"),
+            &300,
+            &1_000,
+            &vec![&env],
+        );
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &300);
+        client.subscribe(&student, &plan.id, &Some(token));
+        assert!(client.has_course_access(&course.id, &student));
+
+        client.ban_student(&creator, &course.id, &student, &String::from_str(&env, "spam"));
+        assert!(!client.has_course_access(&course.id, &student));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #713)")]
+    fn test_subscribe_twice() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        let plan = client.create_plan(
+            &admin,
+            &String::from_str(&env, "Monthly"),
+            &100,
+            &2_592_000,
+            &vec![&env],
+        );
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &200);
+
+        client.subscribe(&student, &plan.id, &Some(token.clone()));
+        client.subscribe(&student, &plan.id, &Some(token));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_create_plan_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin, _token) = setup_registry_with_token(&env);
+
+        client.create_plan(
+            &Address::generate(&env),
+            &String::from_str(&env, "Monthly"),
+            &100,
+            &2_592_000,
+            &vec![&env],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_plan_active_by_non_admin() {
+        let env = Env::default();
+        let (client, admin, _token) = setup_registry_with_token(&env);
+        let plan = client.create_plan(
+            &admin,
+            &String::from_str(&env, "Monthly"),
+            &100,
+            &2_592_000,
+            &vec![&env],
+        );
+
+        client.set_plan_active(&Address::generate(&env), &plan.id, &false);
+    }
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+//! Fixtures shared by the unit tests of the contract functions.
+
+use crate::schema::{Course, EditCourseParams};
+use crate::{CourseRegistry, CourseRegistryClient};
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, String, Symbol,
+};
+
+const ADMIN_KEY: Symbol = symbol_short!("admin");
+
+/// Stand-in for the user management contract that knows a single admin.
+#[contract]
+pub struct AdminOracle;
+
+#[contractimpl]
+impl AdminOracle {
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&ADMIN_KEY, &admin);
+    }
+
+    pub fn is_admin(env: Env, who: Address) -> bool {
+        env.storage().instance().get(&ADMIN_KEY) == Some(who)
+    }
+}
+
+/// Registers the contract with an `AdminOracle` and mocks all auths.
+///
+/// Returns the client and the only admin address.
+pub fn setup_registry<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    let user_mgmt_id = env.register(AdminOracle, (admin.clone(),));
+    let client = CourseRegistryClient::new(env, &env.register(CourseRegistry, ()));
+    client.initialize(&admin, &user_mgmt_id);
+    (client, admin)
+}
+
+/// Like `setup_registry`, with a Stellar asset set as the payment token.
+///
+/// Returns the client, the admin and the token address.
+pub fn setup_registry_with_token<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Address) {
+    let (client, admin) = setup_registry(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_payment_token(&admin, &token);
+    (client, admin, token)
+}
+
+/// Edit parameters that only publish a course.
+pub fn publish_params() -> EditCourseParams {
+    EditCourseParams {
+        new_title: None,
+        new_description: None,
+        new_price: None,
+        new_category: None,
+        new_language: None,
+        new_thumbnail_url: None,
+        new_published: Some(true),
+        new_duration_hours: None,
+        new_is_free: None,
+    }
+}
+
+/// Edit parameters that publish a course and make it free.
+pub fn publish_free_params() -> EditCourseParams {
+    EditCourseParams {
+        new_is_free: Some(true),
+        ..publish_params()
+    }
+}
+
+/// Creates a course and publishes it.
+pub fn create_published_course(
+    client: &CourseRegistryClient,
+    creator: &Address,
+    title: &str,
+    price: u128,
+) -> Course {
+    let course = client.create_course(
+        creator,
+        &String::from_str(&client.env, title),
+        &String::from_str(&client.env, "description"),
+        &price,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    client.edit_course(creator, &course.id, &publish_params())
+}
+
+/// Creates a course and publishes it as a free course.
+pub fn create_free_course(client: &CourseRegistryClient, creator: &Address, title: &str) -> Course {
+    let course = client.create_course(
+        creator,
+        &String::from_str(&client.env, title),
+        &String::from_str(&client.env, "description"),
+        &1000,
+        &None,
+        &None,
+        &None,
+        &None,
+        &None,
+    );
+    client.edit_course(creator, &course.id, &publish_free_params())
+}
+
+/// Mints `amount` of the Stellar asset `token` to `to`.
+pub fn mint(env: &Env, token: &Address, to: &Address, amount: i128) {
+    StellarAssetClient::new(env, token).mint(to, &amount);
+}
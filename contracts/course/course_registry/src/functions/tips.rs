@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::payments::to_token_amount;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, PaymentKey, TipTotals};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const TIP_EVENT: Symbol = symbol_short!("tip");
+
+/// Sends a tip straight to an instructor.
+///
+/// A tip made for a course is added to that course's tip totals. The event is
+/// published under the instructor's address so their dashboard can list it.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - from: the tipping address (must authorize).
+/// - instructor: the instructor receiving the tip.
+/// - token: the token to tip in.
+/// - amount: the tip amount, above 0.
+/// - course_id: optional course the tip is for; it must be by the instructor.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the amount is 0 or the course is not by
+///   the instructor.
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+///
+/// Storage used:
+/// - PaymentKey::CourseTips(course_id, token) -> TipTotals
+pub fn tip_instructor(
+    env: &Env,
+    from: Address,
+    instructor: Address,
+    token: Address,
+    amount: u128,
+    course_id: Option<u128>,
+) {
+    from.require_auth();
+    if amount == 0 {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    if let Some(course_id) = course_id {
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+            Some(course) => course,
+            None => handle_error(env, Error::CourseNotFound),
+        };
+        if course.creator != instructor {
+            handle_error(env, Error::InvalidInput)
+        }
+
+        let key = PaymentKey::CourseTips(course_id, token.clone());
+        let mut totals: TipTotals = get_course_tips(env, course_id, token.clone());
+        totals.count += 1;
+        totals.amount += amount;
+        env.storage().persistent().set(&key, &totals);
+    }
+
+    token::Client::new(env, &token).transfer(&from, &instructor, &to_token_amount(env, amount));
+
+    env.events()
+        .publish((TIP_EVENT, instructor), (from, token, amount, course_id));
+}
+
+/// Returns the tips received for a course in a token.
+pub fn get_course_tips(env: &Env, course_id: u128, token: Address) -> TipTotals {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::CourseTips(course_id, token))
+        .unwrap_or(TipTotals {
+            count: 0,
+            amount: 0,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+    #[test]
+    fn test_tip_instructor_for_course() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let fan = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&fan, &100);
+
+        client.tip_instructor(&fan, &creator, &token, &60, &Some(course.id));
+        client.tip_instructor(&fan, &creator, &token, &40, &None);
+
+        assert_eq!(token::Client::new(&env, &token).balance(&creator), 100);
+        let tips = client.get_course_tips(&course.id, &token);
+        assert_eq!(tips.count, 1);
+        assert_eq!(tips.amount, 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_tip_for_course_of_another_instructor() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let course = client.create_course(
+            &Address::generate(&env),
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+
+        client.tip_instructor(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &token,
+            &10,
+            &Some(course.id),
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::utils::{course_hash, normalize_title};
+use crate::schema::Course;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
+
+const TITLE_KEY: Symbol = symbol_short!("title");
+const COURSE_HASH_KEY: Symbol = symbol_short!("crs_hash");
+
+/// Storage key reserving a normalized title.
+pub fn title_key(env: &Env, title: &String) -> (Symbol, String) {
+    (TITLE_KEY, normalize_title(env, title))
+}
+
+/// Storage key mapping the deterministic creator + title hash to a course ID.
+pub fn hash_key(env: &Env, creator: &Address, title: &String) -> (Symbol, BytesN<32>) {
+    (COURSE_HASH_KEY, course_hash(env, creator, title))
+}
+
+/// Returns whether a live course already holds the given title.
+pub fn is_title_taken(env: &Env, title: &String) -> bool {
+    env.storage().persistent().has(&title_key(env, title))
+}
+
+/// Claims the title and hash slots for a course.
+///
+/// Storage used:
+/// - (("title", normalized_title),) -> u128
+/// - (("crs_hash", hash),) -> u128
+pub fn reserve_title(env: &Env, course: &Course) {
+    env.storage()
+        .persistent()
+        .set(&title_key(env, &course.title), &course.id);
+    env.storage()
+        .persistent()
+        .set(&hash_key(env, &course.creator, &course.title), &course.id);
+}
+
+/// Frees the title and hash slots held by a course so they can be reused.
+pub fn release_title(env: &Env, course: &Course) {
+    env.storage()
+        .persistent()
+        .remove(&title_key(env, &course.title));
+    env.storage()
+        .persistent()
+        .remove(&hash_key(env, &course.creator, &course.title));
+}
@@ -0,0 +1,472 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use super::utils::normalize_title;
+use crate::error::{handle_error, CatalogError, Error};
+use crate::schema::{DataKey, MAX_BLOCKED_TERMS, MAX_RESERVED_PREFIXES};
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+const BLOCK_TERM_EVENT: Symbol = symbol_short!("blockTerm");
+const UNBLOCK_TERM_EVENT: Symbol = symbol_short!("unblkTerm");
+const RESERVE_PREFIX_EVENT: Symbol = symbol_short!("rsvPrefix");
+const RELEASE_PREFIX_EVENT: Symbol = symbol_short!("relPrefix");
+const SET_OFFICIAL_EVENT: Symbol = symbol_short!("official");
+
+/// Longest blocked term or reserved prefix accepted, in bytes.
+const MAX_TERM_LEN: u32 = 50;
+
+/// Adds a word or phrase to the title blocklist (admin-only).
+///
+/// Terms are stored normalized (trimmed, lowercased, single-spaced). Adding a
+/// term that is already blocked does nothing.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - term: the word or phrase to ban.
+///
+/// Returns:
+/// - Vec<String>: the blocked terms.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the term is empty or too long, or the
+///   blocklist already holds `MAX_BLOCKED_TERMS` terms.
+///
+/// Storage used:
+/// - DataKey::BlockedTitleTerms -> Vec<String>
+pub fn add_blocked_term(env: &Env, caller: Address, term: String) -> Vec<String> {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let term: String = normalize_title(env, &term);
+    if term.is_empty() || term.len() > MAX_TERM_LEN {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut terms: Vec<String> = get_blocked_terms(env);
+    if terms.contains(&term) {
+        return terms;
+    }
+    if terms.len() >= MAX_BLOCKED_TERMS {
+        handle_error(env, Error::InvalidInput)
+    }
+    terms.push_back(term.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::BlockedTitleTerms, &terms);
+
+    env.events().publish((BLOCK_TERM_EVENT,), term);
+
+    terms
+}
+
+/// Removes a word or phrase from the title blocklist (admin-only).
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the term is not blocked.
+pub fn remove_blocked_term(env: &Env, caller: Address, term: String) -> Vec<String> {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let term: String = normalize_title(env, &term);
+    let mut terms: Vec<String> = get_blocked_terms(env);
+    let index = match terms.first_index_of(&term) {
+        Some(index) => index,
+        None => handle_error(env, Error::InvalidInput),
+    };
+    terms.remove(index);
+    if terms.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BlockedTitleTerms);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::BlockedTitleTerms, &terms);
+    }
+
+    env.events().publish((UNBLOCK_TERM_EVENT,), term);
+
+    terms
+}
+
+/// Returns the blocked title terms, in the order they were added.
+pub fn get_blocked_terms(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BlockedTitleTerms)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Reserves a title prefix for official creators (admin-only).
+///
+/// Prefixes are stored normalized, so reserving "SkillCert Official:" also
+/// covers "skillcert  official: Intro". Reserving a prefix twice does nothing.
+/// Existing courses are not affected.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - prefix: the title prefix to reserve.
+///
+/// Returns:
+/// - Vec<String>: the reserved prefixes.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the prefix is empty or too long, or
+///   `MAX_RESERVED_PREFIXES` prefixes are already reserved.
+///
+/// Storage used:
+/// - DataKey::ReservedTitlePrefixes -> Vec<String>
+pub fn reserve_title_prefix(env: &Env, caller: Address, prefix: String) -> Vec<String> {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let prefix: String = normalize_title(env, &prefix);
+    if prefix.is_empty() || prefix.len() > MAX_TERM_LEN {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut prefixes: Vec<String> = get_reserved_prefixes(env);
+    if prefixes.contains(&prefix) {
+        return prefixes;
+    }
+    if prefixes.len() >= MAX_RESERVED_PREFIXES {
+        handle_error(env, Error::InvalidInput)
+    }
+    prefixes.push_back(prefix.clone());
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReservedTitlePrefixes, &prefixes);
+
+    env.events().publish((RESERVE_PREFIX_EVENT,), prefix);
+
+    prefixes
+}
+
+/// Releases a reserved title prefix (admin-only).
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the prefix is not reserved.
+pub fn release_title_prefix(env: &Env, caller: Address, prefix: String) -> Vec<String> {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let prefix: String = normalize_title(env, &prefix);
+    let mut prefixes: Vec<String> = get_reserved_prefixes(env);
+    let index = match prefixes.first_index_of(&prefix) {
+        Some(index) => index,
+        None => handle_error(env, Error::InvalidInput),
+    };
+    prefixes.remove(index);
+    if prefixes.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReservedTitlePrefixes);
+    } else {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReservedTitlePrefixes, &prefixes);
+    }
+
+    env.events().publish((RELEASE_PREFIX_EVENT,), prefix);
+
+    prefixes
+}
+
+/// Returns the reserved title prefixes, in the order they were reserved.
+pub fn get_reserved_prefixes(env: &Env) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReservedTitlePrefixes)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds a creator to, or removes them from, the official creator allow-list
+/// (admin-only).
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+///
+/// Storage used:
+/// - DataKey::OfficialCreator(creator) -> bool
+pub fn set_official_creator(env: &Env, caller: Address, creator: Address, allowed: bool) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    let key = DataKey::OfficialCreator(creator.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+
+    env.events().publish((SET_OFFICIAL_EVENT, creator), allowed);
+}
+
+/// Whether a creator may use reserved title prefixes.
+pub fn is_official_creator(env: &Env, creator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::OfficialCreator(creator.clone()))
+}
+
+/// Rejects a title containing a blocked term, or starting with a reserved
+/// prefix when the creator is not on the official allow-list.
+///
+/// The title is normalized before matching, and a blocked term only matches
+/// whole words: "ass" blocks "Ass Kicking Rust" but not "Classes in Rust".
+///
+/// Errors:
+/// - Returns `CatalogError::TitleBlocked` if the title contains a blocked term.
+/// - Returns `CatalogError::TitleReserved` if the title uses a reserved prefix.
+pub fn validate_title_allowed(env: &Env, title: &String, creator: &Address) {
+    let terms: Vec<String> = get_blocked_terms(env);
+    let prefixes: Vec<String> = get_reserved_prefixes(env);
+    if terms.is_empty() && prefixes.is_empty() {
+        return;
+    }
+
+    let normalized = normalize_title(env, title);
+    let len = normalized.len() as usize;
+    let mut buffer = [0u8; 1024];
+    normalized.copy_into_slice(&mut buffer[..len]);
+    let title_bytes = &buffer[..len];
+    let mut term_buffer = [0u8; MAX_TERM_LEN as usize];
+
+    for term in terms.iter() {
+        let term_len = term.len() as usize;
+        term.copy_into_slice(&mut term_buffer[..term_len]);
+        if contains_word(title_bytes, &term_buffer[..term_len]) {
+            handle_error(env, CatalogError::TitleBlocked)
+        }
+    }
+
+    if prefixes.is_empty() || is_official_creator(env, creator) {
+        return;
+    }
+    for prefix in prefixes.iter() {
+        let prefix_len = prefix.len() as usize;
+        prefix.copy_into_slice(&mut term_buffer[..prefix_len]);
+        if title_bytes.starts_with(&term_buffer[..prefix_len]) {
+            handle_error(env, CatalogError::TitleReserved)
+        }
+    }
+}
+
+/// Whether `needle` occurs in `haystack` bounded by non-word characters.
+fn contains_word(haystack: &[u8], needle: &[u8]) -> bool {
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return false;
+    }
+    for start in 0..=haystack.len() - needle.len() {
+        let end = start + needle.len();
+        if &haystack[start..end] == needle
+            && (start == 0 || !is_word_byte(haystack[start - 1]))
+            && (end == haystack.len() || !is_word_byte(haystack[end]))
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// Letters, digits and any byte of a multi-byte UTF-8 character.
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte >= 0x80
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry;
+    use crate::schema::EditCourseParams;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_course<'a>(
+        env: &Env,
+        client: &CourseRegistryClient<'a>,
+        title: &str,
+    ) -> crate::schema::Course {
+        client.create_course(
+            &Address::generate(env),
+            &String::from_str(env, title),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_blocklist_matches_whole_words() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.add_blocked_term(&admin, &String::from_str(&env, "Free  Money"));
+        assert_eq!(
+            client.get_blocked_terms().get(0).unwrap(),
+            String::from_str(&env, "free money")
+        );
+
+        create_course(&env, &client, "Free Moneyball Analytics");
+        let course = create_course(&env, &client, "Budgeting");
+
+        let params = EditCourseParams {
+            new_title: Some(String::from_str(&env, "Get FREE money now")),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        assert!(client
+            .try_edit_course(&course.creator, &course.id, &params)
+            .is_err());
+
+        client.remove_blocked_term(&admin, &String::from_str(&env, "free money"));
+        client.edit_course(&course.creator, &course.id, &params);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #610)")]
+    fn test_create_course_with_blocked_title() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.add_blocked_term(&admin, &String::from_str(&env, "scam"));
+
+        create_course(&env, &client, "Crypto  SCAM  Masterclass");
+    }
+
+    #[test]
+    fn test_reserved_prefix_for_official_creators() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let official = Address::generate(&env);
+        client.reserve_title_prefix(&admin, &String::from_str(&env, "SkillCert Official:"));
+        client.set_official_creator(&admin, &official, &true);
+
+        let title = String::from_str(&env, "skillcert  official: Soroban 101");
+        let result = client.try_create_course(
+            &Address::generate(&env),
+            &title,
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert!(result.is_err());
+
+        let course = client.create_course(
+            &official,
+            &title,
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(course.creator, official);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #611)")]
+    fn test_edit_title_into_reserved_prefix() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.reserve_title_prefix(&admin, &String::from_str(&env, "Official"));
+        let course = create_course(&env, &client, "Rust Basics");
+
+        let params = EditCourseParams {
+            new_title: Some(String::from_str(&env, "Official Rust Basics")),
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: None,
+            new_duration_hours: None,
+            new_is_free: None,
+        };
+        client.edit_course(&course.creator, &course.id, &params);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_add_blocked_term_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+
+        client.add_blocked_term(&Address::generate(&env), &String::from_str(&env, "scam"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_remove_blocked_term_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.add_blocked_term(&admin, &String::from_str(&env, "scam"));
+
+        client.remove_blocked_term(&Address::generate(&env), &String::from_str(&env, "scam"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_reserve_title_prefix_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+
+        client.reserve_title_prefix(
+            &Address::generate(&env),
+            &String::from_str(&env, "Official"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_release_title_prefix_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        client.reserve_title_prefix(&admin, &String::from_str(&env, "Official"));
+
+        client.release_title_prefix(
+            &Address::generate(&env),
+            &String::from_str(&env, "Official"),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_official_creator_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin) = setup_registry(&env);
+        let creator = Address::generate(&env);
+
+        client.set_official_creator(&creator, &creator, &true);
+    }
+}
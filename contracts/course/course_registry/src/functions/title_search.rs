@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::utils::normalize_title;
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility, DataKey, MAX_PAGE_SIZE};
+use soroban_sdk::{symbol_short, Env, String, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Number of leading characters of a normalized title that are indexed.
+const TITLE_PREFIX_LEN: u32 = 3;
+
+/// Records a course under the first one to `TITLE_PREFIX_LEN` characters of
+/// its normalized title.
+///
+/// Storage used:
+/// - DataKey::TitlePrefix(prefix) -> Vec<u128>
+pub fn index_title_prefixes(env: &Env, course_id: u128, title: &String) {
+    let normalized = normalize_title(env, title);
+    for chars in 1..=TITLE_PREFIX_LEN {
+        if let Some(prefix) = char_prefix(env, &normalized, chars) {
+            let key = DataKey::TitlePrefix(prefix);
+            let mut ids: Vec<u128> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+            ids.push_back(course_id);
+            env.storage().persistent().set(&key, &ids);
+        }
+    }
+}
+
+/// Removes a course from the prefix index of its title.
+pub fn unindex_title_prefixes(env: &Env, course_id: u128, title: &String) {
+    let normalized = normalize_title(env, title);
+    for chars in 1..=TITLE_PREFIX_LEN {
+        if let Some(prefix) = char_prefix(env, &normalized, chars) {
+            let key = DataKey::TitlePrefix(prefix);
+            let mut ids: Vec<u128> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+            if let Some(i) = ids.first_index_of(course_id) {
+                ids.remove(i);
+                if ids.is_empty() {
+                    env.storage().persistent().remove(&key);
+                } else {
+                    env.storage().persistent().set(&key, &ids);
+                }
+            }
+        }
+    }
+}
+
+/// Lists published, public courses whose title starts with `prefix`.
+///
+/// The prefix is normalized like titles are (trimmed, lowercased,
+/// single-spaced). Only the courses sharing its first `TITLE_PREFIX_LEN`
+/// characters are read, then filtered on the full prefix.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - prefix: the start of the title, at least one character.
+/// - offset: number of matching courses to skip.
+/// - limit: maximum number of courses to return, from 1 to `MAX_PAGE_SIZE`.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if the prefix is blank, or `limit` is 0 or
+///   exceeds `MAX_PAGE_SIZE`.
+pub fn search_courses_by_prefix(env: &Env, prefix: String, offset: u32, limit: u32) -> Vec<Course> {
+    let prefix = normalize_title(env, &prefix);
+    if prefix.is_empty() || limit == 0 || limit > MAX_PAGE_SIZE {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let lookup = match char_prefix(env, &prefix, TITLE_PREFIX_LEN) {
+        Some(lookup) => lookup,
+        None => prefix.clone(),
+    };
+    let ids: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::TitlePrefix(lookup))
+        .unwrap_or(Vec::new(env));
+
+    let mut results: Vec<Course> = Vec::new(env);
+    let mut matched: u32 = 0;
+    for id in ids.iter() {
+        if results.len() >= limit {
+            break;
+        }
+
+        let course: Course = match env.storage().persistent().get(&(COURSE_KEY, id)) {
+            Some(course) => course,
+            None => continue,
+        };
+        if course.status != CourseStatus::Published
+            || course.visibility != CourseVisibility::Public
+            || !starts_with(&normalize_title(env, &course.title), &prefix)
+        {
+            continue;
+        }
+
+        if matched >= offset {
+            results.push_back(course);
+        }
+        matched += 1;
+    }
+    results
+}
+
+/// Returns the first `chars` characters of a string, or `None` if it is shorter.
+fn char_prefix(env: &Env, s: &String, chars: u32) -> Option<String> {
+    let len = s.len() as usize;
+    let mut buffer = [0u8; 3072];
+    s.copy_into_slice(&mut buffer[..len]);
+
+    let mut seen: u32 = 0;
+    for (i, byte) in buffer[..len].iter().enumerate() {
+        // UTF-8 continuation bytes look like 0b10xxxxxx
+        if byte & 0xC0 != 0x80 {
+            if seen == chars {
+                return Some(String::from_bytes(env, &buffer[..i]));
+            }
+            seen += 1;
+        }
+    }
+    if seen == chars {
+        Some(s.clone())
+    } else {
+        None
+    }
+}
+
+fn starts_with(s: &String, prefix: &String) -> bool {
+    let (len, prefix_len) = (s.len() as usize, prefix.len() as usize);
+    if prefix_len > len {
+        return false;
+    }
+    let mut buffer = [0u8; 3072];
+    let mut prefix_buffer = [0u8; 3072];
+    s.copy_into_slice(&mut buffer[..len]);
+    prefix.copy_into_slice(&mut prefix_buffer[..prefix_len]);
+    buffer[..prefix_len] == prefix_buffer[..prefix_len]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::{Course, EditCourseParams};
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+    fn create_published_course<'a>(client: &CourseRegistryClient<'a>, creator: &Address, title: &str) -> Course {
+        let course = client.create_course(
+            creator,
+            &String::from_str(&client.env, title),
+            &String::from_str(&client.env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(creator, &course.id, &edit_params(Some(true), None))
+    }
+
+    fn edit_params(published: Option<bool>, title: Option<String>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: title,
+            new_description: None,
+            new_price: None,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    #[test]
+    fn test_search_courses_by_prefix() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let rust = create_published_course(&client, &creator, "Rust Basics");
+        create_published_course(&client, &creator, "Ruby on Rails");
+        create_published_course(&client, &creator, "Go");
+
+        assert_eq!(client.search_courses_by_prefix(&String::from_str(&env, "ru"), &0, &10).len(), 2);
+        let found = client.search_courses_by_prefix(&String::from_str(&env, "RUST b"), &0, &10);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found.get(0).unwrap().id, rust.id);
+        assert_eq!(client.search_courses_by_prefix(&String::from_str(&env, "go"), &0, &10).len(), 1);
+        assert!(client
+            .search_courses_by_prefix(&String::from_str(&env, "gopher"), &0, &10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_search_courses_by_prefix_follows_title_edits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+
+        let course = create_published_course(&client, &creator, "Rust Basics");
+        client.edit_course(
+            &creator,
+            &course.id,
+            &edit_params(None, Some(String::from_str(&env, "Soroban Basics"))),
+        );
+
+        assert!(client
+            .search_courses_by_prefix(&String::from_str(&env, "rust"), &0, &10)
+            .is_empty());
+        assert_eq!(client.search_courses_by_prefix(&String::from_str(&env, "soro"), &0, &10).len(), 1);
+    }
+}
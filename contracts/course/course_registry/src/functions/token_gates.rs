@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{EnrollmentKey, TokenGate};
+use soroban_sdk::{symbol_short, token, Address, Env, IntoVal, Symbol};
+
+const SET_TOKEN_GATE_EVENT: Symbol = symbol_short!("tokenGate");
+
+/// Sets what students must hold to enroll in a course, or removes the gate.
+///
+/// The gate is checked with a call to the token or NFT contract whenever a
+/// student enrolls. Students already enrolled keep their access if they later
+/// sell the token, and the creator can still grant access to anyone.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - gate: a minimum token balance or a specific NFT, or `None` to remove it.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the minimum balance is not positive.
+///
+/// Storage used:
+/// - EnrollmentKey::TokenGate(course_id) -> TokenGate
+pub fn set_token_gate(env: &Env, caller: Address, course_id: u128, gate: Option<TokenGate>) {
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::TokenGate(course_id);
+    match &gate {
+        Some(gate) => {
+            if let TokenGate::Balance(_, min_balance) = gate {
+                if *min_balance <= 0 {
+                    handle_error(env, Error::InvalidInput)
+                }
+            }
+            env.storage().persistent().set(&key, gate);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_TOKEN_GATE_EVENT, course_id), gate);
+}
+
+/// Returns the token gate of a course, if any.
+pub fn get_token_gate(env: &Env, course_id: u128) -> Option<TokenGate> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::TokenGate(course_id))
+}
+
+/// Fails with `EnrollmentError::TokenGateNotMet` if a course is token-gated
+/// and the student does not hold the required balance or NFT.
+pub fn require_token_gate(env: &Env, course_id: u128, student: &Address) {
+    let holds: bool = match get_token_gate(env, course_id) {
+        Some(TokenGate::Balance(token, min_balance)) => {
+            token::Client::new(env, &token).balance(student) >= min_balance
+        }
+        Some(TokenGate::Nft(collection, token_id)) => {
+            let owner: Address = env.invoke_contract(
+                &collection,
+                &Symbol::new(env, "owner_of"),
+                (token_id,).into_val(env),
+            );
+            owner == *student
+        }
+        None => true,
+    };
+    if !holds {
+        handle_error(env, EnrollmentError::TokenGateNotMet)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_free_params;
+    use crate::schema::TokenGate;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{
+        contract, contractimpl, symbol_short, testutils::Address as _, token, Address, Env, String,
+    };
+
+    #[contract]
+    pub struct Nft;
+
+    #[contractimpl]
+    impl Nft {
+        pub fn mint(env: Env, to: Address, token_id: u32) {
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("owner"), token_id), &to);
+        }
+
+        pub fn owner_of(env: Env, token_id: u32) -> Address {
+            env.storage()
+                .persistent()
+                .get(&(symbol_short!("owner"), token_id))
+                .unwrap()
+        }
+    }
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_free_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_balance_gate() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let gate = TokenGate::Balance(token.clone(), 100);
+        client.set_token_gate(&creator, &course_id, &Some(gate.clone()));
+        assert_eq!(client.get_token_gate(&course_id), Some(gate));
+
+        let holder = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&holder, &100);
+        token::StellarAssetClient::new(&env, &token).mint(&outsider, &99);
+
+        client.enroll(&holder, &course_id, &None, &None, &None, &None);
+        assert!(client
+            .try_enroll(&outsider, &course_id, &None, &None, &None, &None)
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #519)")]
+    fn test_nft_gate() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let nft_id = env.register(Nft, ());
+        let nft = NftClient::new(&env, &nft_id);
+        let holder = Address::generate(&env);
+        nft.mint(&holder, &7);
+        client.set_token_gate(&creator, &course_id, &Some(TokenGate::Nft(nft_id, 7)));
+
+        client.enroll(&holder, &course_id, &None, &None, &None, &None);
+        client.enroll(
+            &Address::generate(&env),
+            &course_id,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+    }
+}
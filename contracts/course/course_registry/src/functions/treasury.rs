@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::is_admin;
+use super::payments::{get_platform_fee, to_token_amount};
+use crate::error::{handle_error, Error, PaymentError};
+use crate::schema::{PaymentKey, PlatformFee, TreasuryAccount};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol, Vec};
+
+const TREASURY_INCOME_EVENT: Symbol = symbol_short!("tsyIncome");
+const TREASURY_WITHDRAW_EVENT: Symbol = symbol_short!("tsyWithdr");
+
+/// Adds platform revenue in a token to the treasury.
+///
+/// The funds are held by the contract until an admin withdraws them.
+///
+/// Storage used:
+/// - PaymentKey::Treasury(token) -> TreasuryAccount
+/// - PaymentKey::TreasuryTokens -> Vec<Address>
+pub fn credit_treasury(env: &Env, token: &Address, amount: u128) {
+    if amount == 0 {
+        return;
+    }
+    let key = PaymentKey::Treasury(token.clone());
+    let mut account: TreasuryAccount = match env.storage().persistent().get(&key) {
+        Some(account) => account,
+        None => {
+            let mut tokens: Vec<Address> = get_treasury_tokens(env);
+            tokens.push_back(token.clone());
+            env.storage()
+                .persistent()
+                .set(&PaymentKey::TreasuryTokens, &tokens);
+            TreasuryAccount {
+                income: 0,
+                withdrawn: 0,
+            }
+        }
+    };
+    account.income += amount;
+    env.storage().persistent().set(&key, &account);
+
+    env.events()
+        .publish((TREASURY_INCOME_EVENT, token.clone()), amount);
+}
+
+/// Sends treasury funds in a token to the treasury address (admin-only).
+///
+/// The treasury address is the one set with the platform fee.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: transaction caller (must be admin).
+/// - token: the token to withdraw.
+/// - amount: the amount to withdraw, up to the treasury balance.
+///
+/// Returns:
+/// - TreasuryAccount: the updated account of the token.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+/// - Returns `Error::InvalidInput` if the amount is 0 or no treasury address
+///   is set.
+/// - Returns `PaymentError::NothingToWithdraw` if the amount exceeds the
+///   treasury balance.
+pub fn withdraw_treasury(
+    env: &Env,
+    caller: Address,
+    token: Address,
+    amount: u128,
+) -> TreasuryAccount {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+    let fee: PlatformFee = match get_platform_fee(env) {
+        Some(fee) => fee,
+        None => handle_error(env, Error::InvalidInput),
+    };
+    if amount == 0 {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let mut account: TreasuryAccount = get_treasury_account(env, token.clone());
+    if amount > account.income - account.withdrawn {
+        handle_error(env, PaymentError::NothingToWithdraw)
+    }
+    account.withdrawn += amount;
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::Treasury(token.clone()), &account);
+
+    token::Client::new(env, &token).transfer(
+        &env.current_contract_address(),
+        &fee.treasury,
+        &to_token_amount(env, amount),
+    );
+
+    env.events()
+        .publish((TREASURY_WITHDRAW_EVENT, token), (fee.treasury, amount));
+
+    account
+}
+
+/// Returns the platform income and withdrawals in a token.
+///
+/// The balance still held is `income - withdrawn`.
+pub fn get_treasury_account(env: &Env, token: Address) -> TreasuryAccount {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::Treasury(token))
+        .unwrap_or(TreasuryAccount {
+            income: 0,
+            withdrawn: 0,
+        })
+}
+
+/// Returns every token the treasury has received income in.
+pub fn get_treasury_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::TreasuryTokens)
+        .unwrap_or(Vec::new(env))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry};
+    use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+    #[test]
+    fn test_fees_accrue_and_withdraw() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let treasury = Address::generate(&env);
+        client.set_payment_token(&admin, &token);
+        client.set_platform_fee(&admin, &1_000, &treasury);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        for _ in 0..2 {
+            let student = Address::generate(&env);
+            token::StellarAssetClient::new(&env, &token).mint(&student, &1000);
+            client.enroll(
+                &student,
+                &course.id,
+                &Some(token.clone()),
+                &None,
+                &None,
+                &None,
+            );
+        }
+
+        assert_eq!(client.get_treasury_account(&token).income, 200);
+        assert_eq!(client.get_treasury_tokens().len(), 1);
+        assert!(client.try_withdraw_treasury(&admin, &token, &201).is_err());
+
+        let account = client.withdraw_treasury(&admin, &token, &150);
+        assert_eq!(account.withdrawn, 150);
+        assert_eq!(token::Client::new(&env, &token).balance(&treasury), 150);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_withdraw_treasury_by_non_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_registry(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let treasury = Address::generate(&env);
+        client.set_platform_fee(&admin, &1_000, &treasury);
+
+        client.withdraw_treasury(&treasury, &token, &100);
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use crate::error::{handle_error, Error};
+use crate::schema::{Course, CourseStatus, CourseVisibility, DataKey};
+use soroban_sdk::{symbol_short, Env, Map, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+/// Length of an enrollment bucket, in seconds.
+const SECONDS_PER_DAY: u64 = 86_400;
+/// Longest trending window accepted, in days.
+const MAX_TRENDING_WINDOW_DAYS: u32 = 30;
+/// Number of courses returned by `get_trending_courses`.
+const TRENDING_COURSES: u32 = 10;
+
+/// Counts an enrollment in the bucket of the current day.
+///
+/// Storage used:
+/// - DataKey::DailyEnrollments(day) -> Map<u128, u32>
+pub fn record_daily_enrollment(env: &Env, course_id: u128) {
+    let key = DataKey::DailyEnrollments(env.ledger().timestamp() / SECONDS_PER_DAY);
+    let mut counts: Map<u128, u32> = env.storage().persistent().get(&key).unwrap_or(Map::new(env));
+    counts.set(course_id, counts.get(course_id).unwrap_or(0) + 1);
+    env.storage().persistent().set(&key, &counts);
+}
+
+/// Returns the published, public courses with the most enrollments over the
+/// last `window` days, today included, most enrollments first.
+///
+/// At most `TRENDING_COURSES` courses are returned.
+///
+/// Errors:
+/// - Returns `Error::InvalidInput` if `window` is 0 or longer than 30 days.
+pub fn get_trending_courses(env: &Env, window: u32) -> Vec<Course> {
+    if window == 0 || window > MAX_TRENDING_WINDOW_DAYS {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let today: u64 = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let mut totals: Map<u128, u32> = Map::new(env);
+    let mut order: Vec<u128> = Vec::new(env);
+    for days_ago in 0..window as u64 {
+        if days_ago > today {
+            break;
+        }
+        let counts: Option<Map<u128, u32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DailyEnrollments(today - days_ago));
+        if let Some(counts) = counts {
+            for (course_id, count) in counts.iter() {
+                match totals.get(course_id) {
+                    Some(total) => totals.set(course_id, total + count),
+                    None => {
+                        totals.set(course_id, count);
+                        order.push_back(course_id);
+                    }
+                }
+            }
+        }
+    }
+
+    // Insertion sort of the listed courses by total, keeping the top entries
+    let mut ranked: Vec<u128> = Vec::new(env);
+    for course_id in order.iter() {
+        let course: Option<Course> = env.storage().persistent().get(&(COURSE_KEY, course_id));
+        match course {
+            Some(course)
+                if course.status == CourseStatus::Published
+                    && course.visibility == CourseVisibility::Public => {}
+            _ => continue,
+        }
+
+        let total = totals.get(course_id).unwrap_or(0);
+        let mut i = ranked.len();
+        while i > 0 && totals.get(ranked.get_unchecked(i - 1)).unwrap_or(0) < total {
+            i -= 1;
+        }
+        if i < TRENDING_COURSES {
+            ranked.insert(i, course_id);
+            if ranked.len() > TRENDING_COURSES {
+                ranked.pop_back();
+            }
+        }
+    }
+
+    let mut results: Vec<Course> = Vec::new(env);
+    for course_id in ranked.iter() {
+        results.push_back(env.storage().persistent().get(&(COURSE_KEY, course_id)).unwrap());
+    }
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::create_free_course;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env};
+
+    #[test]
+    fn test_get_trending_courses_uses_recent_enrollments() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+        let creator = Address::generate(&env);
+        let classic = create_free_course(&client, &creator, "Classic");
+        let rising = create_free_course(&client, &creator, "Rising");
+
+        // Ten days ago the classic course was the popular one
+        env.ledger().set_timestamp(86_400 * 100);
+        for _ in 0..3 {
+            client.enroll(&Address::generate(&env), &classic.id, &None, &None, &None, &None);
+        }
+
+        env.ledger().set_timestamp(86_400 * 110);
+        client.enroll(&Address::generate(&env), &classic.id, &None, &None, &None, &None);
+        client.enroll(&Address::generate(&env), &rising.id, &None, &None, &None, &None);
+        client.enroll(&Address::generate(&env), &rising.id, &None, &None, &None, &None);
+
+        let week = client.get_trending_courses(&7);
+        assert_eq!(week.len(), 2);
+        assert_eq!(week.get(0).unwrap().id, rising.id);
+
+        let month = client.get_trending_courses(&30);
+        assert_eq!(month.get(0).unwrap().id, classic.id);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #29)")]
+    fn test_get_trending_courses_window_too_long() {
+        let env = Env::default();
+        let contract_id = env.register(CourseRegistry, {});
+        let client = CourseRegistryClient::new(&env, &contract_id);
+
+        client.get_trending_courses(&31);
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_log::append_access_log;
+use super::cohorts::leave_cohort;
+use super::course_capacity::free_seat;
+use super::enrollment::get_enrollment;
+use super::escrow::refund_escrowed_payment;
+use crate::error::{handle_error, EnrollmentError};
+use crate::schema::{
+    AccessChange, AccessLogEntry, DataKey, Enrollment, EnrollmentStatus, Payment, PaymentKey,
+    PaymentStatus,
+};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const UNENROLL_EVENT: Symbol = symbol_short!("unenroll");
+
+/// Lets a student leave a course.
+///
+/// While the refund window of the student's payment is open, leaving
+/// refunds the payment through the escrow and removes the enrollment, as
+/// `escrow::request_refund` does. Otherwise the enrollment is kept, marked
+/// `EnrollmentStatus::Withdrawn`, and the student's seat and cohort place are
+/// freed. A student who left may enroll again.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolled student (must authorize).
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - bool: whether the payment was refunded.
+///
+/// Errors:
+/// - Returns `EnrollmentError::NotEnrolled` if the student has no active
+///   enrollment in the course.
+pub fn unenroll(env: &Env, student: Address, course_id: u128) -> bool {
+    student.require_auth();
+
+    let mut enrollment: Enrollment = match get_enrollment(env, course_id, student.clone()) {
+        Some(enrollment) if enrollment.status == EnrollmentStatus::Active => enrollment,
+        _ => handle_error(env, EnrollmentError::NotEnrolled),
+    };
+    let now: u64 = env.ledger().timestamp();
+    append_access_log(
+        env,
+        course_id,
+        AccessLogEntry {
+            student: student.clone(),
+            change: AccessChange::Withdrawn,
+            actor: Some(student.clone()),
+            reason: None,
+            at: now,
+        },
+    );
+
+    let payment: Option<Payment> = env
+        .storage()
+        .persistent()
+        .get(&PaymentKey::Payment(course_id, student.clone()));
+    let refunded: bool = match payment {
+        Some(payment)
+            if payment.status == PaymentStatus::Escrowed && now < payment.escrow_until =>
+        {
+            refund_escrowed_payment(env, student.clone(), payment);
+            true
+        }
+        _ => {
+            enrollment.status = EnrollmentStatus::Withdrawn;
+            free_seat(env, course_id);
+            leave_cohort(env, course_id, &student);
+            env.storage().persistent().set(
+                &DataKey::Enrollment(course_id, student.clone()),
+                &enrollment,
+            );
+            false
+        }
+    };
+
+    env.events()
+        .publish((UNENROLL_EVENT, course_id), (student, refunded));
+
+    refunded
+}
+
+#[cfg(test)]
+mod test {
+    use crate::schema::EnrollmentStatus;
+
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    #[test]
+    fn test_unenroll_with_and_without_refund() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        client.set_refund_window(&admin, &(14 * DAY));
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        client.set_max_students(&creator, &course.id, &Some(2));
+
+        let early = Address::generate(&env);
+        let late = Address::generate(&env);
+        let minter = token::StellarAssetClient::new(&env, &token);
+        minter.mint(&early, &1000);
+        minter.mint(&late, &1000);
+        client.enroll(
+            &early,
+            &course.id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        client.enroll(&late, &course.id, &Some(token.clone()), &None, &None, &None);
+
+        assert!(client.unenroll(&early, &course.id));
+        assert_eq!(token::Client::new(&env, &token).balance(&early), 1000);
+        assert_eq!(client.get_enrollment(&course.id, &early), None);
+
+        env.ledger().set_timestamp(20 * DAY);
+        assert!(!client.unenroll(&late, &course.id));
+        assert_eq!(
+            client.get_enrollment(&course.id, &late).unwrap().status,
+            EnrollmentStatus::Withdrawn
+        );
+        assert!(!client.is_enrolled(&late, &course.id));
+        assert_eq!(client.get_course(&course.id).seats_remaining, Some(2));
+        assert!(client.try_unenroll(&late, &course.id).is_err());
+    }
+}
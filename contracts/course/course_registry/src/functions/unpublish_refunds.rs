@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::enrollment::remove_enrollment;
+use super::enrollment_events::publish_transition;
+use super::payments::to_token_amount;
+use super::refund_requests::{close_refund_request, refund_from_earnings};
+use crate::error::{handle_error, Error, PaymentError};
+use crate::schema::{
+    Course, CourseStatus, EnrollmentTransition, Payment, PaymentKey, PaymentStatus,
+    UNPUBLISH_REFUND_PERIOD,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const UNPUBLISH_REFUND_EVENT: Symbol = symbol_short!("unpubRfnd");
+
+/// Records when a published course is unpublished or archived.
+///
+/// Storage used:
+/// - PaymentKey::UnpublishedAt(course_id) -> u64
+pub fn record_unpublished(env: &Env, course_id: u128) {
+    env.storage().persistent().set(
+        &PaymentKey::UnpublishedAt(course_id),
+        &env.ledger().timestamp(),
+    );
+}
+
+/// Whether a student may claim a refund because the course was taken down.
+///
+/// A payment is refundable when the course is no longer published and was
+/// unpublished or archived within `UNPUBLISH_REFUND_PERIOD` of the payment,
+/// and the payment has not been refunded yet.
+pub fn is_unpublish_refund_eligible(env: &Env, course_id: u128, student: Address) -> bool {
+    let payment: Payment = match env
+        .storage()
+        .persistent()
+        .get(&PaymentKey::Payment(course_id, student))
+    {
+        Some(payment) => payment,
+        None => return false,
+    };
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => return false,
+    };
+    let unpublished_at: u64 = match env
+        .storage()
+        .persistent()
+        .get(&PaymentKey::UnpublishedAt(course_id))
+    {
+        Some(unpublished_at) => unpublished_at,
+        None => return false,
+    };
+    course.status != CourseStatus::Published
+        && payment.status != PaymentStatus::Refunded
+        && unpublished_at >= payment.paid_at
+        && unpublished_at - payment.paid_at <= UNPUBLISH_REFUND_PERIOD
+}
+
+/// Refunds a student whose course was unpublished shortly after they paid.
+///
+/// An escrowed payment is returned in full. A released one returns the
+/// creator's share out of the instructor's unwithdrawn earnings, as for an
+/// approved refund request. The enrollment is cancelled, and a pending
+/// refund request of the student for the course is closed.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the enrolled student (must authorize).
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - Payment: the refunded payment record.
+///
+/// Errors:
+/// - Returns `PaymentError::PaymentNotFound` if the student did not pay for the course.
+/// - Returns `Error::CourseNotFound` if the course was deleted.
+/// - Returns `PaymentError::NotRefundable` if the payment is not eligible.
+/// - Returns `PaymentError::InsufficientEarnings` if a released payment cannot
+///   be covered by the instructor's earnings.
+pub fn claim_unpublish_refund(env: &Env, student: Address, course_id: u128) -> Payment {
+    student.require_auth();
+
+    let key = PaymentKey::Payment(course_id, student.clone());
+    let mut payment: Payment = match env.storage().persistent().get(&key) {
+        Some(payment) => payment,
+        None => handle_error(env, PaymentError::PaymentNotFound),
+    };
+    if !env.storage().persistent().has(&(COURSE_KEY, course_id)) {
+        handle_error(env, Error::CourseNotFound)
+    }
+    if !is_unpublish_refund_eligible(env, course_id, student.clone()) {
+        handle_error(env, PaymentError::NotRefundable)
+    }
+
+    let refunded: u128 = if payment.status == PaymentStatus::Escrowed {
+        token::Client::new(env, &payment.token).transfer(
+            &env.current_contract_address(),
+            &payment.payer,
+            &to_token_amount(env, payment.amount),
+        );
+        payment.amount
+    } else {
+        refund_from_earnings(env, &payment);
+        payment.creator_amount
+    };
+    payment.status = PaymentStatus::Refunded;
+    env.storage().persistent().set(&key, &payment);
+    close_refund_request(env, course_id, &student);
+    remove_enrollment(env, course_id, &student);
+    publish_transition(
+        env,
+        course_id,
+        &student,
+        EnrollmentTransition::Refunded(refunded),
+    );
+
+    env.events()
+        .publish((UNPUBLISH_REFUND_EVENT, course_id), (student, refunded));
+
+    payment
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use crate::schema::{Course, PaymentStatus};
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    const DAY: u64 = 86_400;
+
+    fn setup<'a>(env: &Env, refund_window: u64) -> (CourseRegistryClient<'a>, Address, Course) {
+        let (client, admin, token) = setup_registry_with_token(env);
+        client.set_refund_window(&admin, &refund_window);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        let course = client.edit_course(&creator, &course.id, &publish_params());
+        (client, token, course)
+    }
+
+    fn enroll_paying<'a>(env: &Env, client: &CourseRegistryClient<'a>, token: &Address) -> Address {
+        let student = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&student, &1000);
+        client.enroll(&student, &1, &Some(token.clone()), &None, &None, &None);
+        student
+    }
+
+    #[test]
+    fn test_refund_after_archive() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env, 0);
+        let student = enroll_paying(&env, &client, &token);
+        assert!(!client.is_unpublish_refund_eligible(&course.id, &student));
+
+        env.ledger().set_timestamp(2 * DAY);
+        client.archive_course(&course.creator, &course.id);
+        assert!(client.is_unpublish_refund_eligible(&course.id, &student));
+
+        let payment = client.claim_unpublish_refund(&student, &course.id);
+        assert_eq!(payment.status, PaymentStatus::Refunded);
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 1000);
+        assert_eq!(client.get_earnings(&course.creator, &token), 0);
+        assert_eq!(client.get_enrollment(&course.id, &student), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #730)")]
+    fn test_no_refund_when_unpublished_long_after_payment() {
+        let env = Env::default();
+        let (client, token, course) = setup(&env, DAY);
+        let student = enroll_paying(&env, &client, &token);
+
+        env.ledger().set_timestamp(60 * DAY);
+        client.archive_course(&course.creator, &course.id);
+        client.claim_unpublish_refund(&student, &course.id);
+    }
+}
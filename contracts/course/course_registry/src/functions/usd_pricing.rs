@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::{is_admin, require_course_management_auth};
+use crate::error::{handle_error, Error, PaymentError};
+use crate::schema::{Course, PaymentKey};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_ORACLE_EVENT: Symbol = symbol_short!("setOracle");
+const SET_USD_PRICE_EVENT: Symbol = symbol_short!("setUsdPrc");
+
+/// Sets the price oracle used to convert USD prices to tokens (admin-only).
+///
+/// The oracle must expose `usd_price(token: Address) -> u128`, returning how
+/// many base units of the token are worth one US dollar.
+///
+/// Errors:
+/// - Returns `Error::Unauthorized` if the caller is not an admin.
+///
+/// Storage used:
+/// - PaymentKey::PriceOracle -> Address
+pub fn set_price_oracle(env: &Env, caller: Address, oracle: Address) {
+    caller.require_auth();
+    if !is_admin(env, &caller) {
+        handle_error(env, Error::Unauthorized)
+    }
+
+    env.storage()
+        .persistent()
+        .set(&PaymentKey::PriceOracle, &oracle);
+
+    env.events().publish((SET_ORACLE_EVENT,), oracle);
+}
+
+/// Returns the price oracle, if one is set.
+pub fn get_price_oracle(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&PaymentKey::PriceOracle)
+}
+
+/// Prices a course in US cents, or goes back to its token price.
+///
+/// While a USD price is set, `enroll` charges its value in the chosen token
+/// at the oracle rate of the moment, instead of the token price.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - usd_cents: the price in US cents, or `None` to remove it.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+/// - Returns `Error::InvalidInput` if the course is free.
+/// - Returns `Error::InvalidPrice` if the price is 0.
+///
+/// Storage used:
+/// - PaymentKey::UsdPrice(course_id) -> u128
+pub fn set_usd_price(env: &Env, caller: Address, course_id: u128, usd_cents: Option<u128>) {
+    let course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    };
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = PaymentKey::UsdPrice(course_id);
+    match usd_cents {
+        Some(_) if course.is_free => handle_error(env, Error::InvalidInput),
+        Some(0) => handle_error(env, Error::InvalidPrice),
+        Some(cents) => env.storage().persistent().set(&key, &cents),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_USD_PRICE_EVENT, course_id), usd_cents);
+}
+
+/// Returns the price of a course in US cents, if it is priced in USD.
+pub fn get_usd_price(env: &Env, course_id: u128) -> Option<u128> {
+    env.storage()
+        .persistent()
+        .get(&PaymentKey::UsdPrice(course_id))
+}
+
+/// Converts an amount of US cents to base units of a token, rounding up.
+///
+/// Errors:
+/// - Returns `PaymentError::OracleUnavailable` if no oracle is set or it has
+///   no rate for the token.
+/// - Returns `Error::InvalidInput` if the amount overflows.
+pub fn usd_to_token(env: &Env, token: &Address, usd_cents: u128) -> u128 {
+    let oracle: Address = match get_price_oracle(env) {
+        Some(oracle) => oracle,
+        None => handle_error(env, PaymentError::OracleUnavailable),
+    };
+    let units_per_usd: u128 = env.invoke_contract(
+        &oracle,
+        &Symbol::new(env, "usd_price"),
+        (token.clone(),).into_val(env),
+    );
+    if units_per_usd == 0 {
+        handle_error(env, PaymentError::OracleUnavailable)
+    }
+    match usd_cents.checked_mul(units_per_usd) {
+        Some(units) => units.div_ceil(100),
+        None => handle_error(env, Error::InvalidInput),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::{publish_params, setup_registry_with_token};
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env, String};
+
+    /// Prices every token at 10 000 000 base units per dollar.
+    #[contract]
+    pub struct PriceOracle;
+
+    #[contractimpl]
+    impl PriceOracle {
+        pub fn usd_price(_env: Env, _token: Address) -> u128 {
+            10_000_000
+        }
+    }
+
+    #[test]
+    fn test_enroll_converts_usd_price() {
+        let env = Env::default();
+        let (client, admin, token) = setup_registry_with_token(&env);
+        client.set_price_oracle(&admin, &env.register(PriceOracle, ()));
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        client.set_usd_price(&creator, &course.id, &Some(2999));
+
+        let student = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&student, &300_000_000);
+        client.enroll(&student, &course.id, &Some(token.clone()), &None, &None, &None);
+
+        assert_eq!(client.get_payment(&course.id, &student).unwrap().amount, 299_900_000);
+        assert_eq!(token::Client::new(&env, &token).balance(&student), 100_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #717)")]
+    fn test_usd_price_without_oracle() {
+        let env = Env::default();
+        let (client, _admin, token) = setup_registry_with_token(&env);
+
+        let creator = Address::generate(&env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(&env, "Course"),
+            &String::from_str(&env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        client.set_usd_price(&creator, &course.id, &Some(2999));
+
+        client.enroll(&Address::generate(&env), &course.id, &Some(token), &None, &None, &None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #6)")]
+    fn test_set_price_oracle_by_non_admin() {
+        let env = Env::default();
+        let (client, _admin, _token) = setup_registry_with_token(&env);
+
+        client.set_price_oracle(&Address::generate(&env), &env.register(PriceOracle, ()));
+    }
+}
@@ -2,7 +2,7 @@
 // Copyright (c) 2025 SkillCert
 
 pub use crate::schema::{Course, CourseModule};
-use soroban_sdk::{vec, Bytes, Env, String, Vec};
+use soroban_sdk::{vec, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
 
 pub fn generate_unique_id(env: &Env) -> String {
     let ts: u64 = env.ledger().timestamp();
@@ -74,6 +74,44 @@ pub fn u32_to_string(env: &Env, n: u32) -> String {
     String::from_bytes(env, &new_slice)
 }
 
+pub fn u128_to_string(env: &Env, n: u128) -> String {
+    // u128::MAX has 39 decimal digits
+    let mut buffer = [0u8; 39];
+    let mut start = buffer.len();
+    let mut num = n;
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (num % 10) as u8;
+        num /= 10;
+        if num == 0 {
+            break;
+        }
+    }
+    String::from_bytes(env, &buffer[start..])
+}
+
+/// Parses a decimal string into a `u128`, returning `None` if it is empty,
+/// contains non-digit characters or overflows.
+pub fn parse_u128(s: &String) -> Option<u128> {
+    let len = s.len() as usize;
+    if len == 0 || len > 39 {
+        return None;
+    }
+    let mut buffer = [0u8; 39];
+    s.copy_into_slice(&mut buffer[..len]);
+
+    let mut result: u128 = 0;
+    for byte in buffer[..len].iter() {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        result = result
+            .checked_mul(10)?
+            .checked_add((*byte - b'0') as u128)?;
+    }
+    Some(result)
+}
+
 pub fn trim(env: &Env, s: &String) -> String {
     // Create a fixed-size buffer for the string's bytes
     let len = s.len() as usize;
@@ -141,17 +179,89 @@ pub fn concat_strings(env: &Env, strings: Vec<String>) -> String {
     String::from_bytes(env, &new_slice)
 }
 
+/// Normalizes a title for duplicate detection.
+///
+/// Leading and trailing whitespace is dropped, interior whitespace runs are
+/// collapsed to a single space and every character is lowercased using the
+/// Unicode case mapping, so "Rust  Basics" and "rust basics" compare equal.
+/// Titles that are not valid UTF-8 fall back to ASCII-only lowercasing.
+pub fn normalize_title(env: &Env, title: &String) -> String {
+    let len = title.len() as usize;
+    let mut input = [0u8; 1024];
+    if len > input.len() {
+        panic!("String too long for fixed-size buffer");
+    }
+    title.copy_into_slice(&mut input[..len]);
+
+    // Lowercasing can grow a character to at most three times its UTF-8 size
+    let mut output = [0u8; 3072];
+    let mut out_len = 0;
+    let mut pending_space = false;
+
+    match core::str::from_utf8(&input[..len]) {
+        Ok(text) => {
+            for c in text.chars() {
+                if c.is_whitespace() {
+                    pending_space = out_len > 0;
+                    continue;
+                }
+                if pending_space {
+                    output[out_len] = b' ';
+                    out_len += 1;
+                    pending_space = false;
+                }
+                for lower in c.to_lowercase() {
+                    out_len += lower.encode_utf8(&mut output[out_len..]).len();
+                }
+            }
+        }
+        Err(_) => {
+            for byte in input[..len].iter() {
+                if byte.is_ascii_whitespace() {
+                    pending_space = out_len > 0;
+                    continue;
+                }
+                if pending_space {
+                    output[out_len] = b' ';
+                    out_len += 1;
+                    pending_space = false;
+                }
+                output[out_len] = byte.to_ascii_lowercase();
+                out_len += 1;
+            }
+        }
+    }
+
+    String::from_bytes(env, &output[..out_len])
+}
+
+/// Derives the deterministic course hash from the creator and normalized title.
+///
+/// The hash is `sha256(xdr(creator) || normalize_title(title))`, so off-chain
+/// systems can compute it without querying the contract.
+pub fn course_hash(env: &Env, creator: &Address, title: &String) -> BytesN<32> {
+    let normalized: String = normalize_title(env, title);
+    // A string's XDR is its type and length, 4 bytes each, then its bytes
+    let title_xdr: Bytes = normalized.clone().to_xdr(env);
+    let title_bytes: Bytes = title_xdr.slice(8..8 + normalized.len());
+
+    let mut data: Bytes = creator.clone().to_xdr(env);
+    data.append(&title_bytes);
+
+    env.crypto().sha256(&data).to_bytes()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::Course;
+    use crate::schema::{Course, CourseLevel, CourseStatus, CourseVisibility};
 
     use crate::CourseRegistry;
     use soroban_sdk::{testutils::Address as _, vec, Address, Env};
 
-    fn create_test_course(env: &Env, id: &str) -> Course {
+    fn create_test_course(env: &Env, id: u128) -> Course {
         Course {
-            id: String::from_str(env, id),
+            id,
             title: String::from_str(env, "Test Course"),
             description: String::from_str(env, "Test Description"),
             creator: Address::generate(env),
@@ -159,12 +269,24 @@ mod tests {
             category: None,
             language: None,
             thumbnail_url: None,
-            published: false,
+            status: CourseStatus::Draft,
             prerequisites: Vec::new(&env),
-            is_archived: false,
 
             duration_hours: Some(1),
-            level: Some(String::from_str(env, "entry")),
+            level: CourseLevel::Beginner,
+            version: 1,
+            created_at: 0,
+            updated_at: 0,
+            slug: None,
+            visibility: CourseVisibility::Public,
+            metadata_uri: None,
+            metadata_hash: None,
+            lesson_count: 0,
+            total_duration_minutes: 0,
+            variant_group: None,
+            is_free: false,
+            max_students: None,
+            seats_remaining: None,
         }
     }
 
@@ -173,7 +295,7 @@ mod tests {
         let env = Env::default();
         let _contract_id = env.register(CourseRegistry, {});
         let course_id = String::from_str(&env, "course_1");
-        let _course = create_test_course(&env, "course_1.");
+        let _course = create_test_course(&env, 1);
         let course_id2 = String::from_str(&env, "    course_1.  ");
 
         let counter = 5;
@@ -199,4 +321,45 @@ mod tests {
         assert!(!lowercase_result.is_empty());
         assert!(!trim_result.is_empty());
     }
+
+    #[test]
+    fn test_u128_string_round_trip() {
+        let env = Env::default();
+
+        assert_eq!(u128_to_string(&env, 0), String::from_str(&env, "0"));
+        assert_eq!(u128_to_string(&env, 42), String::from_str(&env, "42"));
+        assert_eq!(parse_u128(&u128_to_string(&env, u128::MAX)), Some(u128::MAX));
+        assert_eq!(parse_u128(&String::from_str(&env, "123")), Some(123));
+        assert_eq!(parse_u128(&String::from_str(&env, "")), None);
+        assert_eq!(parse_u128(&String::from_str(&env, "course-1")), None);
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        let env = Env::default();
+        let expected = String::from_str(&env, "rust basics");
+
+        assert_eq!(normalize_title(&env, &String::from_str(&env, "Rust Basics")), expected);
+        assert_eq!(normalize_title(&env, &String::from_str(&env, "  Rust \t Basics ")), expected);
+        assert_eq!(
+            normalize_title(&env, &String::from_str(&env, "ÉCOLE Rust")),
+            String::from_str(&env, "école rust")
+        );
+    }
+
+    #[test]
+    fn test_course_hash_of_long_title() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        // "İ" is 2 bytes and lowercases to the 3 bytes of "i̇"
+        let title: [u8; 1000] = core::array::from_fn(|i| [0xC4, 0xB0][i % 2]);
+        let normalized: [u8; 1500] = core::array::from_fn(|i| [0x69, 0xCC, 0x87][i % 3]);
+
+        let mut data: Bytes = creator.clone().to_xdr(&env);
+        data.extend_from_slice(&normalized);
+        assert_eq!(
+            course_hash(&env, &creator, &String::from_bytes(&env, &title)),
+            env.crypto().sha256(&data).to_bytes()
+        );
+    }
 }
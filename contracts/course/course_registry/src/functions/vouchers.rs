@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::access_control::require_course_management_auth;
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{Course, CourseStatus, Enrollment, EnrollmentKey, EnrollmentSource, Voucher};
+use soroban_sdk::{symbol_short, xdr::ToXdr, Address, BytesN, Env, Symbol};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const SET_VOUCHER_SIGNER_EVENT: Symbol = symbol_short!("vchSigner");
+const REDEEM_VOUCHER_EVENT: Symbol = symbol_short!("voucher");
+
+/// Sets the Ed25519 public key that signs vouchers for a course, or removes it.
+///
+/// Vouchers are signed off-chain, so a creator can hand them out, at a
+/// conference for instance, without sending a transaction per recipient.
+/// Removing the key stops all outstanding vouchers from being redeemed.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - caller: the course creator or an admin (must authorize).
+/// - course_id: unique identifier of the course.
+/// - signer: the public key, or `None` to stop accepting vouchers.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `Error::Unauthorized` if the caller may not manage the course.
+///
+/// Storage used:
+/// - EnrollmentKey::VoucherSigner(course_id) -> BytesN<32>
+pub fn set_voucher_signer(env: &Env, caller: Address, course_id: u128, signer: Option<BytesN<32>>) {
+    load_course(env, course_id);
+    require_course_management_auth(env, &caller, course_id);
+
+    let key = EnrollmentKey::VoucherSigner(course_id);
+    match &signer {
+        Some(signer) => env.storage().persistent().set(&key, signer),
+        None => env.storage().persistent().remove(&key),
+    }
+
+    env.events()
+        .publish((SET_VOUCHER_SIGNER_EVENT, course_id), signer);
+}
+
+/// Returns the public key that signs vouchers for a course, if any.
+pub fn get_voucher_signer(env: &Env, course_id: u128) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::VoucherSigner(course_id))
+}
+
+/// Enrolls the recipient of a signed voucher for free.
+///
+/// The signature must be the course's voucher signer's over the XDR of the
+/// `Voucher`, which names this contract. Each nonce can be redeemed once per course, so a voucher cannot
+/// be replayed, even by its recipient after unenrolling.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - recipient: the address the voucher was issued to (must authorize).
+/// - course_id: unique identifier of the course.
+/// - expires_at: ledger timestamp from which the voucher is rejected.
+/// - nonce: the voucher's nonce.
+/// - signature: Ed25519 signature of the voucher.
+///
+/// Errors:
+/// - Returns `EnrollmentError::VouchersDisabled` if the course has no signer.
+/// - Returns `EnrollmentError::VoucherExpired` if the voucher has expired.
+/// - Returns `EnrollmentError::VoucherUsed` if the nonce was already redeemed.
+/// - Panics if the signature does not match the voucher.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the recipient is already enrolled.
+///
+/// Storage used:
+/// - EnrollmentKey::VoucherNonce(course_id, nonce) -> Address
+pub fn redeem_voucher(
+    env: &Env,
+    recipient: Address,
+    course_id: u128,
+    expires_at: u64,
+    nonce: u64,
+    signature: BytesN<64>,
+) -> Enrollment {
+    recipient.require_auth();
+
+    let signer: BytesN<32> = match get_voucher_signer(env, course_id) {
+        Some(signer) => signer,
+        None => handle_error(env, EnrollmentError::VouchersDisabled),
+    };
+    if env.ledger().timestamp() >= expires_at {
+        handle_error(env, EnrollmentError::VoucherExpired)
+    }
+    let nonce_key = EnrollmentKey::VoucherNonce(course_id, nonce);
+    if env.storage().persistent().has(&nonce_key) {
+        handle_error(env, EnrollmentError::VoucherUsed)
+    }
+    let voucher = Voucher {
+        contract: env.current_contract_address(),
+        course_id,
+        recipient: recipient.clone(),
+        expires_at,
+        nonce,
+    };
+    env.crypto()
+        .ed25519_verify(&signer, &voucher.to_xdr(env), &signature);
+
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &recipient) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+
+    env.storage().persistent().set(&nonce_key, &recipient);
+
+    env.events().publish(
+        (REDEEM_VOUCHER_EVENT, course_id),
+        (recipient.clone(), nonce),
+    );
+
+    record_student_enrollment(env, &course, &recipient, 0, EnrollmentSource::Voucher)
+}
+
+/// Returns who redeemed the voucher with a nonce for a course, if anyone.
+pub fn get_voucher_redeemer(env: &Env, course_id: u128, nonce: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::VoucherNonce(course_id, nonce))
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::publish_params;
+    use crate::schema::Voucher;
+    use crate::{CourseRegistry, CourseRegistryClient};
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        xdr::ToXdr,
+        Address, BytesN, Env, String,
+    };
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, u128) {
+        env.mock_all_auths();
+        let contract_id = env.register(CourseRegistry, ());
+        let client = CourseRegistryClient::new(env, &contract_id);
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &publish_params());
+        (client, creator, course.id)
+    }
+
+    #[test]
+    fn test_voucher_signer_management() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let signer = BytesN::from_array(&env, &[7; 32]);
+
+        client.set_voucher_signer(&creator, &course_id, &Some(signer.clone()));
+        assert_eq!(client.get_voucher_signer(&course_id), Some(signer));
+        assert!(client
+            .try_redeem_voucher(
+                &Address::generate(&env),
+                &course_id,
+                &1000,
+                &1,
+                &BytesN::from_array(&env, &[0; 64]),
+            )
+            .is_err());
+        assert_eq!(client.get_voucher_redeemer(&course_id, &1), None);
+
+        client.set_voucher_signer(&creator, &course_id, &None);
+        assert_eq!(client.get_voucher_signer(&course_id), None);
+    }
+
+    /// Signs a voucher the way a creator's off-chain tooling would.
+    fn sign_voucher(env: &Env, key: &SigningKey, voucher: &Voucher) -> BytesN<64> {
+        let xdr = voucher.clone().to_xdr(env);
+        let mut message = [0u8; 512];
+        let len = xdr.len() as usize;
+        xdr.copy_into_slice(&mut message[..len]);
+        BytesN::from_array(env, &key.sign(&message[..len]).to_bytes())
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #529)")]
+    fn test_redeem_signed_voucher_once() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let signer = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        client.set_voucher_signer(&creator, &course_id, &Some(signer));
+
+        let student = Address::generate(&env);
+        let voucher = Voucher {
+            contract: client.address.clone(),
+            course_id,
+            recipient: student.clone(),
+            expires_at: 1000,
+            nonce: 1,
+        };
+        let signature = sign_voucher(&env, &key, &voucher);
+        let enrollment = client.redeem_voucher(&student, &course_id, &1000, &1, &signature);
+        assert_eq!(enrollment.amount_paid, 0);
+        assert_eq!(client.get_voucher_redeemer(&course_id, &1), Some(student.clone()));
+
+        client.unenroll(&student, &course_id);
+        client.redeem_voucher(&student, &course_id, &1000, &1, &signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "HostError: Error(Contract, #528)")]
+    fn test_expired_voucher() {
+        let env = Env::default();
+        let (client, creator, course_id) = setup(&env);
+        client.set_voucher_signer(
+            &creator,
+            &course_id,
+            &Some(BytesN::from_array(&env, &[7; 32])),
+        );
+        env.ledger().set_timestamp(1000);
+
+        client.redeem_voucher(
+            &Address::generate(&env),
+            &course_id,
+            &1000,
+            &1,
+            &BytesN::from_array(&env, &[0; 64]),
+        );
+    }
+}
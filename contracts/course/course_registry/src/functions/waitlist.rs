@@ -0,0 +1,435 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 SkillCert
+
+use super::course_bans::is_banned;
+use super::enrollment::{is_enrolled, record_student_enrollment};
+use super::payments::collect_payment;
+use crate::error::{handle_error, EnrollmentError, Error};
+use crate::schema::{
+    Course, CourseStatus, Enrollment, EnrollmentKey, EnrollmentSource, WaitlistEntry, MAX_WAITLIST,
+    WAITLIST_CLAIM_PERIOD,
+};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+const COURSE_KEY: Symbol = symbol_short!("course");
+
+const JOIN_WAITLIST_EVENT: Symbol = symbol_short!("waitlist");
+const SEAT_OFFER_EVENT: Symbol = symbol_short!("seatOffer");
+
+/// Puts a student on the waitlist of a full course.
+///
+/// When a seat frees up, through a revocation, a refund or a higher
+/// `max_students`, it is offered to the first student in line, who has
+/// `WAITLIST_CLAIM_PERIOD` to claim it at the price the course had when they
+/// joined.
+///
+/// Arguments:
+/// - env: Soroban environment.
+/// - student: the waiting student (must authorize).
+/// - course_id: unique identifier of the course.
+///
+/// Returns:
+/// - WaitlistEntry: the student's place on the waitlist.
+///
+/// Errors:
+/// - Returns `Error::CourseNotFound` if the course does not exist.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is not published.
+/// - Returns `EnrollmentError::AlreadyEnrolled` if the student is already enrolled.
+/// - Returns `EnrollmentError::AlreadyWaitlisted` if the student is already waiting.
+/// - Returns `EnrollmentError::StudentBanned` if the student is banned from the course.
+/// - Returns `Error::InvalidInput` if the course has seats left or the
+///   waitlist holds `MAX_WAITLIST` students.
+///
+/// Storage used:
+/// - EnrollmentKey::Waitlist(course_id) -> Vec<Address>
+/// - EnrollmentKey::WaitlistEntry(course_id, student) -> WaitlistEntry
+pub fn join_waitlist(env: &Env, student: Address, course_id: u128) -> WaitlistEntry {
+    student.require_auth();
+
+    let course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+    if is_enrolled(env, course_id, &student) {
+        handle_error(env, EnrollmentError::AlreadyEnrolled)
+    }
+    if is_banned(env, course_id, student.clone()) {
+        handle_error(env, EnrollmentError::StudentBanned)
+    }
+    let entry_key = EnrollmentKey::WaitlistEntry(course_id, student.clone());
+    if env.storage().persistent().has(&entry_key) {
+        handle_error(env, EnrollmentError::AlreadyWaitlisted)
+    }
+    let mut queue: Vec<Address> = get_waitlist(env, course_id);
+    if course.seats_remaining != Some(0) || queue.len() >= MAX_WAITLIST {
+        handle_error(env, Error::InvalidInput)
+    }
+
+    let entry = WaitlistEntry {
+        course_id,
+        student: student.clone(),
+        joined_at: env.ledger().timestamp(),
+        price: course.price,
+        offer_expires_at: None,
+    };
+    env.storage().persistent().set(&entry_key, &entry);
+    queue.push_back(student.clone());
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::Waitlist(course_id), &queue);
+
+    env.events()
+        .publish((JOIN_WAITLIST_EVENT, course_id), student);
+
+    entry
+}
+
+/// Enrolls a waitlisted student on the seat offered to them, charging the
+/// price the course had when they joined the waitlist.
+///
+/// Errors:
+/// - Returns `EnrollmentError::NotWaitlisted` if the student is not on the waitlist.
+/// - Returns `EnrollmentError::NoWaitlistOffer` if no seat was offered to them yet.
+/// - Returns `EnrollmentError::WaitlistOfferExpired` if the offer has expired.
+/// - Returns `EnrollmentError::CourseNotOpen` if the course is no longer published.
+/// - Any error `enrollment::enroll` can return for the payment.
+pub fn claim_waitlist_seat(
+    env: &Env,
+    student: Address,
+    course_id: u128,
+    token: Option<Address>,
+) -> Enrollment {
+    student.require_auth();
+
+    let entry: WaitlistEntry = load_entry(env, course_id, &student);
+    match entry.offer_expires_at {
+        None => handle_error(env, EnrollmentError::NoWaitlistOffer),
+        Some(expires_at) if env.ledger().timestamp() >= expires_at => {
+            handle_error(env, EnrollmentError::WaitlistOfferExpired)
+        }
+        Some(_) => {}
+    }
+    let mut course: Course = load_course(env, course_id);
+    if course.status != CourseStatus::Published {
+        handle_error(env, EnrollmentError::CourseNotOpen)
+    }
+
+    // Hand the held seat back so the enrollment below takes it.
+    remove_offer(env, course_id, &student);
+    release_held_seat(env, &mut course);
+
+    let mut priced: Course = course.clone();
+    priced.price = entry.price;
+    let payment = collect_payment(env, &priced, &student, &student, token, None, None, None);
+    let amount_paid: u128 = payment.map(|payment| payment.amount).unwrap_or(0);
+
+    record_student_enrollment(
+        env,
+        &course,
+        &student,
+        amount_paid,
+        EnrollmentSource::Purchase,
+    )
+}
+
+/// Takes a student off the waitlist, passing on any seat offered to them.
+///
+/// Errors:
+/// - Returns `EnrollmentError::NotWaitlisted` if the student is not on the waitlist.
+pub fn leave_waitlist(env: &Env, student: Address, course_id: u128) {
+    student.require_auth();
+
+    remove_from_waitlist(env, course_id, &student);
+}
+
+/// Takes a student off the waitlist without their authorization, for
+/// instance when they are banned from the course.
+///
+/// Errors:
+/// - Returns `EnrollmentError::NotWaitlisted` if the student is not on the waitlist.
+pub fn remove_from_waitlist(env: &Env, course_id: u128, student: &Address) {
+    let entry: WaitlistEntry = load_entry(env, course_id, student);
+    if entry.offer_expires_at.is_some() {
+        remove_offer(env, course_id, student);
+        let mut course: Course = load_course(env, course_id);
+        release_held_seat(env, &mut course);
+        offer_free_seats(env, course_id);
+    } else {
+        let mut queue: Vec<Address> = get_waitlist(env, course_id);
+        if let Some(index) = queue.first_index_of(student) {
+            queue.remove(index);
+            env.storage()
+                .persistent()
+                .set(&EnrollmentKey::Waitlist(course_id), &queue);
+        }
+        env.storage()
+            .persistent()
+            .remove(&EnrollmentKey::WaitlistEntry(course_id, student.clone()));
+    }
+}
+
+/// Withdraws the seat offers nobody claimed in time and passes the seats on
+/// to the next students in line. Anyone may call this.
+///
+/// Returns:
+/// - u32: the number of offers withdrawn.
+pub fn expire_waitlist_offers(env: &Env, course_id: u128) -> u32 {
+    let now: u64 = env.ledger().timestamp();
+    let mut expired: u32 = 0;
+    for student in get_waitlist_offers(env, course_id).iter() {
+        let entry: WaitlistEntry = load_entry(env, course_id, &student);
+        if entry
+            .offer_expires_at
+            .is_some_and(|expires_at| now >= expires_at)
+        {
+            remove_offer(env, course_id, &student);
+            let mut course: Course = load_course(env, course_id);
+            release_held_seat(env, &mut course);
+            expired += 1;
+        }
+    }
+    if expired > 0 {
+        offer_free_seats(env, course_id);
+    }
+    expired
+}
+
+/// Offers the free seats of a course to the first students on its waitlist.
+///
+/// Each offered seat is held for the student until they claim it, leave the
+/// waitlist or the offer expires.
+///
+/// Storage used:
+/// - EnrollmentKey::WaitlistOffers(course_id) -> Vec<Address>
+pub fn offer_free_seats(env: &Env, course_id: u128) {
+    let mut queue: Vec<Address> = get_waitlist(env, course_id);
+    if queue.is_empty() {
+        return;
+    }
+    let mut course: Course = match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => return,
+    };
+    let mut offers: Vec<Address> = get_waitlist_offers(env, course_id);
+    let expires_at: u64 = env.ledger().timestamp() + WAITLIST_CLAIM_PERIOD;
+    while let (Some(seats), Some(student)) = (course.seats_remaining, queue.first()) {
+        if seats == 0 {
+            break;
+        }
+        queue.pop_front();
+        course.seats_remaining = Some(seats - 1);
+        let entry_key = EnrollmentKey::WaitlistEntry(course_id, student.clone());
+        if let Some(mut entry) = env
+            .storage()
+            .persistent()
+            .get::<_, WaitlistEntry>(&entry_key)
+        {
+            entry.offer_expires_at = Some(expires_at);
+            env.storage().persistent().set(&entry_key, &entry);
+        }
+        offers.push_back(student.clone());
+
+        env.events()
+            .publish((SEAT_OFFER_EVENT, course_id), (student, expires_at));
+    }
+    env.storage()
+        .persistent()
+        .set(&(COURSE_KEY, course_id), &course);
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::Waitlist(course_id), &queue);
+    env.storage()
+        .persistent()
+        .set(&EnrollmentKey::WaitlistOffers(course_id), &offers);
+}
+
+/// Returns the students waiting for a seat of a course, first in line first.
+///
+/// Students holding a seat offer are no longer in line.
+pub fn get_waitlist(env: &Env, course_id: u128) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::Waitlist(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the waitlisted students holding a seat offer for a course.
+pub fn get_waitlist_offers(env: &Env, course_id: u128) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::WaitlistOffers(course_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns a student's place on the waitlist of a course, if any.
+pub fn get_waitlist_entry(env: &Env, course_id: u128, student: Address) -> Option<WaitlistEntry> {
+    env.storage()
+        .persistent()
+        .get(&EnrollmentKey::WaitlistEntry(course_id, student))
+}
+
+fn load_entry(env: &Env, course_id: u128, student: &Address) -> WaitlistEntry {
+    match get_waitlist_entry(env, course_id, student.clone()) {
+        Some(entry) => entry,
+        None => handle_error(env, EnrollmentError::NotWaitlisted),
+    }
+}
+
+fn remove_offer(env: &Env, course_id: u128, student: &Address) {
+    let mut offers: Vec<Address> = get_waitlist_offers(env, course_id);
+    if let Some(index) = offers.first_index_of(student) {
+        offers.remove(index);
+        env.storage()
+            .persistent()
+            .set(&EnrollmentKey::WaitlistOffers(course_id), &offers);
+    }
+    env.storage()
+        .persistent()
+        .remove(&EnrollmentKey::WaitlistEntry(course_id, student.clone()));
+}
+
+fn release_held_seat(env: &Env, course: &mut Course) {
+    if let Some(seats) = course.seats_remaining {
+        course.seats_remaining = Some(seats + 1);
+        env.storage()
+            .persistent()
+            .set(&(COURSE_KEY, course.id), course);
+    }
+}
+
+fn load_course(env: &Env, course_id: u128) -> Course {
+    match env.storage().persistent().get(&(COURSE_KEY, course_id)) {
+        Some(course) => course,
+        None => handle_error(env, Error::CourseNotFound),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::test_utils::setup_registry_with_token;
+    use crate::schema::EditCourseParams;
+    use crate::CourseRegistryClient;
+    use soroban_sdk::{
+        testutils::{Address as _, Ledger},
+        token, Address, Env, String,
+    };
+
+    fn params(price: Option<u128>, published: Option<bool>) -> EditCourseParams {
+        EditCourseParams {
+            new_title: None,
+            new_description: None,
+            new_price: price,
+            new_category: None,
+            new_language: None,
+            new_thumbnail_url: None,
+            new_published: published,
+            new_duration_hours: None,
+            new_is_free: None,
+        }
+    }
+
+    fn setup<'a>(env: &Env) -> (CourseRegistryClient<'a>, Address, Address, u128) {
+        let (client, _admin, token) = setup_registry_with_token(env);
+
+        let creator = Address::generate(env);
+        let course = client.create_course(
+            &creator,
+            &String::from_str(env, "Course"),
+            &String::from_str(env, "description"),
+            &1000_u128,
+            &None,
+            &None,
+            &None,
+            &None,
+            &None,
+        );
+        client.edit_course(&creator, &course.id, &params(None, Some(true)));
+        client.set_max_students(&creator, &course.id, &Some(1));
+        (client, token, creator, course.id)
+    }
+
+    fn enroll_paying<'a>(
+        env: &Env,
+        client: &CourseRegistryClient<'a>,
+        token: &Address,
+        course_id: u128,
+    ) -> Address {
+        let student = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&student, &1000);
+        client.enroll(
+            &student,
+            &course_id,
+            &Some(token.clone()),
+            &None,
+            &None,
+            &None,
+        );
+        student
+    }
+
+    #[test]
+    fn test_freed_seat_offered_at_original_price() {
+        let env = Env::default();
+        let (client, token, creator, course_id) = setup(&env);
+        let first = enroll_paying(&env, &client, &token, course_id);
+        let waiting = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&waiting, &1000);
+        assert_eq!(client.join_waitlist(&waiting, &course_id).price, 1000);
+        client.edit_course(&creator, &course_id, &params(Some(2000), None));
+
+        client.revoke_access(
+            &creator,
+            &course_id,
+            &first,
+            &String::from_str(&env, "Refund dispute"),
+        );
+        let entry = client.get_waitlist_entry(&course_id, &waiting).unwrap();
+        assert_eq!(entry.offer_expires_at, Some(2 * 86_400));
+        assert!(client
+            .try_enroll(
+                &Address::generate(&env),
+                &course_id,
+                &None,
+                &None,
+                &None,
+                &None
+            )
+            .is_err());
+
+        let enrollment = client.claim_waitlist_seat(&waiting, &course_id, &Some(token.clone()));
+        assert_eq!(enrollment.amount_paid, 1000);
+        assert_eq!(client.get_waitlist_entry(&course_id, &waiting), None);
+        assert_eq!(client.get_course(&course_id).seats_remaining, Some(0));
+    }
+
+    #[test]
+    fn test_expired_offer_passes_to_next_student() {
+        let env = Env::default();
+        let (client, token, creator, course_id) = setup(&env);
+        let first = enroll_paying(&env, &client, &token, course_id);
+        let second = Address::generate(&env);
+        let third = Address::generate(&env);
+        client.join_waitlist(&second, &course_id);
+        client.join_waitlist(&third, &course_id);
+        client.set_max_students(&creator, &course_id, &Some(2));
+        assert!(client
+            .get_waitlist_entry(&course_id, &second)
+            .unwrap()
+            .offer_expires_at
+            .is_some());
+        assert_eq!(client.get_waitlist(&course_id).len(), 1);
+
+        env.ledger().set_timestamp(2 * 86_400);
+        assert!(client
+            .try_claim_waitlist_seat(&second, &course_id, &Some(token.clone()))
+            .is_err());
+        assert_eq!(client.expire_waitlist_offers(&course_id), 1);
+        assert_eq!(client.get_waitlist_entry(&course_id, &second), None);
+        assert!(client
+            .get_waitlist_entry(&course_id, &third)
+            .unwrap()
+            .offer_expires_at
+            .is_some());
+        assert!(client.is_enrolled(&first, &course_id));
+    }
+}
@@ -14,9 +14,18 @@ pub mod schema;
 mod test;
 
 use crate::schema::{
-    Course, CourseCategory, CourseFilters, CourseGoal, CourseLevel, CourseModule, EditCourseParams,
+    AccessLogEntry, AccessTerms, Assignment, AssignmentSubmission, Bundle, CategoryStats, Cohort,
+    ContentLimits, Coupon, Course, CourseCategory, CourseExportPage, CourseFilters, CourseGoal,
+    CourseInput, CourseLevel, CourseModule, CourseOutline, CourseRating, CourseSkill, CourseStats,
+    CourseVisibility, CreatorStats, Discount, EditCourseParams, EditLessonParams, Enrollment,
+    EnrollmentSummary, InstallmentPlan, InstallmentSchedule, InstructorSummary, Invite, LatePolicy,
+    Lesson, LessonContentType, LiveSession, ModuleAccess, ModuleInput, ModuleOverride,
+    ModuleRelease, ModuleUnlock, Payment, PendingGift, PlatformFee, PrerequisiteCheck, PriceChange,
+    PriceLimits, Quiz, QuizQuestion, RateLimit, Receipt, RefundRequest, ResourceLink, Sale,
+    ScholarshipPool, ScholarshipTarget, SeatPool, Skill, SortKey, Subscription, SubscriptionPlan,
+    TipTotals, TokenGate, TreasuryAccount, UpdateModuleParams, VolumeDiscount, WaitlistEntry,
 };
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Map, String, Vec};
 
 /// Course Registry Contract
 ///
@@ -28,6 +37,21 @@ pub struct CourseRegistry;
 
 #[contractimpl]
 impl CourseRegistry {
+    /// Initialize the contract with its owner and the user management contract.
+    ///
+    /// The user management contract is asked whether a caller is an admin, so
+    /// no admin-only function can be used before this is called. It can only
+    /// be called once.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The address of the contract owner
+    /// * `user_mgmt_addr` - The address of the user management contract
+    pub fn initialize(env: Env, owner: Address, user_mgmt_addr: Address) {
+        functions::access_control::initialize(&env, &owner, &user_mgmt_addr)
+    }
+
     /// Create a new course in the registry.
     ///
     /// This function creates a new course with the specified metadata and
@@ -78,11 +102,13 @@ impl CourseRegistry {
     /// Create a new course category.
     ///
     /// This function creates a new category that can be used to classify courses.
+    /// Once a category exists, courses can only be filed under registered,
+    /// active categories.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `caller` - The address of the user creating the category
+    /// * `caller` - The address of the admin creating the category
     /// * `name` - The name of the category
     /// * `description` - Optional description of the category
     ///
@@ -98,350 +124,4400 @@ impl CourseRegistry {
         functions::create_course_category::create_course_category(env, caller, name, description)
     }
 
-    /// Retrieve a course by its ID.
+    /// Rename a course category.
     ///
-    /// This function fetches a course's complete information using its unique identifier.
+    /// Courses filed under the previous name stay in the category.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `course_id` - The unique identifier of the course to retrieve
+    /// * `caller` - The address of the admin renaming the category
+    /// * `category_id` - The unique identifier of the category
+    /// * `new_name` - The new name of the category
     ///
     /// # Returns
     ///
-    /// Returns the `Course` object containing all course metadata.
-    pub fn get_course(env: Env, course_id: String) -> Course {
-        functions::get_course::get_course(&env, course_id)
+    /// Returns the renamed `CourseCategory`.
+    pub fn rename_course_category(
+        env: Env,
+        caller: Address,
+        category_id: u128,
+        new_name: String,
+    ) -> CourseCategory {
+        functions::category_registry::rename_course_category(&env, caller, category_id, new_name)
     }
 
-    /// Retrieve a course category by its ID.
+    /// Activate or deactivate a course category.
     ///
-    /// This function fetches a category's information using its unique identifier.
+    /// Inactive categories are hidden from `list_categories` and cannot be
+    /// assigned to courses.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `category_id` - The unique identifier of the category to retrieve
+    /// * `caller` - The address of the admin updating the category
+    /// * `category_id` - The unique identifier of the category
+    /// * `active` - Whether the category can be used
+    pub fn set_course_category_active(env: Env, caller: Address, category_id: u128, active: bool) {
+        functions::category_registry::set_course_category_active(&env, caller, category_id, active)
+    }
+
+    /// Create several courses in one transaction.
+    ///
+    /// All entries are validated like `create_course` and receive sequential
+    /// IDs. If any entry is invalid, none of the courses are created.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `inputs` - The course definitions (at most 20)
     ///
     /// # Returns
     ///
-    /// Returns `Some(CourseCategory)` if found, `None` if the category doesn't exist.
-    pub fn get_course_category(env: Env, category_id: u128) -> Option<CourseCategory> {
-        functions::get_course_category::get_course_category(&env, category_id)
+    /// Returns the created `Course` objects in input order.
+    pub fn create_courses(env: Env, creator: Address, inputs: Vec<CourseInput>) -> Vec<Course> {
+        functions::create_course::create_courses(env, creator, inputs)
     }
 
-    /// Get all courses created by a specific instructor.
+    /// Retrieve a course by its ID.
     ///
-    /// This function retrieves all courses that were created by the specified instructor.
+    /// This function fetches a course's complete information using its unique identifier.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `instructor` - The address of the instructor to query courses for
+    /// * `course_id` - The unique identifier of the course to retrieve
     ///
     /// # Returns
     ///
-    /// Returns a vector of `Course` objects created by the instructor.
-    pub fn get_courses_by_instructor(env: Env, instructor: Address) -> Vec<Course> {
-        functions::get_courses_by_instructor::get_courses_by_instructor(&env, instructor)
+    /// Returns the `Course` object containing all course metadata.
+    pub fn get_course(env: Env, course_id: u128) -> Course {
+        functions::get_course::get_course(&env, course_id)
     }
 
-    /// Remove a module from a course.
+    /// Retrieve a course as it looked at a specific version.
     ///
-    /// This function removes a specific module from its associated course.
+    /// Every edit snapshots the previous state of the course, so students can
+    /// verify what a course contained at the time they bought it.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `module_id` - The unique identifier of the module to remove
+    /// * `course_id` - The unique identifier of the course
+    /// * `version` - The version number to retrieve (starting at 1)
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// Panics if the module removal fails or if the module doesn't exist.
-    pub fn remove_module(env: Env, module_id: String) -> () {
-        functions::remove_module::remove_module(&env, module_id).unwrap_or_else(|e| panic!("{}", e))
+    /// Returns the `Course` object as it was at the requested version.
+    pub fn get_course_version(env: Env, course_id: u128, version: u32) -> Course {
+        functions::get_course_version::get_course_version(&env, course_id, version)
     }
 
-    /// Add a new module to a course.
+    /// Compute the deterministic hash of a creator and course title.
     ///
-    /// This function creates and adds a new module to the specified course
-    /// at the given position.
+    /// The hash is derived from the creator address and the normalized
+    /// (whitespace-collapsed, lowercased) title, so off-chain systems can predict it.
+    /// It is a lookup key only: courses keep their sequential `u128` IDs, which
+    /// every other function takes.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `course_id` - The unique identifier of the course to add the module to
-    /// * `position` - The position where the module should be inserted
-    /// * `title` - The title of the new module
+    /// * `creator` - The address of the course creator
+    /// * `title` - The course title
     ///
     /// # Returns
     ///
-    /// Returns the created `CourseModule` object.
-    pub fn add_module(env: Env, caller: Address, course_id: String, position: u32, title: String) -> CourseModule {
-        functions::add_module::course_registry_add_module(env, caller, course_id, position, title)
+    /// Returns the 32-byte course hash.
+    pub fn compute_course_hash(env: Env, creator: Address, title: String) -> BytesN<32> {
+        functions::get_course_by_hash::compute_course_hash(&env, creator, title)
     }
 
-    /// Delete a course from the registry.
+    /// Retrieve a course by its deterministic hash.
     ///
-    /// This function permanently removes a course from the registry.
-    /// Only the course creator can delete their own courses.
+    /// The hash follows the course title: after a rename the course is found
+    /// under the hash of its new title only.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
-    /// * `course_id` - The unique identifier of the course to delete
+    /// * `hash` - The hash returned by `compute_course_hash`
     ///
-    /// # Panics
+    /// # Returns
     ///
-    /// Panics if the deletion fails or if the creator is not authorized.
-    pub fn delete_course(env: Env, creator: Address, course_id: String) -> () {
-        functions::delete_course::delete_course(&env, creator, course_id)
-            .unwrap_or_else(|e| panic!("{}", e))
+    /// Returns the `Course` object indexed under the hash.
+    pub fn get_course_by_hash(env: Env, hash: BytesN<32>) -> Course {
+        functions::get_course_by_hash::get_course_by_hash(&env, hash)
     }
 
-    /// Simple hello world function for testing.
+    /// Retrieve a course by its title.
     ///
-    /// This is a basic function that returns a greeting message,
-    /// primarily used for testing contract deployment and basic functionality.
+    /// Titles are matched after normalization, so case and extra whitespace
+    /// are ignored. Only live (non-archived) courses hold their title.
     ///
     /// # Arguments
     ///
-    /// * `_env` - The Soroban environment (unused)
+    /// * `env` - The Soroban environment
+    /// * `title` - The course title to look up
     ///
     /// # Returns
     ///
-    /// Returns a greeting string.
-    pub fn hello_world(_env: Env) -> String {
-        String::from_str(&_env, "Hello from Web3 👋")
+    /// Returns the `Course` object currently holding the title.
+    pub fn get_course_by_title(env: Env, title: String) -> Course {
+        functions::get_course_by_title::get_course_by_title(&env, title)
     }
 
-    /// Edit an existing course goal.
+    /// Set or clear the slug of a course.
     ///
-    /// This function allows the course creator to modify the content of an existing goal.
+    /// Slugs are short, unique handles such as `intro-to-soroban` that can be
+    /// used in links instead of numeric IDs. Only the course creator can set them.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
     /// * `creator` - The address of the course creator
     /// * `course_id` - The unique identifier of the course
-    /// * `goal_id` - The unique identifier of the goal to edit
-    /// * `new_content` - The new content for the goal
+    /// * `slug` - The new slug, or `None` to remove it
     ///
     /// # Returns
     ///
-    /// Returns the updated `CourseGoal` object.
-    pub fn edit_goal(
+    /// Returns the updated `Course` object.
+    pub fn set_course_slug(
         env: Env,
         creator: Address,
-        course_id: String,
-        goal_id: String,
-        new_content: String,
-    ) -> CourseGoal {
-        functions::edit_goal::edit_goal(env, creator, course_id, goal_id, new_content)
+        course_id: u128,
+        slug: Option<String>,
+    ) -> Course {
+        functions::course_slug::set_course_slug(&env, creator, course_id, slug)
     }
 
-    /// Add a new goal to a course.
+    /// Retrieve a course by its slug.
     ///
-    /// This function creates and adds a new learning goal to the specified course.
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `slug` - The slug assigned with `set_course_slug`
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Course` object using the slug.
+    pub fn get_course_by_slug(env: Env, slug: String) -> Course {
+        functions::course_slug::get_course_by_slug(&env, slug)
+    }
+
+    /// Set the visibility of a course.
+    ///
+    /// Public courses appear in listings, unlisted courses are only reachable
+    /// by ID, slug or hash, and private courses can only be read by the
+    /// creator and admins through `get_course_as`.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
     /// * `creator` - The address of the course creator
     /// * `course_id` - The unique identifier of the course
-    /// * `content` - The content/description of the goal
+    /// * `visibility` - The new visibility level
     ///
     /// # Returns
     ///
-    /// Returns the created `CourseGoal` object.
-    pub fn add_goal(env: Env, creator: Address, course_id: String, content: String) -> CourseGoal {
-        functions::add_goal::add_goal(env, creator, course_id, content)
+    /// Returns the updated `Course` object.
+    pub fn set_course_visibility(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        visibility: CourseVisibility,
+    ) -> Course {
+        functions::course_visibility::set_course_visibility(&env, creator, course_id, visibility)
     }
 
-    /// Remove a goal from a course.
+    /// Retrieve a course on behalf of a viewer.
     ///
-    /// This function removes a specific learning goal from the course.
+    /// Works like `get_course`, but also returns private courses when the
+    /// viewer is allowed to read them.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `caller` - The address of the user requesting the removal
+    /// * `viewer` - The address requesting the course
     /// * `course_id` - The unique identifier of the course
-    /// * `goal_id` - The unique identifier of the goal to remove
-    pub fn remove_goal(env: Env, caller: Address, course_id: String, goal_id: String) -> () {
-        functions::remove_goal::remove_goal(env, caller, course_id, goal_id)
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Course` object.
+    pub fn get_course_as(env: Env, viewer: Address, course_id: u128) -> Course {
+        functions::course_visibility::get_course_as(&env, viewer, course_id)
     }
 
-    /// Add prerequisites to a course.
+    /// Set the off-chain metadata document of a course.
     ///
-    /// This function adds prerequisite courses that must be completed
-    /// before a student can enroll in the target course.
+    /// Lets a course keep minimal text on-chain and point to a rich document
+    /// on IPFS, Arweave or HTTPS, optionally pinned by its SHA-256 hash.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
     /// * `creator` - The address of the course creator
     /// * `course_id` - The unique identifier of the course
-    /// * `prerequisite_course_ids` - Vector of course IDs that are prerequisites
-    pub fn add_prerequisite(
+    /// * `metadata_uri` - The document URI, or `None` to clear it
+    /// * `metadata_hash` - The SHA-256 hash of the document, if any
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object.
+    pub fn set_course_metadata(
         env: Env,
         creator: Address,
-        course_id: String,
-        prerequisite_course_ids: Vec<String>,
-    ) {
-        functions::create_prerequisite::add_prerequisite(
-            env,
+        course_id: u128,
+        metadata_uri: Option<String>,
+        metadata_hash: Option<BytesN<32>>,
+    ) -> Course {
+        functions::course_metadata::set_course_metadata(
+            &env,
             creator,
             course_id,
-            prerequisite_course_ids,
+            metadata_uri,
+            metadata_hash,
         )
     }
 
-    /// Remove a prerequisite from a course.
+    /// Retrieve a course category by its ID.
     ///
-    /// This function removes a specific prerequisite course requirement
-    /// from the target course.
+    /// This function fetches a category's information using its unique identifier.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
-    /// * `course_id` - The unique identifier of the course
-    /// * `prerequisite_course_id` - The ID of the prerequisite course to remove
-    pub fn remove_prerequisite(
-        env: Env,
-        creator: Address,
-        course_id: String,
-        prerequisite_course_id: String,
-    ) {
-        functions::remove_prerequisite::remove_prerequisite(
-            env,
-            creator,
-            course_id,
-            prerequisite_course_id,
-        )
+    /// * `category_id` - The unique identifier of the category to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(CourseCategory)` if found, `None` if the category doesn't exist.
+    pub fn get_course_category(env: Env, category_id: u128) -> Option<CourseCategory> {
+        functions::get_course_category::get_course_category(&env, category_id)
     }
 
-    /// Edit the prerequisites for a course.
+    /// Get all courses created by a specific instructor.
     ///
-    /// This function replaces all existing prerequisites with a new set
-    /// of prerequisite courses.
+    /// This function retrieves all courses that were created by the specified instructor.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
-    /// * `course_id` - The unique identifier of the course
-    /// * `new_prerequisites` - Vector of new prerequisite course IDs
-    pub fn edit_prerequisite(
-        env: Env,
-        creator: Address,
-        course_id: String,
-        new_prerequisites: Vec<String>,
-    ) {
-        functions::edit_prerequisite::edit_prerequisite(env, creator, course_id, new_prerequisites)
+    /// * `instructor` - The address of the instructor to query courses for
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects created by the instructor.
+    pub fn get_courses_by_instructor(env: Env, instructor: Address) -> Vec<Course> {
+        functions::get_courses_by_instructor::get_courses_by_instructor(&env, instructor)
     }
 
-    /// Edit course information.
+    /// Get course counters for a creator.
     ///
-    /// This function allows the course creator to update various aspects
-    /// of the course using the provided parameters.
+    /// Returns how many courses the creator currently owns and how many they
+    /// have created in total, including deleted ones.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
-    /// * `course_id` - The unique identifier of the course to edit
-    /// * `params` - Parameters containing the fields to update
+    /// * `creator` - The address of the creator to query
     ///
     /// # Returns
     ///
-    /// Returns the updated `Course` object.
-    pub fn edit_course(
-        env: Env,
-        creator: Address,
-        course_id: String,
-        params: EditCourseParams,
-    ) -> Course {
-        functions::edit_course::edit_course(env, creator, course_id, params)
+    /// Returns the `CreatorStats` of the creator.
+    pub fn get_creator_stats(env: Env, creator: Address) -> CreatorStats {
+        functions::creator_stats::get_creator_stats(&env, creator)
     }
 
-    /// Archive a course.
-    ///
-    /// This function marks a course as archived, making it unavailable for new enrollments
-    /// while preserving existing data and access for current students.
+    /// Get the instructors with the most enrollments.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the course creator
-    /// * `course_id` - The unique identifier of the course to archive
+    /// * `limit` - Number of instructors to return (1 to 20)
     ///
     /// # Returns
     ///
-    /// Returns the updated `Course` object with archived status.
-    pub fn archive_course(env: &Env, creator: Address, course_id: String) -> Course {
-        functions::archive_course::archive_course(env, creator, course_id)
+    /// Returns instructor summaries, most enrollments first and best rated first on ties.
+    pub fn get_top_instructors(env: Env, limit: u32) -> Vec<InstructorSummary> {
+        functions::creator_stats::get_top_instructors(&env, limit)
     }
 
-    /// Check if a user is the creator of a specific course.
+    /// Rate a course as an enrolled student.
     ///
-    /// This function verifies whether the specified user is the original creator
-    /// of the given course.
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolled student
+    /// * `course_id` - The unique identifier of the course
+    /// * `rating` - The rating, from 1 to 5
+    pub fn rate_course(env: Env, student: Address, course_id: u128, rating: u32) {
+        functions::course_ratings::rate_course(&env, student, course_id, rating)
+    }
+
+    /// Get the rating totals of a course.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
     /// * `course_id` - The unique identifier of the course
-    /// * `user` - The address of the user to check
     ///
     /// # Returns
     ///
-    /// Returns `true` if the user is the course creator, `false` otherwise.
-    pub fn is_course_creator(env: &Env, course_id: String, user: Address) -> bool {
-        functions::is_course_creator::is_course_creator(env, course_id, user)
+    /// Returns the sum and number of ratings given to the course.
+    pub fn get_course_rating(env: Env, course_id: u128) -> CourseRating {
+        functions::course_ratings::get_course_rating(&env, course_id)
     }
 
-    /// List all available course categories.
+    /// Remove a module from a course.
+    ///
+    /// This function removes a specific module from its associated course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module to remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if the module removal fails, if the module doesn't exist or if
+    /// the caller may not manage the course.
+    pub fn remove_module(env: Env, caller: Address, module_id: String) -> () {
+        functions::remove_module::remove_module(&env, caller, module_id)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Add a new module to a course.
+    ///
+    /// This function creates and adds a new module to the specified course
+    /// at the given position.
+    ///
+    /// # Arguments
     ///
-    /// This function retrieves all course categories that have been created
-    /// in the system.
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course to add the module to
+    /// * `position` - The position where the module should be inserted
+    /// * `title` - The title of the new module
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseModule` object.
+    pub fn add_module(env: Env, caller: Address, course_id: u128, position: u32, title: String) -> CourseModule {
+        functions::add_module::course_registry_add_module(env, caller, course_id, position, title)
+    }
+
+    /// Add several modules to a course in one transaction.
+    ///
+    /// All entries are validated first; either every module is added or none is.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `inputs` - Up to 50 module definitions with distinct free positions
     ///
     /// # Returns
     ///
-    /// Returns a vector of all available `Category` objects.
-    pub fn list_categories(env: Env) -> Vec<crate::schema::Category> {
-        functions::list_categories::list_categories(&env)
+    /// Returns the created modules, in input order.
+    pub fn add_modules(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        inputs: Vec<ModuleInput>,
+    ) -> Vec<CourseModule> {
+        functions::add_module::course_registry_add_modules(env, caller, course_id, inputs)
     }
 
-    /// List courses with filtering and pagination.
+    /// Replace the title or content hash of a module.
     ///
-    /// This function retrieves courses based on the provided filters
-    /// with optional pagination support.
+    /// The module version is bumped, the previous version stays readable and a
+    /// `module_updated` event notifies enrolled students of the change.
     ///
     /// # Arguments
     ///
     /// * `env` - The Soroban environment
-    /// * `filters` - Filtering criteria for courses
-    /// * `limit` - Optional maximum number of courses to return
-    /// * `offset` - Optional number of courses to skip for pagination
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module
+    /// * `params` - The fields to replace
     ///
     /// # Returns
     ///
-    /// Returns a vector of `Course` objects matching the filter criteria.
-    pub fn list_courses_with_filters(
+    /// Returns the module at its new version.
+    pub fn update_module(
         env: Env,
-        filters: CourseFilters,
-        limit: Option<u32>,
-        offset: Option<u32>,
-    ) -> Vec<Course> {
-        functions::list_courses_with_filters::list_courses_with_filters(
-            &env, filters, limit, offset,
-        )
+        caller: Address,
+        module_id: String,
+        params: UpdateModuleParams,
+    ) -> CourseModule {
+        functions::module_versions::update_module(&env, caller, module_id, params)
+    }
+
+    /// Get a module as it was at a given version.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    /// * `version` - The version to read, starting at 1
+    ///
+    /// # Returns
+    ///
+    /// Returns the `CourseModule` at that version.
+    pub fn get_module_version(env: Env, module_id: String, version: u32) -> CourseModule {
+        functions::module_versions::get_module_version(&env, module_id, version)
+    }
+
+    /// Attach a quiz to a module or update its grading rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module holding the quiz
+    /// * `passing_score` - Percentage of points needed to pass (1-100)
+    /// * `max_attempts` - Attempts allowed per student
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Quiz` configuration.
+    pub fn set_quiz(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        passing_score: u32,
+        max_attempts: u32,
+    ) -> Quiz {
+        functions::quiz::set_quiz(&env, caller, module_id, passing_score, max_attempts)
+    }
+
+    /// Add a question, stored as a hash, to the quiz of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module holding the quiz
+    /// * `content_hash` - Hash of the off-chain question and answer key
+    /// * `points` - Points the question is worth
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the new question.
+    pub fn add_quiz_question(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        content_hash: BytesN<32>,
+        points: u32,
+    ) -> u32 {
+        functions::quiz::add_quiz_question(&env, caller, module_id, content_hash, points)
+    }
+
+    /// Remove a question from the quiz of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module holding the quiz
+    /// * `question_id` - The question to remove
+    pub fn remove_quiz_question(env: Env, caller: Address, module_id: String, question_id: u32) {
+        functions::quiz::remove_quiz_question(&env, caller, module_id, question_id)
+    }
+
+    /// Get the quiz configuration of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The module holding the quiz
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Quiz` with its question IDs, passing score and attempt limit.
+    pub fn get_quiz(env: Env, module_id: String) -> Quiz {
+        functions::quiz::get_quiz(&env, module_id)
+    }
+
+    /// Get one question of the quiz of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The module holding the quiz
+    /// * `question_id` - The question to read
+    ///
+    /// # Returns
+    ///
+    /// Returns the `QuizQuestion`.
+    pub fn get_quiz_question(env: Env, module_id: String, question_id: u32) -> QuizQuestion {
+        functions::quiz::get_quiz_question(&env, module_id, question_id)
+    }
+
+    /// Attach an assignment to a module or replace its rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module holding the assignment
+    /// * `deadline` - Ledger timestamp after which submissions are late
+    /// * `max_submissions` - Submissions allowed per student
+    /// * `late_policy` - Whether late submissions are rejected, accepted or penalized
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Assignment` configuration.
+    pub fn set_assignment(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        deadline: u64,
+        max_submissions: u32,
+        late_policy: LatePolicy,
+    ) -> Assignment {
+        functions::assignment::set_assignment(
+            &env,
+            caller,
+            module_id,
+            deadline,
+            max_submissions,
+            late_policy,
+        )
+    }
+
+    /// Submit work to the assignment of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The submitting student
+    /// * `module_id` - The module holding the assignment
+    /// * `file_hash` - Hash of the submitted file
+    ///
+    /// # Returns
+    ///
+    /// Returns the recorded `AssignmentSubmission`.
+    pub fn submit_assignment(
+        env: Env,
+        student: Address,
+        module_id: String,
+        file_hash: BytesN<32>,
+    ) -> AssignmentSubmission {
+        functions::assignment::submit_assignment(&env, student, module_id, file_hash)
+    }
+
+    /// Get the assignment configuration of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The module holding the assignment
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Assignment`.
+    pub fn get_assignment(env: Env, module_id: String) -> Assignment {
+        functions::assignment::get_assignment(&env, module_id)
+    }
+
+    /// Get a student's submissions to the assignment of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The module holding the assignment
+    /// * `student` - The student
+    ///
+    /// # Returns
+    ///
+    /// Returns the submissions, oldest first.
+    pub fn get_submissions(env: Env, module_id: String, student: Address) -> Vec<AssignmentSubmission> {
+        functions::assignment::get_submissions(&env, module_id, student)
+    }
+
+    /// Schedule a live session for a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module the session belongs to
+    /// * `start_time` - Ledger timestamp the session opens at
+    /// * `end_time` - Ledger timestamp the session closes at
+    /// * `join_uri_hash` - Hash of the join URI
+    ///
+    /// # Returns
+    ///
+    /// Returns the `LiveSession` schedule.
+    pub fn set_live_session(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        start_time: u64,
+        end_time: u64,
+        join_uri_hash: BytesN<32>,
+    ) -> LiveSession {
+        functions::live_session::set_live_session(
+            &env,
+            caller,
+            module_id,
+            start_time,
+            end_time,
+            join_uri_hash,
+        )
+    }
+
+    /// Get the schedule of the live session of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The module the session belongs to
+    ///
+    /// # Returns
+    ///
+    /// Returns the `LiveSession` schedule.
+    pub fn get_live_session(env: Env, module_id: String) -> LiveSession {
+        functions::live_session::get_live_session(&env, module_id)
+    }
+
+    /// Get the join information of a running live session.
+    ///
+    /// Only students who may read the module get it, and only between the
+    /// session's start and end times.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The attending student
+    /// * `module_id` - The module the session belongs to
+    ///
+    /// # Returns
+    ///
+    /// Returns the join URI hash.
+    pub fn join_live_session(env: Env, student: Address, module_id: String) -> BytesN<32> {
+        functions::live_session::join_live_session(&env, student, module_id)
+    }
+
+    /// Reorder the modules of a course.
+    ///
+    /// The modules are renumbered to positions `0..n` following `new_order`,
+    /// which must list every module of the course exactly once.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `new_order` - The module IDs in their new order
+    ///
+    /// # Returns
+    ///
+    /// Returns the modules in their new order.
+    pub fn reorder_modules(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        new_order: Vec<String>,
+    ) -> Vec<CourseModule> {
+        functions::reorder_modules::reorder_modules(&env, caller, course_id, new_order)
+    }
+
+    /// Get all modules of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course modules sorted by position.
+    pub fn get_modules(env: Env, course_id: u128) -> Vec<CourseModule> {
+        functions::reorder_modules::get_modules(&env, course_id)
+    }
+
+    /// Set the duration of a module that has no lessons.
+    ///
+    /// Modules with lessons take their duration from their lessons. The
+    /// course totals are updated either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module
+    /// * `duration_minutes` - Expected time to complete the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseModule`.
+    pub fn set_module_duration(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        duration_minutes: u32,
+    ) -> CourseModule {
+        functions::course_totals::set_module_duration(&env, caller, module_id, duration_minutes)
+    }
+
+    /// Get the module count, lesson count and total duration of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the `CourseStats` of the course.
+    pub fn get_course_stats(env: Env, course_id: u128) -> CourseStats {
+        functions::course_totals::get_course_stats(&env, course_id)
+    }
+
+    /// Get the full outline of a course in one call.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course with its modules, sorted by position, and a summary
+    /// of each module's lessons.
+    pub fn get_course_outline(env: Env, course_id: u128) -> CourseOutline {
+        functions::course_outline::get_course_outline(&env, course_id)
+    }
+
+    /// Add a lesson to the end of a module.
+    ///
+    /// The lesson count and duration of the module and its course are
+    /// updated accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module the lesson belongs to
+    /// * `title` - The lesson title
+    /// * `content_type` - Video, text, quiz or assignment
+    /// * `content_uri` - Optional `ipfs://`, `ar://` or `https://` content URI
+    /// * `duration_minutes` - Expected time to complete the lesson
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `Lesson`.
+    pub fn add_lesson(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        title: String,
+        content_type: LessonContentType,
+        content_uri: Option<String>,
+        duration_minutes: u32,
+    ) -> Lesson {
+        functions::lessons::add_lesson(
+            &env,
+            caller,
+            module_id,
+            title,
+            content_type,
+            content_uri,
+            duration_minutes,
+        )
+    }
+
+    /// Edit a lesson.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `lesson_id` - The unique identifier of the lesson
+    /// * `params` - The fields to update
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Lesson`.
+    pub fn edit_lesson(env: Env, caller: Address, lesson_id: u128, params: EditLessonParams) -> Lesson {
+        functions::lessons::edit_lesson(&env, caller, lesson_id, params)
+    }
+
+    /// Change the kind of content a lesson delivers.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `lesson_id` - The unique identifier of the lesson
+    /// * `content_type` - The new content type
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Lesson`.
+    pub fn set_lesson_content_type(
+        env: Env,
+        caller: Address,
+        lesson_id: u128,
+        content_type: LessonContentType,
+    ) -> Lesson {
+        functions::lessons::set_lesson_content_type(&env, caller, lesson_id, content_type)
+    }
+
+    /// Set or clear the content hash of a lesson.
+    ///
+    /// A `lesnHash` event is emitted whenever the hash changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `lesson_id` - The unique identifier of the lesson
+    /// * `content_hash` - SHA-256 of the off-chain content, or `None` to clear it
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Lesson`.
+    pub fn set_lesson_content_hash(
+        env: Env,
+        caller: Address,
+        lesson_id: u128,
+        content_hash: Option<BytesN<32>>,
+    ) -> Lesson {
+        functions::lessons::set_lesson_content_hash(&env, caller, lesson_id, content_hash)
+    }
+
+    /// Attach a downloadable resource to a lesson.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `lesson_id` - The unique identifier of the lesson
+    /// * `resource` - The resource label, URI and optional hash
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Lesson`.
+    pub fn add_lesson_resource(env: Env, caller: Address, lesson_id: u128, resource: ResourceLink) -> Lesson {
+        functions::lesson_resources::add_lesson_resource(&env, caller, lesson_id, resource)
+    }
+
+    /// Remove a resource from a lesson.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `lesson_id` - The unique identifier of the lesson
+    /// * `index` - The position of the resource in the lesson's list
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Lesson`.
+    pub fn remove_lesson_resource(env: Env, caller: Address, lesson_id: u128, index: u32) -> Lesson {
+        functions::lesson_resources::remove_lesson_resource(&env, caller, lesson_id, index)
+    }
+
+    /// Remove a lesson from its module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `lesson_id` - The unique identifier of the lesson
+    pub fn remove_lesson(env: Env, caller: Address, lesson_id: u128) {
+        functions::lessons::remove_lesson(&env, caller, lesson_id)
+    }
+
+    /// Reorder the lessons of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The module whose lessons are reordered
+    /// * `new_order` - Every lesson ID of the module, in the new order
+    ///
+    /// # Returns
+    ///
+    /// Returns the lessons in their new order.
+    pub fn reorder_lessons(env: Env, caller: Address, module_id: String, new_order: Vec<u128>) -> Vec<Lesson> {
+        functions::lessons::reorder_lessons(&env, caller, module_id, new_order)
+    }
+
+    /// Get a lesson by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `lesson_id` - The unique identifier of the lesson
+    ///
+    /// # Returns
+    ///
+    /// Returns the requested `Lesson`.
+    pub fn get_lesson(env: Env, lesson_id: u128) -> Lesson {
+        functions::lessons::get_lesson(&env, lesson_id)
+    }
+
+    /// Get the lessons of a module in display order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the module's lessons.
+    pub fn get_module_lessons(env: Env, module_id: String) -> Vec<Lesson> {
+        functions::lessons::get_module_lessons(&env, module_id)
+    }
+
+    /// Set when a module opens to enrolled students.
+    ///
+    /// Modules can open immediately, at a fixed timestamp, or a number of
+    /// days after each student enrolled (drip release).
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module
+    /// * `release` - The release rule of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseModule`.
+    pub fn set_module_release(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        release: ModuleRelease,
+    ) -> CourseModule {
+        functions::module_access::set_module_release(&env, caller, module_id, release)
+    }
+
+    /// Mark a module as a free preview readable without enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module
+    /// * `is_preview` - Whether the module is a free preview
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseModule`.
+    pub fn set_module_preview(env: Env, caller: Address, module_id: String, is_preview: bool) -> CourseModule {
+        functions::module_access::set_module_preview(&env, caller, module_id, is_preview)
+    }
+
+    /// Require students to complete modules in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `enabled` - Whether each module is gated behind the previous one
+    pub fn set_sequential_unlock(env: Env, caller: Address, course_id: u128, enabled: bool) {
+        functions::module_access::set_sequential_unlock(&env, caller, course_id, enabled)
+    }
+
+    /// Check whether a course requires modules to be completed in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if sequential unlock is enabled.
+    pub fn is_sequential_unlock(env: Env, course_id: u128) -> bool {
+        functions::module_access::is_sequential_unlock(&env, course_id)
+    }
+
+    /// Mark a module as completed by a student.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the student
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the completion timestamp.
+    pub fn complete_module(env: Env, student: Address, module_id: String) -> u64 {
+        functions::module_progress::complete_module(&env, student, module_id)
+    }
+
+    /// Get when a student completed a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the completion timestamp, or `None` if not completed.
+    pub fn get_module_completion(env: Env, module_id: String, student: Address) -> Option<u64> {
+        functions::module_progress::get_module_completion(&env, module_id, student)
+    }
+
+    /// Grant or deny a module to a specific address, or clear the override.
+    ///
+    /// Overrides take precedence over enrollment, release schedules, previews
+    /// and sequential unlock.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `module_id` - The unique identifier of the module
+    /// * `user` - The address the override applies to
+    /// * `access` - `Grant`, `Deny`, or `None` to clear the override
+    pub fn set_module_override(
+        env: Env,
+        caller: Address,
+        module_id: String,
+        user: Address,
+        access: Option<ModuleOverride>,
+    ) {
+        functions::module_overrides::set_module_override(&env, caller, module_id, user, access)
+    }
+
+    /// Get the override of a module for one address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    /// * `user` - The address to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns the override, or `None` if the address has none.
+    pub fn get_module_override(env: Env, module_id: String, user: Address) -> Option<ModuleOverride> {
+        functions::module_overrides::get_module_override(&env, module_id, user)
+    }
+
+    /// Get every override of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns the overrides keyed by address.
+    pub fn get_module_overrides(env: Env, module_id: String) -> Map<Address, ModuleOverride> {
+        functions::module_overrides::get_module_overrides(&env, module_id)
+    }
+
+    /// Check whether a student may read the content of a module.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the student
+    /// * `module_id` - The unique identifier of the module
+    ///
+    /// # Returns
+    ///
+    /// Returns `Granted`, `Denied`, `NotEnrolled`, `Locked` with the unlock
+    /// timestamp, or `BlockedBy` with the module that must be completed first.
+    pub fn check_module_access(env: Env, student: Address, module_id: String) -> ModuleAccess {
+        functions::module_access::check_module_access(&env, student, module_id)
+    }
+
+    /// Get when each module of a course opens for a student.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns one `ModuleUnlock` per module, sorted by position.
+    pub fn get_unlock_schedule(env: Env, course_id: u128, student: Address) -> Vec<ModuleUnlock> {
+        functions::module_access::get_unlock_schedule(&env, course_id, student)
+    }
+
+    /// Enroll in a published course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolling student
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The token to pay with, once payments are configured
+    /// * `amount` - The amount to pay for pay-what-you-want courses (defaults to the price)
+    /// * `coupon` - An optional coupon code
+    /// * `referrer` - An optional affiliate who referred the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn enroll(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        token: Option<Address>,
+        amount: Option<u128>,
+        coupon: Option<String>,
+        referrer: Option<Address>,
+    ) -> Enrollment {
+        functions::enrollment::enroll(&env, student, course_id, token, amount, coupon, referrer)
+    }
+
+    /// Buy a course for another address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `payer` - The address paying for the course
+    /// * `recipient` - The address to enroll
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the recipient's new `Enrollment`.
+    pub fn gift_course(
+        env: Env,
+        payer: Address,
+        recipient: Address,
+        course_id: u128,
+        token: Option<Address>,
+    ) -> Enrollment {
+        functions::gifts::gift_course(&env, payer, recipient, course_id, token)
+    }
+
+    /// Buy a course for an address that has not registered yet, held until claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `payer` - The address paying for the course
+    /// * `recipient` - The address the gift is for
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The token to pay with
+    /// * `claim_period` - Seconds the recipient has to claim the gift
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored `PendingGift`.
+    pub fn create_pending_gift(
+        env: Env,
+        payer: Address,
+        recipient: Address,
+        course_id: u128,
+        token: Option<Address>,
+        claim_period: u64,
+    ) -> PendingGift {
+        functions::gifts::create_pending_gift(
+            &env,
+            payer,
+            recipient,
+            course_id,
+            token,
+            claim_period,
+        )
+    }
+
+    /// Claim a pending gift and enroll in the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `recipient` - The address the gift is for
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn claim_gift(env: Env, recipient: Address, course_id: u128) -> Enrollment {
+        functions::gifts::claim_gift(&env, recipient, course_id)
+    }
+
+    /// Take back the funds of an expired, unclaimed gift.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `payer` - The address that paid for the gift
+    /// * `course_id` - The unique identifier of the course
+    /// * `recipient` - The address the gift was for
+    ///
+    /// # Returns
+    ///
+    /// Returns the reclaimed `PendingGift`.
+    pub fn reclaim_gift(
+        env: Env,
+        payer: Address,
+        course_id: u128,
+        recipient: Address,
+    ) -> PendingGift {
+        functions::gifts::reclaim_gift(&env, payer, course_id, recipient)
+    }
+
+    /// Get the pending gift of a course for a recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `recipient` - The address the gift is for
+    ///
+    /// # Returns
+    ///
+    /// Returns the `PendingGift`, or `None` if there is none.
+    pub fn get_pending_gift(env: Env, course_id: u128, recipient: Address) -> Option<PendingGift> {
+        functions::gifts::get_pending_gift(&env, course_id, recipient)
+    }
+
+    /// Set the discount organizations get when buying many seats of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `discount` - The volume discount, or `None` to remove it
+    pub fn set_volume_discount(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        discount: Option<VolumeDiscount>,
+    ) {
+        functions::seats::set_volume_discount(&env, caller, course_id, discount)
+    }
+
+    /// Get the volume discount of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the `VolumeDiscount`, or `None` if there is none.
+    pub fn get_volume_discount(env: Env, course_id: u128) -> Option<VolumeDiscount> {
+        functions::seats::get_volume_discount(&env, course_id)
+    }
+
+    /// Buy seats of a course for an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `org` - The address of the organization
+    /// * `course_id` - The unique identifier of the course
+    /// * `seat_count` - The number of seats to buy
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the organization's `SeatPool` for the course.
+    pub fn purchase_seats(
+        env: Env,
+        org: Address,
+        course_id: u128,
+        seat_count: u32,
+        token: Option<Address>,
+    ) -> SeatPool {
+        functions::seats::purchase_seats(&env, org, course_id, seat_count, token)
+    }
+
+    /// Enroll a member of an organization on one of its seats.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `org` - The address of the organization
+    /// * `course_id` - The unique identifier of the course
+    /// * `member` - The address to enroll
+    ///
+    /// # Returns
+    ///
+    /// Returns the member's new `Enrollment`.
+    pub fn assign_seat(env: Env, org: Address, course_id: u128, member: Address) -> Enrollment {
+        functions::seats::assign_seat(&env, org, course_id, member)
+    }
+
+    /// Get the seats an organization bought for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `org` - The address of the organization
+    ///
+    /// # Returns
+    ///
+    /// Returns the `SeatPool`, or `None` if the organization bought no seats.
+    pub fn get_seat_pool(env: Env, course_id: u128, org: Address) -> Option<SeatPool> {
+        functions::seats::get_seat_pool(&env, course_id, org)
+    }
+
+    /// Get the former prices of a course, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns each former price with the time it was replaced.
+    pub fn get_price_history(env: Env, course_id: u128) -> Vec<PriceChange> {
+        functions::price_history::get_price_history(&env, course_id)
+    }
+
+    /// Set the token accepted for course payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin configuring payments
+    /// * `token` - The address of the token contract to accept
+    pub fn set_payment_token(env: Env, caller: Address, token: Address) {
+        functions::payments::set_payment_token(&env, caller, token)
+    }
+
+    /// Get the token accepted for course payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the accepted token, or `None` while enrollment is free.
+    pub fn get_payment_token(env: Env) -> Option<Address> {
+        functions::payments::get_payment_token(&env)
+    }
+
+    /// Set the platform fee taken from course payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin configuring payments
+    /// * `fee_bps` - The fee rate in basis points (0 to 10000)
+    /// * `treasury` - The address treasury withdrawals are sent to
+    pub fn set_platform_fee(env: Env, caller: Address, fee_bps: u32, treasury: Address) {
+        functions::payments::set_platform_fee(&env, caller, fee_bps, treasury)
+    }
+
+    /// Get the platform fee taken from course payments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the fee rate and treasury, or `None` if no fee is taken.
+    pub fn get_platform_fee(env: Env) -> Option<PlatformFee> {
+        functions::payments::get_platform_fee(&env)
+    }
+
+    /// Send platform revenue in a token to the treasury address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin withdrawing
+    /// * `token` - The token to withdraw
+    /// * `amount` - The amount to withdraw
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `TreasuryAccount` of the token.
+    pub fn withdraw_treasury(
+        env: Env,
+        caller: Address,
+        token: Address,
+        amount: u128,
+    ) -> TreasuryAccount {
+        functions::treasury::withdraw_treasury(&env, caller, token, amount)
+    }
+
+    /// Get the platform revenue received and withdrawn in a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `token` - The token to look up
+    ///
+    /// # Returns
+    ///
+    /// Returns the `TreasuryAccount` of the token.
+    pub fn get_treasury_account(env: Env, token: Address) -> TreasuryAccount {
+        functions::treasury::get_treasury_account(&env, token)
+    }
+
+    /// Get every token the platform treasury has received revenue in.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the token addresses, in order of first income.
+    pub fn get_treasury_tokens(env: Env) -> Vec<Address> {
+        functions::treasury::get_treasury_tokens(&env)
+    }
+
+    /// Split the revenue of a course among its co-instructors.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course owner
+    /// * `course_id` - The ID of the course
+    /// * `shares` - Each co-instructor's share in basis points, adding up to
+    ///   10000 (an empty map gives all revenue back to the creator)
+    pub fn set_revenue_split(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        shares: Map<Address, u32>,
+    ) {
+        functions::royalty_splits::set_revenue_split(&env, creator, course_id, shares)
+    }
+
+    /// Get the revenue split of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The ID of the course
+    ///
+    /// # Returns
+    ///
+    /// Each co-instructor's share in basis points; empty if the creator gets it all.
+    pub fn get_revenue_split(env: Env, course_id: u128) -> Map<Address, u32> {
+        functions::royalty_splits::get_revenue_split(&env, course_id)
+    }
+
+    /// Set how long payments stay in escrow, refundable by the student.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin configuring payments
+    /// * `seconds` - The length of the refund window (0 disables escrow)
+    pub fn set_refund_window(env: Env, caller: Address, seconds: u64) {
+        functions::payments::set_refund_window(&env, caller, seconds)
+    }
+
+    /// Get the refund window.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the refund window in seconds.
+    pub fn get_refund_window(env: Env) -> u64 {
+        functions::payments::get_refund_window(&env)
+    }
+
+    /// Refund an escrowed payment and cancel the enrollment, or request a
+    /// refund from the instructor after the refund window.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolled student
+    /// * `course_id` - The unique identifier of the course
+    /// * `reason` - Why a refund is wanted; required after the refund window
+    ///
+    /// # Returns
+    ///
+    /// Returns the refunded `Payment`, or the released one when a request was opened.
+    pub fn request_refund(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        reason: Option<String>,
+    ) -> Payment {
+        functions::escrow::request_refund(&env, student, course_id, reason)
+    }
+
+    /// Check whether a student can claim a refund because the course was taken down.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the payment qualifies for `claim_unpublish_refund`.
+    pub fn is_unpublish_refund_eligible(env: Env, course_id: u128, student: Address) -> bool {
+        functions::unpublish_refunds::is_unpublish_refund_eligible(&env, course_id, student)
+    }
+
+    /// Claim a refund for a course unpublished or archived shortly after paying.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolled student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the refunded `Payment`.
+    pub fn claim_unpublish_refund(env: Env, student: Address, course_id: u128) -> Payment {
+        functions::unpublish_refunds::claim_unpublish_refund(&env, student, course_id)
+    }
+
+    /// Approve a refund request, paying it from the instructor's earnings.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student who requested the refund
+    ///
+    /// # Returns
+    ///
+    /// Returns the approved `RefundRequest`.
+    pub fn approve_refund(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        student: Address,
+    ) -> RefundRequest {
+        functions::refund_requests::approve_refund(&env, caller, course_id, student)
+    }
+
+    /// Reject a refund request with a reason.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student who requested the refund
+    /// * `reason` - Why the refund is rejected
+    ///
+    /// # Returns
+    ///
+    /// Returns the rejected `RefundRequest`.
+    pub fn reject_refund(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        student: Address,
+        reason: String,
+    ) -> RefundRequest {
+        functions::refund_requests::reject_refund(&env, caller, course_id, student, reason)
+    }
+
+    /// Get a student's refund request for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the `RefundRequest`, or `None` if the student made none.
+    pub fn get_refund_request(
+        env: Env,
+        course_id: u128,
+        student: Address,
+    ) -> Option<RefundRequest> {
+        functions::refund_requests::get_refund_request(&env, course_id, student)
+    }
+
+    /// Release an escrowed payment once its refund window has ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student who paid
+    ///
+    /// # Returns
+    ///
+    /// Returns the released `Payment`.
+    pub fn release_payment(env: Env, course_id: u128, student: Address) -> Payment {
+        functions::escrow::release_payment(&env, course_id, student)
+    }
+
+    /// Get the payment a student made for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Payment`, or `None` if the student did not pay.
+    pub fn get_payment(env: Env, course_id: u128, student: Address) -> Option<Payment> {
+        functions::payments::get_payment(&env, course_id, student)
+    }
+
+    /// Turn pay-what-you-want pricing on or off for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `enabled` - Whether the price is only a minimum
+    pub fn set_pay_what_you_want(env: Env, caller: Address, course_id: u128, enabled: bool) {
+        functions::payments::set_pay_what_you_want(&env, caller, course_id, enabled)
+    }
+
+    /// Check whether a course uses pay-what-you-want pricing.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if students may pay more than the price.
+    pub fn is_pay_what_you_want(env: Env, course_id: u128) -> bool {
+        functions::payments::is_pay_what_you_want(&env, course_id)
+    }
+
+    /// Mint a coupon for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - The SHA-256 hash of the coupon code
+    /// * `discount` - The price reduction granted
+    /// * `max_uses` - How many enrollments may use the coupon
+    /// * `expires_at` - Ledger timestamp from which the coupon is rejected
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Coupon`.
+    pub fn create_coupon(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        code_hash: BytesN<32>,
+        discount: Discount,
+        max_uses: u32,
+        expires_at: u64,
+    ) -> Coupon {
+        functions::coupons::create_coupon(
+            &env,
+            caller,
+            course_id,
+            code_hash,
+            discount,
+            max_uses,
+            expires_at,
+        )
+    }
+
+    /// Delete a coupon.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - The SHA-256 hash of the coupon code
+    pub fn revoke_coupon(env: Env, caller: Address, course_id: u128, code_hash: BytesN<32>) {
+        functions::coupons::revoke_coupon(&env, caller, course_id, code_hash)
+    }
+
+    /// Get a coupon by the hash of its code.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - The SHA-256 hash of the coupon code
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Coupon`, or `None` if it does not exist.
+    pub fn get_coupon(env: Env, course_id: u128, code_hash: BytesN<32>) -> Option<Coupon> {
+        functions::coupons::get_coupon(&env, course_id, code_hash)
+    }
+
+    /// Set the prices of a course in tokens other than the payment token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `prices` - The amount per token; empty to remove the price list
+    pub fn set_course_prices(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        prices: Map<Address, u128>,
+    ) {
+        functions::price_list::set_course_prices(&env, caller, course_id, prices)
+    }
+
+    /// Get the prices of a course in tokens other than the payment token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount per token.
+    pub fn get_course_prices(env: Env, course_id: u128) -> Map<Address, u128> {
+        functions::price_list::get_course_prices(&env, course_id)
+    }
+
+    /// Set the price oracle used to convert USD prices to tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin
+    /// * `oracle` - The address of the oracle contract
+    pub fn set_price_oracle(env: Env, caller: Address, oracle: Address) {
+        functions::usd_pricing::set_price_oracle(&env, caller, oracle)
+    }
+
+    /// Get the price oracle.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the oracle address, or `None` if none is set.
+    pub fn get_price_oracle(env: Env) -> Option<Address> {
+        functions::usd_pricing::get_price_oracle(&env)
+    }
+
+    /// Price a course in US cents, or remove its USD price.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `usd_cents` - The price in US cents, or `None` to remove it
+    pub fn set_usd_price(env: Env, caller: Address, course_id: u128, usd_cents: Option<u128>) {
+        functions::usd_pricing::set_usd_price(&env, caller, course_id, usd_cents)
+    }
+
+    /// Get the price of a course in US cents.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the price in US cents, or `None` if the course is not priced in USD.
+    pub fn get_usd_price(env: Env, course_id: u128) -> Option<u128> {
+        functions::usd_pricing::get_usd_price(&env, course_id)
+    }
+
+    /// Schedule a time-limited sale price for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `price` - The discounted price
+    /// * `starts_at` - Ledger timestamp the sale starts at
+    /// * `ends_at` - Ledger timestamp the sale ends at (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// Returns the scheduled `Sale`.
+    pub fn set_sale(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        price: u128,
+        starts_at: u64,
+        ends_at: u64,
+    ) -> Sale {
+        functions::sales::set_sale(&env, caller, course_id, price, starts_at, ends_at)
+    }
+
+    /// Cancel the sale of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    pub fn clear_sale(env: Env, caller: Address, course_id: u128) {
+        functions::sales::clear_sale(&env, caller, course_id)
+    }
+
+    /// Get the sale scheduled for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Sale`, or `None` if no sale is scheduled.
+    pub fn get_sale(env: Env, course_id: u128) -> Option<Sale> {
+        functions::sales::get_sale(&env, course_id)
+    }
+
+    /// Create a bundle of courses sold at one price.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - An admin or the creator of every course in the bundle
+    /// * `course_ids` - The courses included in the bundle
+    /// * `price` - The bundle price
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Bundle`.
+    pub fn create_bundle(env: Env, caller: Address, course_ids: Vec<u128>, price: u128) -> Bundle {
+        functions::bundles::create_bundle(&env, caller, course_ids, price)
+    }
+
+    /// Get a bundle by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `bundle_id` - The unique identifier of the bundle
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Bundle`.
+    pub fn get_bundle(env: Env, bundle_id: u128) -> Bundle {
+        functions::bundles::get_bundle(&env, bundle_id)
+    }
+
+    /// Buy a bundle, enrolling the student in every course it includes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the buying student
+    /// * `bundle_id` - The unique identifier of the bundle
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the new enrollments, in bundle order.
+    pub fn purchase_bundle(
+        env: Env,
+        student: Address,
+        bundle_id: u128,
+        token: Option<Address>,
+    ) -> Vec<Enrollment> {
+        functions::bundles::purchase_bundle(&env, student, bundle_id, token)
+    }
+
+    /// Create a subscription plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin creating the plan
+    /// * `name` - The plan name
+    /// * `price` - The price charged per period
+    /// * `period` - The length of one period in seconds
+    /// * `course_ids` - The courses covered by the plan; empty for the whole catalog
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `SubscriptionPlan`.
+    pub fn create_plan(
+        env: Env,
+        caller: Address,
+        name: String,
+        price: u128,
+        period: u64,
+        course_ids: Vec<u128>,
+    ) -> SubscriptionPlan {
+        functions::subscriptions::create_plan(&env, caller, name, price, period, course_ids)
+    }
+
+    /// Open or close a plan to new subscriptions and renewals.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin
+    /// * `plan_id` - The unique identifier of the plan
+    /// * `active` - Whether the plan accepts subscriptions
+    pub fn set_plan_active(env: Env, caller: Address, plan_id: u128, active: bool) {
+        functions::subscriptions::set_plan_active(&env, caller, plan_id, active)
+    }
+
+    /// Get a subscription plan by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `plan_id` - The unique identifier of the plan
+    ///
+    /// # Returns
+    ///
+    /// Returns the `SubscriptionPlan`.
+    pub fn get_plan(env: Env, plan_id: u128) -> SubscriptionPlan {
+        functions::subscriptions::get_plan(&env, plan_id)
+    }
+
+    /// Subscribe to a plan for one period.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `subscriber` - The address of the subscribing student
+    /// * `plan_id` - The unique identifier of the plan
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Subscription`.
+    pub fn subscribe(
+        env: Env,
+        subscriber: Address,
+        plan_id: u128,
+        token: Option<Address>,
+    ) -> Subscription {
+        functions::subscriptions::subscribe(&env, subscriber, plan_id, token)
+    }
+
+    /// Extend a subscription by one period.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `subscriber` - The address of the subscriber
+    /// * `plan_id` - The unique identifier of the plan
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the extended `Subscription`.
+    pub fn renew(env: Env, subscriber: Address, plan_id: u128, token: Option<Address>) -> Subscription {
+        functions::subscriptions::renew(&env, subscriber, plan_id, token)
+    }
+
+    /// Get a subscriber's subscription to a plan.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `subscriber` - The address of the subscriber
+    /// * `plan_id` - The unique identifier of the plan
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Subscription`, running or lapsed, or `None`.
+    pub fn get_subscription(env: Env, subscriber: Address, plan_id: u128) -> Option<Subscription> {
+        functions::subscriptions::get_subscription(&env, subscriber, plan_id)
+    }
+
+    /// Check whether a student may study a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the student is enrolled or holds a running
+    /// subscription covering the course.
+    pub fn has_course_access(env: Env, course_id: u128, student: Address) -> bool {
+        functions::subscriptions::has_course_access(&env, course_id, student)
+    }
+
+    /// Offer a course in installments, or stop offering it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `plan` - The installment terms, or `None` to stop offering installments
+    pub fn set_installment_plan(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        plan: Option<InstallmentPlan>,
+    ) {
+        functions::installments::set_installment_plan(&env, caller, course_id, plan)
+    }
+
+    /// Get the installment plan offered for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the `InstallmentPlan`, or `None` if installments are not offered.
+    pub fn get_installment_plan(env: Env, course_id: u128) -> Option<InstallmentPlan> {
+        functions::installments::get_installment_plan(&env, course_id)
+    }
+
+    /// Enroll in a course paying the first of its installments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolling student
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the student's `InstallmentSchedule`.
+    pub fn enroll_with_installments(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        token: Option<Address>,
+    ) -> InstallmentSchedule {
+        functions::installments::enroll_with_installments(&env, student, course_id, token)
+    }
+
+    /// Pay the next installment of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the paying student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `InstallmentSchedule`.
+    pub fn pay_installment(env: Env, student: Address, course_id: u128) -> InstallmentSchedule {
+        functions::installments::pay_installment(&env, student, course_id)
+    }
+
+    /// Get a student's installment schedule for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the `InstallmentSchedule`, or `None` if the student does not
+    /// pay the course in installments.
+    pub fn get_installment_schedule(
+        env: Env,
+        course_id: u128,
+        student: Address,
+    ) -> Option<InstallmentSchedule> {
+        functions::installments::get_installment_schedule(&env, course_id, student)
+    }
+
+    /// Get the earnings an instructor has not withdrawn yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The address of the instructor
+    /// * `token` - The token the earnings are held in
+    ///
+    /// # Returns
+    ///
+    /// Returns the withdrawable balance.
+    pub fn get_earnings(env: Env, instructor: Address, token: Address) -> u128 {
+        functions::earnings::get_earnings(&env, instructor, token)
+    }
+
+    /// Send a tip to an instructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `from` - The address sending the tip
+    /// * `instructor` - The address of the instructor
+    /// * `token` - The token to tip in
+    /// * `amount` - The tip amount
+    /// * `course_id` - Optional course the tip is for
+    pub fn tip_instructor(
+        env: Env,
+        from: Address,
+        instructor: Address,
+        token: Address,
+        amount: u128,
+        course_id: Option<u128>,
+    ) {
+        functions::tips::tip_instructor(&env, from, instructor, token, amount, course_id)
+    }
+
+    /// Get the tips received for a course in a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The token the tips were sent in
+    ///
+    /// # Returns
+    ///
+    /// Returns the number and total amount of tips.
+    pub fn get_course_tips(env: Env, course_id: u128, token: Address) -> TipTotals {
+        functions::tips::get_course_tips(&env, course_id, token)
+    }
+
+    /// Register an affiliate of a course with a commission, or remove them.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `affiliate` - The address of the affiliate
+    /// * `commission_bps` - The commission in basis points, or `None` to remove the affiliate
+    pub fn set_affiliate(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        affiliate: Address,
+        commission_bps: Option<u32>,
+    ) {
+        functions::affiliates::set_affiliate(&env, caller, course_id, affiliate, commission_bps)
+    }
+
+    /// Get the commission of an affiliate of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `affiliate` - The address of the affiliate
+    ///
+    /// # Returns
+    ///
+    /// Returns the commission in basis points, or `None` if the address is not an affiliate.
+    pub fn get_affiliate_commission(env: Env, course_id: u128, affiliate: Address) -> Option<u32> {
+        functions::affiliates::get_affiliate_commission(&env, course_id, affiliate)
+    }
+
+    /// Get the commissions ever credited to an affiliate in a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `affiliate` - The address of the affiliate
+    /// * `token` - The token the commissions were paid in
+    ///
+    /// # Returns
+    ///
+    /// Returns the lifetime commissions.
+    pub fn get_affiliate_earnings(env: Env, affiliate: Address, token: Address) -> u128 {
+        functions::affiliates::get_affiliate_earnings(&env, affiliate, token)
+    }
+
+    /// Open a scholarship pool for a course or a category.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `sponsor` - The address opening the pool
+    /// * `target` - The course or category the pool pays for
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `ScholarshipPool`.
+    pub fn create_scholarship_pool(
+        env: Env,
+        sponsor: Address,
+        target: ScholarshipTarget,
+    ) -> ScholarshipPool {
+        functions::scholarships::create_scholarship_pool(&env, sponsor, target)
+    }
+
+    /// Deposit tokens into a scholarship pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `sponsor` - The address of the sponsor
+    /// * `pool_id` - The unique identifier of the pool
+    /// * `amount` - The amount to deposit
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `ScholarshipPool`.
+    pub fn fund_scholarship_pool(
+        env: Env,
+        sponsor: Address,
+        pool_id: u128,
+        amount: u128,
+    ) -> ScholarshipPool {
+        functions::scholarships::fund_scholarship_pool(&env, sponsor, pool_id, amount)
+    }
+
+    /// Allow-list a student for a scholarship from a pool, or remove them.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `pool_id` - The unique identifier of the pool
+    /// * `student` - The address of the student
+    /// * `eligible` - Whether the student may use the pool
+    pub fn set_scholarship_eligible(
+        env: Env,
+        caller: Address,
+        pool_id: u128,
+        student: Address,
+        eligible: bool,
+    ) {
+        functions::scholarships::set_scholarship_eligible(&env, caller, pool_id, student, eligible)
+    }
+
+    /// Check whether a student is allow-listed for a scholarship from a pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `pool_id` - The unique identifier of the pool
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the student may use the pool.
+    pub fn is_scholarship_eligible(env: Env, pool_id: u128, student: Address) -> bool {
+        functions::scholarships::is_scholarship_eligible(&env, pool_id, student)
+    }
+
+    /// Enroll in a course with a scholarship pool paying the price.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolling student
+    /// * `course_id` - The unique identifier of the course
+    /// * `pool_id` - The unique identifier of the pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn enroll_with_scholarship(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        pool_id: u128,
+    ) -> Enrollment {
+        functions::scholarships::enroll_with_scholarship(&env, student, course_id, pool_id)
+    }
+
+    /// Get a scholarship pool by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `pool_id` - The unique identifier of the pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the `ScholarshipPool`.
+    pub fn get_scholarship_pool(env: Env, pool_id: u128) -> ScholarshipPool {
+        functions::scholarships::get_scholarship_pool(&env, pool_id)
+    }
+
+    /// Get how much a sponsor has deposited into a pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `pool_id` - The unique identifier of the pool
+    /// * `sponsor` - The address of the sponsor
+    ///
+    /// # Returns
+    ///
+    /// Returns the total deposited.
+    pub fn get_sponsor_contribution(env: Env, pool_id: u128, sponsor: Address) -> u128 {
+        functions::scholarships::get_sponsor_contribution(&env, pool_id, sponsor)
+    }
+
+    /// Get the total earnings ever credited for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the lifetime earnings of the course.
+    pub fn get_course_earnings(env: Env, course_id: u128) -> u128 {
+        functions::earnings::get_course_earnings(&env, course_id)
+    }
+
+    /// Withdraw everything an instructor has earned in a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `instructor` - The address of the instructor
+    /// * `token` - The token to withdraw
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount transferred.
+    pub fn withdraw_earnings(env: Env, instructor: Address, token: Address) -> u128 {
+        functions::earnings::withdraw_earnings(&env, instructor, token)
+    }
+
+    /// Get the enrollment of a student in a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Enrollment`, or `None` if the student is not enrolled.
+    pub fn get_enrollment(env: Env, course_id: u128, student: Address) -> Option<Enrollment> {
+        functions::enrollment::get_enrollment(&env, course_id, student)
+    }
+
+    /// Check whether a student is enrolled in a course with active access.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the student holds an active enrollment in the course.
+    pub fn is_enrolled(env: Env, student: Address, course_id: u128) -> bool {
+        functions::enrollment::is_enrolled(&env, course_id, &student)
+    }
+
+    /// Give a student free access to a course, recorded as a granted enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student to give access to
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn grant_access(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        student: Address,
+    ) -> Enrollment {
+        functions::access_grants::grant_access(&env, creator, course_id, student)
+    }
+
+    /// Revoke a student's access to a course, keeping the enrollment marked as revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student losing access
+    /// * `reason` - Why access is revoked, recorded in the event
+    ///
+    /// # Returns
+    ///
+    /// Returns the revoked `Enrollment`.
+    pub fn revoke_access(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        student: Address,
+        reason: String,
+    ) -> Enrollment {
+        functions::access_grants::revoke_access(&env, caller, course_id, student, reason)
+    }
+
+    /// Make the list of a course's students public, or private again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `public` - Whether anyone may list the course's students
+    pub fn set_roster_public(env: Env, caller: Address, course_id: u128, public: bool) {
+        functions::course_roster::set_roster_public(&env, caller, course_id, public)
+    }
+
+    /// List a page of a course's enrollments, in enrollment order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address listing the students; the course creator or
+    ///   an admin unless the roster is public
+    /// * `course_id` - The unique identifier of the course
+    /// * `offset` - Number of enrollments to skip
+    /// * `limit` - Number of enrollments to return (at most 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the enrollments of the page, revoked ones included.
+    pub fn list_course_students(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Enrollment> {
+        functions::course_roster::list_course_students(&env, caller, course_id, offset, limit)
+    }
+
+    /// List the courses a student is enrolled in, in enrollment order.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns a summary of each enrollment, revoked ones included.
+    pub fn list_user_courses(env: Env, student: Address) -> Vec<EnrollmentSummary> {
+        functions::student_courses::list_user_courses(&env, student)
+    }
+
+    /// Limit how many students may have active access to a course at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `max_students` - The new limit, or `None` to remove it
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course`, with its seats remaining.
+    pub fn set_max_students(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        max_students: Option<u32>,
+    ) -> Course {
+        functions::course_capacity::set_max_students(&env, caller, course_id, max_students)
+    }
+
+    /// Set the time after which a course takes no new students.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `deadline` - Ledger timestamp of the deadline, or `None` to remove it
+    pub fn set_enrollment_deadline(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        deadline: Option<u64>,
+    ) {
+        functions::enrollment_deadlines::set_enrollment_deadline(&env, caller, course_id, deadline)
+    }
+
+    /// Get the enrollment deadline of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the deadline timestamp, or `None` if the course has none.
+    pub fn get_enrollment_deadline(env: Env, course_id: u128) -> Option<u64> {
+        functions::enrollment_deadlines::get_enrollment_deadline(&env, course_id)
+    }
+
+    /// Add a cohort with its own study window and capacity to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `starts_at` - Timestamp from which cohort students can study
+    /// * `ends_at` - Timestamp at which their access ends
+    /// * `capacity` - Most students in the cohort
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Cohort`.
+    pub fn create_cohort(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        starts_at: u64,
+        ends_at: u64,
+        capacity: u32,
+    ) -> Cohort {
+        functions::cohorts::create_cohort(&env, caller, course_id, starts_at, ends_at, capacity)
+    }
+
+    /// List the cohorts of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the cohorts by cohort ID.
+    pub fn list_cohorts(env: Env, course_id: u128) -> Vec<Cohort> {
+        functions::cohorts::list_cohorts(&env, course_id)
+    }
+
+    /// Enroll in a cohort of a course, paying the course price.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolling student
+    /// * `course_id` - The unique identifier of the course
+    /// * `cohort_id` - The cohort to join
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn enroll_in_cohort(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        cohort_id: u32,
+        token: Option<Address>,
+    ) -> Enrollment {
+        functions::cohorts::enroll_in_cohort(&env, student, course_id, cohort_id, token)
+    }
+
+    /// Get the cohort a student joined in a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the student's `Cohort`, or `None` if they did not join one.
+    pub fn get_student_cohort(env: Env, course_id: u128, student: Address) -> Option<Cohort> {
+        functions::cohorts::get_student_cohort(&env, course_id, student)
+    }
+
+    /// Join the waitlist of a full course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the waiting student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the student's `WaitlistEntry`, with the price they will pay.
+    pub fn join_waitlist(env: Env, student: Address, course_id: u128) -> WaitlistEntry {
+        functions::waitlist::join_waitlist(&env, student, course_id)
+    }
+
+    /// Leave the waitlist of a course, passing on any seat offered.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the waiting student
+    /// * `course_id` - The unique identifier of the course
+    pub fn leave_waitlist(env: Env, student: Address, course_id: u128) {
+        functions::waitlist::leave_waitlist(&env, student, course_id)
+    }
+
+    /// Claim the seat offered from the waitlist, paying the price from when
+    /// the student joined it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the waiting student
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The token to pay with
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn claim_waitlist_seat(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        token: Option<Address>,
+    ) -> Enrollment {
+        functions::waitlist::claim_waitlist_seat(&env, student, course_id, token)
+    }
+
+    /// Withdraw expired seat offers and pass the seats on to the next students.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of offers withdrawn.
+    pub fn expire_waitlist_offers(env: Env, course_id: u128) -> u32 {
+        functions::waitlist::expire_waitlist_offers(&env, course_id)
+    }
+
+    /// Get the students waiting in line for a seat of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the students in line, first in line first; students holding an
+    /// offer are not included.
+    pub fn get_waitlist(env: Env, course_id: u128) -> Vec<Address> {
+        functions::waitlist::get_waitlist(&env, course_id)
+    }
+
+    /// Get a student's place on the waitlist of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the `WaitlistEntry`, or `None` if the student is not waiting.
+    pub fn get_waitlist_entry(
+        env: Env,
+        course_id: u128,
+        student: Address,
+    ) -> Option<WaitlistEntry> {
+        functions::waitlist::get_waitlist_entry(&env, course_id, student)
+    }
+
+    /// Sell a course for a limited time of access, or for good again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `terms` - Access duration and renewal price, or `None` for lifetime access
+    pub fn set_access_terms(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        terms: Option<AccessTerms>,
+    ) {
+        functions::access_terms::set_access_terms(&env, caller, course_id, terms)
+    }
+
+    /// Get the access terms of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the `AccessTerms`, or `None` if enrollments give lifetime access.
+    pub fn get_access_terms(env: Env, course_id: u128) -> Option<AccessTerms> {
+        functions::access_terms::get_access_terms(&env, course_id)
+    }
+
+    /// Extend a student's time-limited access to a course, paying the renewal price.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolled student
+    /// * `course_id` - The unique identifier of the course
+    /// * `token` - The payment token, when the renewal has a price
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Enrollment` with its new expiry.
+    pub fn renew_access(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        token: Option<Address>,
+    ) -> Enrollment {
+        functions::access_terms::renew_access(&env, student, course_id, token)
+    }
+
+    /// Allow or forbid moving enrollments in a course to other addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `enabled` - Whether enrollments may be transferred
+    pub fn set_transfers_enabled(env: Env, caller: Address, course_id: u128, enabled: bool) {
+        functions::enrollment_transfers::set_transfers_enabled(&env, caller, course_id, enabled)
+    }
+
+    /// Move an enrollment, with its progress, to another address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `from` - The enrolled address
+    /// * `to` - The address taking over the enrollment
+    /// * `approver` - The course creator or an admin approving the transfer
+    ///   instead of both addresses, or `None`
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Enrollment`, now held by `to`.
+    pub fn transfer_enrollment(
+        env: Env,
+        course_id: u128,
+        from: Address,
+        to: Address,
+        approver: Option<Address>,
+    ) -> Enrollment {
+        functions::enrollment_transfers::transfer_enrollment(&env, course_id, from, to, approver)
+    }
+
+    /// Restrict enrollment in a course to allowlisted students, or open it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `enabled` - Whether only allowlisted students may enroll
+    pub fn set_allowlist_mode(env: Env, caller: Address, course_id: u128, enabled: bool) {
+        functions::allowlist::set_allowlist_mode(&env, caller, course_id, enabled)
+    }
+
+    /// Add students to the allowlist of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `students` - The students to allow (at most 50)
+    pub fn add_to_allowlist(env: Env, caller: Address, course_id: u128, students: Vec<Address>) {
+        functions::allowlist::add_to_allowlist(&env, caller, course_id, students)
+    }
+
+    /// Remove students from the allowlist of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `students` - The students to remove (at most 50)
+    pub fn remove_from_allowlist(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        students: Vec<Address>,
+    ) {
+        functions::allowlist::remove_from_allowlist(&env, caller, course_id, students)
+    }
+
+    /// Check whether a student may enroll in a course under its allowlist mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course is open to everyone or the student is allowlisted.
+    pub fn is_allowlisted(env: Env, course_id: u128, student: Address) -> bool {
+        functions::allowlist::is_allowlisted(&env, course_id, student)
+    }
+
+    /// Set what students must hold to enroll in a course, or remove the gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `gate` - A minimum token balance or a specific NFT, or `None` to remove it
+    pub fn set_token_gate(env: Env, caller: Address, course_id: u128, gate: Option<TokenGate>) {
+        functions::token_gates::set_token_gate(&env, caller, course_id, gate)
+    }
+
+    /// Get the token gate of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the token gate, or `None` if enrollment is not token-gated.
+    pub fn get_token_gate(env: Env, course_id: u128) -> Option<TokenGate> {
+        functions::token_gates::get_token_gate(&env, course_id)
+    }
+
+    /// Set how the prerequisites of a course are checked at enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `check` - Completion of each prerequisite, or a certificate for it
+    pub fn set_prerequisite_check(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        check: PrerequisiteCheck,
+    ) {
+        functions::prerequisite_checks::set_prerequisite_check(&env, caller, course_id, check)
+    }
+
+    /// Get how the prerequisites of a course are checked at enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the check, `PrerequisiteCheck::Completion` by default.
+    pub fn get_prerequisite_check(env: Env, course_id: u128) -> PrerequisiteCheck {
+        functions::prerequisite_checks::get_prerequisite_check(&env, course_id)
+    }
+
+    /// Check whether a student completed every module of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the course is completed; a course without modules
+    /// counts as completed once the student is enrolled.
+    pub fn is_course_completed(env: Env, course_id: u128, student: Address) -> bool {
+        functions::prerequisite_checks::is_course_completed(&env, course_id, &student)
+    }
+
+    /// List the prerequisites of a course a student does not meet yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the missing prerequisite course IDs, empty if the student may enroll.
+    pub fn get_missing_prerequisites(env: Env, course_id: u128, student: Address) -> Vec<u128> {
+        functions::prerequisite_checks::get_missing_prerequisites(&env, course_id, &student)
+    }
+
+    /// Give a whole class free access to a course at once; all or nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `students` - The students to give access to (at most 50)
+    ///
+    /// # Returns
+    ///
+    /// Returns the new enrollments, in the order of `students`.
+    pub fn grant_access_batch(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        students: Vec<Address>,
+    ) -> Vec<Enrollment> {
+        functions::access_grants::grant_access_batch(&env, creator, course_id, students)
+    }
+
+    /// Get a page of the access log of a course, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `offset` - The number of entries to skip
+    /// * `limit` - The number of entries to return (at most 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the grants, revocations, transfers and expiries of the page.
+    pub fn get_access_history(
+        env: Env,
+        course_id: u128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AccessLogEntry> {
+        functions::access_log::get_access_history(&env, course_id, offset, limit)
+    }
+
+    /// Leave a course, with a full refund while the refund window is open.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the enrolled student
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the payment was refunded.
+    pub fn unenroll(env: Env, student: Address, course_id: u128) -> bool {
+        functions::unenrollment::unenroll(&env, student, course_id)
+    }
+
+    /// Ban a student from a course, revoking their access and blocking
+    /// re-enrollment and the waitlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student to ban
+    /// * `reason` - Why the student is banned
+    pub fn ban_student(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        student: Address,
+        reason: String,
+    ) {
+        functions::course_bans::ban_student(&env, caller, course_id, student, reason)
+    }
+
+    /// Lift a student's ban from a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the banned student
+    pub fn unban_student(env: Env, caller: Address, course_id: u128, student: Address) {
+        functions::course_bans::unban_student(&env, caller, course_id, student)
+    }
+
+    /// Check whether a student is banned from a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the student is banned.
+    pub fn is_banned(env: Env, course_id: u128, student: Address) -> bool {
+        functions::course_bans::is_banned(&env, course_id, student)
+    }
+
+    /// Get why a student was banned from a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the reason, or `None` if the student is not banned.
+    pub fn get_ban_reason(env: Env, course_id: u128, student: Address) -> Option<String> {
+        functions::course_bans::get_ban_reason(&env, course_id, student)
+    }
+
+    /// Allow or forbid transferring the enrollment receipts of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `transferable` - Whether receipts may change hands
+    pub fn set_receipts_transferable(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        transferable: bool,
+    ) {
+        functions::receipts::set_receipts_transferable(&env, caller, course_id, transferable)
+    }
+
+    /// Transfer an enrollment receipt to another address.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `from` - The address holding the receipt
+    /// * `to` - The address receiving the receipt
+    /// * `receipt_id` - The unique identifier of the receipt
+    ///
+    /// # Returns
+    ///
+    /// Returns the transferred `Receipt`.
+    pub fn transfer_receipt(env: Env, from: Address, to: Address, receipt_id: u128) -> Receipt {
+        functions::receipts::transfer_receipt(&env, from, to, receipt_id)
+    }
+
+    /// Get an enrollment receipt by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `receipt_id` - The unique identifier of the receipt
+    ///
+    /// # Returns
+    ///
+    /// Returns the receipt, or `None` if it does not exist.
+    pub fn get_receipt(env: Env, receipt_id: u128) -> Option<Receipt> {
+        functions::receipts::get_receipt(&env, receipt_id)
+    }
+
+    /// Get the receipt of a student's latest enrollment in a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the receipt, or `None` if the student never enrolled.
+    pub fn get_enrollment_receipt(env: Env, course_id: u128, student: Address) -> Option<Receipt> {
+        functions::receipts::get_enrollment_receipt(&env, course_id, student)
+    }
+
+    /// List the enrollment receipts an address holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `owner` - The address holding the receipts
+    ///
+    /// # Returns
+    ///
+    /// Returns the receipts, oldest first.
+    pub fn list_receipts(env: Env, owner: Address) -> Vec<Receipt> {
+        functions::receipts::list_receipts(&env, owner)
+    }
+
+    /// Create single-use invite codes for free or discounted enrollment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hashes` - SHA-256 hashes of the codes (at most 50)
+    /// * `discount` - The price reduction each code grants
+    /// * `expires_at` - Ledger timestamp from which the codes are rejected
+    pub fn create_invites(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        code_hashes: Vec<BytesN<32>>,
+        discount: Discount,
+        expires_at: u64,
+    ) {
+        functions::invites::create_invites(
+            &env,
+            caller,
+            course_id,
+            code_hashes,
+            discount,
+            expires_at,
+        )
+    }
+
+    /// Delete an invite code nobody used yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - SHA-256 hash of the code
+    pub fn revoke_invite(env: Env, caller: Address, course_id: u128, code_hash: BytesN<32>) {
+        functions::invites::revoke_invite(&env, caller, course_id, code_hash)
+    }
+
+    /// Enroll in a course with a single-use invite code.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the student enrolling
+    /// * `course_id` - The unique identifier of the course
+    /// * `code` - The invite code
+    /// * `token` - The token to pay any remaining price with
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn enroll_with_invite(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        code: String,
+        token: Option<Address>,
+    ) -> Enrollment {
+        functions::invites::enroll_with_invite(&env, student, course_id, code, token)
+    }
+
+    /// Get the invite behind a code hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `code_hash` - SHA-256 hash of the code
+    ///
+    /// # Returns
+    ///
+    /// Returns the invite, or `None` if it does not exist.
+    pub fn get_invite(env: Env, course_id: u128, code_hash: BytesN<32>) -> Option<Invite> {
+        functions::invites::get_invite(&env, course_id, code_hash)
+    }
+
+    /// Get the hash of the invite code a student enrolled with.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns the code hash, or `None` if the student used no invite.
+    pub fn get_student_invite(env: Env, course_id: u128, student: Address) -> Option<BytesN<32>> {
+        functions::invites::get_student_invite(&env, course_id, student)
+    }
+
+    /// Set the Ed25519 public key that signs vouchers for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `signer` - The public key, or `None` to stop accepting vouchers
+    pub fn set_voucher_signer(
+        env: Env,
+        caller: Address,
+        course_id: u128,
+        signer: Option<BytesN<32>>,
+    ) {
+        functions::vouchers::set_voucher_signer(&env, caller, course_id, signer)
+    }
+
+    /// Get the public key that signs vouchers for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the public key, or `None` if the course takes no vouchers.
+    pub fn get_voucher_signer(env: Env, course_id: u128) -> Option<BytesN<32>> {
+        functions::vouchers::get_voucher_signer(&env, course_id)
+    }
+
+    /// Enroll for free with a voucher signed off-chain by the course's signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `recipient` - The address the voucher was issued to
+    /// * `course_id` - The unique identifier of the course
+    /// * `expires_at` - Ledger timestamp from which the voucher is rejected
+    /// * `nonce` - The voucher's nonce, redeemable once per course
+    /// * `signature` - Ed25519 signature of the XDR-encoded `Voucher`
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn redeem_voucher(
+        env: Env,
+        recipient: Address,
+        course_id: u128,
+        expires_at: u64,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Enrollment {
+        functions::vouchers::redeem_voucher(
+            &env,
+            recipient,
+            course_id,
+            expires_at,
+            nonce,
+            signature,
+        )
+    }
+
+    /// Get who redeemed the voucher with a nonce for a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `nonce` - The voucher's nonce
+    ///
+    /// # Returns
+    ///
+    /// Returns the recipient, or `None` if the nonce is unused.
+    pub fn get_voucher_redeemer(env: Env, course_id: u128, nonce: u64) -> Option<Address> {
+        functions::vouchers::get_voucher_redeemer(&env, course_id, nonce)
+    }
+
+    /// Commit the Merkle root of the addresses eligible for a course's airdrop.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `root` - The Merkle root, or `None` to end the airdrop
+    pub fn set_airdrop_root(env: Env, caller: Address, course_id: u128, root: Option<BytesN<32>>) {
+        functions::airdrops::set_airdrop_root(&env, caller, course_id, root)
+    }
+
+    /// Get the Merkle root of a course's airdrop.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the root, or `None` if the course has no airdrop.
+    pub fn get_airdrop_root(env: Env, course_id: u128) -> Option<BytesN<32>> {
+        functions::airdrops::get_airdrop_root(&env, course_id)
+    }
+
+    /// Enroll for free with a Merkle proof of eligibility for a course's airdrop.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `student` - The address of the eligible student
+    /// * `course_id` - The unique identifier of the course
+    /// * `proof` - Sibling hashes from the student's leaf up to the root
+    ///
+    /// # Returns
+    ///
+    /// Returns the new `Enrollment`.
+    pub fn claim_airdrop_access(
+        env: Env,
+        student: Address,
+        course_id: u128,
+        proof: Vec<BytesN<32>>,
+    ) -> Enrollment {
+        functions::airdrops::claim_airdrop_access(&env, student, course_id, proof)
+    }
+
+    /// Check whether a student already claimed a course's airdrop.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `student` - The address of the student
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the student claimed the airdrop.
+    pub fn has_claimed_airdrop(env: Env, course_id: u128, student: Address) -> bool {
+        functions::airdrops::has_claimed_airdrop(&env, course_id, student)
+    }
+
+    /// Delete a course from the registry.
+    ///
+    /// This function permanently removes a course from the registry.
+    /// Only the course creator can delete their own courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course to delete
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deletion fails or if the creator is not authorized.
+    pub fn delete_course(env: Env, creator: Address, course_id: u128) -> () {
+        functions::delete_course::delete_course(&env, creator, course_id)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Simple hello world function for testing.
+    ///
+    /// This is a basic function that returns a greeting message,
+    /// primarily used for testing contract deployment and basic functionality.
+    ///
+    /// # Arguments
+    ///
+    /// * `_env` - The Soroban environment (unused)
+    ///
+    /// # Returns
+    ///
+    /// Returns a greeting string.
+    pub fn hello_world(_env: Env) -> String {
+        String::from_str(&_env, "Hello from Web3 👋")
+    }
+
+    /// Edit an existing course goal.
+    ///
+    /// This function allows the course creator to modify the content of an existing goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `goal_id` - The unique identifier of the goal to edit
+    /// * `new_content` - The new content for the goal
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `CourseGoal` object.
+    pub fn edit_goal(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        goal_id: String,
+        new_content: String,
+    ) -> CourseGoal {
+        functions::edit_goal::edit_goal(env, creator, course_id, goal_id, new_content)
+    }
+
+    /// Add a new goal to a course.
+    ///
+    /// This function creates and adds a new learning goal to the specified course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `content` - The content/description of the goal
+    ///
+    /// # Returns
+    ///
+    /// Returns the created `CourseGoal` object.
+    pub fn add_goal(env: Env, creator: Address, course_id: u128, content: String) -> CourseGoal {
+        functions::add_goal::add_goal(env, creator, course_id, content)
+    }
+
+    /// Remove a goal from a course.
+    ///
+    /// This function removes a specific learning goal from the course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the user requesting the removal
+    /// * `course_id` - The unique identifier of the course
+    /// * `goal_id` - The unique identifier of the goal to remove
+    pub fn remove_goal(env: Env, caller: Address, course_id: u128, goal_id: String) -> () {
+        functions::remove_goal::remove_goal(env, caller, course_id, goal_id)
+    }
+
+    /// Add prerequisites to a course.
+    ///
+    /// This function adds prerequisite courses that must be completed
+    /// before a student can enroll in the target course.
+    /// The IDs are appended to the existing prerequisites. IDs already listed
+    /// are ignored and circular dependencies are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `prerequisite_course_ids` - Vector of course IDs that are prerequisites
+    pub fn add_prerequisite(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        prerequisite_course_ids: Vec<u128>,
+    ) {
+        functions::create_prerequisite::add_prerequisite(
+            env,
+            creator,
+            course_id,
+            prerequisite_course_ids,
+        )
+    }
+
+    /// Remove a prerequisite from a course.
+    ///
+    /// This function removes a specific prerequisite course requirement
+    /// from the target course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `prerequisite_course_id` - The ID of the prerequisite course to remove
+    pub fn remove_prerequisite(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        prerequisite_course_id: u128,
+    ) {
+        functions::remove_prerequisite::remove_prerequisite(
+            env,
+            creator,
+            course_id,
+            prerequisite_course_id,
+        )
+    }
+
+    /// Edit the prerequisites for a course.
+    ///
+    /// This function replaces all existing prerequisites with a new set
+    /// of prerequisite courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `new_prerequisites` - Vector of new prerequisite course IDs
+    pub fn edit_prerequisite(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        new_prerequisites: Vec<u128>,
+    ) {
+        functions::edit_prerequisite::edit_prerequisite(env, creator, course_id, new_prerequisites)
+    }
+
+    /// Get the prerequisites of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the prerequisite course IDs, or an empty vector if there are none.
+    pub fn get_prerequisites(env: Env, course_id: u128) -> Vec<u128> {
+        functions::prerequisites::get_prerequisites(&env, course_id)
+    }
+
+    /// Set the courses related to a course.
+    ///
+    /// Replaces the curated list of cross-sell suggestions shown alongside
+    /// the course. Only published courses can be linked.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `related` - Up to 10 related course IDs; empty clears the list
+    pub fn set_related_courses(env: Env, creator: Address, course_id: u128, related: Vec<u128>) {
+        functions::related_courses::set_related_courses(&env, creator, course_id, related)
+    }
+
+    /// Get the courses related to a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the related courses that are still published and public.
+    pub fn get_related_courses(env: Env, course_id: u128) -> Vec<Course> {
+        functions::related_courses::get_related_courses(&env, course_id)
+    }
+
+    /// Add a tag to a course.
+    ///
+    /// Tags are normalized, so differences in case and spacing are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `tag` - The tag to add
+    ///
+    /// # Returns
+    ///
+    /// Returns the tags of the course.
+    pub fn add_tag(env: Env, caller: Address, course_id: u128, tag: String) -> Vec<String> {
+        functions::course_tags::add_tag(&env, caller, course_id, tag)
+    }
+
+    /// Remove a tag from a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `tag` - The tag to remove
+    ///
+    /// # Returns
+    ///
+    /// Returns the remaining tags of the course.
+    pub fn remove_tag(env: Env, caller: Address, course_id: u128, tag: String) -> Vec<String> {
+        functions::course_tags::remove_tag(&env, caller, course_id, tag)
+    }
+
+    /// Get the tags of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the normalized tags, in the order they were added.
+    pub fn get_course_tags(env: Env, course_id: u128) -> Vec<String> {
+        functions::course_tags::get_course_tags(&env, course_id)
+    }
+
+    /// List published courses carrying a tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `tag` - The tag to look up
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, public courses with the tag, in tagging order.
+    pub fn get_courses_by_tag(env: Env, tag: String, offset: u32, limit: u32) -> Vec<Course> {
+        functions::course_tags::get_courses_by_tag(&env, tag, offset, limit)
+    }
+
+    /// List published courses filed under a category.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `category` - The category name
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, public courses of the category, oldest first.
+    pub fn get_courses_by_category(
+        env: Env,
+        category: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Course> {
+        functions::list_courses_by_category::get_courses_by_category(&env, category, offset, limit)
+    }
+
+    /// Get the statistics of a category.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `category` - The category name
+    ///
+    /// # Returns
+    ///
+    /// Returns the course count, total enrollments and average course price
+    /// of the category.
+    pub fn get_category_stats(env: Env, category: String) -> CategoryStats {
+        functions::category_stats::get_category_stats(&env, category)
+    }
+
+    /// List published courses taught in a language.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `language` - The language code, matched ignoring case
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, public courses in the language, oldest first.
+    pub fn get_courses_by_language(
+        env: Env,
+        language: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Course> {
+        functions::list_courses_by_language::get_courses_by_language(&env, language, offset, limit)
+    }
+
+    /// List published courses within a price range.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `min` - Lowest price included
+    /// * `max` - Highest price included
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, public courses in the range, cheapest price band first.
+    pub fn get_courses_by_price_range(
+        env: Env,
+        min: u128,
+        max: u128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Course> {
+        functions::list_courses_by_price::get_courses_by_price_range(&env, min, max, offset, limit)
+    }
+
+    /// List published courses newest first or most enrolled first.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `sort` - The ordering to use
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, public courses in the requested order.
+    pub fn list_courses_sorted(env: Env, sort: SortKey, offset: u32, limit: u32) -> Vec<Course> {
+        functions::list_courses_sorted::list_courses_sorted(&env, sort, offset, limit)
+    }
+
+    /// Export the catalog in ID order for off-chain indexers.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `cursor` - The last course ID already exported, or 0 to start
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of course summaries and the cursor for the next call,
+    /// which is `None` once the whole catalog has been walked.
+    pub fn export_courses(env: Env, cursor: u128, limit: u32) -> CourseExportPage {
+        functions::export_courses::export_courses(&env, cursor, limit)
+    }
+
+    /// Search published courses by the start of their title.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `prefix` - The start of the title, matched ignoring case
+    /// * `offset` - Number of matching courses to skip
+    /// * `limit` - Maximum number of courses to return (1 to 20)
+    ///
+    /// # Returns
+    ///
+    /// Returns the published, public courses whose title starts with the prefix.
+    pub fn search_courses_by_prefix(env: Env, prefix: String, offset: u32, limit: u32) -> Vec<Course> {
+        functions::title_search::search_courses_by_prefix(&env, prefix, offset, limit)
+    }
+
+    /// Add a course to the featured list.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin curating the list
+    /// * `course_id` - The published course to feature
+    pub fn pin_featured_course(env: Env, caller: Address, course_id: u128) {
+        functions::featured_courses::pin_featured_course(&env, caller, course_id)
+    }
+
+    /// Remove a course from the featured list.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin curating the list
+    /// * `course_id` - The course to remove
+    pub fn unpin_featured_course(env: Env, caller: Address, course_id: u128) {
+        functions::featured_courses::unpin_featured_course(&env, caller, course_id)
+    }
+
+    /// Get the featured courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the featured courses that are still published and public, in display order.
+    pub fn get_featured_courses(env: Env) -> Vec<Course> {
+        functions::featured_courses::get_featured_courses(&env)
+    }
+
+    /// Ban a word or phrase from course titles.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin moderating titles
+    /// * `term` - The word or phrase to ban
+    ///
+    /// # Returns
+    ///
+    /// Returns the blocked terms, normalized.
+    pub fn add_blocked_term(env: Env, caller: Address, term: String) -> Vec<String> {
+        functions::title_moderation::add_blocked_term(&env, caller, term)
+    }
+
+    /// Lift the ban on a word or phrase.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin moderating titles
+    /// * `term` - The blocked word or phrase
+    ///
+    /// # Returns
+    ///
+    /// Returns the remaining blocked terms.
+    pub fn remove_blocked_term(env: Env, caller: Address, term: String) -> Vec<String> {
+        functions::title_moderation::remove_blocked_term(&env, caller, term)
+    }
+
+    /// Get the words and phrases banned from course titles.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the blocked terms, in the order they were added.
+    pub fn get_blocked_terms(env: Env) -> Vec<String> {
+        functions::title_moderation::get_blocked_terms(&env)
+    }
+
+    /// Reserve a title prefix for official creators.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin moderating titles
+    /// * `prefix` - The title prefix to reserve
+    ///
+    /// # Returns
+    ///
+    /// Returns the reserved prefixes, normalized.
+    pub fn reserve_title_prefix(env: Env, caller: Address, prefix: String) -> Vec<String> {
+        functions::title_moderation::reserve_title_prefix(&env, caller, prefix)
+    }
+
+    /// Release a reserved title prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin moderating titles
+    /// * `prefix` - The reserved prefix
+    ///
+    /// # Returns
+    ///
+    /// Returns the remaining reserved prefixes.
+    pub fn release_title_prefix(env: Env, caller: Address, prefix: String) -> Vec<String> {
+        functions::title_moderation::release_title_prefix(&env, caller, prefix)
+    }
+
+    /// Get the reserved title prefixes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the reserved prefixes, in the order they were reserved.
+    pub fn get_reserved_prefixes(env: Env) -> Vec<String> {
+        functions::title_moderation::get_reserved_prefixes(&env)
+    }
+
+    /// Allow or disallow a creator to use reserved title prefixes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin moderating titles
+    /// * `creator` - The creator to update
+    /// * `allowed` - Whether the creator is an official creator
+    pub fn set_official_creator(env: Env, caller: Address, creator: Address, allowed: bool) {
+        functions::title_moderation::set_official_creator(&env, caller, creator, allowed)
+    }
+
+    /// Check whether a creator may use reserved title prefixes.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The creator to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the creator is on the official allow-list.
+    pub fn is_official_creator(env: Env, creator: Address) -> bool {
+        functions::title_moderation::is_official_creator(&env, &creator)
+    }
+
+    /// Get the courses with the most recent enrollments.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `window` - Number of days to look back, today included (1 to 30)
+    ///
+    /// # Returns
+    ///
+    /// Returns up to 10 published, public courses, most enrollments in the window first.
+    pub fn get_trending_courses(env: Env, window: u32) -> Vec<Course> {
+        functions::trending_courses::get_trending_courses(&env, window)
+    }
+
+    /// Get the number of enrollments of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns how many students enrolled in the course.
+    pub fn get_enrollment_count(env: Env, course_id: u128) -> u32 {
+        functions::course_index::enrollment_count(&env, course_id)
+    }
+
+    /// Add a skill to the shared skill taxonomy.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin creating the skill
+    /// * `name` - The skill name, unique ignoring case
+    /// * `parent_id` - Optional broader skill this one belongs to
+    ///
+    /// # Returns
+    ///
+    /// Returns the unique ID of the created skill.
+    pub fn create_skill(env: Env, caller: Address, name: String, parent_id: Option<u128>) -> u128 {
+        functions::skills::create_skill(&env, caller, name, parent_id)
+    }
+
+    /// Get a skill of the taxonomy by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `skill_id` - The unique identifier of the skill
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Skill`.
+    pub fn get_skill(env: Env, skill_id: u128) -> Skill {
+        functions::skills::get_skill(&env, skill_id)
+    }
+
+    /// Look a skill up by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `name` - The skill name, compared ignoring case and spacing
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Skill)` if found, `None` otherwise.
+    pub fn find_skill(env: Env, name: String) -> Option<Skill> {
+        functions::skills::find_skill(&env, name)
+    }
+
+    /// Set the skills a course teaches.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course
+    /// * `skills` - Up to 10 skills with the level taught; empty clears them
+    pub fn set_course_skills(env: Env, caller: Address, course_id: u128, skills: Vec<CourseSkill>) {
+        functions::skills::set_course_skills(&env, caller, course_id, skills)
+    }
+
+    /// Get the skills a course teaches.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    ///
+    /// # Returns
+    ///
+    /// Returns the course skills with the level taught.
+    pub fn get_course_skills(env: Env, course_id: u128) -> Vec<CourseSkill> {
+        functions::skills::get_course_skills(&env, course_id)
+    }
+
+    /// Link a course as a language variant of another course.
+    ///
+    /// Groups translations of the same course (e.g. English and Spanish) so
+    /// frontends can offer a language switch. Both courses must belong to
+    /// the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the creator of both courses
+    /// * `course_id` - The course to add to the group
+    /// * `variant_of` - A course already in, or anchoring, the group
+    ///
+    /// # Returns
+    ///
+    /// Returns the ID of the variant group.
+    pub fn link_course_variant(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        variant_of: u128,
+    ) -> BytesN<32> {
+        functions::course_variants::link_course_variant(&env, creator, course_id, variant_of)
+    }
+
+    /// Remove a course from its variant group.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The course to unlink
+    pub fn unlink_course_variant(env: Env, creator: Address, course_id: u128) {
+        functions::course_variants::unlink_course_variant(&env, creator, course_id)
+    }
+
+    /// Get all language variants in a group.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `group_id` - The ID of the variant group
+    ///
+    /// # Returns
+    ///
+    /// Returns the courses in the group, in the order they were linked.
+    pub fn get_course_variants(env: Env, group_id: BytesN<32>) -> Vec<Course> {
+        functions::course_variants::get_course_variants(&env, group_id)
+    }
+
+    /// Edit course information.
+    ///
+    /// This function allows the course creator to update various aspects
+    /// of the course using the provided parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course to edit
+    /// * `params` - Parameters containing the fields to update
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object.
+    pub fn edit_course(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        params: EditCourseParams,
+    ) -> Course {
+        functions::edit_course::edit_course(env, creator, course_id, params)
+    }
+
+    /// Archive a course.
+    ///
+    /// This function marks a course as archived, making it unavailable for new enrollments
+    /// while preserving existing data and access for current students.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course to archive
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object with archived status.
+    pub fn archive_course(env: &Env, creator: Address, course_id: u128) -> Course {
+        functions::archive_course::archive_course(env, creator, course_id)
+    }
+
+    /// Suspend a course.
+    ///
+    /// Admin-only moderation action. A suspended course is hidden from
+    /// listings, cannot be read through `get_course` and cannot be
+    /// republished by its creator until an admin reinstates it.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the admin suspending the course
+    /// * `course_id` - The unique identifier of the course to suspend
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object with `Suspended` status.
+    pub fn suspend_course(env: Env, admin: Address, course_id: u128) -> Course {
+        functions::course_status::suspend_course(&env, admin, course_id)
+    }
+
+    /// Reinstate a suspended course.
+    ///
+    /// The course returns to `Draft` so its creator can publish it again.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `admin` - The address of the admin lifting the suspension
+    /// * `course_id` - The unique identifier of the suspended course
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object with `Draft` status.
+    pub fn reinstate_course(env: Env, admin: Address, course_id: u128) -> Course {
+        functions::course_status::reinstate_course(&env, admin, course_id)
+    }
+
+    /// Clone a course under a new title.
+    ///
+    /// Copies the course metadata, prerequisites, modules and goals into a new
+    /// draft course owned by the caller. Useful for re-running a course
+    /// for a new cohort.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The course creator or an admin
+    /// * `course_id` - The unique identifier of the course to copy
+    /// * `new_title` - The title of the new course
+    ///
+    /// # Returns
+    ///
+    /// Returns the newly created `Course` object.
+    pub fn clone_course(env: Env, caller: Address, course_id: u128, new_title: String) -> Course {
+        functions::clone_course::clone_course(&env, caller, course_id, new_title)
+    }
+
+    /// Migrate a course stored under a legacy string ID.
+    ///
+    /// Courses created before IDs became `u128` were keyed by their decimal
+    /// string ID. This admin-only function moves such a course to the new key.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin performing the migration
+    /// * `legacy_id` - The string ID the course was stored under
+    ///
+    /// # Returns
+    ///
+    /// Returns the migrated `Course` object.
+    pub fn migrate_course(env: Env, caller: Address, legacy_id: String) -> Course {
+        functions::migrate_course::migrate_course(env, caller, legacy_id)
+    }
+
+    /// Check if a user is the creator of a specific course.
+    ///
+    /// This function verifies whether the specified user is the original creator
+    /// of the given course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `course_id` - The unique identifier of the course
+    /// * `user` - The address of the user to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user is the course creator, `false` otherwise.
+    pub fn is_course_creator(env: &Env, course_id: u128, user: Address) -> bool {
+        functions::is_course_creator::is_course_creator(env, course_id, user)
+    }
+
+    /// List all available course categories.
+    ///
+    /// This function retrieves the active categories of the registry, with
+    /// the number of courses filed under each.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Category` objects ordered by category ID.
+    pub fn list_categories(env: Env) -> Vec<crate::schema::Category> {
+        functions::list_categories::list_categories(&env)
+    }
+
+    /// List courses with filtering and pagination.
+    ///
+    /// This function retrieves courses based on the provided filters
+    /// with optional pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `filters` - Filtering criteria for courses
+    /// * `limit` - Optional maximum number of courses to return
+    /// * `offset` - Optional number of courses to skip for pagination
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `Course` objects matching the filter criteria.
+    pub fn list_courses_with_filters(
+        env: Env,
+        filters: CourseFilters,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::list_courses_with_filters::list_courses_with_filters(
+            &env, filters, limit, offset,
+        )
+    }
+
+    /// Set the difficulty level of a course.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `creator` - The address of the course creator
+    /// * `course_id` - The unique identifier of the course
+    /// * `level` - The new level, or `CourseLevel::Unspecified` to clear it
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated `Course` object.
+    pub fn set_course_level(
+        env: Env,
+        creator: Address,
+        course_id: u128,
+        level: CourseLevel,
+    ) -> Course {
+        functions::course_level::set_course_level(&env, creator, course_id, level)
+    }
+
+    /// List published courses of a difficulty level.
+    ///
+    /// Uses a per-level index, so only courses of the requested level are read.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `level` - The difficulty level to list; `Unspecified` is rejected
+    /// * `limit` - Optional maximum number of results (capped at 20)
+    /// * `offset` - Optional number of results to skip for pagination
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of published, public `Course` objects of the level.
+    pub fn list_courses_by_level(
+        env: Env,
+        level: CourseLevel,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Vec<Course> {
+        functions::list_courses_by_level::list_courses_by_level(&env, level, limit, offset)
+    }
+
+    /// Get the length limits enforced on course text fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the active `ContentLimits` (defaults if never configured).
+    pub fn get_content_limits(env: Env) -> ContentLimits {
+        functions::content_limits::get_content_limits(&env)
+    }
+
+    /// Get the price range allowed for paid courses.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the active `PriceLimits` (any price above 0 if never configured).
+    pub fn get_price_limits(env: Env) -> PriceLimits {
+        functions::price_limits::get_price_limits(&env)
+    }
+
+    /// Set the lowest and highest price of paid courses.
+    ///
+    /// Only admins can change the range. It applies to `create_course` and
+    /// to `edit_course` calls changing the price made afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin performing the update
+    /// * `limits` - The new minimum and maximum price
+    pub fn set_price_limits(env: Env, caller: Address, limits: PriceLimits) {
+        functions::price_limits::set_price_limits(&env, caller, limits)
+    }
+
+    /// Set the length limits enforced on course text fields.
+    ///
+    /// Only admins can change the limits. They apply to `create_course`
+    /// and `edit_course` calls made afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin performing the update
+    /// * `limits` - The new title, description and thumbnail URL limits
+    pub fn set_content_limits(env: Env, caller: Address, limits: ContentLimits) {
+        functions::content_limits::set_content_limits(&env, caller, limits)
+    }
+
+    /// Get the course creation rate limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    ///
+    /// # Returns
+    ///
+    /// Returns the active `RateLimit`; `max_courses == 0` means unlimited.
+    pub fn get_rate_limit(env: Env) -> RateLimit {
+        functions::rate_limit::get_rate_limit(&env)
+    }
+
+    /// Set the course creation rate limit.
+    ///
+    /// Only admins can change the limit. Each address may then create at most
+    /// `max_courses` courses per `window_seconds`; further calls fail with
+    /// `RateLimitExceeded` until the window expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The Soroban environment
+    /// * `caller` - The address of the admin performing the update
+    /// * `limit` - The new limit; `max_courses == 0` disables it
+    pub fn set_rate_limit(env: Env, caller: Address, limit: RateLimit) {
+        functions::rate_limit::set_rate_limit(&env, caller, limit)
     }
 }
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2025 SkillCert
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// Course registry defaults and limits
 pub const DEFAULT_COURSE_PRICE: u128 = 1000;
@@ -9,33 +9,752 @@ pub const MAX_LOOP_GUARD: u32 = 1000;
 pub const FILTER_MIN_PRICE: u128 = 500;
 pub const MAX_SCAN_ID: u32 = 50;
 pub const MAX_EMPTY_CHECKS: u32 = 10;
+pub const MAX_BATCH_COURSES: u32 = 20;
+pub const MAX_BATCH_MODULES: u32 = 50;
+pub const MAX_RELATED_COURSES: u32 = 10;
+pub const MAX_LESSONS_PER_MODULE: u32 = 100;
+pub const MAX_LESSON_RESOURCES: u32 = 10;
+pub const MAX_QUIZ_QUESTIONS: u32 = 50;
+pub const MAX_MODULE_OVERRIDES: u32 = 200;
+pub const MAX_COURSE_TAGS: u32 = 10;
+pub const MAX_COURSE_SKILLS: u32 = 10;
+pub const MAX_PAGE_SIZE: u32 = 20;
+pub const MAX_RANKED_COURSES: u32 = 100;
+pub const MAX_FEATURED_COURSES: u32 = 12;
+pub const MAX_EXPORT_SCAN: u32 = 100;
+pub const MAX_BLOCKED_TERMS: u32 = 100;
+pub const MAX_RESERVED_PREFIXES: u32 = 20;
+pub const MAX_FEE_BPS: u32 = 10_000;
+pub const MAX_REFUND_WINDOW: u64 = 90 * 86_400;
+pub const MAX_BUNDLE_COURSES: u32 = 10;
+pub const MAX_PLAN_COURSES: u32 = 50;
+pub const MAX_INSTALLMENTS: u32 = 12;
+pub const MAX_PRICE_LIST_TOKENS: u32 = 5;
+pub const MAX_GIFT_CLAIM_PERIOD: u64 = 365 * 86_400;
+pub const MAX_SEATS_PER_PURCHASE: u32 = 1000;
+pub const MAX_PRICE_HISTORY: u32 = 20;
+pub const UNPUBLISH_REFUND_PERIOD: u64 = 7 * 86_400;
+pub const MAX_SPLIT_RECIPIENTS: u32 = 10;
+pub const MAX_COHORTS: u32 = 20;
+pub const MAX_WAITLIST: u32 = 100;
+pub const MAX_ALLOWLIST_BATCH: u32 = 50;
+pub const MAX_GRANT_BATCH: u32 = 50;
+pub const MAX_INVITE_BATCH: u32 = 50;
+pub const MAX_AIRDROP_PROOF: u32 = 32;
+pub const WAITLIST_CLAIM_PERIOD: u64 = 2 * 86_400;
+pub const DEFAULT_MAX_TITLE_LEN: u32 = 200;
+pub const DEFAULT_MAX_DESCRIPTION_LEN: u32 = 2000;
+pub const DEFAULT_MAX_THUMBNAIL_URL_LEN: u32 = 500;
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct CourseModule {
     pub id: String,
-    pub course_id: String,
+    pub course_id: u128,
     pub position: u32,
     pub title: String,
     pub created_at: u64,
+    pub lesson_count: u32,
+    pub duration_minutes: u32,
+    pub release: ModuleRelease,
+    /// Free preview: readable by anyone, enrolled or not.
+    pub is_preview: bool,
+    /// Starts at 1 and is bumped by every `update_module`.
+    pub version: u32,
+    /// SHA-256 of the module's off-chain content.
+    pub content_hash: Option<BytesN<32>>,
+}
+
+/// Fields of a module that `update_module` can change; `None` leaves a field as is.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateModuleParams {
+    pub new_title: Option<String>,
+    pub new_content_hash: Option<Option<BytesN<32>>>,
+}
+
+/// A quiz attached to a module.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quiz {
+    pub module_id: String,
+    /// Question IDs in display order.
+    pub question_ids: Vec<u32>,
+    pub next_question_id: u32,
+    /// Percentage of points needed to pass, from 1 to 100.
+    pub passing_score: u32,
+    pub max_attempts: u32,
+}
+
+/// A quiz question. The question and its answer key stay off-chain; only
+/// their hash is stored.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuizQuestion {
+    pub id: u32,
+    pub content_hash: BytesN<32>,
+    pub points: u32,
+}
+
+/// How an assignment treats submissions made after its deadline.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LatePolicy {
+    Reject,
+    Accept,
+    /// Accepted, with the given percentage taken off the grade.
+    Penalty(u32),
+}
+
+/// Graded coursework attached to a module.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Assignment {
+    pub module_id: String,
+    /// Ledger timestamp after which submissions are late.
+    pub deadline: u64,
+    pub max_submissions: u32,
+    pub late_policy: LatePolicy,
+}
+
+/// One submission of a student to an assignment.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssignmentSubmission {
+    /// Hash of the submitted file, which is stored off-chain.
+    pub file_hash: BytesN<32>,
+    pub submitted_at: u64,
+    pub late: bool,
+}
+
+/// Schedule of a live session held as a module. The join URI itself is
+/// only handed out by `join_live_session` while the session runs.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiveSession {
+    pub module_id: String,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Lesson fields shown in a course outline.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LessonSummary {
+    pub id: u128,
+    pub title: String,
+    pub content_type: LessonContentType,
+    pub duration_minutes: u32,
+}
+
+/// A module and the summaries of its lessons, in display order.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleOutline {
+    pub module: CourseModule,
+    pub lessons: Vec<LessonSummary>,
+}
+
+/// A course with its modules and lessons, as returned by `get_course_outline`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseOutline {
+    pub course: Course,
+    pub modules: Vec<ModuleOutline>,
+}
+
+/// A module definition for `add_modules`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleInput {
+    pub position: u32,
+    pub title: String,
+}
+
+/// When the content of a module opens to enrolled students.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModuleRelease {
+    /// Open as soon as the student is enrolled.
+    Immediate,
+    /// Open from a fixed ledger timestamp.
+    At(u64),
+    /// Open a number of days after the student enrolled.
+    DaysAfterEnrollment(u32),
+}
+
+/// Outcome of checking whether a student may read a module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ModuleAccess {
+    Granted,
+    NotEnrolled,
+    /// The module opens at the given ledger timestamp.
+    Locked(u64),
+    /// Sequential unlock: the given previous module must be completed first.
+    BlockedBy(String),
+    /// The course creator denied this module to the student.
+    Denied,
+    /// An installment is overdue past its grace period.
+    Suspended,
+}
+
+/// Per-address exception to the access rules of a module.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModuleOverride {
+    /// Readable without enrollment, release or completion requirements.
+    Grant,
+    /// Never readable, even when enrolled or previewed.
+    Deny,
+}
+
+/// When one module of a course opens for a given student.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleUnlock {
+    pub module_id: String,
+    pub position: u32,
+    /// Ledger timestamp the module opens at; `0` when it is open from
+    /// enrollment, `None` when it depends on an enrollment that does not exist.
+    pub unlock_at: Option<u64>,
+}
+
+/// A payment made to enroll in a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payment {
+    pub course_id: u128,
+    pub payer: Address,
+    /// Course creator credited when the payment is released.
+    pub creator: Address,
+    pub token: Address,
+    pub amount: u128,
+    /// Part of `amount` sent to the platform treasury.
+    pub platform_fee: u128,
+    /// Part of `amount` sent to the course creator.
+    pub creator_amount: u128,
+    /// Affiliate who referred the student, if they earn a commission.
+    pub referrer: Option<Address>,
+    /// Part of `amount` credited to the referrer.
+    pub referral_commission: u128,
+    pub paid_at: u64,
+    pub status: PaymentStatus,
+    /// End of the refund window; the payment is released from then on.
+    pub escrow_until: u64,
+}
+
+/// Price reduction granted by a coupon.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Discount {
+    /// Percentage off the price, from 1 to 100.
+    Percent(u32),
+    /// Amount off the price, down to 0.
+    Fixed(u128),
+}
+
+/// A discount code minted by a course creator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Coupon {
+    pub course_id: u128,
+    pub discount: Discount,
+    pub remaining_uses: u32,
+    pub expires_at: u64,
+}
+
+/// A single-use invite code to enroll in a course for free or at a discount.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Invite {
+    pub course_id: u128,
+    /// `Discount::Percent(100)` for a free enrollment.
+    pub discount: Discount,
+    pub expires_at: u64,
+    /// Student who used the code, once it is used.
+    pub used_by: Option<Address>,
+}
+
+/// The message a course's voucher signer signs off-chain, as XDR.
+///
+/// The recipient redeems it once with `redeem_voucher`; the nonce is unique
+/// per course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voucher {
+    /// The registry the voucher is for, so that it cannot be redeemed on
+    /// another deployment.
+    pub contract: Address,
+    pub course_id: u128,
+    pub recipient: Address,
+    pub expires_at: u64,
+    pub nonce: u64,
+}
+
+/// A time-limited sale price for a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sale {
+    pub price: u128,
+    /// Ledger timestamp the sale starts at.
+    pub starts_at: u64,
+    /// Ledger timestamp the sale ends at, exclusive.
+    pub ends_at: u64,
+}
+
+/// Several courses sold together at one price.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bundle {
+    pub id: u128,
+    pub creator: Address,
+    pub course_ids: Vec<u128>,
+    pub price: u128,
+}
+
+/// An admin-defined subscription plan.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionPlan {
+    pub id: u128,
+    pub name: String,
+    /// Price charged per period.
+    pub price: u128,
+    /// Length of one period in seconds.
+    pub period: u64,
+    /// Courses covered by the plan; empty for the whole catalog.
+    pub course_ids: Vec<u128>,
+    /// Whether the plan accepts new subscriptions and renewals.
+    pub active: bool,
+}
+
+/// A subscriber's access to a plan.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Subscription {
+    pub plan_id: u128,
+    pub subscriber: Address,
+    /// Start of the current uninterrupted run of periods.
+    pub started_at: u64,
+    pub expires_at: u64,
+}
+
+/// Terms on which a course can be paid in installments.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstallmentPlan {
+    /// Number of installments, from 2 to `MAX_INSTALLMENTS`.
+    pub count: u32,
+    /// Seconds between two installments.
+    pub interval: u64,
+    /// Seconds an installment may be late before access is suspended.
+    pub grace_period: u64,
+}
+
+/// A student's progress through paying a course in installments.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstallmentSchedule {
+    pub course_id: u128,
+    pub student: Address,
+    pub token: Address,
+    /// Price being paid off.
+    pub total: u128,
+    pub paid: u128,
+    pub count: u32,
+    pub paid_count: u32,
+    pub interval: u64,
+    pub grace_period: u64,
+    pub started_at: u64,
+    /// When the next installment is due; `None` once everything is paid.
+    pub next_due_at: Option<u64>,
+}
+
+/// Tips received for a course in one token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TipTotals {
+    pub count: u32,
+    pub amount: u128,
+}
+
+/// A former price of a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceChange {
+    pub price: u128,
+    /// When the price was replaced.
+    pub changed_at: u64,
+}
+
+/// Discount on seats bought in bulk by organizations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeDiscount {
+    /// Fewest seats bought at once that get the discount.
+    pub min_seats: u32,
+    /// Percentage off the total, from 1 to 99.
+    pub percent: u32,
+}
+
+/// Seats of a course bought by an organization for its members.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatPool {
+    pub course_id: u128,
+    pub org: Address,
+    /// Token every purchase of the seats is paid in.
+    pub token: Address,
+    pub seats: u32,
+    /// Seats already given to members.
+    pub assigned: u32,
+    /// Total paid for all seats.
+    pub amount_paid: u128,
+}
+
+/// A paid course gift waiting for its recipient to claim it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingGift {
+    pub course_id: u128,
+    pub payer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    /// Amount paid, held by the contract until the gift is claimed or reclaimed.
+    pub amount: u128,
+    pub created_at: u64,
+    /// The gift can be claimed until then, and reclaimed by the payer after.
+    pub expires_at: u64,
+}
+
+/// What a scholarship pool pays for.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScholarshipTarget {
+    Course(u128),
+    /// Every course filed under the category.
+    Category(String),
+}
+
+/// Sponsor funds earmarked for enrolling allow-listed students.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScholarshipPool {
+    pub id: u128,
+    pub target: ScholarshipTarget,
+    pub token: Address,
+    /// Funds not awarded yet.
+    pub balance: u128,
+    pub total_contributed: u128,
+    pub total_awarded: u128,
+    /// Number of enrollments paid by the pool.
+    pub scholarships: u32,
+}
+
+/// Where the funds of a payment are.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaymentStatus {
+    /// Held by the contract until the refund window ends.
+    Escrowed,
+    /// Split between the treasury and the creator's earnings.
+    Released,
+    /// Returned to the payer.
+    Refunded,
+}
+
+/// Where a refund request made after the refund window stands.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RefundRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    /// The payment was refunded another way before a decision.
+    Closed,
+}
+
+/// A student's request for a refund after the refund window.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefundRequest {
+    pub course_id: u128,
+    pub student: Address,
+    pub reason: String,
+    pub requested_at: u64,
+    pub status: RefundRequestStatus,
+    /// Why the instructor rejected the request.
+    pub rejection_reason: Option<String>,
+    pub decided_at: Option<u64>,
+}
+
+/// Platform fee taken from every course payment.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlatformFee {
+    /// Fee rate in basis points (1/100 of a percent).
+    pub fee_bps: u32,
+    pub treasury: Address,
+}
+
+/// Platform revenue held in one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryAccount {
+    /// Platform fees and subscription payments ever received.
+    pub income: u128,
+    /// Amount sent to the treasury address so far.
+    pub withdrawn: u128,
+}
+
+/// How a student came to be enrolled in a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnrollmentSource {
+    /// Enrolled and paid the price, or nothing for a free course.
+    Purchase,
+    /// Paying the price in installments.
+    Installments,
+    /// Enrolled as part of a bundle purchase.
+    Bundle,
+    /// Gifted the course by another address.
+    Gift,
+    /// Given a seat bought by an organization.
+    Seat,
+    /// Funded from a scholarship pool.
+    Scholarship,
+    /// Given access for free by the course creator.
+    Granted,
+    /// Enrolled with an invite code from the course creator.
+    Invite,
+    /// Redeemed a voucher signed by the course's voucher signer.
+    Voucher,
+    /// Claimed with a Merkle proof of eligibility for the course's airdrop.
+    Airdrop,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnrollmentStatus {
+    Active,
+    /// Access was taken away by the course creator or an admin.
+    Revoked,
+    /// The student left the course.
+    Withdrawn,
+}
+
+/// A change to a student's access, as recorded in the course access log.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessChange {
+    Granted,
+    Revoked,
+    /// The enrollment was moved to the given address.
+    Transferred(Address),
+    /// Time-limited access ran out.
+    Expired,
+    /// The student left the course.
+    Withdrawn,
+}
+
+/// A step in the lifecycle of an enrollment, published as an event with the
+/// course ID and the student.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnrollmentTransition {
+    Created(EnrollmentSource),
+    /// Token and amount paid, for the enrollment, an installment or a renewal.
+    Paid(Address, u128),
+    Granted,
+    Revoked,
+    Expired,
+    /// The enrollment was moved to the given address.
+    Transferred(Address),
+    /// Amount returned to the payer.
+    Refunded(u128),
+    Withdrawn,
+}
+
+/// An entry of the append-only access log of a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessLogEntry {
+    pub student: Address,
+    pub change: AccessChange,
+    /// Who made the change; `None` for expiries.
+    pub actor: Option<Address>,
+    pub reason: Option<String>,
+    pub at: u64,
+}
+
+/// A student's enrollment in a course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Enrollment {
+    pub course_id: u128,
+    pub student: Address,
+    pub enrolled_at: u64,
+    /// Amount charged on enrollment; 0 if nothing was paid.
+    pub amount_paid: u128,
+    pub source: EnrollmentSource,
+    pub status: EnrollmentStatus,
+    /// End of access for courses sold for a limited time; see `AccessTerms`.
+    pub expires_at: Option<u64>,
+}
+
+/// An on-chain proof of enrollment that wallets can display.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Receipt {
+    pub id: u128,
+    pub course_id: u128,
+    /// Student the receipt was issued to.
+    pub student: Address,
+    /// Current holder; differs from `student` once the receipt is transferred.
+    pub owner: Address,
+    pub amount_paid: u128,
+    pub source: EnrollmentSource,
+    pub issued_at: u64,
+}
+
+/// How long an enrollment gives access to a course, and what extending it
+/// costs.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessTerms {
+    /// Seconds of access from enrollment, and added by each renewal.
+    pub duration: u64,
+    /// Price of a renewal in the payment token; 0 for free renewals.
+    pub renewal_price: u128,
+}
+
+/// What a student must hold to enroll in a token-gated course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenGate {
+    /// At least this balance of a token contract.
+    Balance(Address, i128),
+    /// A specific token ID of an NFT contract exposing `owner_of`.
+    Nft(Address, u32),
+}
+
+/// What a student must have for each prerequisite to enroll in a course.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PrerequisiteCheck {
+    /// Every module of the prerequisite completed in this contract.
+    Completion,
+    /// A certificate for the prerequisite from a certificate contract
+    /// exposing `has_certificate(student, course_id) -> bool`.
+    Certificate(Address),
+}
+
+/// A group of students taking a course together over a fixed window.
+///
+/// Students enrolled in a cohort can only study the course between
+/// `starts_at` and `ends_at`; releases relative to enrollment count from
+/// `starts_at`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cohort {
+    /// Index of the cohort within its course, from 0.
+    pub id: u32,
+    pub course_id: u128,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub capacity: u32,
+    pub enrolled: u32,
+}
+
+/// A student's place on the waitlist of a full course.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaitlistEntry {
+    pub course_id: u128,
+    pub student: Address,
+    pub joined_at: u64,
+    /// Course price when the student joined, charged when they claim a seat.
+    pub price: u128,
+    /// Until when the student may claim the seat offered to them, if any.
+    pub offer_expires_at: Option<u64>,
+}
+
+/// One line of a student's course list.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnrollmentSummary {
+    pub course_id: u128,
+    pub status: EnrollmentStatus,
+    pub source: EnrollmentSource,
+    pub enrolled_at: u64,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct CourseGoal {
     pub goal_id: String,
-    pub course_id: String,
+    pub course_id: u128,
     pub content: String,
     pub created_by: Address,
     pub created_at: u64,
 }
 
+/// Kind of content a lesson delivers.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LessonContentType {
+    Video,
+    Text,
+    Quiz,
+    Assignment,
+}
+
+/// A lesson inside a course module.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lesson {
+    pub id: u128,
+    pub module_id: String,
+    pub course_id: u128,
+    pub title: String,
+    pub content_type: LessonContentType,
+    pub content_uri: Option<String>,
+    /// SHA-256 of the off-chain content, to verify what students receive.
+    pub content_hash: Option<BytesN<32>>,
+    pub duration_minutes: u32,
+    pub created_at: u64,
+    pub updated_at: u64,
+    /// Downloadable materials, at most `MAX_LESSON_RESOURCES`.
+    pub resources: Vec<ResourceLink>,
+}
+
+/// A downloadable material attached to a lesson.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceLink {
+    pub label: String,
+    pub uri: String,
+    /// SHA-256 of the file behind `uri`.
+    pub hash: Option<BytesN<32>>,
+}
+
+/// Fields of a lesson that `edit_lesson` can change; `None` leaves a field as is.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditLessonParams {
+    pub new_title: Option<String>,
+    pub new_content_uri: Option<Option<String>>,
+    pub new_duration_minutes: Option<u32>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct CourseCategory {
     pub id: u128,
     pub name: String,
     pub description: Option<String>,
+    pub active: bool,
 }
 
 #[contracttype]
@@ -43,17 +762,293 @@ pub struct CourseCategory {
 pub enum DataKey {
     Module(String),
     Courses,
-    CourseGoalList(String),     // Optional: Keep a list of goal IDs per course
-    CourseGoal(String, String), // (course_id, goal_id)
-    CoursePrerequisites(String),
+    CourseGoalList(u128),     // Optional: Keep a list of goal IDs per course
+    CourseGoal(u128, String), // (course_id, goal_id)
+    CoursePrerequisites(u128),
     CategorySeq,          // Sequence counter for category IDs
     CourseCategory(u128), // Course category by ID
     Admins,               // List of admin addresses
+    CourseModules(u128),  // Module IDs per course, in insertion order
+    CreatorStats(Address), // Course counters per creator
+    CreationWindow(Address), // Rate limit window per creator
+    CoursesByLevel(CourseLevel), // Course IDs per difficulty level
+    RelatedCourses(u128), // Curated related course IDs per course
+    VariantGroup(BytesN<32>), // Course IDs of the language variants in a group
+    LessonSeq,            // Sequence counter for lesson IDs
+    Lesson(u128),         // Lesson by ID
+    ModuleLessons(String), // Lesson IDs per module, in display order
+    Enrollment(u128, Address), // (course_id, student)
+    SequentialUnlock(u128), // Whether modules must be completed in order
+    ModuleCompleted(String, Address), // (module_id, student) -> completion timestamp
+    ModuleVersion(String, u32), // Superseded module snapshots: (module_id, version)
+    Quiz(String),         // Quiz attached to a module
+    QuizQuestion(String, u32), // (module_id, question_id)
+    Assignment(String),   // Assignment attached to a module
+    AssignmentSubmissions(String, Address), // (module_id, student)
+    LiveSession(String),  // Live session schedule per module
+    LiveSessionJoin(String), // Join URI hash per module
+    ModuleOverrides(String), // Per-address access overrides per module
+    CategoryByName(String), // Normalized category name -> category ID
+    CourseTags(u128),     // Normalized tags per course
+    TagCourses(String),   // Course IDs per normalized tag, in tagging order
+    SkillSeq,             // Sequence counter for skill IDs
+    Skill(u128),          // Skill by ID
+    SkillByName(String),  // Normalized skill name -> skill ID
+    CourseSkills(u128),   // Skills taught by a course
+    CategoryCourses(CategoryKey), // Course IDs per category, in filing order
+    LanguageCourses(String), // Course IDs per normalized language code
+    PriceBucket(u32),     // Course IDs per price bucket (bit length of the price)
+    EnrollmentCount(u128), // Number of enrollments per course
+    EnrollmentRanking,    // Most-enrolled course IDs, highest count first
+    TitlePrefix(String),  // Course IDs per normalized title prefix
+    FeaturedCourses,      // Admin-curated course IDs, in display order
+    DailyEnrollments(u64), // Enrollments per course on one day (timestamp / 86400)
+    Instructors,          // Every address that created a course, in order
+    CourseRating(u128),   // Rating totals per course
+    StudentRating(u128, Address), // (course_id, student) -> rating from 1 to 5
+    CategoryTotals(CategoryKey), // Running totals per category
+    BlockedTitleTerms,    // Admin-managed words and phrases banned from titles
+    ReservedTitlePrefixes, // Title prefixes only official creators may use
+    OfficialCreator(Address), // Creators allowed to use reserved title prefixes
+}
+
+/// Storage keys of the enrollment indexes.
+///
+/// Enrollments themselves are stored under `DataKey::Enrollment`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnrollmentKey {
+    StudentCourses(Address), // IDs of the courses a student is enrolled in
+    CourseStudents(u128),    // Students enrolled in a course, in enrollment order
+    PublicRoster(u128),      // Set when anyone may list a course's students
+    ActiveStudents(u128),    // Number of students with active access to a course
+    ReservedSeats(u128),     // Seats organizations bought and have not assigned yet
+    Deadline(u128),          // Time after which a course takes no new students
+    Cohorts(u128),           // Cohorts of a course, by cohort ID
+    StudentCohort(u128, Address), // (course_id, student) -> cohort ID
+    Waitlist(u128),          // Students waiting for a seat, first come first served
+    WaitlistOffers(u128),    // Waitlisted students holding a seat offer
+    WaitlistEntry(u128, Address), // (course_id, student) -> WaitlistEntry
+    AccessTerms(u128),       // Access duration and renewal price of a course
+    TransfersDisabled(u128), // Set when enrollments in a course cannot change hands
+    AllowlistOnly(u128),     // Set when only allowlisted students may enroll in a course
+    Allowlisted(u128, Address), // (course_id, student) allowed to enroll
+    TokenGate(u128),         // What students must hold to enroll in a course
+    PrerequisiteCheck(u128), // How prerequisites of a course are checked at enrollment
+    AccessLogCount(u128),    // Number of entries in the access log of a course
+    AccessLog(u128, u32),    // (course_id, index) -> AccessLogEntry
+    Banned(u128, Address),   // (course_id, student) -> reason for the ban
+    ReceiptSeq,              // Sequence counter for enrollment receipt IDs
+    Receipt(u128),           // Enrollment receipt by ID
+    EnrollmentReceipt(u128, Address), // (course_id, student) -> receipt ID
+    OwnerReceipts(Address),  // IDs of the receipts an address holds
+    ReceiptsTransferable(u128), // Set when receipts of a course may change hands
+    Invite(u128, BytesN<32>), // (course_id, code hash) -> Invite
+    StudentInvite(u128, Address), // (course_id, student) -> hash of the invite code used
+    VoucherSigner(u128),     // Ed25519 public key that signs vouchers for a course
+    VoucherNonce(u128, u64), // (course_id, nonce) -> recipient who redeemed the voucher
+    AirdropRoot(u128),       // Merkle root of the addresses eligible for a course's airdrop
+    AirdropClaimed(u128, Address), // (course_id, student) set once the airdrop is claimed
+}
+
+/// Storage keys of course payments.
+///
+/// Kept apart from `DataKey`, which has reached the 50-case limit of a
+/// contract type union.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaymentKey {
+    PaymentToken,              // Token accepted for course payments
+    Payment(u128, Address),    // (course_id, student) -> Payment
+    PlatformFee,               // Fee rate and treasury address
+    Earnings(Address, Address), // (instructor, token) -> unwithdrawn earnings
+    CourseEarnings(u128),      // Lifetime earnings credited per course
+    RefundWindow,              // Seconds a payment stays in escrow
+    PayWhatYouWant(u128),      // Courses whose price is a minimum
+    Coupon(u128, BytesN<32>),  // (course_id, sha256 of the code) -> Coupon
+    Sale(u128),                // Time-limited sale price per course
+    BundleSeq,                 // Sequence counter for bundle IDs
+    Bundle(u128),              // Bundle by ID
+    PlanSeq,                   // Sequence counter for subscription plan IDs
+    Plan(u128),                // Subscription plan by ID
+    Subscription(Address, u128), // (subscriber, plan_id) -> Subscription
+    SubscriberPlans(Address),  // Plan IDs a subscriber ever subscribed to
+    InstallmentPlan(u128),     // Installment terms offered per course
+    Installments(u128, Address), // (course_id, student) -> InstallmentSchedule
+    PriceList(u128),           // Course prices in tokens other than the payment token
+    PriceOracle,               // Oracle converting USD prices to token amounts
+    UsdPrice(u128),            // Course prices in US cents
+    CourseTips(u128, Address), // (course_id, token) -> TipTotals
+    Affiliate(u128, Address),  // (course_id, affiliate) -> commission in basis points
+    AffiliateEarnings(Address, Address), // (affiliate, token) -> lifetime commissions
+    PoolSeq,                   // Sequence counter for scholarship pool IDs
+    ScholarshipPool(u128),     // Scholarship pool by ID
+    SponsorContribution(u128, Address), // (pool_id, sponsor) -> total deposited
+    ScholarshipEligible(u128, Address), // (pool_id, student) allow-listed for a scholarship
+    PendingGift(u128, Address), // (course_id, recipient) gift waiting to be claimed
+    VolumeDiscount(u128),      // Seat volume discount by course ID
+    SeatPool(u128, Address),   // (course_id, org) -> seats bought by an organization
+    PriceHistory(u128),        // Former prices of a course
+    RefundRequest(u128, Address), // (course_id, student) refund requested after the window
+    UnpublishedAt(u128),       // When a published course was last unpublished or archived
+    Treasury(Address),         // Platform revenue by token
+    TreasuryTokens,            // Tokens the treasury has received revenue in
+    RevenueSplit(u128),        // Co-instructor shares of a course's revenue in basis points
 }
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Course {
+    pub id: u128,
+    pub title: String,
+    pub description: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub status: CourseStatus,
+    pub prerequisites: Vec<u128>,
+    pub level: CourseLevel,
+    pub duration_hours: Option<u32>,
+    pub version: u32,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub slug: Option<String>,
+    pub visibility: CourseVisibility,
+    pub metadata_uri: Option<String>,
+    pub metadata_hash: Option<BytesN<32>>,
+    pub lesson_count: u32,
+    pub total_duration_minutes: u32,
+    pub variant_group: Option<BytesN<32>>,
+    /// Free courses have a price of 0 and enroll without payment.
+    pub is_free: bool,
+    /// Most students with active access at once; `None` for no limit.
+    pub max_students: Option<u32>,
+    /// Seats left under `max_students`; `None` when there is no limit.
+    pub seats_remaining: Option<u32>,
+}
+
+/// Size and length of a course, as returned by `get_course_stats`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseStats {
+    pub module_count: u32,
+    pub lesson_count: u32,
+    pub total_duration_minutes: u32,
+}
+
+/// Course counters kept per creator.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CreatorStats {
+    /// Courses currently owned by the creator (deleted courses excluded).
+    pub course_count: u32,
+    /// Courses ever created by the creator, including deleted ones.
+    pub total_created: u32,
+    /// Enrollments across all of the creator's courses.
+    pub enrollment_count: u32,
+    /// Sum of the ratings given to the creator's courses.
+    pub rating_total: u64,
+    /// Number of ratings given to the creator's courses.
+    pub rating_count: u32,
+}
+
+/// Ratings given to a course by its students.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CourseRating {
+    /// Sum of the ratings, each from 1 to 5.
+    pub total: u64,
+    pub count: u32,
+}
+
+/// Running totals of the courses filed under a category.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CategoryTotals {
+    pub course_count: u32,
+    pub enrollment_count: u32,
+    pub price_total: u128,
+}
+
+/// Statistics of a category as returned by `get_category_stats`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoryStats {
+    pub course_count: u32,
+    pub enrollment_count: u32,
+    /// Mean course price, rounded down; 0 if the category has no courses.
+    pub average_price: u128,
+}
+
+/// Compact view of a course, as returned by `export_courses`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseSummary {
+    pub id: u128,
+    pub title: String,
+    pub creator: Address,
+    pub price: u128,
+    pub category: Option<String>,
+    pub status: CourseStatus,
+    pub visibility: CourseVisibility,
+    pub version: u32,
+    pub updated_at: u64,
+}
+
+/// One page of `export_courses`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseExportPage {
+    pub courses: Vec<CourseSummary>,
+    /// Cursor to pass to the next call, or `None` once the catalog is exhausted.
+    pub next_cursor: Option<u128>,
+}
+
+/// An instructor as ranked by `get_top_instructors`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstructorSummary {
+    pub instructor: Address,
+    pub course_count: u32,
+    pub enrollment_count: u32,
+    /// Average rating in hundredths of a star (e.g. 450 = 4.5); 0 if unrated.
+    pub average_rating: u32,
+}
+
+/// Lifecycle state of a course.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CourseStatus {
+    /// Being prepared by its creator; not listed or open for enrollment.
+    Draft,
+    /// Live: listed in the catalog and open for enrollment.
+    Published,
+    /// Retired by its creator; read-only and its title can be reused.
+    Archived,
+    /// Taken down by an admin; hidden until an admin reinstates it.
+    Suspended,
+}
+
+/// Who can discover and read a course.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CourseVisibility {
+    /// Listed in catalog queries and readable by anyone.
+    Public,
+    /// Hidden from catalog queries but readable by ID, slug or hash.
+    Unlisted,
+    /// Hidden from catalog queries; metadata is only readable by the creator and admins.
+    Private,
+}
+
+/// Course record as stored before course IDs moved from `String` to `u128`.
+///
+/// Only used to decode legacy entries during `migrate_course`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyCourse {
     pub id: String,
     pub title: String,
     pub description: String,
@@ -65,7 +1060,7 @@ pub struct Course {
     pub published: bool,
     pub prerequisites: Vec<CourseId>,
     pub is_archived: bool,
-    pub level: Option<CourseLevel>,
+    pub level: Option<String>, // "Beginner", "Intermediate" or "Advanced"
     pub duration_hours: Option<u32>,
 }
 
@@ -79,13 +1074,62 @@ pub struct CourseId {
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Category {
+    pub id: u128,
     pub name: String,
     pub count: u128,
 }
 
-// Course level as string to avoid Soroban enum serialization issues
-// Valid values: "Beginner", "Intermediate", "Advanced"
-pub type CourseLevel = String;
+/// Orderings offered by `list_courses_sorted`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortKey {
+    Newest,
+    MostEnrolled,
+}
+
+/// Key of the per-category course index.
+///
+/// Registered categories are indexed by ID so renaming one keeps its courses;
+/// free-text categories from before the registry existed are indexed by
+/// normalized name.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CategoryKey {
+    Registered(u128),
+    Unregistered(String),
+}
+
+/// A canonical skill of the shared taxonomy, optionally nested under a
+/// broader parent skill.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Skill {
+    pub id: u128,
+    pub name: String,
+    pub parent_id: Option<u128>,
+}
+
+/// A skill a course teaches, and the level it teaches it to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseSkill {
+    pub skill_id: u128,
+    pub level: CourseLevel,
+}
+
+/// Difficulty level of a course.
+///
+/// Contract types hold `Unspecified` rather than `Option<CourseLevel>`, which
+/// the SDK cannot convert to XDR inside a struct.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CourseLevel {
+    /// No level set; in `CourseFilters`, matches every course.
+    Unspecified,
+    Beginner,
+    Intermediate,
+    Advanced,
+}
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -93,11 +1137,29 @@ pub struct CourseFilters {
     pub min_price: Option<u128>,
     pub max_price: Option<u128>,
     pub category: Option<String>,
-    pub level: Option<CourseLevel>,
+    pub level: CourseLevel,
     pub min_duration: Option<u32>,
     pub max_duration: Option<u32>,
 }
 
+/// A single entry of a `create_courses` batch.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CourseInput {
+    pub title: String,
+    pub description: String,
+    pub price: u128,
+    pub category: Option<String>,
+    pub language: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub level: CourseLevel,
+    pub duration_hours: Option<u32>,
+    pub metadata_uri: Option<String>,
+    pub metadata_hash: Option<BytesN<32>>,
+    /// Marks the course as free; `price` must then be 0.
+    pub is_free: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct EditCourseParams {
@@ -105,9 +1167,48 @@ pub struct EditCourseParams {
     pub new_description: Option<String>,
     pub new_price: Option<u128>,
     pub new_category: Option<Option<String>>,
+    /// `Some(Some(""))` clears the language. `Some(None)` encodes the same
+    /// as `None`, so it leaves the language unchanged.
     pub new_language: Option<Option<String>>,
     pub new_thumbnail_url: Option<Option<String>>,
     pub new_published: Option<bool>,
-    pub new_level: Option<Option<CourseLevel>>,
     pub new_duration_hours: Option<Option<u32>>,
+    /// `Some(true)` makes the course free and sets its price to 0;
+    /// `Some(false)` requires a non-zero `new_price`.
+    pub new_is_free: Option<bool>,
+}
+
+/// Maximum byte lengths accepted for course text fields.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContentLimits {
+    pub max_title_len: u32,
+    pub max_description_len: u32,
+    pub max_thumbnail_url_len: u32,
+}
+
+/// Range of prices allowed for paid courses.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceLimits {
+    pub min_price: u128,
+    pub max_price: u128,
+}
+
+/// Course creation rate limit applied per creator address.
+///
+/// A `max_courses` of zero disables the limit.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimit {
+    pub max_courses: u32,
+    pub window_seconds: u64,
+}
+
+/// Courses created by an address in its current rate limit window.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreationWindow {
+    pub window_start: u64,
+    pub count: u32,
 }
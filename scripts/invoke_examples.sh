@@ -91,14 +91,14 @@ else
 fi
 
 echo "--------------------------------------------------------------------------------------------------------------------------------------"
-# Get course with course_id="1"
+# Get course with course_id=1
 echo "Fetching course with course_id=1..."
 get_course_output=$(stellar contract invoke \
   --id "$course_registry_id" \
   --source-account default \
   --network local \
   -- get_course \
-  --course_id '{"string": "1"}' )
+  --course_id 1 )
 if [ $? -eq 0 ]; then
   echo "Successfully fetched course with course_id=1."
   echo "$get_course_output"
@@ -107,14 +107,14 @@ else
 fi
 
 echo "--------------------------------------------------------------------------------------------------------------------------------------"
-# Get course with course_id="1"
+# Get course with course_id=1
 echo "Fetching course with course_id=1..."
 get_course_output=$(stellar contract invoke \
   --id "$course_registry_id" \
   --source-account default \
   --network local \
   -- get_course \
-  --course_id '{"string": "1"}' )
+  --course_id 1 )
 if [ $? -eq 0 ]; then
   echo "Successfully fetched course with course_id=1."
   echo "$get_course_output"